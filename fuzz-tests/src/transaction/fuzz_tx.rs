@@ -820,6 +820,10 @@ impl TxFuzzer {
                     Some(InstructionV1::DropAuthZoneRegularProofs)
                 }
                 InstructionDiscriminants::DropNamedProofs => Some(InstructionV1::DropNamedProofs),
+                // Subintent instructions aren't valid in a V1 transaction
+                InstructionDiscriminants::YieldToChild | InstructionDiscriminants::YieldToParent => {
+                    None
+                }
             };
             if let Some(instruction) = instruction {
                 let (
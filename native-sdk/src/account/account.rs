@@ -1,8 +1,12 @@
 use radix_engine_interface::api::ClientObjectApi;
-use radix_engine_interface::blueprints::account::{AccountDepositInput, ACCOUNT_DEPOSIT_IDENT};
+use radix_engine_interface::blueprints::account::{
+    AccountBalanceOfInput, AccountBalanceOfOutput, AccountDepositInput, AccountGetVaultsInput,
+    AccountGetVaultsOutput, ACCOUNT_BALANCE_OF_IDENT, ACCOUNT_DEPOSIT_IDENT,
+    ACCOUNT_GET_VAULTS_IDENT,
+};
 use radix_engine_interface::blueprints::resource::Bucket;
-use radix_engine_interface::data::scrypto::{scrypto_encode, ScryptoDecode};
-use radix_engine_interface::types::ComponentAddress;
+use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode, ScryptoDecode};
+use radix_engine_interface::types::{ComponentAddress, ResourceAddress};
 use sbor::rust::fmt::Debug;
 
 #[derive(Debug)]
@@ -21,4 +25,39 @@ impl Account {
 
         Ok(())
     }
+
+    pub fn get_vaults<Y, E: Debug + ScryptoDecode>(
+        &self,
+        cursor: u32,
+        limit: u32,
+        api: &mut Y,
+    ) -> Result<AccountGetVaultsOutput, E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            ACCOUNT_GET_VAULTS_IDENT,
+            scrypto_encode(&AccountGetVaultsInput { cursor, limit }).unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    pub fn balance_of<Y, E: Debug + ScryptoDecode>(
+        &self,
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<AccountBalanceOfOutput, E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            ACCOUNT_BALANCE_OF_IDENT,
+            scrypto_encode(&AccountBalanceOfInput { resource_address }).unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
 }
@@ -92,6 +92,90 @@ impl ResourceManager {
         Ok((ResourceManager(resource_address), bucket))
     }
 
+    pub fn new_fungible_with_withdraw_limit<Y, E: Debug + ScryptoDecode, M: Into<MetadataInit>>(
+        owner_role: OwnerRole,
+        track_total_supply: bool,
+        divisibility: u8,
+        resource_roles: FungibleResourceRoles,
+        withdraw_limit: FungibleWithdrawLimit,
+        metadata: M,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<Self, E>
+    where
+        Y: ClientBlueprintApi<E>,
+    {
+        let metadata = ModuleConfig {
+            init: metadata.into(),
+            roles: RoleAssignmentInit::default(),
+        };
+
+        let result = api.call_function(
+            RESOURCE_PACKAGE,
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT,
+            scrypto_encode(&FungibleResourceManagerCreateWithWithdrawLimitInput {
+                owner_role,
+                track_total_supply,
+                metadata,
+                resource_roles,
+                withdraw_limit,
+                divisibility,
+                address_reservation,
+            })
+            .unwrap(),
+        )?;
+
+        let resource_address = scrypto_decode(result.as_slice()).unwrap();
+        Ok(ResourceManager(resource_address))
+    }
+
+    pub fn new_fungible_with_initial_supply_and_withdraw_limit<
+        Y,
+        E: Debug + ScryptoDecode,
+        M: Into<MetadataInit>,
+    >(
+        owner_role: OwnerRole,
+        track_total_supply: bool,
+        divisibility: u8,
+        initial_supply: Decimal,
+        resource_roles: FungibleResourceRoles,
+        withdraw_limit: FungibleWithdrawLimit,
+        metadata: M,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<(Self, Bucket), E>
+    where
+        Y: ClientBlueprintApi<E>,
+    {
+        let metadata = ModuleConfig {
+            init: metadata.into(),
+            roles: RoleAssignmentInit::default(),
+        };
+
+        let result = api.call_function(
+            RESOURCE_PACKAGE,
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT,
+            scrypto_encode(
+                &FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput {
+                    owner_role,
+                    track_total_supply,
+                    metadata,
+                    resource_roles,
+                    withdraw_limit,
+                    divisibility,
+                    initial_supply,
+                    address_reservation,
+                },
+            )
+            .unwrap(),
+        )?;
+        let (resource_address, bucket): (ResourceAddress, Bucket) =
+            scrypto_decode(result.as_slice()).unwrap();
+        Ok((ResourceManager(resource_address), bucket))
+    }
+
     pub fn new_non_fungible<
         N: NonFungibleData,
         Y,
@@ -220,6 +304,23 @@ impl ResourceManager {
         Ok(data)
     }
 
+    pub fn non_fungible_exists<Y, E: Debug + ScryptoDecode>(
+        &self,
+        id: NonFungibleLocalId,
+        api: &mut Y,
+    ) -> Result<bool, E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerExistsInput { id }).unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn resource_type<Y, E: Debug + ScryptoDecode>(&self, api: &mut Y) -> Result<ResourceType, E>
     where
         Y: ClientObjectApi<E>,
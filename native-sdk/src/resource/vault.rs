@@ -75,6 +75,15 @@ pub trait NativeFungibleVault {
     where
         Y: ClientApi<E>;
 
+    fn lock_royalty_payment<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        api: &mut Y,
+        amount: Decimal,
+        max_per_function: Decimal,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>;
+
     fn create_proof_of_amount<Y, E: Debug + ScryptoDecode>(
         &self,
         amount: Decimal,
@@ -282,6 +291,27 @@ impl NativeFungibleVault for Vault {
         Ok(scrypto_decode(&rtn).unwrap())
     }
 
+    fn lock_royalty_payment<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        api: &mut Y,
+        amount: Decimal,
+        max_per_function: Decimal,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT,
+            scrypto_encode(&FungibleVaultLockRoyaltyPaymentInput {
+                amount,
+                max_per_function,
+            })
+            .unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     fn create_proof_of_amount<Y, E: Debug + ScryptoDecode>(
         &self,
         amount: Decimal,
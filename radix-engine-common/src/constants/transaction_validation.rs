@@ -1,9 +1,13 @@
 pub const TRANSACTION_VERSION_V1: u8 = 1;
 
+pub const TRANSACTION_VERSION_V2: u8 = 2;
+
 pub const MAX_NUMBER_OF_INTENT_SIGNATURES: usize = 16;
 
 pub const MAX_NUMBER_OF_BLOBS: usize = 64;
 
+pub const MAX_NUMBER_OF_SUBINTENTS: usize = 16;
+
 /// The minimum value of tip percentage
 ///
 /// 100 means 100%
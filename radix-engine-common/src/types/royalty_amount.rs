@@ -1,4 +1,5 @@
 use crate::math::*;
+use crate::types::ResourceAddress;
 use crate::ManifestSbor;
 use crate::ScryptoSbor;
 #[cfg(feature = "radix_engine_fuzzing")]
@@ -10,6 +11,8 @@ pub enum RoyaltyAmount {
     Free,
     Xrd(Decimal),
     Usd(Decimal),
+    /// An amount of a fungible resource, paid from royalty payments locked by the caller.
+    Resource(ResourceAddress, Decimal),
 }
 
 impl RoyaltyAmount {
@@ -17,6 +20,7 @@ impl RoyaltyAmount {
         match self {
             RoyaltyAmount::Xrd(x) => x.is_zero(),
             RoyaltyAmount::Usd(x) => x.is_zero(),
+            RoyaltyAmount::Resource(_, x) => x.is_zero(),
             RoyaltyAmount::Free => true,
         }
     }
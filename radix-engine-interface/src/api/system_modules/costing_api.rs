@@ -13,6 +13,16 @@ pub trait ClientCostingApi<E> {
         contingent: bool,
     ) -> Result<LiquidFungibleResource, E>;
 
+    /// Locks a payment for royalties charged in the given resource, returning the part that
+    /// couldn't be locked. No single function or method may charge more than `max_per_function`.
+    fn lock_royalty_payment(
+        &mut self,
+        vault_id: NodeId,
+        resource_address: ResourceAddress,
+        payment: LiquidFungibleResource,
+        max_per_function: Decimal,
+    ) -> Result<LiquidFungibleResource, E>;
+
     fn execution_cost_unit_limit(&mut self) -> Result<u32, E>;
 
     fn execution_cost_unit_price(&mut self) -> Result<Decimal, E>;
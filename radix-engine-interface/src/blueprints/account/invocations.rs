@@ -32,6 +32,27 @@ pub enum DefaultDepositRule {
     AllowExisting,
 }
 
+/// A composable rule that third-party deposits must satisfy in addition to the default deposit rule.
+/// Resources on the allow list and deposits made with an authorized depositor badge are exempt.
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, ScryptoSbor, ManifestSbor, Clone, Hash)]
+pub enum DepositRule {
+    /// The resource's `tags` metadata contains the given tag.
+    ResourceHasTag(String),
+
+    /// The deposit is made by one of the given global components.
+    SenderIsOneOf(BTreeSet<GlobalAddress>),
+
+    /// At most the given number of resources which the account holds no vault for may be deposited
+    /// per epoch.
+    NewResourcesPerEpoch(u32),
+
+    /// All of the given rules must be satisfied.
+    AllOf(Vec<DepositRule>),
+
+    /// At least one of the given rules must be satisfied.
+    AnyOf(Vec<DepositRule>),
+}
+
 pub const ACCOUNT_BLUEPRINT: &str = "Account";
 
 //=============
@@ -105,6 +126,21 @@ pub struct AccountLockContingentFeeInput {
 
 pub type AccountLockContingentFeeOutput = ();
 
+//==============================
+// Account Lock Royalty Payment
+//==============================
+
+pub const ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT: &str = "lock_royalty_payment";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockRoyaltyPaymentInput {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub max_per_function: Decimal,
+}
+
+pub type AccountLockRoyaltyPaymentOutput = ();
+
 //=================
 // Account Deposit
 //=================
@@ -241,6 +277,19 @@ pub struct AccountSetDefaultDepositRuleInput {
 
 pub type AccountSetDefaultDepositRuleOutput = ();
 
+//==================
+// Set Deposit Rule
+//==================
+
+pub const ACCOUNT_SET_DEPOSIT_RULE_IDENT: &str = "set_deposit_rule";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountSetDepositRuleInput {
+    pub deposit_rule: Option<DepositRule>,
+}
+
+pub type AccountSetDepositRuleOutput = ();
+
 //=========================
 // Set Resource Preference
 //=========================
@@ -401,3 +450,43 @@ pub struct AccountRemoveAuthorizedDepositorInput {
 }
 
 pub type AccountRemoveAuthorizedDepositorOutput = ();
+
+//====================
+// Account Get Vaults
+//====================
+
+pub const ACCOUNT_GET_VAULTS_IDENT: &str = "get_vaults";
+
+/// The largest number of vaults that a single `get_vaults` call returns.
+pub const ACCOUNT_GET_VAULTS_MAX_LIMIT: u32 = 100;
+
+/// Lists the resources the account holds vaults for in the order the vaults were created, starting
+/// at the `cursor`-th vault and returning at most `limit` of them. A `limit` above
+/// [`ACCOUNT_GET_VAULTS_MAX_LIMIT`] is lowered to it.
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountGetVaultsInput {
+    pub cursor: u32,
+    pub limit: u32,
+}
+
+pub type AccountGetVaultsOutput = AccountVaultsPage;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountVaultsPage {
+    pub resource_addresses: Vec<ResourceAddress>,
+    /// The cursor of the next page, or [`None`] if this is the last page.
+    pub next_cursor: Option<u32>,
+}
+
+//====================
+// Account Balance Of
+//====================
+
+pub const ACCOUNT_BALANCE_OF_IDENT: &str = "balance_of";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountBalanceOfInput {
+    pub resource_address: ResourceAddress,
+}
+
+pub type AccountBalanceOfOutput = Decimal;
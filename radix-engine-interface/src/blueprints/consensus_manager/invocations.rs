@@ -1,6 +1,7 @@
 use crate::blueprints::resource::*;
 use crate::*;
 use radix_engine_common::data::manifest::model::ManifestAddressReservation;
+use radix_engine_common::data::scrypto::model::NonFungibleLocalId;
 use radix_engine_common::prelude::ManifestBucket;
 use radix_engine_common::time::{Instant, TimeComparisonOperator};
 use radix_engine_common::types::*;
@@ -373,6 +374,18 @@ pub struct ValidatorUnstakeInput {
 
 pub type ValidatorUnstakeOutput = Bucket;
 
+pub const VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT: &str = "unstake_for_claimant";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct ValidatorUnstakeForClaimantInput {
+    pub stake_unit_bucket: Bucket,
+    /// The address of the component (usually an account) which will claim the unstaked XRD - the
+    /// returned claim NFT is listed under it by [`VALIDATOR_GET_PENDING_UNSTAKES_IDENT`].
+    pub claimant: ComponentAddress,
+}
+
+pub type ValidatorUnstakeForClaimantOutput = Bucket;
+
 pub const VALIDATOR_CLAIM_XRD_IDENT: &str = "claim_xrd";
 
 #[derive(Debug, Eq, PartialEq, ScryptoSbor)]
@@ -418,6 +431,58 @@ pub struct ValidatorAcceptsDelegatedStakeInput {}
 
 pub type ValidatorAcceptsDelegatedStakeOutput = bool;
 
+pub const VALIDATOR_UPDATE_MAX_STAKE_IDENT: &str = "update_max_stake";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorUpdateMaxStakeInput {
+    /// The total XRD stake above which delegated stake is rejected, or `None` for no limit.
+    /// Must not be negative.
+    pub max_stake_xrd: Option<Decimal>,
+}
+
+pub type ValidatorUpdateMaxStakeOutput = ();
+
+pub const VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT: &str = "update_commission_tiers";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorCommissionTier {
+    /// The total XRD stake from which this tier applies.
+    pub min_stake_xrd: Decimal,
+    /// A fraction of the effective emission amount which gets transferred to the validator's owner
+    /// while this tier applies. Must be within `[0.0, 1.0]`.
+    pub fee_factor: Decimal,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorUpdateCommissionTiersInput {
+    /// The new commission tiers, sorted strictly ascending by `min_stake_xrd`.
+    /// An empty list removes all tiers, leaving only the validator's base fee factor.
+    pub tiers: Vec<ValidatorCommissionTier>,
+}
+
+pub type ValidatorUpdateCommissionTiersOutput = ();
+
+pub const VALIDATOR_GET_PENDING_UNSTAKES_IDENT: &str = "get_pending_unstakes";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ValidatorGetPendingUnstakesInput {
+    /// The claimant given to [`VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT`].
+    pub claimant: ComponentAddress,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ValidatorPendingUnstake {
+    pub claim_nft_id: NonFungibleLocalId,
+    /// An epoch number at (or after) which the XRD may be claimed.
+    pub claim_epoch: Epoch,
+    /// An XRD amount to be claimed.
+    pub claim_amount: Decimal,
+}
+
+/// The pending unstakes of the given claimant which have not been claimed yet, in the order they
+/// were made in.
+pub type ValidatorGetPendingUnstakesOutput = Vec<ValidatorPendingUnstake>;
+
 pub const VALIDATOR_TOTAL_STAKE_XRD_AMOUNT_IDENT: &str = "total_stake_xrd_amount";
 
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
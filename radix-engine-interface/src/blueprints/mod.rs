@@ -2,10 +2,12 @@ pub mod access_controller;
 pub mod account;
 pub mod consensus_manager;
 pub mod identity;
+pub mod multisig;
 pub mod package;
 pub mod pool;
 pub mod resource;
 pub mod test_utils;
 pub mod transaction_processor;
+pub mod vesting;
 
 pub mod macros;
@@ -0,0 +1,133 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::data::manifest::ManifestValue;
+use radix_engine_common::data::scrypto::ScryptoValue;
+use radix_engine_common::prelude::*;
+use radix_engine_common::time::*;
+use radix_engine_common::*;
+use sbor::rust::collections::BTreeSet;
+
+pub const MULTISIG_BLUEPRINT: &str = "Multisig";
+
+/// A call that a multisig component makes on behalf of its signers once enough of them have
+/// approved it. The call is made with the multisig component as the global caller.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct MultisigProposal {
+    /// The global entity the method is called on.
+    pub target: GlobalAddress,
+    /// The name of the method to call on the main module of the target.
+    pub method: String,
+    /// The arguments to call the method with - this must be a tuple.
+    pub args: ScryptoValue,
+    /// The instant from which the proposal can no longer be approved or executed.
+    pub expires_at: Instant,
+    /// The signers that have approved the proposal so far.
+    pub approvals: BTreeSet<NonFungibleGlobalId>,
+    /// Whether the proposal has been executed.
+    pub executed: bool,
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: instantiate,
+    input: struct {
+        signers: BTreeSet<NonFungibleGlobalId>,
+        threshold: u8,
+        destination: ComponentAddress,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        signers: BTreeSet<NonFungibleGlobalId>,
+        threshold: u8,
+        destination: ComponentAddress,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: propose,
+    input: struct {
+        signer: NonFungibleGlobalId,
+        target: GlobalAddress,
+        method: String,
+        args: ScryptoValue,
+        expires_at: Instant
+    },
+    output: type u64,
+    manifest_input: struct {
+        signer: NonFungibleGlobalId,
+        target: GlobalAddress,
+        method: String,
+        args: ManifestValue,
+        expires_at: Instant
+    }
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: approve,
+    input: struct {
+        proposal_id: u64,
+        signer: NonFungibleGlobalId
+    },
+    output: type (),
+    manifest_input: struct {
+        proposal_id: u64,
+        signer: NonFungibleGlobalId
+    }
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: execute,
+    input: struct {
+        proposal_id: u64,
+        signer: NonFungibleGlobalId
+    },
+    output: type (),
+    manifest_input: struct {
+        proposal_id: u64,
+        signer: NonFungibleGlobalId
+    }
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: update_signers,
+    input: struct {
+        signers: BTreeSet<NonFungibleGlobalId>,
+        threshold: u8
+    },
+    output: type (),
+    manifest_input: struct {
+        signers: BTreeSet<NonFungibleGlobalId>,
+        threshold: u8
+    }
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: set_destination,
+    input: struct {
+        destination: ComponentAddress
+    },
+    output: type (),
+    manifest_input: struct {
+        destination: ComponentAddress
+    }
+}
+
+define_invocation! {
+    blueprint_name: Multisig,
+    function_name: get_proposal,
+    input: struct {
+        proposal_id: u64
+    },
+    output: type Option<MultisigProposal>,
+    manifest_input: struct {
+        proposal_id: u64
+    }
+}
@@ -83,6 +83,17 @@ pub struct PackageClaimRoyaltiesInput {}
 
 pub type PackageClaimRoyaltiesOutput = Bucket;
 
+pub const PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT: &str = "PackageRoyalty_claim_resource_royalties";
+
+#[derive(
+    Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestCategorize, ManifestEncode, ManifestDecode,
+)]
+pub struct PackageClaimResourceRoyaltiesInput {
+    pub resource_address: ResourceAddress,
+}
+
+pub type PackageClaimResourceRoyaltiesOutput = Bucket;
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, ScryptoSbor, ManifestSbor)]
 pub struct PackageDefinition {
     pub blueprints: BTreeMap<String, BlueprintDefinitionInit>,
@@ -0,0 +1,146 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::time::*;
+use radix_engine_common::*;
+
+pub const CONSTANT_PRODUCT_POOL_BLUEPRINT: &str = "ConstantProductPool";
+
+/// The fees charged by a constant product pool on swaps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct ConstantProductPoolFees {
+    /// The fraction of the input amount of a swap which is charged as a fee, e.g. `0.003` for a
+    /// 0.3% fee. Must be between zero and one.
+    pub fee_rate: Decimal,
+    /// The fraction of the fee which goes to the protocol fee vaults of the pool, the rest of the
+    /// fee stays in the pool for the liquidity providers. Must be between zero and one.
+    pub protocol_fee_share: Decimal,
+}
+
+/// The price accumulators of a constant product pool, from which time-weighted average prices
+/// can be computed by sampling the accumulators at two points in time and dividing the
+/// difference of the accumulators by the difference of the timestamps.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct ConstantProductPoolPriceAccumulators {
+    /// The sum of the prices of the first resource of the pool in terms of the second resource,
+    /// each weighted by the number of seconds it was the price of the pool.
+    pub price1_cumulative: Decimal,
+    /// The sum of the prices of the second resource of the pool in terms of the first resource,
+    /// each weighted by the number of seconds it was the price of the pool.
+    pub price2_cumulative: Decimal,
+    /// The time at which the accumulators were last updated, at minute precision.
+    ///
+    /// The accumulators wrap around at the bounds of `Decimal`, so the average price between two
+    /// observations must be computed from their difference with wrapping arithmetic.
+    pub last_update: Instant,
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: (ResourceAddress, ResourceAddress),
+        fees: ConstantProductPoolFees,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: (ResourceAddress, ResourceAddress),
+        fees: ConstantProductPoolFees,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: contribute,
+    input: struct {
+        buckets: (Bucket, Bucket)
+    },
+    output: type (Bucket, Option<Bucket>),
+    manifest_input: struct {
+        buckets: (ManifestBucket, ManifestBucket)
+    }
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: redeem,
+    input: struct {
+        bucket: Bucket
+    },
+    output: type (Bucket, Bucket),
+    manifest_input: struct {
+        bucket: ManifestBucket
+    }
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: swap,
+    input: struct {
+        input_bucket: Bucket,
+        min_output_amount: Decimal
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        input_bucket: ManifestBucket,
+        min_output_amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: get_swap_output_amount,
+    input: struct {
+        input_resource_address: ResourceAddress,
+        input_amount: Decimal
+    },
+    output: type Decimal,
+    manifest_input: struct {
+        input_resource_address: ResourceAddress,
+        input_amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: get_vault_amounts,
+    input: struct {},
+    output: type BTreeMap<ResourceAddress, Decimal>,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: get_price_accumulators,
+    input: struct {},
+    output: type ConstantProductPoolPriceAccumulators,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: set_fees,
+    input: struct {
+        fees: ConstantProductPoolFees
+    },
+    output: type (),
+    manifest_input: struct {
+        fees: ConstantProductPoolFees
+    }
+}
+
+define_invocation! {
+    blueprint_name: ConstantProductPool,
+    function_name: claim_protocol_fees,
+    input: struct {},
+    output: type (Bucket, Bucket),
+    manifest_input: struct {}
+}
@@ -1,7 +1,9 @@
+mod constant_product_pool;
 mod multi_resource_pool;
 mod one_resource_pool;
 mod two_resource_pool;
 
+pub use constant_product_pool::*;
 pub use multi_resource_pool::*;
 pub use one_resource_pool::*;
 pub use two_resource_pool::*;
@@ -5,6 +5,8 @@ use radix_engine_common::math::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
 
+pub const MULTI_RESOURCE_POOL_BLUEPRINT: &str = "MultiResourcePool";
+
 define_invocation! {
     blueprint_name: MultiResourcePool,
     function_name: instantiate,
@@ -31,7 +33,7 @@ define_invocation! {
     },
     output: type (Bucket, Vec<Bucket>),
     manifest_input: struct {
-        buckets: (ManifestBucket, ManifestBucket)
+        buckets: Vec<ManifestBucket>
     }
 }
 
@@ -5,6 +5,8 @@ use radix_engine_common::math::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
 
+pub const ONE_RESOURCE_POOL_BLUEPRINT: &str = "OneResourcePool";
+
 define_invocation! {
     blueprint_name: OneResourcePool,
     function_name: instantiate,
@@ -5,6 +5,8 @@ use radix_engine_common::math::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
 
+pub const TWO_RESOURCE_POOL_BLUEPRINT: &str = "TwoResourcePool";
+
 define_invocation! {
     blueprint_name: TwoResourcePool,
     function_name: instantiate,
@@ -54,6 +54,33 @@ impl FungibleResourceRoles {
     }
 }
 
+/// The length of the window over which a [`FungibleWithdrawLimit`] applies. Windows are aligned
+/// to multiples of the period, so a limit of 10 minutes resets at every tenth minute of ledger
+/// time rather than ten minutes after the first withdrawal.
+///
+/// Time-based periods have minute precision, as ledger time is only available to blueprints at
+/// [`TimePrecision::Minute`]. There is no period in seconds: a period of N seconds is rounded to
+/// whole minutes by the caller, and sub-minute periods can't be expressed.
+///
+/// [`TimePrecision::Minute`]: crate::blueprints::consensus_manager::TimePrecision::Minute
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub enum WithdrawLimitPeriod {
+    /// A number of minutes of ledger time, read at minute precision.
+    Minutes(u32),
+    Epochs(u64),
+}
+
+/// Caps the amount which may be withdrawn from each vault of the resource within a period.
+/// Withdrawals authorized by the withdraw limit bypasser role are neither limited nor counted.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct FungibleWithdrawLimit {
+    pub amount: Decimal,
+    pub period: WithdrawLimitPeriod,
+    pub bypass_roles: Option<WithdrawLimitBypassRoles<RoleDefinition>>,
+}
+
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
 pub struct FungibleResourceManagerCreateInput {
@@ -107,6 +134,67 @@ pub struct FungibleResourceManagerCreateWithInitialSupplyManifestInput {
 
 pub type FungibleResourceManagerCreateWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT: &str =
+    "create_with_withdraw_limit";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerCreateWithWithdrawLimitInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub resource_roles: FungibleResourceRoles,
+    pub withdraw_limit: FungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerCreateWithWithdrawLimitManifestInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub resource_roles: FungibleResourceRoles,
+    pub withdraw_limit: FungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type FungibleResourceManagerCreateWithWithdrawLimitOutput = ResourceAddress;
+
+pub const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT: &str =
+    "create_with_initial_supply_and_withdraw_limit";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub initial_supply: Decimal,
+    pub resource_roles: FungibleResourceRoles,
+    pub withdraw_limit: FungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitManifestInput {
+    pub owner_role: OwnerRole,
+    pub track_total_supply: bool,
+    pub divisibility: u8,
+    pub initial_supply: Decimal,
+    pub resource_roles: FungibleResourceRoles,
+    pub withdraw_limit: FungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitOutput =
+    (ResourceAddress, Bucket);
+
 pub const FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT: &str = "mint";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
@@ -15,6 +15,21 @@ pub struct FungibleVaultLockFeeInput {
 
 pub type FungibleVaultLockFeeOutput = ();
 
+pub const FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT: &str = "lock_royalty_payment";
+
+/// Locks an amount from the vault for paying royalties charged in this vault's resource.
+/// Whatever isn't charged by the end of a successful transaction is returned to the vault.
+///
+/// No single function or method may charge more than `max_per_function` of the resource; if
+/// several locks are made for the same resource, the smallest maximum applies.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleVaultLockRoyaltyPaymentInput {
+    pub amount: Decimal,
+    pub max_per_function: Decimal,
+}
+
+pub type FungibleVaultLockRoyaltyPaymentOutput = ();
+
 pub const FUNGIBLE_VAULT_LOCK_FUNGIBLE_AMOUNT_IDENT: &str = "lock_amount";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
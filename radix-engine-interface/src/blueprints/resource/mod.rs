@@ -194,6 +194,21 @@ macro_rules! non_fungible_data_update_roles {
     });
 }
 
+resource_roles!(
+    WithdrawLimitBypassRoles,
+    withdraw_limit_bypasser,
+    withdraw_limit_bypasser_updater,
+    WITHDRAW_LIMIT_BYPASSER_ROLE,
+    WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE,
+    AccessRule::DenyAll
+);
+#[macro_export]
+macro_rules! withdraw_limit_bypass_roles {
+    {$($role:ident => $rule:expr;)*} => ({
+        Some($crate::internal_roles_struct!(WithdrawLimitBypassRoles, $($role => $rule;)*))
+    });
+}
+
 /// Define the withdraw strategy when request amount does not match underlying
 /// resource divisibility.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Sbor)]
@@ -68,6 +68,17 @@ impl NonFungibleResourceRoles {
     }
 }
 
+/// Caps the number of non-fungibles which may be withdrawn from each vault of the resource within
+/// a period. Withdrawals authorized by the withdraw limit bypasser role are neither limited nor
+/// counted.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleWithdrawLimit {
+    pub count: u32,
+    pub period: WithdrawLimitPeriod,
+    pub bypass_roles: Option<WithdrawLimitBypassRoles<RoleDefinition>>,
+}
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT: &str = "create";
 
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
@@ -127,6 +138,71 @@ pub struct NonFungibleResourceManagerCreateWithInitialSupplyManifestInput {
 
 pub type NonFungibleResourceManagerCreateWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT: &str =
+    "create_with_withdraw_limit";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithWithdrawLimitInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub withdraw_limit: NonFungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateWithWithdrawLimitManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub withdraw_limit: NonFungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateWithWithdrawLimitOutput = ResourceAddress;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT: &str =
+    "create_with_initial_supply_and_withdraw_limit";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: BTreeMap<NonFungibleLocalId, (ScryptoValue,)>,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub withdraw_limit: NonFungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub track_total_supply: bool,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: BTreeMap<NonFungibleLocalId, (ManifestValue,)>,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub withdraw_limit: NonFungibleWithdrawLimit,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitOutput =
+    (ResourceAddress, Bucket);
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT: &str =
     "create_ruid_non_fungible_with_initial_supply";
 
@@ -189,6 +265,144 @@ pub struct NonFungibleResourceManagerUpdateDataManifestInput {
 
 pub type NonFungibleResourceManagerUpdateDataOutput = ();
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT: &str =
+    "update_non_fungible_data_fields";
+
+/// A change to a single mutable field of a non-fungible's data.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub enum NonFungibleDataUpdate {
+    /// Replaces the value of the field.
+    Set {
+        field_name: String,
+        data: ScryptoValue,
+    },
+    /// Adds the entries to a map-typed field, replacing the values of any keys already present.
+    InsertMapEntries {
+        field_name: String,
+        entries: Vec<(ScryptoValue, ScryptoValue)>,
+    },
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub enum NonFungibleDataManifestUpdate {
+    Set {
+        field_name: String,
+        data: ManifestValue,
+    },
+    InsertMapEntries {
+        field_name: String,
+        entries: Vec<(ManifestValue, ManifestValue)>,
+    },
+}
+
+/// An error converting a typed value into the untyped value held by a [`NonFungibleDataUpdate`]
+/// or a [`NonFungibleDataManifestUpdate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NonFungibleDataUpdateError {
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+}
+
+impl From<EncodeError> for NonFungibleDataUpdateError {
+    fn from(value: EncodeError) -> Self {
+        Self::EncodeError(value)
+    }
+}
+
+impl From<DecodeError> for NonFungibleDataUpdateError {
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+impl NonFungibleDataUpdate {
+    pub fn set<T: ScryptoEncode>(
+        field_name: impl Into<String>,
+        data: T,
+    ) -> Result<Self, NonFungibleDataUpdateError> {
+        Ok(Self::Set {
+            field_name: field_name.into(),
+            data: Self::to_value(&data)?,
+        })
+    }
+
+    pub fn insert_map_entries<K: ScryptoEncode, V: ScryptoEncode>(
+        field_name: impl Into<String>,
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, NonFungibleDataUpdateError> {
+        Ok(Self::InsertMapEntries {
+            field_name: field_name.into(),
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| Ok((Self::to_value(&key)?, Self::to_value(&value)?)))
+                .collect::<Result<_, NonFungibleDataUpdateError>>()?,
+        })
+    }
+
+    fn to_value<T: ScryptoEncode>(value: &T) -> Result<ScryptoValue, NonFungibleDataUpdateError> {
+        Ok(scrypto_decode(&scrypto_encode(value)?)?)
+    }
+}
+
+impl NonFungibleDataManifestUpdate {
+    pub fn set<T: ManifestEncode>(
+        field_name: impl Into<String>,
+        data: T,
+    ) -> Result<Self, NonFungibleDataUpdateError> {
+        Ok(Self::Set {
+            field_name: field_name.into(),
+            data: Self::to_value(&data)?,
+        })
+    }
+
+    pub fn insert_map_entries<K: ManifestEncode, V: ManifestEncode>(
+        field_name: impl Into<String>,
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, NonFungibleDataUpdateError> {
+        Ok(Self::InsertMapEntries {
+            field_name: field_name.into(),
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| Ok((Self::to_value(&key)?, Self::to_value(&value)?)))
+                .collect::<Result<_, NonFungibleDataUpdateError>>()?,
+        })
+    }
+
+    fn to_value<T: ManifestEncode>(value: &T) -> Result<ManifestValue, NonFungibleDataUpdateError> {
+        Ok(manifest_decode(&manifest_encode(value)?)?)
+    }
+}
+
+/// Applies all updates in order and validates the resulting data against the resource's
+/// non-fungible data schema once.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerUpdateDataFieldsInput {
+    pub id: NonFungibleLocalId,
+    pub updates: Vec<NonFungibleDataUpdate>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerUpdateDataFieldsManifestInput {
+    pub id: NonFungibleLocalId,
+    pub updates: Vec<NonFungibleDataManifestUpdate>,
+}
+
+pub type NonFungibleResourceManagerUpdateDataFieldsOutput = ();
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT: &str =
+    "get_non_fungible_data_fields";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleResourceManagerGetDataFieldsInput {
+    pub id: NonFungibleLocalId,
+    pub field_names: Vec<String>,
+}
+
+/// A tuple of the values of the requested fields, in the order they were requested in.
+pub type NonFungibleResourceManagerGetDataFieldsOutput = ScryptoValue;
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT: &str = "non_fungible_exists";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -4,6 +4,7 @@ use crate::math::Decimal;
 use crate::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::Arbitrary;
+use radix_engine_common::time::Instant;
 use radix_engine_common::types::*;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
@@ -53,6 +54,46 @@ pub enum ProofRule {
     AnyOf(Vec<ResourceOrNonFungible>),
 }
 
+/// Time Rules, evaluated against the clock of the consensus manager. Instants are compared at
+/// minute precision, the precision of the consensus manager's clock.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, ScryptoSbor, ManifestSbor)]
+pub enum TimeRule {
+    /// Satisfied at or after the given instant.
+    AfterInstant(Instant),
+    /// Satisfied strictly before the given instant.
+    BeforeInstant(Instant),
+    /// Satisfied at or after the first instant and strictly before the second one.
+    BetweenInstants(Instant, Instant),
+    /// Satisfied at or after the given epoch.
+    AfterEpoch(Epoch),
+    /// Satisfied strictly before the given epoch.
+    BeforeEpoch(Epoch),
+    /// Satisfied at or after the first epoch and strictly before the second one.
+    BetweenEpochs(Epoch, Epoch),
+}
+
+impl TimeRule {
+    /// Checks that the bounds of a between rule are in order, a rule whose start is not before
+    /// its end can never be satisfied.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            TimeRule::BetweenInstants(start, end) => start < end,
+            TimeRule::BetweenEpochs(start, end) => start < end,
+            TimeRule::AfterInstant(..)
+            | TimeRule::BeforeInstant(..)
+            | TimeRule::AfterEpoch(..)
+            | TimeRule::BeforeEpoch(..) => true,
+        }
+    }
+}
+
+impl From<TimeRule> for AccessRuleNode {
+    fn from(time_rule: TimeRule) -> Self {
+        AccessRuleNode::TimeRule(time_rule)
+    }
+}
+
 impl From<ResourceAddress> for AccessRuleNode {
     fn from(resource_address: ResourceAddress) -> Self {
         AccessRuleNode::ProofRule(ProofRule::Require(resource_address.into()))
@@ -77,6 +118,7 @@ pub enum AccessRuleNode {
     ProofRule(ProofRule),
     AnyOf(Vec<AccessRuleNode>),
     AllOf(Vec<AccessRuleNode>),
+    TimeRule(TimeRule),
 }
 
 impl AccessRuleNode {
@@ -153,6 +195,36 @@ where
     AccessRuleNode::ProofRule(ProofRule::AmountOf(amount.into(), resource.into()))
 }
 
+/// A requirement for the current time to be at or after the given instant.
+pub fn after_instant(instant: Instant) -> AccessRuleNode {
+    AccessRuleNode::TimeRule(TimeRule::AfterInstant(instant))
+}
+
+/// A requirement for the current time to be before the given instant.
+pub fn before_instant(instant: Instant) -> AccessRuleNode {
+    AccessRuleNode::TimeRule(TimeRule::BeforeInstant(instant))
+}
+
+/// A requirement for the current time to be at or after `start` and before `end`.
+pub fn between_instants(start: Instant, end: Instant) -> AccessRuleNode {
+    AccessRuleNode::TimeRule(TimeRule::BetweenInstants(start, end))
+}
+
+/// A requirement for the current epoch to be at or after the given epoch.
+pub fn after_epoch(epoch: Epoch) -> AccessRuleNode {
+    AccessRuleNode::TimeRule(TimeRule::AfterEpoch(epoch))
+}
+
+/// A requirement for the current epoch to be before the given epoch.
+pub fn before_epoch(epoch: Epoch) -> AccessRuleNode {
+    AccessRuleNode::TimeRule(TimeRule::BeforeEpoch(epoch))
+}
+
+/// A requirement for the current epoch to be at or after `start` and before `end`.
+pub fn between_epochs(start: Epoch, end: Epoch) -> AccessRuleNode {
+    AccessRuleNode::TimeRule(TimeRule::BetweenEpochs(start, end))
+}
+
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, ScryptoSbor, ManifestSbor)]
 pub enum AccessRule {
@@ -193,7 +265,7 @@ impl AccessRuleNode {
         visitor.visit(self, depth)?;
 
         match self {
-            AccessRuleNode::ProofRule(..) => {}
+            AccessRuleNode::ProofRule(..) | AccessRuleNode::TimeRule(..) => {}
             AccessRuleNode::AnyOf(nodes) | AccessRuleNode::AllOf(nodes) => {
                 for node in nodes {
                     node.dfs_traverse_recursive(visitor, depth + 1)?;
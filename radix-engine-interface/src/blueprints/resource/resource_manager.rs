@@ -18,6 +18,8 @@ pub const FREEZER_ROLE: &str = "freezer";
 pub const FREEZER_UPDATER_ROLE: &str = "freezer_updater";
 pub const NON_FUNGIBLE_DATA_UPDATER_ROLE: &str = "non_fungible_data_updater";
 pub const NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE: &str = "non_fungible_data_updater_updater";
+pub const WITHDRAW_LIMIT_BYPASSER_ROLE: &str = "withdraw_limit_bypasser";
+pub const WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE: &str = "withdraw_limit_bypasser_updater";
 
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor, ManifestSbor)]
@@ -1,15 +1,22 @@
 use crate::*;
-use radix_engine_common::data::scrypto::{scrypto_decode, ScryptoDecode};
+use radix_engine_common::data::scrypto::{scrypto_decode, ScryptoDecode, ScryptoValue};
 use sbor::rust::prelude::*;
 
 pub const TRANSACTION_PROCESSOR_BLUEPRINT: &str = "TransactionProcessor";
 
 pub const TRANSACTION_PROCESSOR_RUN_IDENT: &str = "run";
 
+pub const TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT: &str = "run_subintent";
+
 // TransactionProcessorInput in the engine
 
 pub type TransactionProcessorRunOutput = Vec<InstructionOutput>;
 
+// TransactionProcessorRunSubintentInput in the engine
+
+/// The value the subintent yielded back to its parent.
+pub type TransactionProcessorRunSubintentOutput = ScryptoValue;
+
 #[derive(Debug, Clone, Sbor, Eq, PartialEq)]
 pub enum InstructionOutput {
     CallReturn(Vec<u8>),
@@ -0,0 +1,71 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::time::*;
+use radix_engine_common::*;
+
+pub const VESTING_BLUEPRINT: &str = "Vesting";
+
+/// The schedule according to which a vesting component releases the resources it holds to its
+/// beneficiary. All instants are compared against the consensus manager time at minute precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub enum VestingSchedule {
+    /// Nothing is released before the `cliff`, after which the resources are released linearly
+    /// over the period from `start` to `end`. Any amount that accrued between the `start` and the
+    /// `cliff` is released all at once when the `cliff` is reached.
+    Linear {
+        start: Instant,
+        cliff: Instant,
+        end: Instant,
+    },
+    /// Everything is released at once at the given instant.
+    Cliff { unlock: Instant },
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        revoker_rule: Option<AccessRule>,
+        schedule: VestingSchedule,
+        bucket: Bucket,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        revoker_rule: Option<AccessRule>,
+        schedule: VestingSchedule,
+        bucket: ManifestBucket,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: claim,
+    input: struct {},
+    output: type Bucket,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: get_claimable,
+    input: struct {},
+    output: type Decimal,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: revoke,
+    input: struct {},
+    output: type Bucket,
+    manifest_input: struct {}
+}
@@ -59,7 +59,8 @@ pub mod prelude {
     pub use crate::{
         access_and_or, access_rule_node, burn_roles, deposit_roles, freeze_roles, internal_roles,
         metadata, metadata_init, metadata_init_set_entry, metadata_roles, mint_roles,
-        non_fungible_data_update_roles, recall_roles, role_entry, roles2, rule, withdraw_roles,
+        non_fungible_data_update_roles, recall_roles, role_entry, roles2, rule,
+        withdraw_limit_bypass_roles, withdraw_roles,
     };
 }
 
@@ -243,6 +243,8 @@ blueprint_partition_offset!(
 blueprint_partition_offset!(
     pub enum ValidatorPartitionOffset {
         Field,
+        ClaimantPendingUnstakesKeyValue,
+        ClaimNftClaimantKeyValue,
     }
 );
 
@@ -258,6 +260,7 @@ blueprint_partition_offset!(
         ResourceVaultKeyValue,
         ResourcePreferenceKeyValue,
         AuthorizedDepositorKeyValue,
+        ResourceVaultIndexKeyValue,
     }
 );
 
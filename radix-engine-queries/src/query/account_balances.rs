@@ -0,0 +1,115 @@
+use radix_engine::blueprints::account::{AccountCollection, AccountResourceVaultEntryPayload};
+use radix_engine::blueprints::resource::{
+    FungibleVaultBalanceFieldPayload, FungibleVaultField, NonFungibleVaultBalanceFieldPayload,
+    NonFungibleVaultField,
+};
+use radix_engine::prelude::*;
+use radix_engine::system::system_db_reader::{
+    ObjectCollectionKey, SystemDatabaseReader, SystemReaderError,
+};
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_store_interface::interface::SubstateDatabase;
+
+#[derive(Debug)]
+pub enum AccountBalancesError {
+    SystemReaderError(SystemReaderError),
+    DecodeError(DecodeError),
+}
+
+impl From<SystemReaderError> for AccountBalancesError {
+    fn from(value: SystemReaderError) -> Self {
+        Self::SystemReaderError(value)
+    }
+}
+
+impl From<DecodeError> for AccountBalancesError {
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+/// Reads account balances straight from the account's resource vaults collection, rather than
+/// traversing the account's whole subtree like [`super::ResourceAccounter`] does.
+pub struct AccountBalances<'s, S: SubstateDatabase> {
+    system_db_reader: SystemDatabaseReader<'s, S>,
+}
+
+impl<'s, S: SubstateDatabase> AccountBalances<'s, S> {
+    pub fn new(substate_db: &'s S) -> Self {
+        AccountBalances {
+            system_db_reader: SystemDatabaseReader::new(substate_db),
+        }
+    }
+
+    /// Returns the balance of every resource the account holds a vault for.
+    pub fn balances(
+        &self,
+        account: &ComponentAddress,
+    ) -> Result<IndexMap<ResourceAddress, Decimal>, AccountBalancesError> {
+        let entries = self
+            .system_db_reader
+            .collection_iter(
+                account.as_node_id(),
+                ObjectModuleId::Main,
+                AccountCollection::ResourceVaultKeyValue.collection_index(),
+            )?
+            .collect::<Vec<_>>();
+
+        let mut balances = index_map_new();
+        for (key, value) in entries {
+            let SubstateKey::Map(key) = key else {
+                continue;
+            };
+            let resource_address: ResourceAddress = scrypto_decode(&key)?;
+            let vault: AccountResourceVaultEntryPayload = scrypto_decode(&value)?;
+            let vault = vault.into_latest();
+            balances.insert(resource_address, self.vault_balance(vault.0.as_node_id())?);
+        }
+
+        Ok(balances)
+    }
+
+    /// Returns the balance of the given resource, which is zero if the account holds no vault for
+    /// it.
+    pub fn balance_of(
+        &self,
+        account: &ComponentAddress,
+        resource_address: &ResourceAddress,
+    ) -> Result<Decimal, AccountBalancesError> {
+        let vault = self
+            .system_db_reader
+            .read_object_collection_entry::<_, AccountResourceVaultEntryPayload>(
+                account.as_node_id(),
+                ObjectModuleId::Main,
+                ObjectCollectionKey::KeyValue(
+                    AccountCollection::ResourceVaultKeyValue.collection_index(),
+                    resource_address,
+                ),
+            )?;
+
+        match vault {
+            Some(vault) => Ok(self.vault_balance(vault.into_latest().0.as_node_id())?),
+            None => Ok(Decimal::ZERO),
+        }
+    }
+
+    fn vault_balance(&self, vault_id: &NodeId) -> Result<Decimal, SystemReaderError> {
+        if vault_id.is_internal_fungible_vault() {
+            let balance: FungibleVaultBalanceFieldPayload =
+                self.system_db_reader.read_typed_object_field(
+                    vault_id,
+                    ObjectModuleId::Main,
+                    FungibleVaultField::Balance.into(),
+                )?;
+            Ok(balance.into_latest().amount())
+        } else {
+            let balance: NonFungibleVaultBalanceFieldPayload =
+                self.system_db_reader.read_typed_object_field(
+                    vault_id,
+                    ObjectModuleId::Main,
+                    NonFungibleVaultField::Balance.into(),
+                )?;
+            Ok(balance.into_latest().amount)
+        }
+    }
+}
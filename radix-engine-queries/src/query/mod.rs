@@ -1,7 +1,9 @@
+mod account_balances;
 mod accounter;
 mod traverse;
 mod vault_finder;
 
+pub use account_balances::*;
 pub use accounter::*;
 pub use traverse::*;
 pub use vault_finder::*;
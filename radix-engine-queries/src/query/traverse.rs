@@ -193,21 +193,30 @@ impl<'s, 'v, S: SubstateDatabase, V: StateTreeVisitor + 'v> StateTreeTraverser<'
                                             )
                                             .expect("Broken database")
                                             .into_latest();
-                                        Self::traverse_recursive(
-                                            system_db_reader,
-                                            visitor,
-                                            Some(&(
-                                                node_id,
-                                                ROYALTY_BASE_PARTITION,
-                                                SubstateKey::Field(
-                                                    ComponentRoyaltyField::Accumulator
-                                                        .field_index(),
-                                                ),
-                                            )),
-                                            royalty.royalty_vault.0 .0,
-                                            depth + 1,
-                                            max_depth,
-                                        );
+                                        let royalty_vaults =
+                                            core::iter::once(royalty.royalty_vault.0 .0).chain(
+                                                royalty
+                                                    .resource_royalty_vaults
+                                                    .values()
+                                                    .map(|vault| vault.0 .0),
+                                            );
+                                        for vault_id in royalty_vaults {
+                                            Self::traverse_recursive(
+                                                system_db_reader,
+                                                visitor,
+                                                Some(&(
+                                                    node_id,
+                                                    ROYALTY_BASE_PARTITION,
+                                                    SubstateKey::Field(
+                                                        ComponentRoyaltyField::Accumulator
+                                                            .field_index(),
+                                                    ),
+                                                )),
+                                                vault_id,
+                                                depth + 1,
+                                                max_depth,
+                                            );
+                                        }
                                     }
                                     _ => {}
                                 }
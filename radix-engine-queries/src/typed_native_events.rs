@@ -225,6 +225,8 @@ define_structure! {
             UnstakeEvent,
             ClaimXrdEvent,
             UpdateAcceptingStakeDelegationStateEvent,
+            UpdateMaxStakeEvent,
+            UpdateCommissionTiersEvent,
             ProtocolUpdateReadinessSignalEvent,
             ValidatorEmissionAppliedEvent,
             ValidatorRewardAppliedEvent,
@@ -4,7 +4,7 @@ use radix_engine::ledger::*;
 use radix_engine::transaction::execute_and_commit_transaction;
 use radix_engine::transaction::{CostingParameters, ExecutionConfig, ResourcesUsage};
 use radix_engine::types::*;
-use radix_engine::wasm::{DefaultWasmEngine, WasmValidatorConfig};
+use radix_engine::wasm::{DefaultWasmEngine, WasmDebugCodeCache, WasmValidatorConfig};
 use radix_engine_common::constants::EXECUTION_COST_UNIT_LIMIT;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::dec;
@@ -129,6 +129,8 @@ fn transfer_test(c: &mut Criterion) {
     let mut scrypto_interpreter = ScryptoInterpreter {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_validator_config: WasmValidatorConfig::new(),
+        debug_mode: false,
+        debug_code_cache: WasmDebugCodeCache::default(),
     };
     let mut substate_db = InMemorySubstateDatabase::standard();
     let _ = Bootstrapper::new(&mut substate_db, &scrypto_interpreter)
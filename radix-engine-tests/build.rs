@@ -0,0 +1,21 @@
+//! Generates the manifest builder extensions of the `manifest_builder_codegen` test blueprint,
+//! which `tests/manifest_builder_codegen.rs` is compiled against.
+
+use scrypto_unit::Compile;
+use std::path::Path;
+use transaction::builder::generate_manifest_builder_extensions;
+
+fn main() {
+    let package_dir = "./tests/blueprints/manifest_builder_codegen";
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}/src", package_dir);
+
+    let (_, definition) = Compile::compile(package_dir);
+    let code = generate_manifest_builder_extensions(&definition);
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(
+        Path::new(&out_dir).join("manifest_builder_codegen.rs"),
+        code,
+    )
+    .unwrap();
+}
@@ -1,3 +1,4 @@
+use radix_engine::blueprints::account::AccountField;
 use radix_engine::blueprints::resource::NonFungibleResourceManagerError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
@@ -6,6 +7,11 @@ use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::metadata::MetadataValue;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_queries::query::AccountBalances;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, DatabaseUpdate, DatabaseUpdates, PartitionUpdates,
+};
 use scrypto_unit::*;
 use transaction::prelude::*;
 
@@ -302,6 +308,183 @@ fn account_created_with_create_advanced_has_an_empty_owner_badge() {
     assert!(is_metadata_empty(&metadata))
 }
 
+#[test]
+fn account_vaults_can_be_paged_through_in_creation_order() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let existing_resources =
+        get_account_vaults(&mut test_runner, account, 0, u32::MAX).resource_addresses;
+    let new_resources: Vec<ResourceAddress> = (0..3)
+        .map(|_| test_runner.create_fungible_resource(dec!(100), 18, account))
+        .collect();
+
+    // Act
+    let mut resource_addresses = Vec::new();
+    let mut cursor = Some(0);
+    while let Some(current_cursor) = cursor {
+        let page = get_account_vaults(&mut test_runner, account, current_cursor, 2);
+        assert!(page.resource_addresses.len() <= 2);
+        resource_addresses.extend(page.resource_addresses);
+        cursor = page.next_cursor;
+    }
+
+    // Assert
+    let expected: Vec<ResourceAddress> = existing_resources
+        .into_iter()
+        .chain(new_resources.into_iter())
+        .collect();
+    assert_eq!(resource_addresses, expected);
+}
+
+#[test]
+fn account_vaults_page_is_limited_to_the_maximum_page_size() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    for _ in 0..=ACCOUNT_GET_VAULTS_MAX_LIMIT {
+        test_runner.create_fungible_resource(dec!(100), 18, account);
+    }
+
+    // Act
+    let page = get_account_vaults(&mut test_runner, account, 0, u32::MAX);
+
+    // Assert
+    assert_eq!(
+        page.resource_addresses.len(),
+        ACCOUNT_GET_VAULTS_MAX_LIMIT as usize
+    );
+    assert_eq!(page.next_cursor, Some(ACCOUNT_GET_VAULTS_MAX_LIMIT));
+}
+
+#[test]
+fn account_balance_of_returns_the_vault_amount_or_zero() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let held_resource = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let other_resource = test_runner.create_fungible_resource(dec!(100), 18, other_account);
+    let manifest = ManifestBuilder::new()
+        .get_account_balance(account, held_resource)
+        .get_account_balance(account, other_resource)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(commit_result.output::<Decimal>(1), dec!(100));
+    assert_eq!(commit_result.output::<Decimal>(2), dec!(0));
+}
+
+#[test]
+fn account_balances_query_matches_the_subtree_traversal() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let fungible = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let non_fungible = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let balances = AccountBalances::new(test_runner.substate_db())
+        .balances(&account)
+        .unwrap();
+
+    // Assert
+    for (resource_address, amount) in test_runner.get_component_resources(account) {
+        assert_eq!(balances.get(&resource_address), Some(&amount));
+    }
+    assert_eq!(balances.get(&fungible), Some(&dec!(100)));
+    assert_eq!(balances.get(&non_fungible), Some(&dec!(3)));
+    assert_eq!(
+        AccountBalances::new(test_runner.substate_db())
+            .balance_of(&account, &non_fungible)
+            .unwrap(),
+        dec!(3)
+    );
+}
+
+#[test]
+fn vaults_of_an_account_created_before_vault_indexing_are_backfilled() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    test_runner.create_fungible_resource(dec!(100), 18, account);
+    let existing_resources =
+        get_account_vaults(&mut test_runner, account, 0, u32::MAX).resource_addresses;
+    remove_resource_vault_index(&mut test_runner, account, existing_resources.len() as u32);
+
+    // Act
+    let new_resource = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let resource_addresses =
+        get_account_vaults(&mut test_runner, account, 0, u32::MAX).resource_addresses;
+
+    // Assert
+    let (backfilled_resources, created_resources) =
+        resource_addresses.split_at(existing_resources.len());
+    assert_eq!(
+        backfilled_resources.iter().collect::<BTreeSet<_>>(),
+        existing_resources.iter().collect::<BTreeSet<_>>()
+    );
+    assert_eq!(created_resources, [new_resource]);
+}
+
+/// Puts the account back into the state of accounts created before their vaults were indexed.
+fn remove_resource_vault_index(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+    vault_count: u32,
+) {
+    let mut field_updates = PartitionUpdates::default();
+    field_updates.insert(
+        SpreadPrefixKeyMapper::to_db_sort_key(&AccountField::ResourceVaultCount.into()),
+        DatabaseUpdate::Delete,
+    );
+    let mut index_updates = PartitionUpdates::default();
+    for index in 0..vault_count {
+        index_updates.insert(
+            SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Map(
+                scrypto_encode(&index).unwrap(),
+            )),
+            DatabaseUpdate::Delete,
+        );
+    }
+
+    let mut database_updates = DatabaseUpdates::default();
+    database_updates.insert(
+        SpreadPrefixKeyMapper::to_db_partition_key(
+            account.as_node_id(),
+            AccountPartitionOffset::Field.as_main_partition(),
+        ),
+        field_updates,
+    );
+    database_updates.insert(
+        SpreadPrefixKeyMapper::to_db_partition_key(
+            account.as_node_id(),
+            AccountPartitionOffset::ResourceVaultIndexKeyValue.as_main_partition(),
+        ),
+        index_updates,
+    );
+    test_runner.substate_db_mut().commit(&database_updates);
+}
+
+fn get_account_vaults(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+    cursor: u32,
+    limit: u32,
+) -> AccountVaultsPage {
+    let manifest = ManifestBuilder::new()
+        .get_account_vaults(account, cursor, limit)
+        .build();
+    test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .output(1)
+}
+
 fn is_metadata_empty(metadata_value: &Option<MetadataValue>) -> bool {
     if let None = metadata_value {
         true
@@ -0,0 +1,379 @@
+use radix_engine::blueprints::account::*;
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn deposit_of_resource_without_the_required_tag_is_rejected_with_a_reason() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec![]);
+    env.set_deposit_rule(Some(DepositRule::ResourceHasTag("verified".to_owned())))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.mint_and_try_deposit_or_refund(resource_address);
+
+    // Assert
+    let reason = env.rejected_deposit_reason(&receipt);
+    assert_eq!(
+        reason,
+        RejectedDepositReasonEvent {
+            resource_address,
+            reason: DepositRejectionReason::DepositRule(DepositRule::ResourceHasTag(
+                "verified".to_owned()
+            )),
+        }
+    );
+    assert_eq!(env.balance(resource_address), dec!(0));
+}
+
+#[test]
+fn deposit_of_resource_with_the_required_tag_is_accepted() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec!["defi", "verified"]);
+    env.set_deposit_rule(Some(DepositRule::ResourceHasTag("verified".to_owned())))
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.mint_and_try_deposit_or_refund(resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(resource_address), dec!(1));
+}
+
+#[test]
+fn new_resources_beyond_the_per_epoch_limit_are_rejected_until_the_next_epoch() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let first_resource = env.create_resource(vec![]);
+    let second_resource = env.create_resource(vec![]);
+    env.set_deposit_rule(Some(DepositRule::NewResourcesPerEpoch(1)))
+        .expect_commit_success();
+
+    // Act
+    env.mint_and_try_deposit_or_refund(first_resource)
+        .expect_commit_success();
+    env.mint_and_try_deposit_or_refund(second_resource)
+        .expect_commit_success();
+    let balance_in_same_epoch = env.balance(second_resource);
+    let next_epoch = env.test_runner.get_current_epoch().next();
+    env.test_runner.set_current_epoch(next_epoch);
+    env.mint_and_try_deposit_or_refund(second_resource)
+        .expect_commit_success();
+    let balance_in_next_epoch = env.balance(second_resource);
+
+    // Assert
+    assert_eq!(env.balance(first_resource), dec!(1));
+    assert_eq!(balance_in_same_epoch, dec!(0));
+    assert_eq!(balance_in_next_epoch, dec!(1));
+}
+
+#[test]
+fn existing_resources_do_not_count_against_the_new_resource_limit() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec![]);
+    env.set_deposit_rule(Some(DepositRule::NewResourcesPerEpoch(1)))
+        .expect_commit_success();
+    env.mint_and_try_deposit_or_refund(resource_address)
+        .expect_commit_success();
+
+    // Act
+    env.mint_and_try_deposit_or_refund(resource_address)
+        .expect_commit_success();
+
+    // Assert
+    assert_eq!(env.balance(resource_address), dec!(2));
+}
+
+#[test]
+fn new_resources_in_a_single_batch_count_against_the_limit() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let first_resource = env.create_resource(vec![]);
+    let second_resource = env.create_resource(vec![]);
+    env.set_deposit_rule(Some(DepositRule::NewResourcesPerEpoch(1)))
+        .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .mint_fungible(first_resource, 1)
+        .mint_fungible(second_resource, 1)
+        .try_deposit_entire_worktop_or_refund(env.account, None)
+        .try_deposit_entire_worktop_or_abort(env.sink, None)
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(first_resource), dec!(0));
+    assert_eq!(env.balance(second_resource), dec!(0));
+}
+
+#[test]
+fn deposit_from_a_listed_sender_is_accepted() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec![]);
+    let depositor = env.create_depositor();
+    env.set_deposit_rule(Some(DepositRule::SenderIsOneOf(
+        btreeset!(depositor.into()),
+    )))
+    .expect_commit_success();
+
+    // Act
+    let receipt = env.mint_and_try_deposit_through(depositor, resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(resource_address), dec!(1));
+}
+
+#[test]
+fn deposit_from_an_unlisted_sender_is_rejected() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec![]);
+    let listed_depositor = env.create_depositor();
+    let unlisted_depositor = env.create_depositor();
+    env.set_deposit_rule(Some(DepositRule::SenderIsOneOf(btreeset!(
+        listed_depositor.into()
+    ))))
+    .expect_commit_success();
+
+    // Act
+    let receipt = env.mint_and_try_deposit_through(unlisted_depositor, resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(resource_address), dec!(0));
+}
+
+#[test]
+fn any_of_rule_is_satisfied_by_a_single_satisfied_rule() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec!["verified"]);
+    let depositor = env.create_depositor();
+    env.set_deposit_rule(Some(DepositRule::AnyOf(vec![
+        DepositRule::SenderIsOneOf(btreeset!(depositor.into())),
+        DepositRule::ResourceHasTag("verified".to_owned()),
+    ])))
+    .expect_commit_success();
+
+    // Act
+    let receipt = env.mint_and_try_deposit_or_refund(resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(resource_address), dec!(1));
+}
+
+#[test]
+fn all_of_rule_reports_the_first_unsatisfied_rule() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec!["verified"]);
+    let depositor = env.create_depositor();
+    let sender_rule = DepositRule::SenderIsOneOf(btreeset!(depositor.into()));
+    env.set_deposit_rule(Some(DepositRule::AllOf(vec![
+        DepositRule::ResourceHasTag("verified".to_owned()),
+        sender_rule.clone(),
+    ])))
+    .expect_commit_success();
+
+    // Act
+    let receipt = env.mint_and_try_deposit_or_refund(resource_address);
+
+    // Assert
+    assert_eq!(
+        env.rejected_deposit_reason(&receipt).reason,
+        DepositRejectionReason::DepositRule(sender_rule)
+    );
+}
+
+#[test]
+fn removing_the_deposit_rule_allows_deposits_again() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let resource_address = env.create_resource(vec![]);
+    env.set_deposit_rule(Some(DepositRule::ResourceHasTag("verified".to_owned())))
+        .expect_commit_success();
+
+    // Act
+    env.set_deposit_rule(None).expect_commit_success();
+    let receipt = env.mint_and_try_deposit_or_refund(resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(resource_address), dec!(1));
+}
+
+#[test]
+fn setting_the_deposit_rule_requires_the_owner_signature() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let manifest = ManifestBuilder::new()
+        .set_deposit_rule(env.account, Some(DepositRule::NewResourcesPerEpoch(0)))
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+struct TestEnvironment {
+    test_runner: DefaultTestRunner,
+    account: ComponentAddress,
+    account_badge: NonFungibleGlobalId,
+    sink: ComponentAddress,
+    package_address: PackageAddress,
+}
+
+impl TestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let (_, _, sink) = test_runner.new_allocated_account();
+        let package_address =
+            test_runner.compile_and_publish("./tests/blueprints/account_depositor");
+
+        Self {
+            test_runner,
+            account,
+            account_badge: NonFungibleGlobalId::from_public_key(&public_key),
+            sink,
+            package_address,
+        }
+    }
+
+    fn set_deposit_rule(&mut self, deposit_rule: Option<DepositRule>) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .set_deposit_rule(self.account, deposit_rule)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.account_badge.clone()])
+    }
+
+    /// Creates a freely mintable resource with the given `tags` metadata.
+    fn create_resource(&mut self, tags: Vec<&str>) -> ResourceAddress {
+        let tags: Vec<String> = tags.into_iter().map(|tag| tag.to_owned()).collect();
+        let manifest = ManifestBuilder::new()
+            .create_fungible_resource(
+                OwnerRole::None,
+                true,
+                18,
+                FungibleResourceRoles {
+                    mint_roles: mint_roles! {
+                        minter => rule!(allow_all);
+                        minter_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata! {
+                    init {
+                        "tags" => tags, locked;
+                    }
+                },
+                None,
+            )
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+            .expect_commit_success()
+            .new_resource_addresses()[0]
+    }
+
+    fn create_depositor(&mut self) -> ComponentAddress {
+        let manifest = ManifestBuilder::new()
+            .call_function(
+                self.package_address,
+                "AccountDepositor",
+                "instantiate",
+                manifest_args!(),
+            )
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    }
+
+    /// Mints a single unit of the resource and deposits it into the account, sending anything that
+    /// is refunded to the sink account.
+    fn mint_and_try_deposit_or_refund(
+        &mut self,
+        resource_address: ResourceAddress,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(resource_address, 1)
+            .try_deposit_entire_worktop_or_refund(self.account, None)
+            .try_deposit_entire_worktop_or_abort(self.sink, None)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+    }
+
+    fn mint_and_try_deposit_through(
+        &mut self,
+        depositor: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(resource_address, 1)
+            .take_all_from_worktop(resource_address, "bucket")
+            .with_bucket("bucket", |builder, bucket| {
+                builder.call_method(
+                    depositor,
+                    "try_deposit_or_refund",
+                    manifest_args!(self.account, bucket),
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(self.sink, None)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+    }
+
+    fn rejected_deposit_reason(&self, receipt: &TransactionReceipt) -> RejectedDepositReasonEvent {
+        receipt
+            .expect_commit_success()
+            .application_events
+            .iter()
+            .find_map(|(event_type_identifier, event_data)| {
+                if self.test_runner.event_name(event_type_identifier)
+                    == "RejectedDepositReasonEvent"
+                {
+                    Some(scrypto_decode::<RejectedDepositReasonEvent>(event_data).unwrap())
+                } else {
+                    None
+                }
+            })
+            .expect("Expected a RejectedDepositReasonEvent")
+    }
+
+    fn balance(&mut self, resource_address: ResourceAddress) -> Decimal {
+        self.test_runner
+            .get_component_balance(self.account, resource_address)
+    }
+}
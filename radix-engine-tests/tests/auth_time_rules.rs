@@ -0,0 +1,182 @@
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::system::node_modules::role_assignment::RoleAssignmentError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn after_epoch_rule_is_only_satisfied_from_the_given_epoch() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let unlock_epoch = test_runner.get_current_epoch().after(10);
+    let resource_address =
+        create_resource_with_minter_rule(&mut test_runner, rule!(after_epoch(unlock_epoch)));
+
+    // Act
+    let locked_receipt = mint(&mut test_runner, resource_address, account);
+    test_runner.set_current_epoch(unlock_epoch);
+    let unlocked_receipt = mint(&mut test_runner, resource_address, account);
+
+    // Assert
+    locked_receipt.expect_specific_failure(is_auth_error);
+    unlocked_receipt.expect_commit_success();
+}
+
+#[test]
+fn before_epoch_rule_is_only_satisfied_until_the_given_epoch() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let expiry_epoch = test_runner.get_current_epoch().after(10);
+    let resource_address =
+        create_resource_with_minter_rule(&mut test_runner, rule!(before_epoch(expiry_epoch)));
+
+    // Act
+    let unexpired_receipt = mint(&mut test_runner, resource_address, account);
+    test_runner.set_current_epoch(expiry_epoch);
+    let expired_receipt = mint(&mut test_runner, resource_address, account);
+
+    // Assert
+    unexpired_receipt.expect_commit_success();
+    expired_receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn between_instants_rule_is_only_satisfied_within_the_window() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let now = test_runner.get_current_time(TimePrecision::Minute);
+    let start = now.add_minutes(10).unwrap();
+    let end = now.add_minutes(20).unwrap();
+    let resource_address =
+        create_resource_with_minter_rule(&mut test_runner, rule!(between_instants(start, end)));
+
+    // Act
+    let before_receipt = mint(&mut test_runner, resource_address, account);
+    advance_time_by_minutes(&mut test_runner, 15);
+    let within_receipt = mint(&mut test_runner, resource_address, account);
+    advance_time_by_minutes(&mut test_runner, 10);
+    let after_receipt = mint(&mut test_runner, resource_address, account);
+
+    // Assert
+    before_receipt.expect_specific_failure(is_auth_error);
+    within_receipt.expect_commit_success();
+    after_receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn time_rule_can_be_combined_with_proof_rules() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let unlock_epoch = test_runner.get_current_epoch().after(10);
+    let resource_address = create_resource_with_minter_rule(
+        &mut test_runner,
+        rule!(require(badge.clone()) && after_epoch(unlock_epoch)),
+    );
+    test_runner.set_current_epoch(unlock_epoch);
+
+    // Act
+    let unsigned_receipt =
+        test_runner.execute_manifest_ignoring_fee(mint_manifest(resource_address, account), vec![]);
+    let signed_receipt = test_runner
+        .execute_manifest_ignoring_fee(mint_manifest(resource_address, account), vec![badge]);
+
+    // Assert
+    unsigned_receipt.expect_specific_failure(is_auth_error);
+    signed_receipt.expect_commit_success();
+}
+
+#[test]
+fn time_rule_with_empty_window_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let epoch = test_runner.get_current_epoch().after(10);
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles {
+                mint_roles: mint_roles! {
+                    minter => rule!(between_epochs(epoch, epoch));
+                    minter_updater => rule!(deny_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            None,
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(
+                RoleAssignmentError::InvalidTimeRule(..)
+            ))
+        )
+    });
+}
+
+fn create_resource_with_minter_rule(
+    test_runner: &mut DefaultTestRunner,
+    minter_rule: AccessRule,
+) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles {
+                mint_roles: mint_roles! {
+                    minter => minter_rule;
+                    minter_updater => rule!(deny_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+    receipt.expect_commit_success().new_resource_addresses()[0]
+}
+
+fn mint_manifest(
+    resource_address: ResourceAddress,
+    account: ComponentAddress,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .mint_fungible(resource_address, 1)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build()
+}
+
+fn mint(
+    test_runner: &mut DefaultTestRunner,
+    resource_address: ResourceAddress,
+    account: ComponentAddress,
+) -> TransactionReceipt {
+    test_runner.execute_manifest_ignoring_fee(mint_manifest(resource_address, account), vec![])
+}
+
+fn advance_time_by_minutes(test_runner: &mut DefaultTestRunner, minutes: i64) {
+    let round = test_runner.get_consensus_manager_state().round;
+    let timestamp_ms = test_runner.get_current_proposer_timestamp_ms();
+    test_runner
+        .advance_to_round_at_timestamp(
+            Round::of(round.number() + 1),
+            timestamp_ms + minutes * 60 * 1000,
+        )
+        .expect_commit_success();
+}
@@ -0,0 +1,23 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod account_depositor {
+    struct AccountDepositor {}
+
+    impl AccountDepositor {
+        pub fn instantiate() -> Global<AccountDepositor> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn try_deposit_or_refund(
+            &self,
+            mut account: Global<Account>,
+            bucket: Bucket,
+        ) -> Option<Bucket> {
+            account.try_deposit_or_refund(bucket, None)
+        }
+    }
+}
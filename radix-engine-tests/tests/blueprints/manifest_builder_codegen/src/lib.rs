@@ -0,0 +1,45 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod badge_checker {
+    struct BadgeChecker {
+        vault: Vault,
+        origin: PackageAddress,
+        fee: Decimal,
+    }
+
+    impl BadgeChecker {
+        pub fn instantiate(package_address: PackageAddress, fee: Decimal) -> Global<BadgeChecker> {
+            Self {
+                vault: Vault::new(XRD),
+                origin: package_address,
+                fee,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn deposit(&mut self, bucket: Bucket) {
+            assert!(bucket.amount() >= self.fee);
+            self.vault.put(bucket);
+        }
+
+        pub fn count_proofs(
+            &self,
+            proofs: Vec<Proof>,
+            component_address: ComponentAddress,
+        ) -> usize {
+            assert_eq!(component_address, Runtime::global_address());
+            let count = proofs.len();
+            for proof in proofs {
+                proof.drop();
+            }
+            count
+        }
+
+        pub fn origin(&self) -> PackageAddress {
+            self.origin
+        }
+    }
+}
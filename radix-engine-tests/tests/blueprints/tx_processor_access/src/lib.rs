@@ -6,6 +6,14 @@ pub enum InstructionOutput {
     None,
 }
 
+#[derive(ScryptoSbor)]
+pub struct TransactionProcessorSubintent {
+    pub subintent_hash: Hash,
+    pub manifest_encoded_instructions: Vec<u8>,
+    pub references: Vec<Reference>,
+    pub blobs: IndexMap<Hash, Vec<u8>>,
+}
+
 #[blueprint]
 mod execute_manifest {
     extern_blueprint!(
@@ -17,6 +25,12 @@ mod execute_manifest {
                 references: Vec<Reference>,
                 blobs: IndexMap<Hash, Vec<u8>>
             ) -> Vec<InstructionOutput>;
+
+            fn run_subintent(
+                child_index: u32,
+                subintent: TransactionProcessorSubintent,
+                args: ()
+            ) -> ();
         }
     );
 
@@ -34,5 +48,18 @@ mod execute_manifest {
                 index_map_new(),
             );
         }
+
+        pub fn run_subintent(subintent_hash: Hash, manifest_encoded_instructions: Vec<u8>) {
+            Blueprint::<TransactionProcessor>::run_subintent(
+                0,
+                TransactionProcessorSubintent {
+                    subintent_hash,
+                    manifest_encoded_instructions,
+                    references: vec![],
+                    blobs: index_map_new(),
+                },
+                (),
+            );
+        }
     }
 }
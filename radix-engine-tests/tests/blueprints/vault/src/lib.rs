@@ -3,3 +3,4 @@ pub mod non_fungible_vault;
 pub mod vault_burn;
 pub mod vault_deposit_events;
 pub mod vault_in_structs;
+pub mod vault_withdraw_limit;
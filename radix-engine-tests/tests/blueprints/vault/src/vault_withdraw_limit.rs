@@ -0,0 +1,27 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod vault_withdraw_limit {
+    struct WithdrawLimitedVault {
+        vault: Vault,
+    }
+
+    impl WithdrawLimitedVault {
+        pub fn new(limit: Decimal, minutes: u32) -> Global<WithdrawLimitedVault> {
+            let bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .withdraw_limit(limit, WithdrawLimitPeriod::Minutes(minutes))
+                .mint_initial_supply(1000);
+
+            Self {
+                vault: Vault::with_bucket(bucket.into()),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn take_amount(&mut self, amount: Decimal) -> Bucket {
+            self.vault.as_fungible().take(amount).0
+        }
+    }
+}
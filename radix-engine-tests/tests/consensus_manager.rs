@@ -1,5 +1,6 @@
 use radix_engine::blueprints::consensus_manager::{
-    Validator, ValidatorEmissionAppliedEvent, ValidatorError,
+    UpdateCommissionTiersEvent, UpdateMaxStakeEvent, Validator, ValidatorEmissionAppliedEvent,
+    ValidatorError,
 };
 use radix_engine::blueprints::resource::BucketError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
@@ -733,6 +734,106 @@ fn not_allowing_delegated_stake_should_not_let_non_owner_stake() {
     test_disabled_delegated_stake(false, false);
 }
 
+fn test_max_stake(max_stake_xrd: Decimal, owner: bool, expect_success: bool) {
+    // Arrange
+    let genesis_epoch = Epoch::of(5);
+    let pub_key = Secp256k1PrivateKey::from_u64(1u64).unwrap().public_key();
+    let validator_account_address = ComponentAddress::virtual_account_from_public_key(&pub_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        pub_key,
+        Decimal::one(),
+        validator_account_address,
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config(),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&pub_key);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            validator_account_address,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_MAX_STAKE_IDENT,
+            manifest_args!(Some(max_stake_xrd)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pub_key)],
+    );
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.extract_events_of_type::<UpdateMaxStakeEvent>(result),
+        vec![UpdateMaxStakeEvent {
+            max_stake_xrd: Some(max_stake_xrd)
+        }]
+    );
+
+    // Act: bring the total stake to 101 XRD
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+
+    if owner {
+        builder = builder.create_proof_from_account_of_non_fungibles(
+            validator_account_address,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()],
+        );
+    }
+
+    let manifest = builder
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, dec!(100), "stake")
+        .with_name_lookup(|builder, lookup| {
+            let bucket = lookup.bucket("stake");
+            if owner {
+                builder.call_method(validator_address, "stake_as_owner", manifest_args!(bucket))
+            } else {
+                builder.call_method(validator_address, "stake", manifest_args!(bucket))
+            }
+        })
+        .try_deposit_entire_worktop_or_abort(validator_account_address, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pub_key)],
+    );
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                    ValidatorError::MaxStakeExceeded { .. }
+                ))
+            )
+        });
+    }
+}
+
+#[test]
+fn delegated_stake_up_to_max_stake_is_accepted() {
+    test_max_stake(dec!(101), false, true);
+}
+
+#[test]
+fn delegated_stake_above_max_stake_is_rejected() {
+    test_max_stake(dec!(100), false, false);
+}
+
+#[test]
+fn max_stake_should_still_let_owner_stake() {
+    test_max_stake(dec!(100), true, true);
+}
+
 #[test]
 fn registered_validator_with_no_stake_does_not_become_part_of_validator_set_on_epoch_change() {
     // Arrange
@@ -1408,6 +1509,157 @@ fn increasing_validator_fee_takes_effect_after_configured_epochs_delay() {
     assert_eq!(event.proposals_missed, 0);
 }
 
+#[test]
+fn commission_tiers_take_effect_after_configured_epochs_delay() {
+    // Arrange
+    let genesis_epoch = Epoch::of(7);
+    let initial_epoch = genesis_epoch.next();
+    let fee_increase_delay_epochs = 2;
+    let initial_stake_amount = dec!("4000.0"); // big and round numbers
+    let emission_xrd_per_epoch = dec!("1000.0"); // to avoid rounding errors
+    let tiers = vec![
+        ValidatorCommissionTier {
+            min_stake_xrd: dec!("1000.0"),
+            fee_factor: dec!("0.25"),
+        },
+        ValidatorCommissionTier {
+            min_stake_xrd: dec!("1000000.0"),
+            fee_factor: dec!("0.1"),
+        },
+    ];
+    let validator_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let validator_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_key,
+        initial_stake_amount,
+        validator_account,
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config()
+            .with_total_emission_xrd_per_epoch(emission_xrd_per_epoch)
+            .with_num_fee_increase_delay_epochs(fee_increase_delay_epochs)
+            .with_epoch_change_condition(EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1, // deliberate, to go through rounds/epoch without gaps
+                target_duration_millis: 0,
+            }),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_key);
+
+    // Act: request the commission tiers
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            validator_account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT,
+            manifest_args!(tiers.clone()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_key)],
+    );
+
+    // Assert: the change is scheduled after the fee increase delay
+    let result = receipt.expect_commit_success();
+    let epoch_effective = initial_epoch.after(fee_increase_delay_epochs);
+    assert_eq!(
+        test_runner.extract_events_of_type::<UpdateCommissionTiersEvent>(result),
+        vec![UpdateCommissionTiersEvent {
+            tiers,
+            epoch_effective,
+        }]
+    );
+
+    // Act + Assert: the default `fee_factor = 1.0` stays effective until then
+    for _ in 0..fee_increase_delay_epochs {
+        let receipt = test_runner.advance_to_round(Round::of(1));
+        let event = test_runner
+            .extract_events_of_type::<ValidatorEmissionAppliedEvent>(
+                receipt.expect_commit_success(),
+            )
+            .pop()
+            .unwrap();
+        assert_eq!(event.validator_fee_xrd, emission_xrd_per_epoch);
+    }
+
+    // Act: change epoch
+    let receipt = test_runner.advance_to_round(Round::of(1));
+
+    // Assert: the tier matching the validator's stake determined the fee
+    let event = test_runner
+        .extract_events_of_type::<ValidatorEmissionAppliedEvent>(receipt.expect_commit_success())
+        .pop()
+        .unwrap();
+    assert_eq!(event.epoch, epoch_effective);
+    assert_eq!(event.validator_fee_xrd, dec!("250.0"));
+    assert_eq!(event.stake_pool_added_xrd, dec!("750.0"));
+}
+
+#[test]
+fn cannot_update_commission_tiers_with_unsorted_tiers() {
+    // Arrange
+    let validator_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let validator_account = ComponentAddress::virtual_account_from_public_key(&validator_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_key,
+        Decimal::one(),
+        validator_account,
+        Epoch::of(5),
+        CustomGenesis::default_consensus_manager_config(),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_key);
+
+    // Act
+    let tiers = vec![
+        ValidatorCommissionTier {
+            min_stake_xrd: dec!("1000.0"),
+            fee_factor: dec!("0.1"),
+        },
+        ValidatorCommissionTier {
+            min_stake_xrd: dec!("10.0"),
+            fee_factor: dec!("0.2"),
+        },
+    ];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            validator_account,
+            VALIDATOR_OWNER_BADGE,
+            [NonFungibleLocalId::bytes(validator_address.as_node_id().0).unwrap()],
+        )
+        .call_method(
+            validator_address,
+            VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT,
+            manifest_args!(tiers),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                ValidatorError::InvalidCommissionTiers
+            ))
+        )
+    });
+}
+
 fn create_custom_genesis(
     initial_epoch: Epoch,
     rounds_per_epoch: u64,
@@ -2021,6 +2273,140 @@ fn can_claim_unstake_after_epochs() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn pending_unstakes_are_listed_per_claimant_until_claimed() {
+    // Arrange
+    let genesis_epoch = Epoch::of(5);
+    let initial_epoch = genesis_epoch.next();
+    let num_unstake_epochs = 7;
+    let validator_pub_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let account_pub_key = Secp256k1PrivateKey::from_u64(1u64).unwrap().public_key();
+    let account_with_su = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_pub_key,
+        Decimal::from(10),
+        account_with_su,
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config()
+            .with_num_unstake_epochs(num_unstake_epochs),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_pub_key);
+    let validator_substate = test_runner.get_validator_info(validator_address);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account_with_su, validator_substate.stake_unit_resource, 1)
+        .take_all_from_worktop(validator_substate.stake_unit_resource, "stake_units_1")
+        .unstake_validator_for_claimant(validator_address, "stake_units_1", account_with_su)
+        .withdraw_from_account(account_with_su, validator_substate.stake_unit_resource, 2)
+        .take_all_from_worktop(validator_substate.stake_unit_resource, "stake_units")
+        .unstake_validator(validator_address, "stake_units")
+        .try_deposit_entire_worktop_or_abort(account_with_su, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&account_pub_key)],
+    );
+    receipt.expect_commit_success();
+    let claim_nft_vault =
+        test_runner.get_component_vaults(account_with_su, validator_substate.claim_nft)[0];
+    let claim_nft_ids: IndexSet<NonFungibleLocalId> = test_runner
+        .inspect_non_fungible_vault(claim_nft_vault)
+        .unwrap()
+        .1
+        .collect();
+    assert_eq!(claim_nft_ids.len(), 2);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                validator_address,
+                VALIDATOR_GET_PENDING_UNSTAKES_IDENT,
+                manifest_args!(account_with_su),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert: only the unstake made for the claimant is listed
+    let pending_unstakes: Vec<ValidatorPendingUnstake> = receipt.expect_commit_success().output(1);
+    assert_eq!(pending_unstakes.len(), 1);
+    assert!(claim_nft_ids.contains(&pending_unstakes[0].claim_nft_id));
+    assert_eq!(pending_unstakes[0].claim_amount, dec!(1));
+    assert!(pending_unstakes[0].claim_epoch > initial_epoch);
+
+    // Act: claim
+    test_runner.set_current_epoch(initial_epoch.after(1 + num_unstake_epochs));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account_with_su, validator_substate.claim_nft, 2)
+        .take_all_from_worktop(validator_substate.claim_nft, "unstake_receipts")
+        .claim_xrd(validator_address, "unstake_receipts")
+        .try_deposit_entire_worktop_or_abort(account_with_su, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&account_pub_key)],
+    );
+    receipt.expect_commit_success();
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                validator_address,
+                VALIDATOR_GET_PENDING_UNSTAKES_IDENT,
+                manifest_args!(account_with_su),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert: the claimed unstake is no longer pending
+    let pending_unstakes: Vec<ValidatorPendingUnstake> = receipt.expect_commit_success().output(1);
+    assert!(pending_unstakes.is_empty());
+}
+
+#[test]
+fn pending_unstakes_of_a_claimant_are_empty_without_unstakes_for_it() {
+    // Arrange
+    let genesis_epoch = Epoch::of(5);
+    let validator_pub_key = Secp256k1PrivateKey::from_u64(2u64).unwrap().public_key();
+    let account_pub_key = Secp256k1PrivateKey::from_u64(1u64).unwrap().public_key();
+    let account_with_su = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
+    let genesis = CustomGenesis::single_validator_and_staker(
+        validator_pub_key,
+        Decimal::from(10),
+        account_with_su,
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config(),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let validator_address = test_runner.get_active_validator_with_key(&validator_pub_key);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                validator_address,
+                VALIDATOR_GET_PENDING_UNSTAKES_IDENT,
+                manifest_args!(account_with_su),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let pending_unstakes: Vec<ValidatorPendingUnstake> = receipt.expect_commit_success().output(1);
+    assert!(pending_unstakes.is_empty());
+}
+
 #[test]
 fn owner_can_lock_stake_units() {
     // Arrange
@@ -3,7 +3,7 @@ use radix_engine::transaction::{
     execute_and_commit_transaction, CostingParameters, ExecutionConfig,
 };
 use radix_engine::types::*;
-use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmDebugCodeCache, WasmValidatorConfigV1};
 use radix_engine::vm::{DefaultNativeVm, NativeVm, NoExtension, ScryptoVm, Vm};
 use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
@@ -32,6 +32,8 @@ impl TransactionFuzzer {
         let scrypto_vm = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            debug_mode: false,
+            debug_code_cache: WasmDebugCodeCache::default(),
         };
         let native_vm = DefaultNativeVm::new();
         let vm = Vm::new(&scrypto_vm, native_vm.clone());
@@ -1,5 +1,5 @@
 use radix_engine::{
-    errors::{ApplicationError, RuntimeError},
+    errors::{ApplicationError, RuntimeError, VmError},
     transaction::TransactionReceipt,
     types::*,
 };
@@ -108,3 +108,46 @@ fn test_assert_length_5() {
         })
     }
 }
+
+#[test]
+fn test_rust_panic_in_debug_mode_has_backtrace() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().with_debug_mode().build();
+    let (code, definition) = Compile::compile_with_debug_info("./tests/blueprints/logger");
+    let package_address =
+        test_runner.publish_package(code, definition, BTreeMap::new(), OwnerRole::None);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "Logger",
+            "rust_panic",
+            manifest_args!("Hey".to_owned()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::VmError(VmError::WithBacktrace { error, backtrace }) => {
+            matches!(
+                error.as_ref(),
+                RuntimeError::ApplicationError(ApplicationError::PanicMessage(e))
+                    if e.eq("Hey @ logger/src/lib.rs:15:13")
+            ) && backtrace.package_address == package_address
+                && backtrace.frames.iter().any(|frame| {
+                    frame
+                        .function_name
+                        .as_ref()
+                        .map_or(false, |name| name.contains("Logger_rust_panic"))
+                })
+                && backtrace.frames.iter().any(|frame| {
+                    frame.location.as_ref().map_or(false, |(file, line)| {
+                        file.ends_with("logger/src/lib.rs") && *line == 15
+                    })
+                })
+        }
+        _ => false,
+    })
+}
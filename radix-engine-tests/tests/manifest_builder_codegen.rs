@@ -0,0 +1,48 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+include!(concat!(env!("OUT_DIR"), "/manifest_builder_codegen.rs"));
+
+#[test]
+fn generated_manifest_builder_methods_call_the_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address =
+        test_runner.compile_and_publish("./tests/blueprints/manifest_builder_codegen");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .badge_checker_instantiate(package_address, package_address, dec!(1))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(10))
+        .take_all_from_worktop(XRD, "xrd")
+        .badge_checker_deposit(component_address, "xrd")
+        .create_proof_from_account_of_amount(account, XRD, dec!(1))
+        .pop_from_auth_zone("proof1")
+        .create_proof_from_account_of_amount(account, XRD, dec!(1))
+        .pop_from_auth_zone("proof2")
+        .badge_checker_count_proofs(
+            component_address,
+            vec!["proof1", "proof2"],
+            component_address,
+        )
+        .badge_checker_origin(component_address)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.output::<usize>(8), 2);
+    assert_eq!(commit.output::<PackageAddress>(9), package_address);
+}
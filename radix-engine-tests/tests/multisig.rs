@@ -0,0 +1,448 @@
+use radix_engine::blueprints::multisig::*;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::multisig::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn proposal_is_executed_with_the_multisig_as_the_global_caller_once_the_threshold_is_met() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 60);
+    env.approve(proposal_id, 1).expect_commit_success();
+
+    // Act
+    let receipt = env.execute(proposal_id, 2);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        env.test_runner
+            .get_component_balance(env.destination, env.resource_address),
+        dec!(100)
+    );
+    assert_eq!(
+        env.test_runner
+            .get_component_balance(env.accounts[2], env.resource_address),
+        dec!(0)
+    );
+}
+
+#[test]
+fn signers_and_threshold_can_be_updated_through_a_proposal() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose(
+        0,
+        env.multisig,
+        MULTISIG_UPDATE_SIGNERS_IDENT,
+        MultisigUpdateSignersManifestInput {
+            signers: btreeset!(env.badges[0].clone(), env.badges[1].clone()),
+            threshold: 1,
+        },
+        60,
+    );
+    env.approve(proposal_id, 1).expect_commit_success();
+
+    // Act
+    let receipt = env.execute(proposal_id, 1);
+
+    // Assert
+    receipt.expect_commit_success();
+    let proposal_id = env.propose_mint(0, 60);
+    env.execute(proposal_id, 0).expect_commit_success();
+    env.approve(proposal_id, 2).expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::NotASigner {
+                signer: env.badges[2].clone(),
+            },
+        ))
+    });
+}
+
+#[test]
+fn updating_the_signers_to_an_unreachable_threshold_is_rejected() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose(
+        0,
+        env.multisig,
+        MULTISIG_UPDATE_SIGNERS_IDENT,
+        MultisigUpdateSignersManifestInput {
+            signers: btreeset!(env.badges[0].clone()),
+            threshold: 2,
+        },
+        60,
+    );
+    env.approve(proposal_id, 1).expect_commit_success();
+
+    // Act
+    let receipt = env.execute(proposal_id, 1);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::InvalidThreshold {
+                threshold: 2,
+                number_of_signers: 1,
+            },
+        ))
+    });
+}
+
+#[test]
+fn signers_cannot_be_updated_without_a_proposal() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            env.multisig,
+            MULTISIG_UPDATE_SIGNERS_IDENT,
+            MultisigUpdateSignersManifestInput {
+                signers: btreeset!(env.badges[0].clone()),
+                threshold: 1,
+            },
+        )
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, env.badges.clone());
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn destination_can_be_changed_through_a_proposal() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let (_, _, new_destination) = env.test_runner.new_allocated_account();
+    let proposal_id = env.propose(
+        0,
+        env.multisig,
+        MULTISIG_SET_DESTINATION_IDENT,
+        MultisigSetDestinationManifestInput {
+            destination: new_destination,
+        },
+        60,
+    );
+    env.approve(proposal_id, 1).expect_commit_success();
+    env.execute(proposal_id, 1).expect_commit_success();
+    let proposal_id = env.propose_mint(0, 60);
+    env.approve(proposal_id, 1).expect_commit_success();
+
+    // Act
+    let receipt = env.execute(proposal_id, 1);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        env.test_runner
+            .get_component_balance(new_destination, env.resource_address),
+        dec!(100)
+    );
+    assert_eq!(
+        env.test_runner
+            .get_component_balance(env.destination, env.resource_address),
+        dec!(0)
+    );
+}
+
+#[test]
+fn proposal_cannot_be_executed_below_the_threshold() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 60);
+
+    // Act
+    let receipt = env.execute(proposal_id, 0);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::ThresholdNotMet {
+                approvals: 1,
+                threshold: 2,
+            },
+        ))
+    });
+}
+
+#[test]
+fn proposal_cannot_be_executed_twice() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 60);
+    env.approve(proposal_id, 1).expect_commit_success();
+    env.execute(proposal_id, 1).expect_commit_success();
+
+    // Act
+    let receipt = env.execute(proposal_id, 2);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::ProposalAlreadyExecuted { proposal_id },
+        ))
+    });
+}
+
+#[test]
+fn expired_proposal_cannot_be_approved() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 10);
+
+    // Act
+    env.advance_time_by_minutes(10);
+    let receipt = env.approve(proposal_id, 1);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::ProposalExpired { proposal_id },
+        ))
+    });
+}
+
+#[test]
+fn approvals_are_collected_across_transactions() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 60);
+
+    // Act
+    env.approve(proposal_id, 1).expect_commit_success();
+    env.approve(proposal_id, 1).expect_commit_success();
+
+    // Assert
+    let proposal: Option<MultisigProposal> = env
+        .test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .call_method(
+                    env.multisig,
+                    MULTISIG_GET_PROPOSAL_IDENT,
+                    MultisigGetProposalManifestInput { proposal_id },
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(
+        proposal.unwrap().approvals,
+        btreeset!(env.badges[0].clone(), env.badges[1].clone())
+    );
+}
+
+#[test]
+fn non_signer_cannot_approve() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 60);
+    let (public_key, _, _) = env.test_runner.new_allocated_account();
+    let outsider_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let manifest = ManifestBuilder::new()
+        .approve_multisig_proposal(env.multisig, proposal_id, outsider_badge.clone())
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![outsider_badge.clone()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::NotASigner {
+                signer: outsider_badge.clone(),
+            },
+        ))
+    });
+}
+
+#[test]
+fn signer_cannot_approve_on_behalf_of_another_signer() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let proposal_id = env.propose_mint(0, 60);
+    let manifest = ManifestBuilder::new()
+        .approve_multisig_proposal(env.multisig, proposal_id, env.badges[2].clone())
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![env.badges[1].clone()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+    });
+}
+
+#[test]
+fn multisig_with_threshold_above_the_number_of_signers_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .create_multisig(
+            [NonFungibleGlobalId::from_public_key(&public_key)],
+            2,
+            account,
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::MultisigError(
+            MultisigError::InvalidThreshold {
+                threshold: 2,
+                number_of_signers: 1,
+            },
+        ))
+    });
+}
+
+struct TestEnvironment {
+    test_runner: DefaultTestRunner,
+    accounts: Vec<ComponentAddress>,
+    badges: Vec<NonFungibleGlobalId>,
+    destination: ComponentAddress,
+    multisig: ComponentAddress,
+    resource_address: ResourceAddress,
+}
+
+impl TestEnvironment {
+    /// Creates a two-of-three multisig that deposits into its own account and a resource that
+    /// only the multisig can mint.
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let mut accounts = Vec::new();
+        let mut badges = Vec::new();
+        for _ in 0..3 {
+            let (public_key, _, account) = test_runner.new_allocated_account();
+            accounts.push(account);
+            badges.push(NonFungibleGlobalId::from_public_key(&public_key));
+        }
+        let (_, _, destination) = test_runner.new_allocated_account();
+
+        let multisig = test_runner
+            .execute_manifest_ignoring_fee(
+                ManifestBuilder::new()
+                    .create_multisig(badges.clone(), 2, destination)
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        let resource_address = test_runner
+            .execute_manifest_ignoring_fee(
+                ManifestBuilder::new()
+                    .create_fungible_resource(
+                        OwnerRole::None,
+                        true,
+                        18,
+                        FungibleResourceRoles {
+                            mint_roles: mint_roles! {
+                                minter => rule!(require(global_caller(multisig)));
+                                minter_updater => rule!(deny_all);
+                            },
+                            ..Default::default()
+                        },
+                        metadata!(),
+                        None,
+                    )
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_resource_addresses()[0];
+
+        Self {
+            test_runner,
+            accounts,
+            badges,
+            destination,
+            multisig,
+            resource_address,
+        }
+    }
+
+    fn propose_mint(&mut self, signer: usize, expires_in_minutes: i64) -> u64 {
+        self.propose(
+            signer,
+            self.resource_address,
+            FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT,
+            manifest_args!(dec!(100)),
+            expires_in_minutes,
+        )
+    }
+
+    fn propose(
+        &mut self,
+        signer: usize,
+        target: impl Into<GlobalAddress>,
+        method: &str,
+        args: impl ResolvableArguments,
+        expires_in_minutes: i64,
+    ) -> u64 {
+        let expires_at = self
+            .test_runner
+            .get_current_time(TimePrecision::Minute)
+            .add_minutes(expires_in_minutes)
+            .unwrap();
+        let manifest = ManifestBuilder::new()
+            .propose_to_multisig(
+                self.multisig,
+                self.badges[signer].clone(),
+                target,
+                method,
+                args,
+                expires_at,
+            )
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.badges[signer].clone()])
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn approve(&mut self, proposal_id: u64, signer: usize) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .approve_multisig_proposal(self.multisig, proposal_id, self.badges[signer].clone())
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.badges[signer].clone()])
+    }
+
+    fn execute(&mut self, proposal_id: u64, signer: usize) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .execute_multisig_proposal(self.multisig, proposal_id, self.badges[signer].clone())
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.badges[signer].clone()])
+    }
+
+    fn advance_time_by_minutes(&mut self, minutes: i64) {
+        let round = self.test_runner.get_consensus_manager_state().round;
+        let timestamp_ms = self.test_runner.get_current_proposer_timestamp_ms();
+        self.test_runner
+            .advance_to_round_at_timestamp(
+                Round::of(round.number() + 1),
+                timestamp_ms + minutes * 60 * 1000,
+            )
+            .expect_commit_success();
+    }
+}
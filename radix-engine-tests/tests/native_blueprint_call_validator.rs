@@ -60,6 +60,86 @@ fn validator_invalidates_calls_to_unknown_methods_on_a_known_blueprint() {
     assert!(is_method_not_found(validation_result))
 }
 
+#[test]
+fn validator_sees_native_blueprint_builder_methods_as_valid() {
+    // Arrange
+    let access_controller =
+        ComponentAddress::new_or_panic([EntityType::GlobalAccessController as u8; NodeId::LENGTH]);
+    let one_resource_pool =
+        ComponentAddress::new_or_panic([EntityType::GlobalOneResourcePool as u8; NodeId::LENGTH]);
+    let two_resource_pool =
+        ComponentAddress::new_or_panic([EntityType::GlobalTwoResourcePool as u8; NodeId::LENGTH]);
+    let multi_resource_pool =
+        ComponentAddress::new_or_panic([EntityType::GlobalMultiResourcePool as u8; NodeId::LENGTH]);
+    let rule_set = RuleSet {
+        primary_role: rule!(allow_all),
+        recovery_role: rule!(allow_all),
+        confirmation_role: rule!(allow_all),
+    };
+    let badge = ResourceOrNonFungible::Resource(XRD);
+
+    let manifest = ManifestBuilder::new()
+        .securify_account(account1())
+        .set_default_deposit_rule(account1(), DefaultDepositRule::AllowExisting)
+        .set_resource_preference(account1(), XRD, ResourcePreference::Allowed)
+        .remove_resource_preference(account1(), XRD)
+        .add_authorized_depositor(account1(), badge.clone())
+        .remove_authorized_depositor(account1(), badge)
+        .create_proof_from_access_controller(access_controller)
+        .initiate_recovery_as_primary(access_controller, rule_set.clone(), None)
+        .initiate_recovery_as_recovery(access_controller, rule_set.clone(), Some(10))
+        .initiate_badge_withdraw_attempt_as_primary(access_controller)
+        .initiate_badge_withdraw_attempt_as_recovery(access_controller)
+        .quick_confirm_primary_role_recovery_proposal(access_controller, rule_set.clone(), None)
+        .quick_confirm_recovery_role_recovery_proposal(access_controller, rule_set.clone(), None)
+        .quick_confirm_primary_role_badge_withdraw_attempt(access_controller)
+        .quick_confirm_recovery_role_badge_withdraw_attempt(access_controller)
+        .timed_confirm_recovery(access_controller, rule_set.clone(), None)
+        .cancel_primary_role_recovery_proposal(access_controller)
+        .cancel_recovery_role_recovery_proposal(access_controller)
+        .cancel_primary_role_badge_withdraw_attempt(access_controller)
+        .cancel_recovery_role_badge_withdraw_attempt(access_controller)
+        .lock_primary_role(access_controller)
+        .unlock_primary_role(access_controller)
+        .stop_timed_recovery(access_controller, rule_set, None)
+        .mint_recovery_badges(access_controller, [NonFungibleLocalId::integer(1)])
+        .create_one_resource_pool(OwnerRole::None, rule!(allow_all), XRD)
+        .create_two_resource_pool(
+            OwnerRole::None,
+            rule!(allow_all),
+            (XRD, ACCOUNT_OWNER_BADGE),
+        )
+        .create_multi_resource_pool(OwnerRole::None, rule!(allow_all), [XRD])
+        .withdraw_from_account(account1(), XRD, dec!("10"))
+        .take_from_worktop(XRD, dec!("1"), "one")
+        .contribute_to_one_resource_pool(one_resource_pool, "one")
+        .take_from_worktop(XRD, dec!("1"), "two_a")
+        .take_from_worktop(XRD, dec!("1"), "two_b")
+        .contribute_to_two_resource_pool(two_resource_pool, ("two_a", "two_b"))
+        .take_from_worktop(XRD, dec!("1"), "multi")
+        .contribute_to_multi_resource_pool(multi_resource_pool, ["multi"])
+        .take_from_worktop(XRD, dec!("1"), "redeem")
+        .redeem_pool_units(two_resource_pool, "redeem")
+        .take_from_worktop(XRD, dec!("1"), "deposit")
+        .protected_deposit_to_pool(multi_resource_pool, "deposit")
+        .protected_withdraw_from_one_resource_pool(
+            one_resource_pool,
+            dec!("1"),
+            WithdrawStrategy::Exact,
+        )
+        .protected_withdraw_from_pool(two_resource_pool, XRD, dec!("1"), WithdrawStrategy::Exact)
+        .try_deposit_entire_worktop_or_abort(account1(), None)
+        .build();
+
+    // Act
+    let validation_result = validate_call_arguments_to_native_components(&manifest.instructions);
+
+    // Assert
+    validation_result
+        .clone()
+        .expect(format!("Validation failed: {:?}", validation_result).as_str())
+}
+
 #[test]
 fn common_manifests_are_all_valid() {
     // Arrange
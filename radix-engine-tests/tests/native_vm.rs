@@ -80,6 +80,7 @@ fn panics_can_be_caught_in_the_native_vm_and_converted_into_results() {
             AuthZoneParams {
                 initial_proofs: Default::default(),
                 virtual_resources: Default::default(),
+                subintent_initial_proofs: Default::default(),
             },
             SystemLoanFeeReserve::default(),
             FeeTable::new(),
@@ -862,3 +862,257 @@ fn cant_burn_non_fungible_with_wrong_non_fungible_local_id_type() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+#[derive(ManifestSbor, ScryptoSbor, NonFungibleData)]
+pub struct Ticket {
+    pub event: String,
+    #[mutable]
+    pub seat: u32,
+    #[mutable]
+    pub holder: String,
+    #[mutable]
+    pub stamps: BTreeMap<String, u32>,
+}
+
+fn create_ticket_resource(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            false,
+            NonFungibleResourceRoles {
+                non_fungible_data_update_roles: non_fungible_data_update_roles! {
+                    non_fungible_data_updater => rule!(allow_all);
+                    non_fungible_data_updater_updater => rule!(deny_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            Some([(
+                NonFungibleLocalId::integer(1),
+                Ticket {
+                    event: "concert".to_string(),
+                    seat: 10,
+                    holder: "alice".to_string(),
+                    stamps: btreemap!("entry".to_string() => 1u32),
+                },
+            )]),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_resource_addresses()[0]
+}
+
+#[test]
+fn can_update_several_non_fungible_data_fields_in_one_call() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .update_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec![
+                NonFungibleDataManifestUpdate::set("seat", 42u32).unwrap(),
+                NonFungibleDataManifestUpdate::set("holder", "bob".to_string()).unwrap(),
+            ],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let ticket: Ticket =
+        test_runner.get_non_fungible_data(resource_address, NonFungibleLocalId::integer(1));
+    assert_eq!(ticket.seat, 42);
+    assert_eq!(ticket.holder, "bob");
+    assert_eq!(ticket.event, "concert");
+}
+
+#[test]
+fn can_insert_entries_into_non_fungible_data_map_field() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .update_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec![NonFungibleDataManifestUpdate::insert_map_entries(
+                "stamps",
+                [("entry".to_string(), 2u32), ("merch".to_string(), 1u32)],
+            )
+            .unwrap()],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let ticket: Ticket =
+        test_runner.get_non_fungible_data(resource_address, NonFungibleLocalId::integer(1));
+    assert_eq!(
+        ticket.stamps,
+        btreemap!("entry".to_string() => 2u32, "merch".to_string() => 1u32)
+    );
+}
+
+#[test]
+fn cannot_update_immutable_non_fungible_data_field_in_batch() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .update_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec![
+                NonFungibleDataManifestUpdate::set("seat", 42u32).unwrap(),
+                NonFungibleDataManifestUpdate::set("event", "festival".to_string()).unwrap(),
+            ],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::UnknownMutableFieldName(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_insert_map_entries_into_non_map_field() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .update_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec![NonFungibleDataManifestUpdate::insert_map_entries(
+                "holder",
+                [("entry".to_string(), 2u32)],
+            )
+            .unwrap()],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::FieldIsNotAMap(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_update_non_fungible_data_fields_with_data_not_matching_schema() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .update_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec![NonFungibleDataManifestUpdate::set("seat", "front row".to_string()).unwrap()],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::TypeCheckError(
+                TypeCheckError::BlueprintPayloadValidationError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn can_get_subset_of_non_fungible_data_fields() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec!["holder".to_string(), "event".to_string()],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let (holder, event): (String, String) = receipt.expect_commit_success().output(1);
+    assert_eq!(holder, "alice");
+    assert_eq!(event, "concert");
+}
+
+#[test]
+fn cannot_get_unknown_non_fungible_data_field() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_ticket_resource(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_non_fungible_data_fields(
+            resource_address,
+            NonFungibleLocalId::integer(1),
+            vec!["price".to_string()],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::UnknownFieldName(..)
+            ))
+        )
+    });
+}
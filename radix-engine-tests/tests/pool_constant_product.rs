@@ -0,0 +1,781 @@
+use radix_engine::blueprints::pool::constant_product_pool::*;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::{BalanceChange, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::pool::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+pub fn constant_product_pool_can_be_instantiated() {
+    TestEnvironment::new((18, 18), default_fees());
+}
+
+#[test]
+fn creating_a_pool_with_invalid_fees_fails() {
+    for fees in [
+        ConstantProductPoolFees {
+            fee_rate: dec!("1"),
+            protocol_fee_share: dec!("0"),
+        },
+        ConstantProductPoolFees {
+            fee_rate: dec!("-0.01"),
+            protocol_fee_share: dec!("0"),
+        },
+        ConstantProductPoolFees {
+            fee_rate: dec!("0.01"),
+            protocol_fee_share: dec!("1.1"),
+        },
+    ] {
+        // Arrange
+        let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+        let (_, _, account) = test_runner.new_account(false);
+        let fungible_resource = test_runner.create_fungible_resource(100.into(), 18, account);
+
+        // Act
+        let manifest = ManifestBuilder::new()
+            .call_function(
+                POOL_PACKAGE,
+                CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT,
+                CONSTANT_PRODUCT_POOL_INSTANTIATE_IDENT,
+                ConstantProductPoolInstantiateManifestInput {
+                    owner_role: OwnerRole::None,
+                    pool_manager_rule: rule!(allow_all),
+                    resource_addresses: (XRD, fungible_resource),
+                    fees,
+                    address_reservation: None,
+                },
+            )
+            .build();
+        let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+        // Assert
+        receipt.expect_specific_failure(|error| {
+            matches!(
+                error,
+                RuntimeError::ApplicationError(ApplicationError::ConstantProductPoolError(
+                    ConstantProductPoolError::InvalidFees { .. }
+                ))
+            )
+        });
+    }
+}
+
+#[test]
+fn creating_a_pool_with_non_fungible_resources_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (_, _, account) = test_runner.new_account(false);
+
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            POOL_PACKAGE,
+            CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT,
+            CONSTANT_PRODUCT_POOL_INSTANTIATE_IDENT,
+            ConstantProductPoolInstantiateManifestInput {
+                owner_role: OwnerRole::None,
+                pool_manager_rule: rule!(allow_all),
+                resource_addresses: (non_fungible_resource, XRD),
+                fees: default_fees(),
+                address_reservation: None,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::ConstantProductPoolError(
+                ConstantProductPoolError::NonFungibleResourcesAreNotAccepted { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn contribution_provides_expected_pool_units_and_change() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 100),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.contribute(
+        (test_runner.pool_resource1, 50),
+        (test_runner.pool_resource2, 100),
+    );
+
+    // Assert
+    let account_balance_changes = test_runner.test_runner.sum_descendant_balance_changes(
+        receipt.expect_commit_success(),
+        test_runner.account_component_address.as_node_id(),
+    );
+    assert_eq!(
+        account_balance_changes
+            .get(&test_runner.pool_unit_resource_address)
+            .cloned(),
+        Some(BalanceChange::Fungible(50.into()))
+    );
+    assert_eq!(
+        account_balance_changes
+            .get(&test_runner.pool_resource1)
+            .cloned(),
+        None
+    );
+    assert_eq!(
+        account_balance_changes
+            .get(&test_runner.pool_resource2)
+            .cloned(),
+        Some(BalanceChange::Fungible(50.into()))
+    );
+}
+
+#[test]
+fn contributing_tokens_that_do_not_belong_to_the_pool_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+    let resource_address = test_runner
+        .test_runner
+        .create_freely_mintable_and_burnable_fungible_resource(
+            OwnerRole::None,
+            None,
+            18,
+            test_runner.account_component_address,
+        );
+
+    // Act
+    let receipt =
+        test_runner.contribute((resource_address, 100), (test_runner.pool_resource2, 100));
+
+    // Assert
+    receipt.expect_specific_failure(
+        is_constant_product_pool_resource_does_not_belong_to_the_pool_error,
+    );
+}
+
+#[test]
+fn redemption_returns_pro_rata_share_of_reserves() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((2, 2), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, dec!("2.22")),
+            (test_runner.pool_resource2, dec!("8.88")),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.redeem(dec!("2.22"));
+
+    // Assert
+    let RedemptionEvent {
+        pool_unit_tokens_redeemed,
+        redeemed_resources,
+    } = test_runner.find_event(&receipt, "RedemptionEvent");
+    assert_eq!(pool_unit_tokens_redeemed, dec!("2.22"));
+    assert_eq!(
+        redeemed_resources,
+        btreemap!(
+            test_runner.pool_resource1 => dec!("1.11"),
+            test_runner.pool_resource2 => dec!("4.44"),
+        )
+    );
+}
+
+#[test]
+fn swap_provides_expected_output_and_splits_fee() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((2, 2), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 1000),
+            (test_runner.pool_resource2, 1000),
+        )
+        .expect_commit_success();
+    let expected_output = test_runner.get_swap_output_amount(test_runner.pool_resource1, 100);
+
+    // Act
+    let receipt = test_runner.swap(test_runner.pool_resource1, 100, 0);
+
+    // Assert
+    assert_eq!(expected_output, dec!("90.08"));
+    let account_balance_changes = test_runner.test_runner.sum_descendant_balance_changes(
+        receipt.expect_commit_success(),
+        test_runner.account_component_address.as_node_id(),
+    );
+    assert_eq!(
+        account_balance_changes
+            .get(&test_runner.pool_resource2)
+            .cloned(),
+        Some(BalanceChange::Fungible(expected_output))
+    );
+
+    let SwapEvent {
+        input_resource_address,
+        input_amount,
+        output_resource_address,
+        output_amount,
+        lp_fee_amount,
+        protocol_fee_amount,
+    } = test_runner.find_event(&receipt, "SwapEvent");
+    assert_eq!(input_resource_address, test_runner.pool_resource1);
+    assert_eq!(input_amount, dec!("100"));
+    assert_eq!(output_resource_address, test_runner.pool_resource2);
+    assert_eq!(output_amount, dec!("90.08"));
+    assert_eq!(lp_fee_amount, dec!("0.5"));
+    assert_eq!(protocol_fee_amount, dec!("0.5"));
+
+    // The liquidity provider share of the fee stays in the reserves while the protocol share does
+    // not.
+    assert_eq!(
+        test_runner.get_vault_amounts(),
+        btreemap!(
+            test_runner.pool_resource1 => dec!("1099.5"),
+            test_runner.pool_resource2 => dec!("909.92"),
+        )
+    );
+}
+
+#[test]
+fn swap_fails_when_output_is_below_minimum() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((2, 2), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 1000),
+            (test_runner.pool_resource2, 1000),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.swap(test_runner.pool_resource1, 100, 91);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::ConstantProductPoolError(
+                ConstantProductPoolError::SlippageToleranceExceeded { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn swap_of_empty_pool_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+
+    // Act
+    let receipt = test_runner.swap(test_runner.pool_resource1, 100, 0);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::ConstantProductPoolError(
+                ConstantProductPoolError::PoolIsEmpty
+            ))
+        )
+    });
+}
+
+#[test]
+fn swapping_tokens_that_do_not_belong_to_the_pool_fails() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+    let resource_address = test_runner
+        .test_runner
+        .create_freely_mintable_and_burnable_fungible_resource(
+            OwnerRole::None,
+            None,
+            18,
+            test_runner.account_component_address,
+        );
+
+    // Act
+    let receipt = test_runner.swap(resource_address, 100, 0);
+
+    // Assert
+    receipt.expect_specific_failure(
+        is_constant_product_pool_resource_does_not_belong_to_the_pool_error,
+    );
+}
+
+#[test]
+fn protocol_fees_can_be_claimed_by_pool_manager() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((2, 2), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 1000),
+            (test_runner.pool_resource2, 1000),
+        )
+        .expect_commit_success();
+    test_runner
+        .swap(test_runner.pool_resource1, 100, 0)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.claim_protocol_fees(true);
+
+    // Assert
+    let account_balance_changes = test_runner.test_runner.sum_descendant_balance_changes(
+        receipt.expect_commit_success(),
+        test_runner.account_component_address.as_node_id(),
+    );
+    assert_eq!(
+        account_balance_changes
+            .get(&test_runner.pool_resource1)
+            .cloned(),
+        Some(BalanceChange::Fungible(dec!("0.5")))
+    );
+
+    let ProtocolFeesClaimedEvent { claimed_resources } =
+        test_runner.find_event(&receipt, "ProtocolFeesClaimedEvent");
+    assert_eq!(
+        claimed_resources,
+        btreemap!(
+            test_runner.pool_resource1 => dec!("0.5"),
+            test_runner.pool_resource2 => dec!("0"),
+        )
+    );
+}
+
+#[test]
+fn claiming_protocol_fees_fails_without_proper_authority_present() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+
+    // Act
+    let receipt = test_runner.claim_protocol_fees(false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error)
+}
+
+#[test]
+fn updated_fees_apply_to_subsequent_swaps() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((2, 2), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 1000),
+            (test_runner.pool_resource2, 1000),
+        )
+        .expect_commit_success();
+    let fees = ConstantProductPoolFees {
+        fee_rate: dec!("0"),
+        protocol_fee_share: dec!("0"),
+    };
+
+    // Act
+    let receipt = test_runner.set_fees(fees, true);
+
+    // Assert
+    receipt.expect_commit_success();
+    let FeesUpdatedEvent { fees: emitted_fees } =
+        test_runner.find_event(&receipt, "FeesUpdatedEvent");
+    assert_eq!(emitted_fees, fees);
+    // 1000 * 100 / 1100 = 90.909090...
+    assert_eq!(
+        test_runner.get_swap_output_amount(test_runner.pool_resource1, 100),
+        dec!("90.9")
+    );
+}
+
+#[test]
+fn setting_fees_fails_without_proper_authority_present() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+
+    // Act
+    let receipt = test_runner.set_fees(default_fees(), false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error)
+}
+
+#[test]
+fn price_accumulators_advance_with_time() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 400),
+        )
+        .expect_commit_success();
+    let initial_accumulators = test_runner.get_price_accumulators();
+
+    // Act
+    test_runner.advance_time_by_minutes(10);
+    let accumulators = test_runner.get_price_accumulators();
+
+    // Assert
+    assert_eq!(initial_accumulators.price1_cumulative, dec!("0"));
+    assert_eq!(initial_accumulators.price2_cumulative, dec!("0"));
+    assert_eq!(accumulators.price1_cumulative, dec!("2400"));
+    assert_eq!(accumulators.price2_cumulative, dec!("150"));
+    assert_eq!(
+        accumulators.last_update,
+        test_runner
+            .test_runner
+            .get_current_time(TimePrecision::Minute)
+    );
+}
+
+#[test]
+fn price_accumulators_use_reserves_from_before_swap() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, 100),
+            (test_runner.pool_resource2, 400),
+        )
+        .expect_commit_success();
+    test_runner.advance_time_by_minutes(1);
+
+    // Act
+    test_runner
+        .swap(test_runner.pool_resource1, 100, 0)
+        .expect_commit_success();
+    let accumulators = test_runner.get_price_accumulators();
+
+    // Assert
+    assert_eq!(accumulators.price1_cumulative, dec!("240"));
+    assert_eq!(accumulators.price2_cumulative, dec!("15"));
+}
+
+#[test]
+fn redemption_succeeds_when_price_accumulators_overflow() {
+    // Arrange
+    let mut test_runner = TestEnvironment::new((18, 18), default_fees());
+    test_runner
+        .contribute(
+            (test_runner.pool_resource1, dec!("0.000000000000000001")),
+            (
+                test_runner.pool_resource2,
+                dec!("1000000000000000000000000000000"),
+            ),
+        )
+        .expect_commit_success();
+    test_runner.advance_time_by_minutes(10);
+    let pool_units = test_runner.test_runner.get_component_balance(
+        test_runner.account_component_address,
+        test_runner.pool_unit_resource_address,
+    );
+
+    // Act
+    let receipt = test_runner.redeem(pool_units);
+
+    // Assert
+    receipt.expect_commit_success();
+    let accumulators = test_runner.get_price_accumulators();
+    assert_eq!(accumulators.price1_cumulative, Decimal::MAX);
+    assert_eq!(accumulators.price2_cumulative, dec!("0"));
+}
+
+fn default_fees() -> ConstantProductPoolFees {
+    ConstantProductPoolFees {
+        fee_rate: dec!("0.01"),
+        protocol_fee_share: dec!("0.5"),
+    }
+}
+
+struct TestEnvironment {
+    test_runner: DefaultTestRunner,
+
+    pool_component_address: ComponentAddress,
+    pool_unit_resource_address: ResourceAddress,
+
+    pool_resource1: ResourceAddress,
+    pool_resource2: ResourceAddress,
+
+    account_public_key: PublicKey,
+    account_component_address: ComponentAddress,
+}
+
+impl TestEnvironment {
+    pub fn new((divisibility1, divisibility2): (u8, u8), fees: ConstantProductPoolFees) -> Self {
+        let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+        let (public_key, _, account) = test_runner.new_account(false);
+        let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
+
+        let pool_resource1 = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+            OwnerRole::None,
+            None,
+            divisibility1,
+            account,
+        );
+        let pool_resource2 = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+            OwnerRole::None,
+            None,
+            divisibility2,
+            account,
+        );
+
+        let (pool_component, pool_unit_resource) = {
+            let manifest = ManifestBuilder::new()
+                .call_function(
+                    POOL_PACKAGE,
+                    CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT,
+                    CONSTANT_PRODUCT_POOL_INSTANTIATE_IDENT,
+                    ConstantProductPoolInstantiateManifestInput {
+                        owner_role: OwnerRole::None,
+                        pool_manager_rule: rule!(require(virtual_signature_badge)),
+                        resource_addresses: (pool_resource1, pool_resource2),
+                        fees,
+                        address_reservation: None,
+                    },
+                )
+                .build();
+            let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+            let commit_result = receipt.expect_commit_success();
+
+            (
+                commit_result.new_component_addresses()[0],
+                commit_result.new_resource_addresses()[0],
+            )
+        };
+
+        Self {
+            test_runner,
+            pool_component_address: pool_component,
+            pool_unit_resource_address: pool_unit_resource,
+            pool_resource1,
+            pool_resource2,
+            account_public_key: public_key.into(),
+            account_component_address: account,
+        }
+    }
+
+    pub fn contribute<A, B>(
+        &mut self,
+        (resource_address1, amount1): (ResourceAddress, A),
+        (resource_address2, amount2): (ResourceAddress, B),
+    ) -> TransactionReceipt
+    where
+        A: Into<Decimal>,
+        B: Into<Decimal>,
+    {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(resource_address1, amount1.into())
+            .mint_fungible(resource_address2, amount2.into())
+            .take_all_from_worktop(resource_address1, "resource_1")
+            .take_all_from_worktop(resource_address2, "resource_2")
+            .with_name_lookup(|builder, lookup| {
+                let bucket1 = lookup.bucket("resource_1");
+                let bucket2 = lookup.bucket("resource_2");
+                builder.call_method(
+                    self.pool_component_address,
+                    CONSTANT_PRODUCT_POOL_CONTRIBUTE_IDENT,
+                    ConstantProductPoolContributeManifestInput {
+                        buckets: (bucket1, bucket2),
+                    },
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
+            .build();
+        self.execute_manifest(manifest, false)
+    }
+
+    fn redeem<D: Into<Decimal>>(&mut self, amount: D) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                self.account_component_address,
+                self.pool_unit_resource_address,
+                amount.into(),
+            )
+            .take_all_from_worktop(self.pool_unit_resource_address, "pool_units")
+            .with_name_lookup(|builder, lookup| {
+                let bucket = lookup.bucket("pool_units");
+                builder.call_method(
+                    self.pool_component_address,
+                    CONSTANT_PRODUCT_POOL_REDEEM_IDENT,
+                    ConstantProductPoolRedeemManifestInput { bucket },
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
+            .build();
+        self.execute_manifest(manifest, true)
+    }
+
+    fn swap<A, M>(
+        &mut self,
+        input_resource_address: ResourceAddress,
+        input_amount: A,
+        min_output_amount: M,
+    ) -> TransactionReceipt
+    where
+        A: Into<Decimal>,
+        M: Into<Decimal>,
+    {
+        let manifest = ManifestBuilder::new()
+            .mint_fungible(input_resource_address, input_amount.into())
+            .take_all_from_worktop(input_resource_address, "input")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_method(
+                    self.pool_component_address,
+                    CONSTANT_PRODUCT_POOL_SWAP_IDENT,
+                    ConstantProductPoolSwapManifestInput {
+                        input_bucket: lookup.bucket("input"),
+                        min_output_amount: min_output_amount.into(),
+                    },
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
+            .build();
+        self.execute_manifest(manifest, false)
+    }
+
+    fn set_fees(&mut self, fees: ConstantProductPoolFees, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                CONSTANT_PRODUCT_POOL_SET_FEES_IDENT,
+                ConstantProductPoolSetFeesManifestInput { fees },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn claim_protocol_fees(&mut self, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                CONSTANT_PRODUCT_POOL_CLAIM_PROTOCOL_FEES_IDENT,
+                ConstantProductPoolClaimProtocolFeesManifestInput {},
+            )
+            .try_deposit_entire_worktop_or_abort(self.account_component_address, None)
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn get_swap_output_amount<D: Into<Decimal>>(
+        &mut self,
+        input_resource_address: ResourceAddress,
+        input_amount: D,
+    ) -> ConstantProductPoolGetSwapOutputAmountOutput {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                CONSTANT_PRODUCT_POOL_GET_SWAP_OUTPUT_AMOUNT_IDENT,
+                ConstantProductPoolGetSwapOutputAmountManifestInput {
+                    input_resource_address,
+                    input_amount: input_amount.into(),
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, false)
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn get_vault_amounts(&mut self) -> ConstantProductPoolGetVaultAmountsOutput {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                CONSTANT_PRODUCT_POOL_GET_VAULT_AMOUNTS_IDENT,
+                ConstantProductPoolGetVaultAmountsManifestInput {},
+            )
+            .build();
+        self.execute_manifest(manifest, false)
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn get_price_accumulators(&mut self) -> ConstantProductPoolGetPriceAccumulatorsOutput {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.pool_component_address,
+                CONSTANT_PRODUCT_POOL_GET_PRICE_ACCUMULATORS_IDENT,
+                ConstantProductPoolGetPriceAccumulatorsManifestInput {},
+            )
+            .build();
+        self.execute_manifest(manifest, false)
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn advance_time_by_minutes(&mut self, minutes: i64) {
+        let round = self.test_runner.get_consensus_manager_state().round;
+        let timestamp_ms = self.test_runner.get_current_proposer_timestamp_ms();
+        self.test_runner
+            .advance_to_round_at_timestamp(
+                Round::of(round.number() + 1),
+                timestamp_ms + minutes * 60 * 1000,
+            )
+            .expect_commit_success();
+    }
+
+    fn find_event<T: ScryptoDecode>(&self, receipt: &TransactionReceipt, name: &str) -> T {
+        receipt
+            .expect_commit_success()
+            .application_events
+            .iter()
+            .find_map(|(event_type_identifier, event_data)| {
+                if self.test_runner.event_name(event_type_identifier) == name
+                    && self.is_pool_emitter(event_type_identifier)
+                {
+                    Some(scrypto_decode(event_data).unwrap())
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    }
+
+    fn is_pool_emitter(&self, event_type_identifier: &EventTypeIdentifier) -> bool {
+        match event_type_identifier.0 {
+            Emitter::Method(node_id, ObjectModuleId::Main) => {
+                node_id == *self.pool_component_address.as_node_id()
+            }
+            _ => false,
+        }
+    }
+
+    fn execute_manifest(
+        &mut self,
+        manifest: TransactionManifestV1,
+        sign: bool,
+    ) -> TransactionReceipt {
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, self.initial_proofs(sign))
+    }
+
+    fn virtual_signature_badge(&self) -> NonFungibleGlobalId {
+        NonFungibleGlobalId::from_public_key(&self.account_public_key)
+    }
+
+    fn initial_proofs(&self, sign: bool) -> Vec<NonFungibleGlobalId> {
+        if sign {
+            vec![self.virtual_signature_badge()]
+        } else {
+            vec![]
+        }
+    }
+}
+
+fn is_constant_product_pool_resource_does_not_belong_to_the_pool_error(
+    runtime_error: &RuntimeError,
+) -> bool {
+    matches!(
+        runtime_error,
+        RuntimeError::ApplicationError(ApplicationError::ConstantProductPoolError(
+            ConstantProductPoolError::ResourceDoesNotBelongToPool { .. }
+        ))
+    )
+}
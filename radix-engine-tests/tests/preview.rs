@@ -1,6 +1,7 @@
 use radix_engine::system::system_modules::costing::FeeTable;
 use radix_engine::transaction::CostingParameters;
 use radix_engine::transaction::ExecutionConfig;
+use radix_engine::transaction::FeeEstimationError;
 use radix_engine::types::*;
 use radix_engine_interface::rule;
 use scrypto_unit::*;
@@ -73,6 +74,103 @@ fn test_transaction_preview_cost_estimate() {
     );
 }
 
+#[test]
+fn test_transaction_fee_estimate_matches_notarized_execution() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .drop_auth_zone_proofs()
+        .build();
+    let preview_flags = PreviewFlags {
+        use_free_credit: false,
+        assume_all_signature_proofs: false,
+        skip_epoch_check: false,
+    };
+    let (notarized_transaction, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+
+    // Act
+    let estimate = test_runner
+        .estimate_transaction_fee(
+            preview_intent.intent,
+            preview_intent.signer_public_keys,
+            preview_flags,
+            &network,
+            20,
+        )
+        .unwrap();
+    let actual_receipt = test_runner.execute_transaction(
+        validate(&network, &notarized_transaction).get_executable(),
+        CostingParameters::default(),
+        ExecutionConfig::for_notarized_transaction(network.clone()),
+    );
+
+    // Assert
+    estimate.preview_receipt.expect_commit_success();
+    actual_receipt.expect_commit_success();
+    assert_eq!(
+        estimate.payload.payload_size,
+        notarized_transaction.to_payload_bytes().unwrap().len()
+    );
+    assert_eq!(
+        estimate.signature_verification_cost_units,
+        FeeTable::new().verify_tx_signatures_cost(2)
+    );
+    assert_eq!(
+        estimate.total_cost_in_xrd,
+        actual_receipt.fee_summary.total_cost()
+    );
+    assert_eq!(
+        estimate.total_cost_with_margin_in_xrd,
+        estimate.total_cost_in_xrd.safe_mul(dec!("1.2")).unwrap()
+    );
+}
+
+#[test]
+fn test_transaction_fee_estimate_fails_if_preview_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_worktop_contains(XRD, 1)
+        .build();
+    let preview_flags = PreviewFlags {
+        use_free_credit: false,
+        assume_all_signature_proofs: false,
+        skip_epoch_check: false,
+    };
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+
+    // Act
+    let result = test_runner.estimate_transaction_fee(
+        preview_intent.intent,
+        preview_intent.signer_public_keys,
+        preview_flags,
+        &network,
+        20,
+    );
+
+    // Assert
+    match result {
+        Err(FeeEstimationError::PreviewNotCommittedSuccessfully(receipt)) => {
+            receipt.expect_commit_failure();
+        }
+        _ => panic!("Expected the preview failure to be returned"),
+    }
+}
+
 #[test]
 fn test_transaction_preview_without_locking_fee() {
     // Arrange
@@ -1,6 +1,7 @@
 use radix_engine::blueprints::package::PackageError;
-use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
 use radix_engine::system::node_modules::royalty::ComponentRoyaltyError;
+use radix_engine::system::system_modules::costing::{CostingError, FeeReserveError};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -464,6 +465,229 @@ fn cannot_set_royalty_after_locking() {
     });
 }
 
+#[test]
+fn test_component_royalty_in_resource() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    set_up_resource_royalty(
+        &mut test_runner,
+        account,
+        &public_key,
+        component_address,
+        RoyaltyAmount::Resource(resource_address, dec!(5)),
+    );
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .lock_royalty_payment(account, resource_address, dec!(10), dec!(10))
+            .call_method(component_address, "free_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit(true);
+    assert_eq!(
+        commit_result
+            .fee_destination
+            .to_resource_royalty_recipients
+            .values()
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![(resource_address, dec!(5))]
+    );
+    assert_eq!(receipt.fee_summary.total_royalty_cost_in_xrd, dec!(0));
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(95)
+    );
+    assert_eq!(
+        test_runner.inspect_component_resource_royalty(component_address, resource_address),
+        Some(dec!(5))
+    );
+}
+
+#[test]
+fn test_resource_royalty_without_enough_payment_locked_fails() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    set_up_resource_royalty(
+        &mut test_runner,
+        account,
+        &public_key,
+        component_address,
+        RoyaltyAmount::Resource(resource_address, dec!(5)),
+    );
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .lock_royalty_payment(account, resource_address, dec!(2), dec!(10))
+            .call_method(component_address, "free_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                CostingError::FeeReserveError(FeeReserveError::InsufficientRoyaltyPayment { .. })
+            ))
+        )
+    });
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(100)
+    );
+    assert_eq!(
+        test_runner.inspect_component_resource_royalty(component_address, resource_address),
+        Some(dec!(0))
+    );
+}
+
+#[test]
+fn test_resource_royalty_above_stated_max_per_function_fails() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    set_up_resource_royalty(
+        &mut test_runner,
+        account,
+        &public_key,
+        component_address,
+        RoyaltyAmount::Resource(resource_address, dec!(5)),
+    );
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .lock_royalty_payment(account, resource_address, dec!(10), dec!(4))
+            .call_method(component_address, "free_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                CostingError::FeeReserveError(
+                    FeeReserveError::RoyaltyAmountIsGreaterThanAllowed { .. }
+                )
+            ))
+        )
+    });
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(100)
+    );
+}
+
+#[test]
+fn test_claim_resource_royalty() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    set_up_resource_royalty(
+        &mut test_runner,
+        account,
+        &public_key,
+        component_address,
+        RoyaltyAmount::Resource(resource_address, dec!(5)),
+    );
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .lock_royalty_payment(account, resource_address, dec!(5), dec!(5))
+            .call_method(component_address, "free_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit(true);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .claim_component_resource_royalties(component_address, resource_address)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit(true);
+    assert_eq!(
+        test_runner.inspect_component_resource_royalty(component_address, resource_address),
+        Some(dec!(0))
+    );
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(100)
+    );
+}
+
+#[test]
+fn cannot_set_royalty_in_non_fungible_resource() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .set_component_royalty(
+                component_address,
+                "free_method",
+                RoyaltyAmount::Resource(resource_address, dec!(1)),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ComponentRoyaltyError(
+                ComponentRoyaltyError::InvalidResourceRoyalty(..)
+            ))
+        )
+    });
+}
+
+fn set_up_resource_royalty(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+    public_key: &Secp256k1PublicKey,
+    component_address: ComponentAddress,
+    amount: RoyaltyAmount,
+) {
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .set_component_royalty(component_address, "free_method", amount)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(public_key)],
+    );
+    receipt.expect_commit(true);
+}
+
 fn set_up_package_and_component() -> (
     DefaultTestRunner,
     ComponentAddress,
@@ -0,0 +1,396 @@
+use radix_engine::blueprints::transaction_processor::TransactionProcessorError;
+use radix_engine::errors::{ApplicationError, RejectionReason, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::transaction_processor::{
+    TRANSACTION_PROCESSOR_BLUEPRINT, TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT,
+};
+use scrypto_unit::*;
+use transaction::prelude::*;
+use transaction::validation::*;
+
+#[derive(Debug, Eq, PartialEq, ManifestSbor)]
+struct ManifestTransactionProcessorSubintent {
+    subintent_hash: Hash,
+    manifest_encoded_instructions: Vec<u8>,
+    references: Vec<()>,
+    blobs: IndexMap<Hash, Vec<u8>>,
+}
+
+#[derive(Debug, Eq, PartialEq, ManifestSbor)]
+struct ManifestTransactionProcessorRunSubintentInput {
+    child_index: u32,
+    subintent: ManifestTransactionProcessorSubintent,
+    args: (),
+}
+
+#[test]
+fn subintent_can_yield_buckets_to_parent() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, child_account) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .withdraw_from_account(child_account, XRD, 10)
+            .yield_to_parent(manifest_args!(ManifestExpression::EntireWorktop))
+            .build(),
+        &child_key,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .yield_to_child(0, ())
+        .deposit_batch(parent_account)
+        .build();
+    let child_pre_balance = test_runner.get_component_balance(child_account, XRD);
+
+    // Act
+    let receipt = execute_transaction_v2(&mut test_runner, manifest, subintent, &parent_key);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(child_account, XRD),
+        child_pre_balance - dec!(10)
+    );
+}
+
+#[test]
+fn subintent_cannot_use_proofs_of_parent_signers() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, _) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .withdraw_from_account(parent_account, XRD, 10)
+            .yield_to_parent(manifest_args!(ManifestExpression::EntireWorktop))
+            .build(),
+        &child_key,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .yield_to_child(0, ())
+        .deposit_batch(parent_account)
+        .build();
+
+    // Act
+    let receipt = execute_transaction_v2(&mut test_runner, manifest, subintent, &parent_key);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn parent_can_pass_buckets_to_subintent() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, child_account) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .deposit_batch(child_account)
+            .yield_to_parent(())
+            .build(),
+        &child_key,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .withdraw_from_account(parent_account, XRD, 10)
+        .take_all_from_worktop(XRD, "xrd")
+        .with_name_lookup(|builder, lookup| {
+            let bucket = lookup.bucket("xrd");
+            builder.yield_to_child(0, manifest_args!(bucket))
+        })
+        .build();
+    let child_pre_balance = test_runner.get_component_balance(child_account, XRD);
+
+    // Act
+    let receipt = execute_transaction_v2(&mut test_runner, manifest, subintent, &parent_key);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(child_account, XRD),
+        child_pre_balance + dec!(10)
+    );
+}
+
+#[test]
+fn replayed_subintent_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, _) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new().yield_to_parent(()).build(),
+        &child_key,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .yield_to_child(0, ())
+        .build();
+    execute_transaction_v2(
+        &mut test_runner,
+        manifest.clone(),
+        subintent.clone(),
+        &parent_key,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = execute_transaction_v2(&mut test_runner, manifest, subintent, &parent_key);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| match e {
+        RejectionReason::IntentHashPreviouslyCommitted => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn yielding_to_missing_child_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (parent_public_key, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, _) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new().yield_to_parent(()).build(),
+        &child_key,
+    );
+    let validated = validate_transaction_v2(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .lock_fee(parent_account, 500)
+            .yield_to_child(0, ())
+            .build(),
+        subintent,
+        &parent_key,
+    );
+    let subintents = validated.get_executable().subintents().to_vec();
+
+    // The validator rejects yields to missing children, so the manifest is executed as a test
+    // transaction with the subintent of the valid transaction
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .yield_to_child(1, ())
+        .build();
+    let transaction =
+        TestTransaction::new_from_nonce(manifest, test_runner.next_transaction_nonce())
+            .prepare()
+            .unwrap();
+    let initial_proofs = btreeset!(NonFungibleGlobalId::from_public_key(&parent_public_key));
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        transaction
+            .get_executable(initial_proofs)
+            .with_subintents(subintents),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+            TransactionProcessorError::InvalidChildIndex(1),
+        )) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn yield_instructions_are_rejected_in_v1_transactions() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .yield_to_parent(())
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| match e {
+        RejectionReason::ErrorBeforeFeeLoanRepaid(RuntimeError::ApplicationError(
+            ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::SubintentInstructionsNotSupported,
+            ),
+        )) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn run_subintent_cannot_be_called_from_a_manifest() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, child_account) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new().yield_to_parent(()).build(),
+        &child_key,
+    );
+    let subintent_hash = subintent
+        .subintent
+        .prepare()
+        .unwrap()
+        .subintent_hash()
+        .into_hash();
+    // Instructions of the parent's choosing, run under the subintent's hash
+    let instructions = ManifestBuilder::new()
+        .withdraw_from_account(child_account, XRD, 10)
+        .yield_to_parent(manifest_args!(ManifestExpression::EntireWorktop))
+        .build()
+        .instructions;
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .call_function(
+            TRANSACTION_PROCESSOR_PACKAGE,
+            TRANSACTION_PROCESSOR_BLUEPRINT,
+            TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT,
+            ManifestTransactionProcessorRunSubintentInput {
+                child_index: 0,
+                subintent: ManifestTransactionProcessorSubintent {
+                    subintent_hash,
+                    manifest_encoded_instructions: manifest_encode(&instructions).unwrap(),
+                    references: vec![],
+                    blobs: index_map_new(),
+                },
+                args: (),
+            },
+        )
+        .yield_to_child(0, ())
+        .deposit_batch(parent_account)
+        .build();
+    let child_pre_balance = test_runner.get_component_balance(child_account, XRD);
+
+    // Act
+    let receipt = execute_transaction_v2(&mut test_runner, manifest, subintent, &parent_key);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+            TransactionProcessorError::RunSubintentNotCallable,
+        )) => true,
+        _ => false,
+    });
+    assert_eq!(
+        test_runner.get_component_balance(child_account, XRD),
+        child_pre_balance
+    );
+}
+
+#[test]
+fn run_subintent_cannot_be_called_from_a_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/tx_processor_access");
+    let (_, parent_key, parent_account) = test_runner.new_allocated_account();
+    let (_, child_key, child_account) = test_runner.new_allocated_account();
+    let subintent = create_subintent(
+        &mut test_runner,
+        ManifestBuilder::new().yield_to_parent(()).build(),
+        &child_key,
+    );
+    let subintent_hash = subintent
+        .subintent
+        .prepare()
+        .unwrap()
+        .subintent_hash()
+        .into_hash();
+    let instructions = ManifestBuilder::new()
+        .withdraw_from_account(child_account, XRD, 10)
+        .yield_to_parent(manifest_args!(ManifestExpression::EntireWorktop))
+        .build()
+        .instructions;
+    let manifest = ManifestBuilder::new()
+        .lock_fee(parent_account, 500)
+        .call_function(
+            package_address,
+            "ExecuteManifest",
+            "run_subintent",
+            manifest_args!(subintent_hash, manifest_encode(&instructions).unwrap()),
+        )
+        .yield_to_child(0, ())
+        .build();
+
+    // Act
+    let receipt = execute_transaction_v2(&mut test_runner, manifest, subintent, &parent_key);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+            ..,
+        ))) => true,
+        _ => false,
+    });
+}
+
+fn create_subintent(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    signer: &Secp256k1PrivateKey,
+) -> SignedSubintentV2 {
+    let current_epoch = test_runner.get_current_epoch();
+    SubintentBuilder::new()
+        .header(SubintentHeaderV2 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10),
+            nonce: test_runner.next_transaction_nonce(),
+        })
+        .manifest(manifest)
+        .sign(signer)
+        .build()
+}
+
+fn execute_transaction_v2(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    subintent: SignedSubintentV2,
+    notary: &Secp256k1PrivateKey,
+) -> TransactionReceipt {
+    let validated = validate_transaction_v2(test_runner, manifest, subintent, notary);
+    test_runner.execute_transaction(
+        validated.get_executable(),
+        CostingParameters::default(),
+        ExecutionConfig::for_notarized_transaction(NetworkDefinition::simulator()),
+    )
+}
+
+fn validate_transaction_v2(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    subintent: SignedSubintentV2,
+    notary: &Secp256k1PrivateKey,
+) -> ValidatedNotarizedTransactionV2 {
+    let current_epoch = test_runner.get_current_epoch();
+    let transaction = TransactionV2Builder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10),
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: notary.public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .add_signed_subintent(subintent)
+        .notarize(notary)
+        .build();
+
+    let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+    validator
+        .validate_notarized_v2(transaction.prepare().unwrap())
+        .expect("Expected transaction to be valid")
+}
@@ -3,7 +3,7 @@ use radix_engine::system::bootstrap::Bootstrapper;
 use radix_engine::transaction::execute_and_commit_transaction;
 use radix_engine::transaction::{CostingParameters, ExecutionConfig};
 use radix_engine::types::*;
-use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmDebugCodeCache, WasmValidatorConfigV1};
 use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use scrypto_unit::*;
@@ -97,6 +97,8 @@ fn test_normal_transaction_flow() {
     let scrypto_vm = ScryptoVm {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_validator_config: WasmValidatorConfigV1::new(),
+        debug_mode: false,
+        debug_code_cache: WasmDebugCodeCache::default(),
     };
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
@@ -4,7 +4,7 @@ mod multi_threaded_test {
     use radix_engine::transaction::{execute_and_commit_transaction, execute_transaction};
     use radix_engine::transaction::{CostingParameters, ExecutionConfig};
     use radix_engine::types::*;
-    use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+    use radix_engine::vm::wasm::{DefaultWasmEngine, WasmDebugCodeCache, WasmValidatorConfigV1};
     use radix_engine_interface::dec;
     use radix_engine_interface::rule;
     use radix_engine_stores::memory_db::InMemorySubstateDatabase;
@@ -24,6 +24,8 @@ mod multi_threaded_test {
         let scrypto_vm = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            debug_mode: false,
+            debug_code_cache: WasmDebugCodeCache::default(),
         };
         let native_vm = DefaultNativeVm::new();
         let vm = Vm {
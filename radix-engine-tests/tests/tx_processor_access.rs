@@ -14,6 +14,7 @@ pub struct ManifestTransactionProcessorRunInput {
     pub global_address_reservations: Vec<()>,
     pub references: Vec<()>,
     pub blobs: IndexMap<Hash, Vec<u8>>,
+    pub subintents: Vec<()>,
 }
 
 #[test]
@@ -35,6 +36,7 @@ fn should_not_be_able_to_call_tx_processor_in_tx_processor() {
                 global_address_reservations: vec![],
                 references: vec![],
                 blobs: index_map_new(),
+                subintents: vec![],
             },
         )
         .build();
@@ -0,0 +1,438 @@
+use radix_engine::blueprints::resource::*;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn withdrawals_beyond_the_limit_are_rejected() {
+    // Arrange
+    let mut env = TestEnvironment::new(WithdrawLimitPeriod::Epochs(10));
+
+    // Act
+    let first_receipt = env.withdraw(60, false);
+    let second_receipt = env.withdraw(50, false);
+
+    // Assert
+    first_receipt.expect_commit_success();
+    second_receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::WithdrawLimitExceeded {
+                requested: dec!(50),
+                remaining: dec!(40),
+            },
+        ))
+    });
+}
+
+#[test]
+fn exceeding_the_limit_emits_an_event() {
+    // Arrange
+    let mut env = TestEnvironment::new(WithdrawLimitPeriod::Epochs(10));
+
+    // Act
+    let receipt = env.withdraw(150, false);
+
+    // Assert
+    let event = receipt
+        .expect_commit_failure()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if env.test_runner.event_name(event_type_identifier) == "WithdrawLimitExceededEvent" {
+                Some(
+                    scrypto_decode::<fungible_vault::WithdrawLimitExceededEvent>(event_data)
+                        .unwrap(),
+                )
+            } else {
+                None
+            }
+        })
+        .expect("Expected a WithdrawLimitExceededEvent");
+    assert_eq!(
+        event,
+        fungible_vault::WithdrawLimitExceededEvent {
+            requested: dec!(150),
+            remaining: dec!(100),
+        }
+    );
+}
+
+#[test]
+fn epoch_limit_resets_in_the_next_window() {
+    // Arrange
+    let mut env = TestEnvironment::new(WithdrawLimitPeriod::Epochs(10));
+    env.withdraw(100, false).expect_commit_success();
+
+    // Act
+    let current_epoch = env.test_runner.get_current_epoch().number();
+    env.test_runner
+        .set_current_epoch(Epoch::of((current_epoch / 10 + 1) * 10));
+    let receipt = env.withdraw(100, false);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn minute_limit_resets_in_the_next_window() {
+    // Arrange
+    let mut env = TestEnvironment::new(WithdrawLimitPeriod::Minutes(5));
+    env.withdraw(100, false).expect_commit_success();
+
+    // Act
+    let same_window_receipt = env.withdraw(1, false);
+    env.advance_time_by_minutes(5);
+    let next_window_receipt = env.withdraw(100, false);
+
+    // Assert
+    same_window_receipt.expect_specific_failure(is_withdraw_limit_exceeded);
+    next_window_receipt.expect_commit_success();
+}
+
+#[test]
+fn withdrawals_authorized_by_the_bypasser_are_not_limited() {
+    // Arrange
+    let mut env = TestEnvironment::new(WithdrawLimitPeriod::Epochs(10));
+
+    // Act
+    let bypassed_receipt = env.withdraw(500, true);
+    let limited_receipt = env.withdraw(100, false);
+
+    // Assert
+    bypassed_receipt.expect_commit_success();
+    limited_receipt.expect_commit_success();
+}
+
+#[test]
+fn withdraw_limit_with_zero_period_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        create_resource_manifest(
+            account,
+            FungibleWithdrawLimit {
+                amount: dec!(100),
+                period: WithdrawLimitPeriod::Minutes(0),
+                bypass_roles: None,
+            },
+        ),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::InvalidWithdrawLimit(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn non_fungible_withdrawals_beyond_the_limit_are_rejected() {
+    // Arrange
+    let mut env = TestEnvironment::new_non_fungible(WithdrawLimitPeriod::Epochs(10));
+
+    // Act
+    let first_receipt = env.withdraw(2, false);
+    let second_receipt = env.withdraw(2, false);
+
+    // Assert
+    first_receipt.expect_commit_success();
+    second_receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::WithdrawLimitExceeded {
+                requested: dec!(2),
+                remaining: dec!(1),
+            },
+        ))
+    });
+}
+
+#[test]
+fn non_fungible_withdrawals_by_id_count_against_the_limit() {
+    // Arrange
+    let mut env = TestEnvironment::new_non_fungible(WithdrawLimitPeriod::Epochs(10));
+    env.withdraw_non_fungibles(&[1], false)
+        .expect_commit_success();
+
+    // Act
+    let within_limit_receipt = env.withdraw_non_fungibles(&[2, 3], false);
+    let beyond_limit_receipt = env.withdraw_non_fungibles(&[4], false);
+
+    // Assert
+    within_limit_receipt.expect_commit_success();
+    beyond_limit_receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::WithdrawLimitExceeded {
+                requested: dec!(1),
+                remaining: dec!(0),
+            },
+        ))
+    });
+}
+
+#[test]
+fn exceeding_the_non_fungible_limit_emits_an_event() {
+    // Arrange
+    let mut env = TestEnvironment::new_non_fungible(WithdrawLimitPeriod::Epochs(10));
+
+    // Act
+    let receipt = env.withdraw_non_fungibles(&[1, 2, 3, 4], false);
+
+    // Assert
+    let event = receipt
+        .expect_commit_failure()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if env.test_runner.event_name(event_type_identifier) == "WithdrawLimitExceededEvent" {
+                Some(
+                    scrypto_decode::<non_fungible_vault::WithdrawLimitExceededEvent>(event_data)
+                        .unwrap(),
+                )
+            } else {
+                None
+            }
+        })
+        .expect("Expected a WithdrawLimitExceededEvent");
+    assert_eq!(
+        event,
+        non_fungible_vault::WithdrawLimitExceededEvent {
+            requested: dec!(4),
+            remaining: dec!(3),
+        }
+    );
+}
+
+#[test]
+fn non_fungible_limit_resets_in_the_next_window() {
+    // Arrange
+    let mut env = TestEnvironment::new_non_fungible(WithdrawLimitPeriod::Minutes(5));
+    env.withdraw_non_fungibles(&[1, 2, 3], false)
+        .expect_commit_success();
+
+    // Act
+    let same_window_receipt = env.withdraw(1, false);
+    env.advance_time_by_minutes(5);
+    let next_window_receipt = env.withdraw_non_fungibles(&[4, 5, 6], false);
+
+    // Assert
+    same_window_receipt.expect_specific_failure(is_withdraw_limit_exceeded);
+    next_window_receipt.expect_commit_success();
+}
+
+#[test]
+fn non_fungible_withdrawals_authorized_by_the_bypasser_are_not_limited() {
+    // Arrange
+    let mut env = TestEnvironment::new_non_fungible(WithdrawLimitPeriod::Epochs(10));
+
+    // Act
+    let bypassed_receipt = env.withdraw_non_fungibles(&[1, 2, 3, 4, 5], true);
+    let limited_receipt = env.withdraw(3, false);
+
+    // Assert
+    bypassed_receipt.expect_commit_success();
+    limited_receipt.expect_commit_success();
+}
+
+#[test]
+fn withdraw_limit_set_through_the_resource_builder_is_enforced() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let component_address = {
+        let manifest = ManifestBuilder::new()
+            .call_function(
+                package_address,
+                "WithdrawLimitedVault",
+                "new",
+                manifest_args!(dec!(100), 5u32),
+            )
+            .build();
+        test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    };
+    let (_, _, account) = test_runner.new_allocated_account();
+    let mut take_amount = |amount: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .call_method(component_address, "take_amount", manifest_args!(amount))
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        test_runner.execute_manifest_ignoring_fee(manifest, vec![])
+    };
+
+    // Act
+    let first_receipt = take_amount(dec!(60));
+    let second_receipt = take_amount(dec!(50));
+
+    // Assert
+    first_receipt.expect_commit_success();
+    second_receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::WithdrawLimitExceeded {
+                requested: dec!(50),
+                remaining: dec!(40),
+            },
+        ))
+    });
+}
+
+struct TestEnvironment {
+    test_runner: DefaultTestRunner,
+    account: ComponentAddress,
+    account_badge: NonFungibleGlobalId,
+    bypasser_badge: NonFungibleGlobalId,
+    resource_address: ResourceAddress,
+}
+
+impl TestEnvironment {
+    fn new(period: WithdrawLimitPeriod) -> Self {
+        Self::with_resource(|account, bypass_roles| {
+            create_resource_manifest(
+                account,
+                FungibleWithdrawLimit {
+                    amount: dec!(100),
+                    period,
+                    bypass_roles,
+                },
+            )
+        })
+    }
+
+    /// Creates a non-fungible resource with the integer ids 1 to 10, of which at most 3 may be
+    /// withdrawn from a vault per period.
+    fn new_non_fungible(period: WithdrawLimitPeriod) -> Self {
+        Self::with_resource(|account, bypass_roles| {
+            ManifestBuilder::new()
+                .create_non_fungible_resource_with_withdraw_limit(
+                    OwnerRole::None,
+                    NonFungibleIdType::Integer,
+                    true,
+                    NonFungibleResourceRoles::default(),
+                    NonFungibleWithdrawLimit {
+                        count: 3,
+                        period,
+                        bypass_roles,
+                    },
+                    metadata!(),
+                    Some(
+                        (1..=10).map(|i| (NonFungibleLocalId::integer(i), EmptyNonFungibleData {})),
+                    ),
+                )
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build()
+        })
+    }
+
+    fn with_resource<F>(create_resource_manifest: F) -> Self
+    where
+        F: FnOnce(
+            ComponentAddress,
+            Option<WithdrawLimitBypassRoles<RoleDefinition>>,
+        ) -> TransactionManifestV1,
+    {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let (bypasser_public_key, _, _) = test_runner.new_allocated_account();
+        let account_badge = NonFungibleGlobalId::from_public_key(&public_key);
+        let bypasser_badge = NonFungibleGlobalId::from_public_key(&bypasser_public_key);
+
+        let manifest = create_resource_manifest(
+            account,
+            withdraw_limit_bypass_roles! {
+                withdraw_limit_bypasser => rule!(require(bypasser_badge.clone()));
+                withdraw_limit_bypasser_updater => rule!(deny_all);
+            },
+        );
+        let resource_address = test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+            .expect_commit_success()
+            .new_resource_addresses()[0];
+
+        Self {
+            test_runner,
+            account,
+            account_badge,
+            bypasser_badge,
+            resource_address,
+        }
+    }
+
+    fn withdraw<A: Into<Decimal>>(&mut self, amount: A, with_bypasser: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(self.account, self.resource_address, amount)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        let mut initial_proofs = vec![self.account_badge.clone()];
+        if with_bypasser {
+            initial_proofs.push(self.bypasser_badge.clone());
+        }
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, initial_proofs)
+    }
+
+    fn withdraw_non_fungibles(&mut self, ids: &[u64], with_bypasser: bool) -> TransactionReceipt {
+        let ids = ids
+            .iter()
+            .map(|id| NonFungibleLocalId::integer(*id))
+            .collect::<Vec<_>>();
+        let manifest = ManifestBuilder::new()
+            .withdraw_non_fungibles_from_account(self.account, self.resource_address, ids)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        let mut initial_proofs = vec![self.account_badge.clone()];
+        if with_bypasser {
+            initial_proofs.push(self.bypasser_badge.clone());
+        }
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, initial_proofs)
+    }
+
+    fn advance_time_by_minutes(&mut self, minutes: i64) {
+        let round = self.test_runner.get_consensus_manager_state().round;
+        let timestamp_ms = self.test_runner.get_current_proposer_timestamp_ms();
+        self.test_runner
+            .advance_to_round_at_timestamp(
+                Round::of(round.number() + 1),
+                timestamp_ms + minutes * 60 * 1000,
+            )
+            .expect_commit_success();
+    }
+}
+
+fn create_resource_manifest(
+    account: ComponentAddress,
+    withdraw_limit: FungibleWithdrawLimit,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .create_fungible_resource_with_withdraw_limit(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles::default(),
+            withdraw_limit,
+            metadata!(),
+            Some(dec!(1000)),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build()
+}
+
+fn is_withdraw_limit_exceeded(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::WithdrawLimitExceeded { .. }
+        ))
+    )
+}
@@ -0,0 +1,284 @@
+use radix_engine::blueprints::vesting::*;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::vesting::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn linear_vesting_releases_resources_proportionally_to_elapsed_time() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Linear {
+        start: env.now,
+        cliff: env.now,
+        end: env.now.add_minutes(100).unwrap(),
+    });
+
+    // Act
+    env.advance_time_by_minutes(25);
+    let receipt = env.claim(vesting);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.beneficiary_balance(), dec!(250));
+}
+
+#[test]
+fn linear_vesting_releases_nothing_before_the_cliff() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Linear {
+        start: env.now,
+        cliff: env.now.add_minutes(30).unwrap(),
+        end: env.now.add_minutes(100).unwrap(),
+    });
+
+    // Act
+    env.advance_time_by_minutes(20);
+    env.claim(vesting).expect_commit_success();
+    let balance_before_cliff = env.beneficiary_balance();
+    env.advance_time_by_minutes(20);
+    env.claim(vesting).expect_commit_success();
+    let balance_after_cliff = env.beneficiary_balance();
+
+    // Assert
+    assert_eq!(balance_before_cliff, dec!(0));
+    assert_eq!(balance_after_cliff, dec!(400));
+}
+
+#[test]
+fn linear_vesting_releases_everything_after_the_end() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Linear {
+        start: env.now,
+        cliff: env.now,
+        end: env.now.add_minutes(100).unwrap(),
+    });
+
+    // Act
+    env.advance_time_by_minutes(50);
+    env.claim(vesting).expect_commit_success();
+    env.advance_time_by_minutes(500);
+    env.claim(vesting).expect_commit_success();
+
+    // Assert
+    assert_eq!(env.beneficiary_balance(), dec!(1000));
+}
+
+#[test]
+fn cliff_vesting_releases_everything_at_once() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Cliff {
+        unlock: env.now.add_minutes(60).unwrap(),
+    });
+
+    // Act
+    env.advance_time_by_minutes(59);
+    env.claim(vesting).expect_commit_success();
+    let balance_before_unlock = env.beneficiary_balance();
+    env.advance_time_by_minutes(1);
+    env.claim(vesting).expect_commit_success();
+    let balance_after_unlock = env.beneficiary_balance();
+
+    // Assert
+    assert_eq!(balance_before_unlock, dec!(0));
+    assert_eq!(balance_after_unlock, dec!(1000));
+}
+
+#[test]
+fn revocation_returns_unvested_resources_and_keeps_vested_ones_claimable() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Linear {
+        start: env.now,
+        cliff: env.now,
+        end: env.now.add_minutes(100).unwrap(),
+    });
+    env.advance_time_by_minutes(40);
+
+    // Act
+    let revoker_balance_before = env.revoker_balance();
+    let receipt = env.revoke(vesting);
+    env.advance_time_by_minutes(100);
+    env.claim(vesting).expect_commit_success();
+
+    // Assert
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find_map(|(event_type_identifier, event_data)| {
+            if env.test_runner.event_name(event_type_identifier) == "RevocationEvent" {
+                Some(scrypto_decode::<RevocationEvent>(event_data).unwrap())
+            } else {
+                None
+            }
+        })
+        .expect("Expected a RevocationEvent");
+    assert_eq!(
+        event,
+        RevocationEvent {
+            unvested_amount: dec!(600)
+        }
+    );
+    assert_eq!(env.revoker_balance(), revoker_balance_before + dec!(600));
+    assert_eq!(env.beneficiary_balance(), dec!(400));
+}
+
+#[test]
+fn vesting_cannot_be_revoked_twice() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Cliff {
+        unlock: env.now.add_minutes(60).unwrap(),
+    });
+    env.revoke(vesting).expect_commit_success();
+
+    // Act
+    let receipt = env.revoke(vesting);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::VestingError(
+            VestingError::AlreadyRevoked,
+        ))
+    });
+}
+
+#[test]
+fn only_the_beneficiary_can_claim() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let vesting = env.create_vesting(VestingSchedule::Cliff { unlock: env.now });
+    let manifest = ManifestBuilder::new()
+        .claim_vested(vesting)
+        .try_deposit_entire_worktop_or_abort(env.revoker, None)
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![env.revoker_badge.clone()]);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn linear_schedule_ending_before_its_start_is_rejected() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let schedule = VestingSchedule::Linear {
+        start: env.now,
+        cliff: env.now,
+        end: env.now.add_minutes(-10).unwrap(),
+    };
+
+    // Act
+    let receipt = env.execute_create_vesting(schedule);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::VestingError(
+            VestingError::InvalidSchedule { schedule },
+        ))
+    });
+}
+
+struct TestEnvironment {
+    test_runner: DefaultTestRunner,
+    now: Instant,
+    revoker: ComponentAddress,
+    revoker_badge: NonFungibleGlobalId,
+    beneficiary: ComponentAddress,
+    beneficiary_badge: NonFungibleGlobalId,
+    resource_address: ResourceAddress,
+}
+
+impl TestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().build();
+        let (revoker_public_key, _, revoker) = test_runner.new_allocated_account();
+        let (beneficiary_public_key, _, beneficiary) = test_runner.new_allocated_account();
+        let resource_address = test_runner.create_fungible_resource(dec!(1000), 18, revoker);
+        let now = test_runner.get_current_time(TimePrecision::Minute);
+
+        Self {
+            test_runner,
+            now,
+            revoker,
+            revoker_badge: NonFungibleGlobalId::from_public_key(&revoker_public_key),
+            beneficiary,
+            beneficiary_badge: NonFungibleGlobalId::from_public_key(&beneficiary_public_key),
+            resource_address,
+        }
+    }
+
+    fn execute_create_vesting(&mut self, schedule: VestingSchedule) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(self.revoker, self.resource_address, dec!(1000))
+            .take_all_from_worktop(self.resource_address, "bucket")
+            .with_name_lookup(|builder, lookup| {
+                builder.create_vesting(
+                    OwnerRole::None,
+                    rule!(require(self.beneficiary_badge.clone())),
+                    Some(rule!(require(self.revoker_badge.clone()))),
+                    schedule,
+                    lookup.bucket("bucket"),
+                )
+            })
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.revoker_badge.clone()])
+    }
+
+    fn create_vesting(&mut self, schedule: VestingSchedule) -> ComponentAddress {
+        self.execute_create_vesting(schedule)
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    }
+
+    fn claim(&mut self, vesting: ComponentAddress) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .claim_vested(vesting)
+            .try_deposit_entire_worktop_or_abort(self.beneficiary, None)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.beneficiary_badge.clone()])
+    }
+
+    fn revoke(&mut self, vesting: ComponentAddress) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .revoke_vesting(vesting)
+            .try_deposit_entire_worktop_or_abort(self.revoker, None)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.revoker_badge.clone()])
+    }
+
+    fn beneficiary_balance(&mut self) -> Decimal {
+        self.test_runner
+            .get_component_balance(self.beneficiary, self.resource_address)
+    }
+
+    fn revoker_balance(&mut self) -> Decimal {
+        self.test_runner
+            .get_component_balance(self.revoker, self.resource_address)
+    }
+
+    fn advance_time_by_minutes(&mut self, minutes: i64) {
+        let round = self.test_runner.get_consensus_manager_state().round;
+        let timestamp_ms = self.test_runner.get_current_proposer_timestamp_ms();
+        self.test_runner
+            .advance_to_round_at_timestamp(
+                Round::of(round.number() + 1),
+                timestamp_ms + minutes * 60 * 1000,
+            )
+            .expect_commit_success();
+    }
+}
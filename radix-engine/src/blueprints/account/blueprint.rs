@@ -3,6 +3,8 @@ use crate::blueprints::util::{PresecurifiedRoleAssignment, SecurifiedRoleAssignm
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
 use crate::internal_prelude::*;
+use crate::kernel::kernel_api::KernelSubstateApi;
+use crate::system::system_modules::auth::{Authorization, AuthorizationCheckResult};
 use crate::types::*;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::role_assignment::RoleAssignment;
@@ -14,7 +16,9 @@ use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::FieldValue;
-use radix_engine_interface::api::{ClientApi, GenericArgs, ModuleId, ACTOR_STATE_SELF};
+use radix_engine_interface::api::{
+    ClientApi, GenericArgs, ModuleId, ACTOR_REF_AUTH_ZONE, ACTOR_REF_SELF, ACTOR_STATE_SELF,
+};
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::resource::{Bucket, Proof};
 use radix_engine_interface::hooks::OnVirtualizeInput;
@@ -35,12 +39,42 @@ pub struct AccountSubstate {
     pub default_deposit_rule: DefaultDepositRule,
 }
 
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
+pub struct AccountSubstateV2 {
+    pub default_deposit_rule: DefaultDepositRule,
+    pub deposit_rule: Option<DepositRule>,
+    pub new_resource_deposits: NewResourceDeposits,
+}
+
+impl From<AccountSubstate> for AccountSubstateV2 {
+    fn from(value: AccountSubstate) -> Self {
+        Self {
+            default_deposit_rule: value.default_deposit_rule,
+            deposit_rule: None,
+            new_resource_deposits: NewResourceDeposits {
+                epoch: Epoch::zero(),
+                count: 0,
+            },
+        }
+    }
+}
+
+/// The number of resources without an existing vault that were deposited through the `try_deposit`
+/// methods in the given epoch. Only tracked while a deposit rule is set.
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
+pub struct NewResourceDeposits {
+    pub epoch: Epoch,
+    pub count: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum AccountError {
     VaultDoesNotExist { resource_address: ResourceAddress },
     DepositIsDisallowed { resource_address: ResourceAddress },
     NotAllBucketsCouldBeDeposited,
     NotAnAuthorizedDepositor { depositor: ResourceOrNonFungible },
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
 }
 
 impl From<AccountError> for RuntimeError {
@@ -69,6 +103,15 @@ declare_native_blueprint_state! {
     fields: {
         deposit_rule:  {
             ident: DepositRule,
+            field_type: {
+                kind: StaticMultiVersioned,
+                previous_versions: [1 => { updates_to: 2 }],
+                latest_version: 2,
+            },
+            condition: Condition::Always,
+        },
+        resource_vault_count: {
+            ident: ResourceVaultCount,
             field_type: {
                 kind: StaticSingleVersioned,
             },
@@ -109,13 +152,29 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        resource_vault_indices: KeyValue {
+            entry_ident: ResourceVaultIndex,
+            key_type: {
+                kind: Static,
+                content_type: u32,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
 pub type AccountDepositRuleV1 = AccountSubstate;
+pub type AccountDepositRuleV2 = AccountSubstateV2;
 pub type AccountResourceVaultV1 = Vault;
 pub type AccountResourcePreferenceV1 = ResourcePreference;
 pub type AccountAuthorizedDepositorV1 = ();
+/// The number of vaults the account holds, used to page through them in creation order.
+pub type AccountResourceVaultCountV1 = u32;
+/// The resource of the vault created at the given position.
+pub type AccountResourceVaultIndexV1 = ResourceAddress;
 
 pub struct AccountBlueprint;
 
@@ -198,6 +257,20 @@ impl AccountBlueprint {
             },
         );
 
+        functions.insert(
+            ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockRoyaltyPaymentInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockRoyaltyPaymentOutput>(),
+                ),
+                export: ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_DEPOSIT_IDENT.to_string(),
             FunctionSchemaInit {
@@ -340,6 +413,34 @@ impl AccountBlueprint {
             },
         );
 
+        functions.insert(
+            ACCOUNT_GET_VAULTS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountGetVaultsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountGetVaultsOutput>(),
+                ),
+                export: ACCOUNT_GET_VAULTS_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_BALANCE_OF_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountBalanceOfInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountBalanceOfOutput>(),
+                ),
+                export: ACCOUNT_BALANCE_OF_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -356,6 +457,20 @@ impl AccountBlueprint {
             },
         );
 
+        functions.insert(
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountSetDepositRuleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountSetDepositRuleOutput>(),
+                ),
+                export: ACCOUNT_SET_DEPOSIT_RULE_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -486,9 +601,11 @@ impl AccountBlueprint {
                 WithdrawEvent,
                 DepositEvent,
                 RejectedDepositEvent,
+                RejectedDepositReasonEvent,
                 SetResourcePreferenceEvent,
                 RemoveResourcePreferenceEvent,
                 SetDefaultDepositRuleEvent,
+                SetDepositRuleEvent,
                 AddAuthorizedDepositorEvent,
                 RemoveAuthorizedDepositorEvent,
             ]
@@ -529,12 +646,14 @@ impl AccountBlueprint {
                         ACCOUNT_SECURIFY_IDENT => [SECURIFY_ROLE];
 
                         ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
+                        ACCOUNT_SET_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
                         ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => [OWNER_ROLE];
                         ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT => [OWNER_ROLE];
                         ACCOUNT_WITHDRAW_IDENT => [OWNER_ROLE];
                         ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_FEE_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => [OWNER_ROLE];
+                        ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                         ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => [OWNER_ROLE];
@@ -550,6 +669,8 @@ impl AccountBlueprint {
                         ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_GET_VAULTS_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_BALANCE_OF_IDENT => MethodAccessibility::Public;
                     }
                 )),
             },
@@ -724,9 +845,15 @@ impl AccountBlueprint {
             vec![],
             GenericArgs::default(),
             btreemap! {
-                AccountField::DepositRule.field_index() => FieldValue::new(&AccountDepositRuleFieldPayload::from_content_source(AccountDepositRuleV1 {
+                AccountField::DepositRule.field_index() => FieldValue::new(&AccountDepositRuleFieldPayload::from_content_source(AccountDepositRuleV2 {
                     default_deposit_rule: DefaultDepositRule::Accept,
-                }))
+                    deposit_rule: None,
+                    new_resource_deposits: NewResourceDeposits {
+                        epoch: Epoch::zero(),
+                        count: 0,
+                    },
+                })),
+                AccountField::ResourceVaultCount.field_index() => FieldValue::new(&AccountResourceVaultCountFieldPayload::from_content_source(0u32)),
             },
             btreemap!(),
         )?;
@@ -776,11 +903,34 @@ impl AccountBlueprint {
         Ok(())
     }
 
-    /// Method requires auth - if call goes through it performs the deposit with no questions asked
-    pub fn deposit<Y>(bucket: Bucket, api: &mut Y) -> Result<(), RuntimeError>
+    /// Locks a contingent payment for royalties denominated in the given resource, of which no
+    /// single function or method may charge more than `max_per_function`
+    pub fn lock_royalty_payment<Y>(
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        max_per_function: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
+        Self::get_vault(
+            resource_address,
+            |vault, api| vault.lock_royalty_payment(api, amount, max_per_function),
+            false,
+            api,
+        )?;
+        Ok(())
+    }
+
+    /// Method requires auth - if call goes through it performs the deposit with no questions asked
+    pub fn deposit<Y, L: Default>(bucket: Bucket, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        // The deposit may create a vault, which needs the vault index to be in place
+        Self::backfill_resource_vault_index(api)?;
+
         let resource_address = bucket.resource_address(api)?;
         let event = if resource_address.is_fungible() {
             DepositEvent::Fungible(resource_address, bucket.amount(api)?)
@@ -798,9 +948,12 @@ impl AccountBlueprint {
     }
 
     /// Method requires auth - if call goes through it performs the deposit with no questions asked
-    pub fn deposit_batch<Y>(buckets: Vec<Bucket>, api: &mut Y) -> Result<(), RuntimeError>
+    pub fn deposit_batch<Y, L: Default>(
+        buckets: Vec<Bucket>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         for bucket in buckets {
             Self::deposit(bucket, api)?;
@@ -808,18 +961,21 @@ impl AccountBlueprint {
         Ok(())
     }
 
-    pub fn try_deposit_or_refund<Y>(
+    pub fn try_deposit_or_refund<Y, L: Default>(
         bucket: Bucket,
         authorized_depositor_badge: Option<ResourceOrNonFungible>,
         api: &mut Y,
     ) -> Result<Option<Bucket>, RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         let resource_address = bucket.resource_address(api)?;
-        let is_deposit_allowed = Self::is_deposit_allowed(&resource_address, api)?;
-        if is_deposit_allowed {
+        let mut new_resources = BTreeSet::new();
+        let deposit_rejection =
+            Self::is_deposit_allowed(&resource_address, &mut new_resources, api)?.err();
+        if deposit_rejection.is_none() {
             Self::deposit(bucket, api)?;
+            Self::record_new_resource_deposits(new_resources, api)?;
             Ok(None)
         } else if let Some(badge) = authorized_depositor_badge {
             Self::validate_badge_is_authorized_depositor(&badge, api)??;
@@ -827,48 +983,35 @@ impl AccountBlueprint {
             Self::deposit(bucket, api)?;
             Ok(None)
         } else {
-            let event = if resource_address.is_fungible() {
-                RejectedDepositEvent::Fungible(resource_address, bucket.amount(api)?)
-            } else {
-                RejectedDepositEvent::NonFungible(
-                    resource_address,
-                    bucket.non_fungible_local_ids(api)?,
-                )
-            };
-            Runtime::emit_event(api, event)?;
+            Self::emit_rejected_deposit_events(&bucket, deposit_rejection.unwrap(), api)?;
             Ok(Some(bucket))
         }
     }
 
-    pub fn try_deposit_batch_or_refund<Y>(
+    pub fn try_deposit_batch_or_refund<Y, L: Default>(
         buckets: Vec<Bucket>,
         authorized_depositor_badge: Option<ResourceOrNonFungible>,
         api: &mut Y,
     ) -> Result<Option<Vec<Bucket>>, RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
-        let offending_buckets = buckets
-            .iter()
-            .map(|bucket| {
-                bucket
-                    .resource_address(api)
-                    .and_then(|resource_address| Self::is_deposit_allowed(&resource_address, api))
-                    .map(|can_be_deposited| (bucket, can_be_deposited))
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .filter_map(|(bucket, can_be_deposited)| {
-                if !can_be_deposited {
-                    Some(Bucket(bucket.0))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
+        // The buckets are evaluated in order so that new resources admitted by earlier buckets
+        // count against the per-epoch limit of later ones.
+        let mut new_resources = BTreeSet::new();
+        let mut offending_buckets = Vec::new();
+        for bucket in buckets.iter() {
+            let resource_address = bucket.resource_address(api)?;
+            if let Err(reason) =
+                Self::is_deposit_allowed(&resource_address, &mut new_resources, api)?
+            {
+                offending_buckets.push((Bucket(bucket.0), reason));
+            }
+        }
 
         if offending_buckets.is_empty() {
             Self::deposit_batch(buckets, api)?;
+            Self::record_new_resource_deposits(new_resources, api)?;
             Ok(None)
         } else if let Some(badge) = authorized_depositor_badge {
             Self::validate_badge_is_authorized_depositor(&badge, api)??;
@@ -876,29 +1019,20 @@ impl AccountBlueprint {
             Self::deposit_batch(buckets, api)?;
             Ok(None)
         } else {
-            for bucket in offending_buckets {
-                let resource_address = bucket.resource_address(api)?;
-                let event = if resource_address.is_fungible() {
-                    RejectedDepositEvent::Fungible(resource_address, bucket.amount(api)?)
-                } else {
-                    RejectedDepositEvent::NonFungible(
-                        resource_address,
-                        bucket.non_fungible_local_ids(api)?,
-                    )
-                };
-                Runtime::emit_event(api, event)?;
+            for (bucket, reason) in offending_buckets {
+                Self::emit_rejected_deposit_events(&bucket, reason, api)?;
             }
             Ok(Some(buckets))
         }
     }
 
-    pub fn try_deposit_or_abort<Y>(
+    pub fn try_deposit_or_abort<Y, L: Default>(
         bucket: Bucket,
         authorized_depositor_badge: Option<ResourceOrNonFungible>,
         api: &mut Y,
     ) -> Result<(), RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         if let Some(bucket) = Self::try_deposit_or_refund(bucket, authorized_depositor_badge, api)?
         {
@@ -911,13 +1045,13 @@ impl AccountBlueprint {
 
     /// Method is public to all - if ANY of the resources can't be deposited then the execution
     /// panics.
-    pub fn try_deposit_batch_or_abort<Y>(
+    pub fn try_deposit_batch_or_abort<Y, L: Default>(
         buckets: Vec<Bucket>,
         authorized_depositor_badge: Option<ResourceOrNonFungible>,
         api: &mut Y,
     ) -> Result<(), RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         let buckets = Self::try_deposit_batch_or_refund(buckets, authorized_depositor_badge, api)?;
         if let Some(_) = buckets {
@@ -960,6 +1094,31 @@ impl AccountBlueprint {
         }
     }
 
+    fn emit_rejected_deposit_events<Y>(
+        bucket: &Bucket,
+        reason: DepositRejectionReason,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let resource_address = bucket.resource_address(api)?;
+        let event = if resource_address.is_fungible() {
+            RejectedDepositEvent::Fungible(resource_address, bucket.amount(api)?)
+        } else {
+            RejectedDepositEvent::NonFungible(resource_address, bucket.non_fungible_local_ids(api)?)
+        };
+        Runtime::emit_event(api, event)?;
+        Runtime::emit_event(
+            api,
+            RejectedDepositReasonEvent {
+                resource_address,
+                reason,
+            },
+        )?;
+        Ok(())
+    }
+
     fn validate_badge_is_present<Y>(
         badge: ResourceOrNonFungible,
         api: &mut Y,
@@ -1163,6 +1322,33 @@ impl AccountBlueprint {
         Ok(())
     }
 
+    pub fn set_deposit_rule<Y>(
+        deposit_rule: Option<DepositRule>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountField::DepositRule.field_index(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<AccountDepositRuleFieldPayload>(handle)?
+            .into_latest();
+        substate.deposit_rule = deposit_rule.clone();
+        api.field_write_typed(
+            handle,
+            &AccountDepositRuleFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(api, SetDepositRuleEvent { deposit_rule })?;
+
+        Ok(())
+    }
+
     pub fn set_resource_preference<Y>(
         resource_address: ResourceAddress,
         resource_preference: ResourcePreference,
@@ -1270,7 +1456,179 @@ impl AccountBlueprint {
         Ok(())
     }
 
-    fn get_default_deposit_rule<Y>(api: &mut Y) -> Result<DefaultDepositRule, RuntimeError>
+    pub fn get_vaults<Y, L: Default>(
+        cursor: u32,
+        limit: u32,
+        api: &mut Y,
+    ) -> Result<AccountVaultsPage, RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        Self::backfill_resource_vault_index(api)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountField::ResourceVaultCount.field_index(),
+            LockFlags::read_only(),
+        )?;
+        let count = api
+            .field_read_typed::<AccountResourceVaultCountFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        let limit = limit.min(ACCOUNT_GET_VAULTS_MAX_LIMIT);
+        let start = cursor.min(count);
+        let end = cursor.saturating_add(limit).min(count);
+        let mut resource_addresses = Vec::new();
+        for index in start..end {
+            let encoded_key = scrypto_encode(&index).expect("Impossible Case!");
+            let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                AccountCollection::ResourceVaultIndexKeyValue.collection_index(),
+                &encoded_key,
+                LockFlags::read_only(),
+            )?;
+            let entry = api.key_value_entry_get_typed::<VersionedAccountResourceVaultIndex>(
+                kv_store_entry_lock_handle,
+            )?;
+            api.key_value_entry_close(kv_store_entry_lock_handle)?;
+            if let Some(VersionedAccountResourceVaultIndex::V1(resource_address)) = entry {
+                resource_addresses.push(resource_address);
+            }
+        }
+
+        Ok(AccountVaultsPage {
+            resource_addresses,
+            next_cursor: if end < count { Some(end) } else { None },
+        })
+    }
+
+    pub fn balance_of<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !Self::does_vault_exist(&resource_address, api)? {
+            return Ok(Decimal::ZERO);
+        }
+
+        Self::get_vault(resource_address, |vault, api| vault.amount(api), false, api)
+    }
+
+    fn append_resource_vault_index<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountField::ResourceVaultCount.field_index(),
+            LockFlags::MUTABLE,
+        )?;
+        let count = api
+            .field_read_typed::<AccountResourceVaultCountFieldPayload>(handle)?
+            .into_latest();
+
+        Self::set_resource_vault_index(count, resource_address, api)?;
+
+        api.field_write_typed(
+            handle,
+            &AccountResourceVaultCountFieldPayload::from_content_source(count + 1),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
+    fn set_resource_vault_index<Y>(
+        index: u32,
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let encoded_key = scrypto_encode(&index).expect("Impossible Case!");
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountCollection::ResourceVaultIndexKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            kv_store_entry_lock_handle,
+            &VersionedAccountResourceVaultIndex::V1(resource_address),
+        )?;
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        Ok(())
+    }
+
+    /// Accounts created before their vaults were indexed have neither the vault count nor the
+    /// index. The first time either is needed, the index is built from the keys of the existing
+    /// vaults, which are indexed in key order ahead of any vaults created afterwards.
+    fn backfill_resource_vault_index<Y, L: Default>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let node_id = api.actor_get_node_id(ACTOR_REF_SELF)?;
+        let count_partition = AccountPartitionOffset::Field.as_main_partition();
+        let count_key: SubstateKey = AccountField::ResourceVaultCount.into();
+
+        let mut is_missing = false;
+        let handle = api.kernel_open_substate_with_default(
+            &node_id,
+            count_partition,
+            &count_key,
+            LockFlags::read_only(),
+            Some(|| {
+                is_missing = true;
+                IndexedScryptoValue::from_typed(&())
+            }),
+            L::default(),
+        )?;
+        api.kernel_close_substate(handle)?;
+        if !is_missing {
+            return Ok(());
+        }
+
+        let vault_keys = api.kernel_scan_keys::<MapKey>(
+            &node_id,
+            AccountPartitionOffset::ResourceVaultKeyValue.as_main_partition(),
+            u32::MAX,
+        )?;
+        let mut count = 0u32;
+        for vault_key in vault_keys {
+            let resource_address: ResourceAddress =
+                scrypto_decode(&vault_key.into_map()).expect("Impossible Case!");
+            Self::set_resource_vault_index(count, resource_address, api)?;
+            count += 1;
+        }
+
+        let handle = api.kernel_open_substate_with_default(
+            &node_id,
+            count_partition,
+            &count_key,
+            LockFlags::MUTABLE,
+            Some(|| IndexedScryptoValue::from_typed(&())),
+            L::default(),
+        )?;
+        api.kernel_write_substate(
+            handle,
+            IndexedScryptoValue::from_typed(&FieldSubstate::new_mutable_field(
+                AccountResourceVaultCountFieldPayload::from_content_source(count),
+            )),
+        )?;
+        api.kernel_close_substate(handle)?;
+
+        Ok(())
+    }
+
+    fn get_account_substate<Y>(api: &mut Y) -> Result<AccountSubstateV2, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -1279,13 +1637,12 @@ impl AccountBlueprint {
             AccountField::DepositRule.field_index(),
             LockFlags::read_only(),
         )?;
-        let deposit_rule = api
+        let substate = api
             .field_read_typed::<AccountDepositRuleFieldPayload>(handle)?
             .into_latest();
-        let default = deposit_rule.default_deposit_rule;
         api.field_close(handle)?;
 
-        Ok(default)
+        Ok(substate)
     }
 
     fn get_vault<F, Y, R>(
@@ -1326,6 +1683,7 @@ impl AccountBlueprint {
                             LockFlags::MUTABLE,
                         )?;
                         let vault = Vault::create(resource_address, api)?;
+                        Self::append_resource_vault_index(resource_address, api)?;
                         let own = vault.0;
                         api.key_value_entry_set_typed(
                             kv_store_entry_lock_handle,
@@ -1353,30 +1711,217 @@ impl AccountBlueprint {
         }
     }
 
-    fn is_deposit_allowed<Y>(
+    // Returns a result of a result. The outer result is for failures of the process itself while
+    // the inner result is for whether the deposit is allowed, and if not, why. Resources that the
+    // account holds no vault for are added to `new_resources` when admitted by the deposit rule.
+    fn is_deposit_allowed<Y, L: Default>(
         resource_address: &ResourceAddress,
+        new_resources: &mut BTreeSet<ResourceAddress>,
         api: &mut Y,
-    ) -> Result<bool, RuntimeError>
+    ) -> Result<Result<(), DepositRejectionReason>, RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         match Self::get_resource_preference(resource_address, api)? {
-            Some(ResourcePreference::Allowed) => Ok(true),
-            Some(ResourcePreference::Disallowed) => Ok(false),
+            Some(ResourcePreference::Allowed) => Ok(Ok(())),
+            Some(ResourcePreference::Disallowed) => {
+                Ok(Err(DepositRejectionReason::ResourcePreference))
+            }
             None => {
-                let default = Self::get_default_deposit_rule(api)?;
-                match default {
-                    DefaultDepositRule::Accept => Ok(true),
-                    DefaultDepositRule::Reject => Ok(false),
-                    DefaultDepositRule::AllowExisting => {
-                        Ok(*resource_address == XRD
-                            || Self::does_vault_exist(resource_address, api)?)
+                let AccountSubstateV2 {
+                    default_deposit_rule,
+                    deposit_rule,
+                    new_resource_deposits,
+                } = Self::get_account_substate(api)?;
+
+                let is_existing_resource = match (default_deposit_rule, &deposit_rule) {
+                    (DefaultDepositRule::AllowExisting, _) | (_, Some(_)) => Some(
+                        *resource_address == XRD || Self::does_vault_exist(resource_address, api)?,
+                    ),
+                    _ => None,
+                };
+
+                let is_allowed_by_default = match default_deposit_rule {
+                    DefaultDepositRule::Accept => true,
+                    DefaultDepositRule::Reject => false,
+                    DefaultDepositRule::AllowExisting => is_existing_resource == Some(true),
+                };
+                if !is_allowed_by_default {
+                    return Ok(Err(DepositRejectionReason::DefaultDepositRule(
+                        default_deposit_rule,
+                    )));
+                }
+
+                if let Some(deposit_rule) = deposit_rule {
+                    let is_new_resource = is_existing_resource == Some(false)
+                        && !new_resources.contains(resource_address);
+                    let failing_rule = Self::evaluate_deposit_rule(
+                        &deposit_rule,
+                        resource_address,
+                        is_new_resource,
+                        &new_resource_deposits,
+                        new_resources.len() as u32,
+                        api,
+                    )?;
+                    if let Some(failing_rule) = failing_rule {
+                        return Ok(Err(DepositRejectionReason::DepositRule(failing_rule)));
+                    }
+                    if is_new_resource {
+                        new_resources.insert(*resource_address);
                     }
                 }
+
+                Ok(Ok(()))
             }
         }
     }
 
+    /// Returns the rule which the deposit fails to satisfy, or [`None`] if it satisfies the rule.
+    fn evaluate_deposit_rule<Y, L: Default>(
+        deposit_rule: &DepositRule,
+        resource_address: &ResourceAddress,
+        is_new_resource: bool,
+        new_resource_deposits: &NewResourceDeposits,
+        pending_new_resources: u32,
+        api: &mut Y,
+    ) -> Result<Option<DepositRule>, RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let is_satisfied = match deposit_rule {
+            DepositRule::ResourceHasTag(tag) => {
+                let rtn = api.call_module_method(
+                    resource_address.as_node_id(),
+                    ModuleId::Metadata,
+                    METADATA_GET_IDENT,
+                    scrypto_encode(&MetadataGetInput {
+                        key: "tags".to_owned(),
+                    })
+                    .map_err(AccountError::EncodeError)?,
+                )?;
+                match scrypto_decode::<MetadataGetOutput>(&rtn)
+                    .map_err(AccountError::DecodeError)?
+                {
+                    Some(MetadataValue::StringArray(tags)) => tags.contains(tag),
+                    _ => false,
+                }
+            }
+            DepositRule::SenderIsOneOf(senders) => {
+                let access_rule = AccessRule::Protected(AccessRuleNode::AnyOf(
+                    senders
+                        .iter()
+                        .map(|sender| {
+                            AccessRuleNode::ProofRule(ProofRule::Require(
+                                ResourceOrNonFungible::NonFungible(
+                                    NonFungibleGlobalId::global_caller_badge(*sender),
+                                ),
+                            ))
+                        })
+                        .collect(),
+                ));
+                let auth_zone = api.actor_get_node_id(ACTOR_REF_AUTH_ZONE)?;
+                let result = Authorization::check_authorization_against_access_rule(
+                    api,
+                    &auth_zone,
+                    &access_rule,
+                )?;
+                matches!(result, AuthorizationCheckResult::Authorized)
+            }
+            DepositRule::NewResourcesPerEpoch(limit) => {
+                if is_new_resource {
+                    let current_epoch = Runtime::current_epoch(api)?;
+                    let deposited = if new_resource_deposits.epoch == current_epoch {
+                        new_resource_deposits.count
+                    } else {
+                        0
+                    };
+                    deposited.saturating_add(pending_new_resources) < *limit
+                } else {
+                    true
+                }
+            }
+            DepositRule::AllOf(rules) => {
+                for rule in rules {
+                    let failing_rule = Self::evaluate_deposit_rule(
+                        rule,
+                        resource_address,
+                        is_new_resource,
+                        new_resource_deposits,
+                        pending_new_resources,
+                        api,
+                    )?;
+                    if failing_rule.is_some() {
+                        return Ok(failing_rule);
+                    }
+                }
+                true
+            }
+            DepositRule::AnyOf(rules) => {
+                let mut is_satisfied = false;
+                for rule in rules {
+                    let failing_rule = Self::evaluate_deposit_rule(
+                        rule,
+                        resource_address,
+                        is_new_resource,
+                        new_resource_deposits,
+                        pending_new_resources,
+                        api,
+                    )?;
+                    if failing_rule.is_none() {
+                        is_satisfied = true;
+                        break;
+                    }
+                }
+                is_satisfied
+            }
+        };
+
+        if is_satisfied {
+            Ok(None)
+        } else {
+            Ok(Some(deposit_rule.clone()))
+        }
+    }
+
+    fn record_new_resource_deposits<Y>(
+        new_resources: BTreeSet<ResourceAddress>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if new_resources.is_empty() {
+            return Ok(());
+        }
+
+        let current_epoch = Runtime::current_epoch(api)?;
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountField::DepositRule.field_index(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<AccountDepositRuleFieldPayload>(handle)?
+            .into_latest();
+        if substate.new_resource_deposits.epoch != current_epoch {
+            substate.new_resource_deposits = NewResourceDeposits {
+                epoch: current_epoch,
+                count: 0,
+            };
+        }
+        substate.new_resource_deposits.count = substate
+            .new_resource_deposits
+            .count
+            .saturating_add(new_resources.len() as u32);
+        api.field_write_typed(
+            handle,
+            &AccountDepositRuleFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
     fn does_vault_exist<Y>(
         resource_address: &ResourceAddress,
         api: &mut Y,
@@ -22,6 +22,25 @@ pub enum RejectedDepositEvent {
     NonFungible(ResourceAddress, BTreeSet<NonFungibleLocalId>),
 }
 
+/// Emitted alongside each [`RejectedDepositEvent`] to explain why the deposit was rejected.
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RejectedDepositReasonEvent {
+    pub resource_address: ResourceAddress,
+    pub reason: DepositRejectionReason,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DepositRejectionReason {
+    /// The resource is on the deny list.
+    ResourcePreference,
+
+    /// The resource is not permitted by the default deposit rule.
+    DefaultDepositRule(DefaultDepositRule),
+
+    /// The deposit did not satisfy the given rule of the account's deposit rule.
+    DepositRule(DepositRule),
+}
+
 #[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SetResourcePreferenceEvent {
     pub resource_address: ResourceAddress,
@@ -38,6 +57,11 @@ pub struct SetDefaultDepositRuleEvent {
     pub default_deposit_rule: DefaultDepositRule,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SetDepositRuleEvent {
+    pub deposit_rule: Option<DepositRule>,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AddAuthorizedDepositorEvent {
     pub authorized_depositor_badge: ResourceOrNonFungible,
@@ -1,6 +1,7 @@
 use crate::blueprints::account::AccountBlueprint;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use crate::kernel::kernel_api::KernelSubstateApi;
 use crate::types::*;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::ClientApi;
@@ -21,13 +22,13 @@ impl AccountNativePackage {
         PackageDefinition { blueprints }
     }
 
-    pub fn invoke_export<Y>(
+    pub fn invoke_export<Y, L: Default>(
         export_name: &str,
         input: &IndexedScryptoValue,
         api: &mut Y,
     ) -> Result<IndexedScryptoValue, RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         match export_name {
             ACCOUNT_ON_VIRTUALIZE_EXPORT_NAME => {
@@ -85,6 +86,19 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::lock_contingent_fee(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT => {
+                let input: AccountLockRoyaltyPaymentInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountBlueprint::lock_royalty_payment(
+                    input.resource_address,
+                    input.amount,
+                    input.max_per_function,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_DEPOSIT_IDENT => {
                 let input: AccountDepositInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -244,6 +258,20 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_GET_VAULTS_IDENT => {
+                let AccountGetVaultsInput { cursor, limit } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::get_vaults(cursor, limit, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_BALANCE_OF_IDENT => {
+                let AccountBalanceOfInput { resource_address } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::balance_of(resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => {
                 let AccountSetDefaultDepositRuleInput { default } =
                     input.as_typed().map_err(|e| {
@@ -252,6 +280,14 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::set_default_deposit_rule(default, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT => {
+                let AccountSetDepositRuleInput { deposit_rule } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::set_deposit_rule(deposit_rule, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => {
                 let AccountSetResourcePreferenceInput {
                     resource_address,
@@ -1,4 +1,5 @@
 use crate::types::*;
+use radix_engine_interface::blueprints::consensus_manager::ValidatorCommissionTier;
 use radix_engine_interface::math::Decimal;
 
 #[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
@@ -27,6 +28,18 @@ pub struct UpdateAcceptingStakeDelegationStateEvent {
     pub accepts_delegation: bool,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct UpdateMaxStakeEvent {
+    pub max_stake_xrd: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct UpdateCommissionTiersEvent {
+    pub tiers: Vec<ValidatorCommissionTier>,
+    /// An epoch number at (or after) which the new tiers are effective.
+    pub epoch_effective: Epoch,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
 pub struct ProtocolUpdateReadinessSignalEvent {
     pub protocol_version_name: String,
@@ -145,6 +145,17 @@ impl ConsensusManagerNativePackage {
                 let rtn = ValidatorBlueprint::unstake(input.stake_unit_bucket, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT => {
+                let input: ValidatorUnstakeForClaimantInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::unstake_for_claimant(
+                    input.stake_unit_bucket,
+                    input.claimant,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             VALIDATOR_CLAIM_XRD_IDENT => {
                 let input: ValidatorClaimXrdInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -177,6 +188,27 @@ impl ConsensusManagerNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            VALIDATOR_UPDATE_MAX_STAKE_IDENT => {
+                let input: ValidatorUpdateMaxStakeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::update_max_stake(input.max_stake_xrd, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT => {
+                let input: ValidatorUpdateCommissionTiersInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::update_commission_tiers(input.tiers, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            VALIDATOR_GET_PENDING_UNSTAKES_IDENT => {
+                let input: ValidatorGetPendingUnstakesInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ValidatorBlueprint::get_pending_unstakes(input.claimant, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             VALIDATOR_ACCEPTS_DELEGATED_STAKE_IDENT => {
                 let _: ValidatorAcceptsDelegatedStakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
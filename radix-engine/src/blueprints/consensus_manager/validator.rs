@@ -27,7 +27,7 @@ use sbor::rust::mem;
 
 use super::{
     ClaimXrdEvent, RegisterValidatorEvent, StakeEvent, UnregisterValidatorEvent, UnstakeEvent,
-    UpdateAcceptingStakeDelegationStateEvent,
+    UpdateAcceptingStakeDelegationStateEvent, UpdateCommissionTiersEvent, UpdateMaxStakeEvent,
 };
 
 pub const VALIDATOR_PROTOCOL_VERSION_NAME_LEN: usize = 32;
@@ -36,8 +36,35 @@ pub const VALIDATOR_PROTOCOL_VERSION_NAME_LEN: usize = 32;
 /// operations on any validator's owner's stake units vault.
 pub const OWNER_STAKE_UNITS_PENDING_WITHDRAWALS_LIMIT: usize = 100;
 
+/// A performance-driven limit on the number of commission tiers of any validator.
+pub const VALIDATOR_COMMISSION_TIERS_LIMIT: usize = 16;
+
+/// A performance-driven limit on the number of unclaimed stake claim NFTs listed under a single
+/// claimant of any validator (see [`ValidatorBlueprint::unstake_for_claimant`]).
+pub const CLAIMANT_PENDING_UNSTAKES_LIMIT: usize = 100;
+
+/// The validator state as it was stored before the stake limit and the commission tiers were
+/// introduced. See [`ValidatorSubstateV2`] for the meaning of the fields.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct ValidatorSubstate {
+    pub sorted_key: Option<SortedKey>,
+    pub key: Secp256k1PublicKey,
+    pub is_registered: bool,
+    pub accepts_delegated_stake: bool,
+    pub validator_fee_factor: Decimal,
+    pub validator_fee_change_request: Option<ValidatorFeeChangeRequest>,
+    pub stake_unit_resource: ResourceAddress,
+    pub stake_xrd_vault_id: Own,
+    pub claim_nft: ResourceAddress,
+    pub pending_xrd_withdraw_vault_id: Own,
+    pub locked_owner_stake_unit_vault_id: Own,
+    pub pending_owner_stake_unit_unlock_vault_id: Own,
+    pub pending_owner_stake_unit_withdrawals: BTreeMap<Epoch, Decimal>,
+    pub already_unlocked_owner_stake_unit_amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ValidatorSubstateV2 {
     /// A key used internally for storage of registered validators sorted by their stake descending.
     /// It is only useful when the validator is registered and has non-zero stake - hence, the field
     /// is [`None`] otherwise.
@@ -113,6 +140,49 @@ pub struct ValidatorSubstate {
     /// in the [`pending_owner_stake_unit_withdrawals`] and was automatically moved from there.
     /// The very next [`finish_unlock_owner_stake_units()`] operation will release this amount.
     pub already_unlocked_owner_stake_unit_amount: Decimal,
+
+    /// An upper limit on the total XRD stake which delegated staking may bring this validator to.
+    /// Note: it only applies to [`stake()`] - the owner's stake, emissions and rewards may still
+    /// take the total stake above it.
+    /// Note: [`None`] means that the delegated stake is unlimited.
+    pub max_stake_xrd: Option<Decimal>,
+
+    /// Tiered overrides of the [`validator_fee_factor`], sorted strictly ascending by their
+    /// minimum stake: the tier with the highest minimum stake not exceeding the stake pool's XRD
+    /// (captured before an emission) determines the fee factor for that emission. If no tier
+    /// applies, the [`validator_fee_factor`] is used.
+    /// Note: it may be overridden by [`commission_tiers_change_request`], in the same way as the
+    /// [`validator_fee_factor`] may be overridden by [`validator_fee_change_request`].
+    pub commission_tiers: Vec<ValidatorCommissionTier>,
+
+    /// The most recent request to change the [`commission_tiers`] (which requires a delay).
+    pub commission_tiers_change_request: Option<ValidatorCommissionTiersChangeRequest>,
+}
+
+impl From<ValidatorSubstate> for ValidatorSubstateV2 {
+    fn from(value: ValidatorSubstate) -> Self {
+        Self {
+            sorted_key: value.sorted_key,
+            key: value.key,
+            is_registered: value.is_registered,
+            accepts_delegated_stake: value.accepts_delegated_stake,
+            validator_fee_factor: value.validator_fee_factor,
+            validator_fee_change_request: value.validator_fee_change_request,
+            stake_unit_resource: value.stake_unit_resource,
+            stake_xrd_vault_id: value.stake_xrd_vault_id,
+            claim_nft: value.claim_nft,
+            pending_xrd_withdraw_vault_id: value.pending_xrd_withdraw_vault_id,
+            locked_owner_stake_unit_vault_id: value.locked_owner_stake_unit_vault_id,
+            pending_owner_stake_unit_unlock_vault_id: value
+                .pending_owner_stake_unit_unlock_vault_id,
+            pending_owner_stake_unit_withdrawals: value.pending_owner_stake_unit_withdrawals,
+            already_unlocked_owner_stake_unit_amount: value
+                .already_unlocked_owner_stake_unit_amount,
+            max_stake_xrd: None,
+            commission_tiers: Vec::new(),
+            commission_tiers_change_request: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -138,7 +208,7 @@ pub struct ValidatorFeeChangeRequest {
     /// An epoch number at (or after) which the fee change is effective.
     /// To be specific: when a next epoch `N` begins, we perform accounting of emissions due for
     /// previous epoch `N-1` - this means that we will use this [`new_validator_fee_factor`] only if
-    /// `epoch_effective <= N-1`, and [`ValidatorSubstate.validator_fee_factor`] otherwise.
+    /// `epoch_effective <= N-1`, and [`ValidatorSubstateV2.validator_fee_factor`] otherwise.
     /// Note: when requesting a fee decrease, this will be "next epoch"; and when requesting an
     /// increase, this will be set to [`ConsensusManagerConfigSubstate.num_fee_increase_delay_epochs`]
     /// epochs away.
@@ -148,6 +218,19 @@ pub struct ValidatorFeeChangeRequest {
     pub new_fee_factor: Decimal,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ValidatorCommissionTiersChangeRequest {
+    /// An epoch number at (or after) which the change is effective (interpreted in the same way as
+    /// [`ValidatorFeeChangeRequest.epoch_effective`]).
+    /// Note: since a change of tiers may increase the fee at some stake levels while decreasing it
+    /// at others, it is always set to [`ConsensusManagerConfigSubstate.num_fee_increase_delay_epochs`]
+    /// epochs away.
+    pub epoch_effective: Epoch,
+
+    /// A requested new value of [`ValidatorSubstateV2.commission_tiers`].
+    pub new_tiers: Vec<ValidatorCommissionTier>,
+}
+
 impl NonFungibleData for UnstakeData {
     const MUTABLE_FIELDS: &'static [&'static str] = &[];
 }
@@ -161,7 +244,24 @@ pub enum ValidatorError {
     PendingOwnerStakeWithdrawalLimitReached,
     InvalidValidatorFeeFactor,
     ValidatorIsNotAcceptingDelegatedStake,
-    InvalidProtocolVersionNameLength { expected: usize, actual: usize },
+    InvalidProtocolVersionNameLength {
+        expected: usize,
+        actual: usize,
+    },
+    InvalidMaxStake,
+    MaxStakeExceeded {
+        max_stake_xrd: Decimal,
+        resulting_stake_xrd: Decimal,
+    },
+    InvalidCommissionTiers,
+    CommissionTiersLimitExceeded {
+        limit: usize,
+        actual: usize,
+    },
+    ClaimantPendingUnstakesLimitReached {
+        claimant: ComponentAddress,
+        limit: usize,
+    },
 }
 
 declare_native_blueprint_state! {
@@ -173,7 +273,9 @@ declare_native_blueprint_state! {
         state: {
             ident: State,
             field_type: {
-                kind: StaticSingleVersioned,
+                kind: StaticMultiVersioned,
+                previous_versions: [1 => { updates_to: 2 }],
+                latest_version: 2,
             },
             condition: Condition::Always,
         },
@@ -186,11 +288,38 @@ declare_native_blueprint_state! {
         },
     },
     collections: {
+        claimant_pending_unstakes: KeyValue {
+            entry_ident: ClaimantPendingUnstakes,
+            key_type: {
+                kind: Static,
+                content_type: ComponentAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        claim_nft_claimants: KeyValue {
+            entry_ident: ClaimNftClaimant,
+            key_type: {
+                kind: Static,
+                content_type: NonFungibleLocalId,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
 pub type ValidatorStateV1 = ValidatorSubstate;
+pub type ValidatorStateV2 = ValidatorSubstateV2;
 pub type ValidatorProtocolUpdateReadinessSignalV1 = ValidatorProtocolUpdateReadinessSignalSubstate;
+/// The unclaimed stake claim NFTs minted for the given claimant, in the order they were minted in.
+pub type ValidatorClaimantPendingUnstakesV1 = IndexSet<NonFungibleLocalId>;
+/// The claimant the given stake claim NFT was minted for.
+pub type ValidatorClaimNftClaimantV1 = ComponentAddress;
 
 pub struct ValidatorBlueprint;
 
@@ -267,6 +396,20 @@ impl ValidatorBlueprint {
                 export: VALIDATOR_UNSTAKE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorUnstakeForClaimantInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ValidatorUnstakeForClaimantOutput>(),
+                ),
+                export: VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VALIDATOR_CLAIM_XRD_IDENT.to_string(),
             FunctionSchemaInit {
@@ -332,6 +475,48 @@ impl ValidatorBlueprint {
                 export: VALIDATOR_ACCEPTS_DELEGATED_STAKE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            VALIDATOR_UPDATE_MAX_STAKE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorUpdateMaxStakeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorUpdateMaxStakeOutput>(),
+                ),
+                export: VALIDATOR_UPDATE_MAX_STAKE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ValidatorUpdateCommissionTiersInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ValidatorUpdateCommissionTiersOutput>(),
+                ),
+                export: VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            VALIDATOR_GET_PENDING_UNSTAKES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ValidatorGetPendingUnstakesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ValidatorGetPendingUnstakesOutput>(),
+                ),
+                export: VALIDATOR_GET_PENDING_UNSTAKES_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VALIDATOR_TOTAL_STAKE_XRD_AMOUNT_IDENT.to_string(),
             FunctionSchemaInit {
@@ -472,6 +657,8 @@ impl ValidatorBlueprint {
                 ClaimXrdEvent,
                 ProtocolUpdateReadinessSignalEvent,
                 UpdateAcceptingStakeDelegationStateEvent,
+                UpdateMaxStakeEvent,
+                UpdateCommissionTiersEvent,
                 ValidatorEmissionAppliedEvent,
                 ValidatorRewardAppliedEvent
             ]
@@ -500,12 +687,14 @@ impl ValidatorBlueprint {
                 method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
                     methods {
                         VALIDATOR_UNSTAKE_IDENT => MethodAccessibility::Public;
+                        VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT => MethodAccessibility::Public;
                         VALIDATOR_CLAIM_XRD_IDENT => MethodAccessibility::Public;
                         VALIDATOR_STAKE_IDENT => MethodAccessibility::Public;
                         VALIDATOR_ACCEPTS_DELEGATED_STAKE_IDENT => MethodAccessibility::Public;
                         VALIDATOR_TOTAL_STAKE_XRD_AMOUNT_IDENT => MethodAccessibility::Public;
                         VALIDATOR_TOTAL_STAKE_UNIT_SUPPLY_IDENT => MethodAccessibility::Public;
                         VALIDATOR_GET_REDEMPTION_VALUE_IDENT => MethodAccessibility::Public;
+                        VALIDATOR_GET_PENDING_UNSTAKES_IDENT => MethodAccessibility::Public;
                         VALIDATOR_STAKE_AS_OWNER_IDENT => [OWNER_ROLE];
                         VALIDATOR_REGISTER_IDENT => [OWNER_ROLE];
                         VALIDATOR_UNREGISTER_IDENT => [OWNER_ROLE];
@@ -515,6 +704,8 @@ impl ValidatorBlueprint {
                         VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
                         VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT => [OWNER_ROLE];
                         VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT => [OWNER_ROLE];
+                        VALIDATOR_UPDATE_MAX_STAKE_IDENT => [OWNER_ROLE];
+                        VALIDATOR_UPDATE_COMMISSION_TIERS_IDENT => [OWNER_ROLE];
                         VALIDATOR_SIGNAL_PROTOCOL_UPDATE_READINESS => [OWNER_ROLE];
                         VALIDATOR_GET_PROTOCOL_UPDATE_READINESS_IDENT => MethodAccessibility::OuterObjectOnly;
                         VALIDATOR_APPLY_EMISSION_IDENT => MethodAccessibility::OuterObjectOnly;
@@ -586,6 +777,29 @@ impl ValidatorBlueprint {
 
         let xrd_bucket_amount = xrd_bucket.amount(api)?;
 
+        if !is_owner {
+            if let Some(max_stake_xrd) = validator.max_stake_xrd {
+                let resulting_stake_xrd = Vault(validator.stake_xrd_vault_id)
+                    .amount(api)?
+                    .safe_add(xrd_bucket_amount)
+                    .ok_or(RuntimeError::ApplicationError(
+                        ApplicationError::ValidatorError(
+                            ValidatorError::UnexpectedDecimalComputationError,
+                        ),
+                    ))?;
+                if resulting_stake_xrd > max_stake_xrd {
+                    api.field_close(handle)?;
+
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::ValidatorError(ValidatorError::MaxStakeExceeded {
+                            max_stake_xrd,
+                            resulting_stake_xrd,
+                        }),
+                    ));
+                }
+            }
+        }
+
         // Stake
         let (stake_unit_bucket, new_stake_amount) = {
             let mut stake_unit_resman = ResourceManager(validator.stake_unit_resource);
@@ -623,6 +837,56 @@ impl ValidatorBlueprint {
     }
 
     pub fn unstake<Y>(stake_unit_bucket: Bucket, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (unstake_bucket, _) = Self::unstake_internal(stake_unit_bucket, api)?;
+        Ok(unstake_bucket)
+    }
+
+    /// Unstakes the same way as [`Self::unstake`], additionally listing the returned claim NFT
+    /// under the given claimant until it is claimed (see [`Self::get_pending_unstakes`]).
+    pub fn unstake_for_claimant<Y>(
+        stake_unit_bucket: Bucket,
+        claimant: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut claim_nft_ids = Self::read_claimant_pending_unstakes(claimant, api)?;
+        if claim_nft_ids.len() >= CLAIMANT_PENDING_UNSTAKES_LIMIT {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ValidatorError(
+                    ValidatorError::ClaimantPendingUnstakesLimitReached {
+                        claimant,
+                        limit: CLAIMANT_PENDING_UNSTAKES_LIMIT,
+                    },
+                ),
+            ));
+        }
+
+        let (unstake_bucket, claim_nft_id) = Self::unstake_internal(stake_unit_bucket, api)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            ValidatorCollection::ClaimNftClaimantKeyValue.collection_index(),
+            &scrypto_encode(&claim_nft_id).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(handle, &VersionedValidatorClaimNftClaimant::V1(claimant))?;
+        api.key_value_entry_close(handle)?;
+
+        claim_nft_ids.insert(claim_nft_id);
+        Self::write_claimant_pending_unstakes(claimant, claim_nft_ids, api)?;
+
+        Ok(unstake_bucket)
+    }
+
+    fn unstake_internal<Y>(
+        stake_unit_bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<(Bucket, NonFungibleLocalId), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -638,7 +902,7 @@ impl ValidatorBlueprint {
             .into_latest();
 
         // Unstake
-        let (unstake_bucket, new_stake_amount) = {
+        let (unstake_bucket, claim_nft_id, new_stake_amount) = {
             let xrd_amount = Self::calculate_redemption_value(
                 stake_unit_bucket_amount,
                 &validator_substate,
@@ -682,11 +946,12 @@ impl ValidatorBlueprint {
 
             let bucket = stake_vault.take(xrd_amount, api)?;
             unstake_vault.put(bucket, api)?;
-            let (unstake_bucket, _) = nft_resman.mint_non_fungible_single_ruid(data, api)?;
+            let (unstake_bucket, claim_nft_id) =
+                nft_resman.mint_non_fungible_single_ruid(data, api)?;
 
             let new_stake_amount = stake_vault.amount(api)?;
 
-            (unstake_bucket, new_stake_amount)
+            (unstake_bucket, claim_nft_id, new_stake_amount)
         };
 
         // Update ConsensusManager
@@ -710,7 +975,7 @@ impl ValidatorBlueprint {
             },
         )?;
 
-        Ok(unstake_bucket)
+        Ok((unstake_bucket, claim_nft_id))
     }
 
     pub fn signal_protocol_update_readiness<Y>(
@@ -815,7 +1080,7 @@ impl ValidatorBlueprint {
     }
 
     fn index_update<Y>(
-        validator: &ValidatorSubstate,
+        validator: &ValidatorSubstateV2,
         new_registered: bool,
         new_stake_amount: Decimal,
         api: &mut Y,
@@ -897,7 +1162,8 @@ impl ValidatorBlueprint {
 
         let mut unstake_amount = Decimal::zero();
 
-        for id in bucket.non_fungible_local_ids(api)? {
+        let claim_nft_ids = bucket.non_fungible_local_ids(api)?;
+        for id in claim_nft_ids.iter().cloned() {
             let data: UnstakeData = nft_resman.get_non_fungible_data(id, api)?;
             if current_epoch < data.claim_epoch {
                 return Err(RuntimeError::ApplicationError(
@@ -912,6 +1178,21 @@ impl ValidatorBlueprint {
         }
         nft_resman.burn(bucket, api)?;
 
+        for id in claim_nft_ids {
+            let claimant = api
+                .actor_remove_key_value_entry_typed::<VersionedValidatorClaimNftClaimant>(
+                    ACTOR_STATE_SELF,
+                    ValidatorCollection::ClaimNftClaimantKeyValue.collection_index(),
+                    &scrypto_encode(&id).unwrap(),
+                )?;
+            if let Some(VersionedValidatorClaimNftClaimant::V1(claimant)) = claimant {
+                let mut claimant_claim_nft_ids =
+                    Self::read_claimant_pending_unstakes(claimant, api)?;
+                claimant_claim_nft_ids.shift_remove(&id);
+                Self::write_claimant_pending_unstakes(claimant, claimant_claim_nft_ids, api)?;
+            }
+        }
+
         let claimed_bucket = unstake_vault.take(unstake_amount, api)?;
 
         let amount = claimed_bucket.amount(api)?;
@@ -1156,9 +1437,215 @@ impl ValidatorBlueprint {
         Ok(())
     }
 
+    /// Sets (or removes, if [`None`]) the limit on the total XRD stake which delegated staking may
+    /// bring this validator to (see [`ValidatorSubstateV2.max_stake_xrd`]).
+    /// Note: lowering the limit below the current stake does not affect the stake already present.
+    pub fn update_max_stake<Y>(
+        max_stake_xrd: Option<Decimal>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if let Some(max_stake_xrd) = max_stake_xrd {
+            if max_stake_xrd.is_negative() {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::ValidatorError(ValidatorError::InvalidMaxStake),
+                ));
+            }
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ValidatorField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<ValidatorStateFieldPayload>(handle)?
+            .into_latest();
+        substate.max_stake_xrd = max_stake_xrd;
+        api.field_write_typed(
+            handle,
+            &ValidatorStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(api, UpdateMaxStakeEvent { max_stake_xrd })?;
+
+        Ok(())
+    }
+
+    /// Requests a change of this validator's commission tiers (see
+    /// [`ValidatorSubstateV2.commission_tiers`]), which becomes effective after the network-configured
+    /// [`ConsensusManagerConfigSubstate.num_fee_increase_delay_epochs`].
+    pub fn update_commission_tiers<Y>(
+        new_tiers: Vec<ValidatorCommissionTier>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // check if the new tiers are valid
+        check_commission_tiers(&new_tiers)?;
+
+        // read the current epoch
+        let consensus_manager_handle = api.actor_open_field(
+            ACTOR_STATE_OUTER_OBJECT,
+            ConsensusManagerField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let consensus_manager = api
+            .field_read_typed::<ConsensusManagerStateFieldPayload>(consensus_manager_handle)?
+            .into_latest();
+        let current_epoch = consensus_manager.epoch;
+        api.field_close(consensus_manager_handle)?;
+
+        // read the configured fee increase epochs delay
+        let config_handle = api.actor_open_field(
+            ACTOR_STATE_OUTER_OBJECT,
+            ConsensusManagerField::Configuration.into(),
+            LockFlags::read_only(),
+        )?;
+        let config_substate = api
+            .field_read_typed::<ConsensusManagerConfigurationFieldPayload>(config_handle)?
+            .into_latest();
+        api.field_close(config_handle)?;
+
+        // begin the read+modify+write of the validator substate...
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ValidatorField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<ValidatorStateFieldPayload>(handle)?
+            .into_latest();
+
+        // - promote any currently pending change if it became effective already
+        if let Some(previous_request) = substate.commission_tiers_change_request.take() {
+            if previous_request.epoch_effective <= current_epoch {
+                substate.commission_tiers = previous_request.new_tiers;
+            }
+        }
+
+        // ...end the read+modify+write of the validator substate
+        let epoch_effective =
+            current_epoch.after(config_substate.config.num_fee_increase_delay_epochs);
+        substate.commission_tiers_change_request = Some(ValidatorCommissionTiersChangeRequest {
+            epoch_effective,
+            new_tiers: new_tiers.clone(),
+        });
+        api.field_write_typed(
+            handle,
+            &ValidatorStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            UpdateCommissionTiersEvent {
+                tiers: new_tiers,
+                epoch_effective,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the pending unstakes made for the given claimant using
+    /// [`Self::unstake_for_claimant`] and not claimed yet (see [`UnstakeData`]).
+    /// Note: the claim NFTs may have been transferred since - they are listed under the claimant
+    /// they were minted for until they are claimed.
+    pub fn get_pending_unstakes<Y>(
+        claimant: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<Vec<ValidatorPendingUnstake>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ValidatorField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let substate = api
+            .field_read_typed::<ValidatorStateFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        let claim_nft_ids = Self::read_claimant_pending_unstakes(claimant, api)?;
+
+        let nft_resman = ResourceManager(substate.claim_nft);
+        let mut pending_unstakes = Vec::new();
+        for claim_nft_id in claim_nft_ids {
+            let data: UnstakeData = nft_resman.get_non_fungible_data(claim_nft_id.clone(), api)?;
+            pending_unstakes.push(ValidatorPendingUnstake {
+                claim_nft_id,
+                claim_epoch: data.claim_epoch,
+                claim_amount: data.claim_amount,
+            });
+        }
+
+        Ok(pending_unstakes)
+    }
+
+    fn read_claimant_pending_unstakes<Y>(
+        claimant: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<IndexSet<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            ValidatorCollection::ClaimantPendingUnstakesKeyValue.collection_index(),
+            &scrypto_encode(&claimant).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let entry =
+            api.key_value_entry_get_typed::<VersionedValidatorClaimantPendingUnstakes>(handle)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(match entry {
+            Some(VersionedValidatorClaimantPendingUnstakes::V1(claim_nft_ids)) => claim_nft_ids,
+            None => IndexSet::new(),
+        })
+    }
+
+    fn write_claimant_pending_unstakes<Y>(
+        claimant: ComponentAddress,
+        claim_nft_ids: IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let encoded_key = scrypto_encode(&claimant).unwrap();
+        if claim_nft_ids.is_empty() {
+            api.actor_remove_key_value_entry(
+                ACTOR_STATE_SELF,
+                ValidatorCollection::ClaimantPendingUnstakesKeyValue.collection_index(),
+                &encoded_key,
+            )?;
+        } else {
+            let handle = api.actor_open_key_value_entry(
+                ACTOR_STATE_SELF,
+                ValidatorCollection::ClaimantPendingUnstakesKeyValue.collection_index(),
+                &encoded_key,
+                LockFlags::MUTABLE,
+            )?;
+            api.key_value_entry_set_typed(
+                handle,
+                &VersionedValidatorClaimantPendingUnstakes::V1(claim_nft_ids),
+            )?;
+            api.key_value_entry_close(handle)?;
+        }
+        Ok(())
+    }
+
     /// Locks the given stake units in an internal "delayed withdrawal" vault (which is the owner's
     /// way of showing their commitment to running this validator in an orderly fashion - see
-    /// [`ValidatorSubstate.locked_owner_stake_unit_vault_id`]).
+    /// [`ValidatorSubstateV2.locked_owner_stake_unit_vault_id`]).
     pub fn lock_owner_stake_units<Y>(
         stake_unit_bucket: Bucket,
         api: &mut Y,
@@ -1313,7 +1800,7 @@ impl ValidatorBlueprint {
     /// Note: this house-keeping operation prevents the internal collection from growing to a size
     /// which would affect performance (or exceed the substate size limit).
     fn normalize_available_owner_stake_unit_withdrawals(
-        substate: &mut ValidatorSubstate,
+        substate: &mut ValidatorSubstateV2,
         current_epoch: Epoch,
     ) -> Result<(), RuntimeError> {
         let available_withdrawal_epochs = substate
@@ -1363,10 +1850,27 @@ impl ValidatorBlueprint {
             .field_read_typed::<ValidatorStateFieldPayload>(handle)?
             .into_latest();
 
-        // - resolve the effective validator fee factor
-        let effective_validator_fee_factor = match &substate.validator_fee_change_request {
-            Some(request) if request.epoch_effective <= concluded_epoch => request.new_fee_factor,
-            _ => substate.validator_fee_factor,
+        let mut stake_xrd_vault = Vault(substate.stake_xrd_vault_id);
+        let starting_stake_pool_xrd = stake_xrd_vault.amount(api)?;
+
+        // - resolve the effective validator fee factor (a matching commission tier overrides the
+        //   base one)
+        let effective_commission_tiers = match &substate.commission_tiers_change_request {
+            Some(request) if request.epoch_effective <= concluded_epoch => &request.new_tiers,
+            _ => &substate.commission_tiers,
+        };
+        let effective_validator_fee_factor = match effective_commission_tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_stake_xrd <= starting_stake_pool_xrd)
+        {
+            Some(tier) => tier.fee_factor,
+            None => match &substate.validator_fee_change_request {
+                Some(request) if request.epoch_effective <= concluded_epoch => {
+                    request.new_fee_factor
+                }
+                _ => substate.validator_fee_factor,
+            },
         };
 
         // - calculate the validator fee and subtract it from the emission bucket
@@ -1379,8 +1883,6 @@ impl ValidatorBlueprint {
         let fee_xrd_bucket = xrd_bucket.take(validator_fee_xrd, api)?;
 
         // - put the net emission XRDs into the stake pool
-        let mut stake_xrd_vault = Vault(substate.stake_xrd_vault_id);
-        let starting_stake_pool_xrd = stake_xrd_vault.amount(api)?;
         stake_xrd_vault.put(xrd_bucket, api)?;
 
         // - stake the validator fee XRDs (effectively same as regular staking)
@@ -1600,7 +2102,7 @@ impl ValidatorBlueprint {
 
     fn calculate_redemption_value<Y: ClientApi<RuntimeError>>(
         amount_of_stake_units: Decimal,
-        validator_substate: &ValidatorSubstate,
+        validator_substate: &ValidatorSubstateV2,
         api: &mut Y,
     ) -> Result<Decimal, RuntimeError> {
         let stake_vault = Vault(validator_substate.stake_xrd_vault_id);
@@ -1655,6 +2157,31 @@ fn check_validator_fee_factor(fee_factor: Decimal) -> Result<(), RuntimeError> {
     Ok(())
 }
 
+fn check_commission_tiers(tiers: &[ValidatorCommissionTier]) -> Result<(), RuntimeError> {
+    if tiers.len() > VALIDATOR_COMMISSION_TIERS_LIMIT {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::ValidatorError(ValidatorError::CommissionTiersLimitExceeded {
+                limit: VALIDATOR_COMMISSION_TIERS_LIMIT,
+                actual: tiers.len(),
+            }),
+        ));
+    }
+    for tier in tiers {
+        check_validator_fee_factor(tier.fee_factor)?;
+    }
+    // only allow non-negative minimum stakes, sorted strictly ascending
+    let is_sorted = tiers
+        .windows(2)
+        .all(|pair| pair[0].min_stake_xrd < pair[1].min_stake_xrd);
+    let is_non_negative = tiers.iter().all(|tier| !tier.min_stake_xrd.is_negative());
+    if !is_sorted || !is_non_negative {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::ValidatorError(ValidatorError::InvalidCommissionTiers),
+        ));
+    }
+    Ok(())
+}
+
 fn create_sort_prefix_from_stake(stake: Decimal) -> Result<[u8; 2], RuntimeError> {
     // Note: XRD max supply is 24bn
     // 24bn / MAX::16 = 366210.9375 - so 100k as a divisor here is sensible.
@@ -1792,7 +2319,7 @@ impl ValidatorCreator {
         let pending_owner_stake_unit_unlock_vault = Vault::create(stake_unit_resource, api)?;
         let pending_owner_stake_unit_withdrawals = BTreeMap::new();
 
-        let substate = ValidatorSubstate {
+        let substate = ValidatorSubstateV2 {
             sorted_key: None,
             key,
             is_registered,
@@ -1807,6 +2334,9 @@ impl ValidatorCreator {
             pending_owner_stake_unit_unlock_vault_id: pending_owner_stake_unit_unlock_vault.0,
             pending_owner_stake_unit_withdrawals,
             already_unlocked_owner_stake_unit_amount: Decimal::zero(),
+            max_stake_xrd: None,
+            commission_tiers: Vec::new(),
+            commission_tiers_change_request: None,
         };
 
         let protocol_update_readiness_signal = ValidatorProtocolUpdateReadinessSignalSubstate {
@@ -3,6 +3,7 @@ pub mod account;
 pub mod consensus_manager;
 pub mod identity;
 pub mod models;
+pub mod multisig;
 pub mod native_schema;
 pub mod package;
 pub mod pool;
@@ -10,6 +11,7 @@ pub mod resource;
 pub mod test_utils;
 pub mod transaction_processor;
 pub mod transaction_tracker;
+pub mod vesting;
 pub mod util;
 
 pub(crate) mod internal_prelude {
@@ -0,0 +1,651 @@
+use crate::blueprints::multisig::*;
+use crate::errors::*;
+use crate::internal_prelude::declare_native_blueprint_state;
+use crate::internal_prelude::*;
+use crate::kernel::kernel_api::*;
+use crate::prelude::BlueprintSchemaInit;
+use crate::types::{ReceiverInfo, TypeRef};
+use crate::{event_schema, roles_template};
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::NativeProof;
+use native_sdk::runtime::Runtime;
+use radix_engine_common::prelude::*;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::multisig::*;
+use radix_engine_interface::blueprints::package::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+};
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::prelude::{
+    BlueprintFunctionsSchemaInit, BlueprintHooksInit, BlueprintStateSchemaInit, FunctionSchemaInit,
+};
+use radix_engine_interface::types::*;
+use radix_engine_interface::*;
+
+pub const MULTISIG_BLUEPRINT_IDENT: &'static str = MULTISIG_BLUEPRINT;
+
+declare_native_blueprint_state! {
+    blueprint_ident: Multisig,
+    blueprint_snake_case: multisig,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+        proposals: KeyValue {
+            entry_ident: Proposal,
+            key_type: {
+                kind: Static,
+                content_type: u64,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
+}
+
+pub type MultisigStateV1 = MultisigSubstate;
+pub type MultisigProposalV1 = MultisigProposal;
+
+pub struct MultisigBlueprint;
+impl MultisigBlueprint {
+    pub fn definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = MultisigFeatureSet::all_features();
+        let state = MultisigStateSchemaInit::create_schema_init(&mut aggregator);
+
+        let mut functions = BTreeMap::new();
+
+        functions.insert(
+            MULTISIG_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigInstantiateOutput>(),
+                ),
+                export: MULTISIG_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTISIG_PROPOSE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigProposeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigProposeOutput>(),
+                ),
+                export: MULTISIG_PROPOSE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTISIG_APPROVE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigApproveInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigApproveOutput>(),
+                ),
+                export: MULTISIG_APPROVE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTISIG_EXECUTE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigExecuteInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigExecuteOutput>(),
+                ),
+                export: MULTISIG_EXECUTE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTISIG_GET_PROPOSAL_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigGetProposalInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigGetProposalOutput>(),
+                ),
+                export: MULTISIG_GET_PROPOSAL_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTISIG_UPDATE_SIGNERS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigUpdateSignersInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigUpdateSignersOutput>(),
+                ),
+                export: MULTISIG_UPDATE_SIGNERS_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            MULTISIG_SET_DESTINATION_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigSetDestinationInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<MultisigSetDestinationOutput>(),
+                ),
+                export: MULTISIG_SET_DESTINATION_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                ProposalCreatedEvent,
+                ProposalApprovedEvent,
+                ProposalExecutedEvent,
+                SignersUpdatedEvent,
+                DestinationUpdatedEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: btreeset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                functions: BlueprintFunctionsSchemaInit { functions },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                // Signers are checked against the badge they claim to be by the methods themselves
+                // since each of them is a distinct participant rather than a member of a role.
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    methods {
+                        MULTISIG_PROPOSE_IDENT => MethodAccessibility::Public;
+                        MULTISIG_APPROVE_IDENT => MethodAccessibility::Public;
+                        MULTISIG_EXECUTE_IDENT => MethodAccessibility::Public;
+                        MULTISIG_GET_PROPOSAL_IDENT => MethodAccessibility::Public;
+                        MULTISIG_UPDATE_SIGNERS_IDENT => [OWNER_ROLE];
+                        MULTISIG_SET_DESTINATION_IDENT => [OWNER_ROLE];
+                    }
+                }),
+            },
+        }
+    }
+
+    pub fn instantiate<Y>(
+        signers: BTreeSet<NonFungibleGlobalId>,
+        threshold: u8,
+        destination: ComponentAddress,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<MultisigInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        Self::assert_valid_threshold(&signers, threshold)?;
+
+        let (address_reservation, address) = {
+            if let Some(address_reservation) = address_reservation {
+                let address = api.get_reservation_address(address_reservation.0.as_node_id())?;
+                (address_reservation, address)
+            } else {
+                api.allocate_global_address(BlueprintId {
+                    package_address: MULTISIG_PACKAGE,
+                    blueprint_name: MULTISIG_BLUEPRINT_IDENT.to_string(),
+                })?
+            }
+        };
+
+        // The multisig owns itself - anything the owner could do, including changing the signers,
+        // has to go through a proposal which the multisig executes as the global caller.
+        let owner_role = OwnerRole::Fixed(rule!(require(
+            NonFungibleGlobalId::global_caller_badge(address)
+        )));
+        let role_assignment = RoleAssignment::create(owner_role, btreemap!(), api)?.0;
+
+        let metadata = Metadata::create(api)?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let substate = MultisigSubstate {
+                signers,
+                threshold,
+                destination,
+                next_proposal_id: 0,
+            };
+            api.new_simple_object(
+                MULTISIG_BLUEPRINT_IDENT,
+                btreemap! {
+                    MultisigField::State.field_index() => FieldValue::new(&MultisigStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        api.globalize(
+            object_id,
+            btreemap!(
+                ModuleId::RoleAssignment => role_assignment.0,
+                ModuleId::Metadata => metadata.0,
+                ModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    pub fn propose<Y>(
+        signer: NonFungibleGlobalId,
+        target: GlobalAddress,
+        method: String,
+        args: ScryptoValue,
+        expires_at: Instant,
+        api: &mut Y,
+    ) -> Result<MultisigProposeOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        if expires_at <= now {
+            return Err(MultisigError::ExpiryInThePast.into());
+        }
+
+        // The arguments are kept in state until the proposal is executed, so they must be a valid
+        // set of method arguments that does not hold on to any buckets or proofs.
+        if !matches!(args, ScryptoValue::Tuple { .. }) {
+            return Err(MultisigError::ArgumentsAreNotATuple.into());
+        }
+        if !IndexedScryptoValue::from_typed(&args)
+            .owned_nodes()
+            .is_empty()
+        {
+            return Err(MultisigError::ArgumentsContainOwnedNodes.into());
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            MultisigField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = match api.field_read_typed::<VersionedMultisigState>(handle)? {
+            VersionedMultisigState::V1(substate) => substate,
+        };
+        Self::assert_signer(&substate, &signer, api)?;
+
+        let proposal_id = substate.next_proposal_id;
+        substate.next_proposal_id += 1;
+        api.field_write_typed(
+            handle,
+            &MultisigStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        // The proposer implicitly approves their own proposal.
+        let event = ProposalCreatedEvent {
+            proposal_id,
+            target,
+            method: method.clone(),
+        };
+        let proposal = MultisigProposal {
+            target,
+            method,
+            args,
+            expires_at,
+            approvals: btreeset!(signer.clone()),
+            executed: false,
+        };
+        let handle = Self::open_proposal(proposal_id, LockFlags::MUTABLE, api)?;
+        api.key_value_entry_set_typed(handle, &VersionedMultisigProposal::V1(proposal))?;
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(api, event)?;
+        Runtime::emit_event(
+            api,
+            ProposalApprovedEvent {
+                proposal_id,
+                signer,
+            },
+        )?;
+
+        Ok(proposal_id)
+    }
+
+    pub fn approve<Y>(
+        proposal_id: u64,
+        signer: NonFungibleGlobalId,
+        api: &mut Y,
+    ) -> Result<MultisigApproveOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate = Self::read_state(api)?;
+        Self::assert_signer(&substate, &signer, api)?;
+
+        let handle = Self::open_proposal(proposal_id, LockFlags::MUTABLE, api)?;
+        let mut proposal = Self::read_proposal(proposal_id, handle, api)?;
+        Self::assert_open(proposal_id, &proposal, api)?;
+
+        proposal.approvals.insert(signer.clone());
+        api.key_value_entry_set_typed(handle, &VersionedMultisigProposal::V1(proposal))?;
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            ProposalApprovedEvent {
+                proposal_id,
+                signer,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute<Y>(
+        proposal_id: u64,
+        signer: NonFungibleGlobalId,
+        api: &mut Y,
+    ) -> Result<MultisigExecuteOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate = Self::read_state(api)?;
+        Self::assert_signer(&substate, &signer, api)?;
+
+        let handle = Self::open_proposal(proposal_id, LockFlags::MUTABLE, api)?;
+        let mut proposal = Self::read_proposal(proposal_id, handle, api)?;
+        Self::assert_open(proposal_id, &proposal, api)?;
+
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approver| substate.signers.contains(approver))
+            .count();
+        if approvals < usize::from(substate.threshold) {
+            return Err(MultisigError::ThresholdNotMet {
+                approvals,
+                threshold: substate.threshold,
+            }
+            .into());
+        }
+
+        // The proposal is marked as executed before the call is made so that it can't be executed
+        // again by the target calling back into the multisig.
+        proposal.executed = true;
+        let MultisigProposal {
+            target,
+            method,
+            args,
+            ..
+        } = proposal.clone();
+        api.key_value_entry_set_typed(handle, &VersionedMultisigProposal::V1(proposal))?;
+        api.key_value_entry_close(handle)?;
+
+        let args = scrypto_encode(&args).map_err(MultisigError::EncodeError)?;
+        let rtn = api.call_method(target.as_node_id(), &method, args)?;
+        let rtn = IndexedScryptoValue::from_vec(rtn).map_err(MultisigError::DecodeError)?;
+
+        // Whatever the call returns belongs to the multisig rather than to the signer that
+        // happened to execute the proposal, so buckets are sent to the configured destination.
+        let mut buckets = Vec::new();
+        for node_id in rtn.owned_nodes() {
+            let blueprint_id = api.get_blueprint_id(node_id)?;
+            match (
+                blueprint_id.package_address,
+                blueprint_id.blueprint_name.as_str(),
+            ) {
+                (RESOURCE_PACKAGE, FUNGIBLE_BUCKET_BLUEPRINT)
+                | (RESOURCE_PACKAGE, NON_FUNGIBLE_BUCKET_BLUEPRINT) => {
+                    buckets.push(Bucket(Own(node_id.clone())));
+                }
+                (RESOURCE_PACKAGE, FUNGIBLE_PROOF_BLUEPRINT)
+                | (RESOURCE_PACKAGE, NON_FUNGIBLE_PROOF_BLUEPRINT) => {
+                    Proof(Own(node_id.clone())).drop(api)?;
+                }
+                _ => {}
+            }
+        }
+        if !buckets.is_empty() {
+            api.call_method(
+                substate.destination.as_node_id(),
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+                scrypto_encode(&AccountTryDepositBatchOrAbortInput {
+                    buckets,
+                    authorized_depositor_badge: None,
+                })
+                .map_err(MultisigError::EncodeError)?,
+            )?;
+        }
+
+        Runtime::emit_event(api, ProposalExecutedEvent { proposal_id })?;
+
+        Ok(())
+    }
+
+    pub fn update_signers<Y>(
+        signers: BTreeSet<NonFungibleGlobalId>,
+        threshold: u8,
+        api: &mut Y,
+    ) -> Result<MultisigUpdateSignersOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_valid_threshold(&signers, threshold)?;
+
+        // Approvals of signers that are removed here stop counting towards the threshold of any
+        // open proposal since only current signers are counted when a proposal is executed.
+        let event = SignersUpdatedEvent {
+            signers: signers.clone(),
+            threshold,
+        };
+        Self::update_state(
+            |substate| {
+                substate.signers = signers;
+                substate.threshold = threshold;
+            },
+            api,
+        )?;
+        Runtime::emit_event(api, event)?;
+
+        Ok(())
+    }
+
+    pub fn set_destination<Y>(
+        destination: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<MultisigSetDestinationOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::update_state(|substate| substate.destination = destination, api)?;
+        Runtime::emit_event(api, DestinationUpdatedEvent { destination })?;
+
+        Ok(())
+    }
+
+    pub fn get_proposal<Y>(
+        proposal_id: u64,
+        api: &mut Y,
+    ) -> Result<MultisigGetProposalOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = Self::open_proposal(proposal_id, LockFlags::read_only(), api)?;
+        let proposal = api.key_value_entry_get_typed::<VersionedMultisigProposal>(handle)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(proposal.map(|proposal| match proposal {
+            VersionedMultisigProposal::V1(proposal) => proposal,
+        }))
+    }
+
+    //===================
+    // Utility Functions
+    //===================
+
+    fn read_state<Y>(api: &mut Y) -> Result<MultisigSubstate, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            MultisigField::State.into(),
+            LockFlags::read_only(),
+        )?;
+        let substate = match api.field_read_typed::<VersionedMultisigState>(handle)? {
+            VersionedMultisigState::V1(substate) => substate,
+        };
+        api.field_close(handle)?;
+
+        Ok(substate)
+    }
+
+    fn update_state<Y, F>(update: F, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut MultisigSubstate),
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            MultisigField::State.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = match api.field_read_typed::<VersionedMultisigState>(handle)? {
+            VersionedMultisigState::V1(substate) => substate,
+        };
+        update(&mut substate);
+        api.field_write_typed(
+            handle,
+            &MultisigStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
+    fn assert_valid_threshold(
+        signers: &BTreeSet<NonFungibleGlobalId>,
+        threshold: u8,
+    ) -> Result<(), RuntimeError> {
+        if threshold == 0 || usize::from(threshold) > signers.len() {
+            return Err(MultisigError::InvalidThreshold {
+                threshold,
+                number_of_signers: signers.len(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn open_proposal<Y>(
+        proposal_id: u64,
+        lock_flags: LockFlags,
+        api: &mut Y,
+    ) -> Result<KeyValueEntryHandle, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let encoded_key = scrypto_encode(&proposal_id).map_err(MultisigError::EncodeError)?;
+        api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            MultisigCollection::ProposalKeyValue.collection_index(),
+            &encoded_key,
+            lock_flags,
+        )
+    }
+
+    fn read_proposal<Y>(
+        proposal_id: u64,
+        handle: KeyValueEntryHandle,
+        api: &mut Y,
+    ) -> Result<MultisigProposal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match api.key_value_entry_get_typed::<VersionedMultisigProposal>(handle)? {
+            Some(VersionedMultisigProposal::V1(proposal)) => Ok(proposal),
+            None => Err(MultisigError::ProposalDoesNotExist { proposal_id }.into()),
+        }
+    }
+
+    /// Asserts that the given badge is one of the signers of the multisig and that a proof of it
+    /// is present in the auth zone.
+    fn assert_signer<Y>(
+        substate: &MultisigSubstate,
+        signer: &NonFungibleGlobalId,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !substate.signers.contains(signer) {
+            return Err(MultisigError::NotASigner {
+                signer: signer.clone(),
+            }
+            .into());
+        }
+
+        Runtime::assert_access_rule(rule!(require(signer.clone())), api)
+    }
+
+    fn assert_open<Y>(
+        proposal_id: u64,
+        proposal: &MultisigProposal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if proposal.executed {
+            return Err(MultisigError::ProposalAlreadyExecuted { proposal_id }.into());
+        }
+
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        if now >= proposal.expires_at {
+            return Err(MultisigError::ProposalExpired { proposal_id }.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use radix_engine_common::ScryptoSbor;
+use radix_engine_interface::blueprints::resource::*;
+use sbor::{DecodeError, EncodeError};
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum MultisigError {
+    InvalidThreshold {
+        threshold: u8,
+        number_of_signers: usize,
+    },
+    NotASigner {
+        signer: NonFungibleGlobalId,
+    },
+    ProposalDoesNotExist {
+        proposal_id: u64,
+    },
+    ProposalExpired {
+        proposal_id: u64,
+    },
+    ProposalAlreadyExecuted {
+        proposal_id: u64,
+    },
+    ThresholdNotMet {
+        approvals: usize,
+        threshold: u8,
+    },
+    ExpiryInThePast,
+    ArgumentsAreNotATuple,
+    ArgumentsContainOwnedNodes,
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+}
+
+impl From<MultisigError> for RuntimeError {
+    fn from(error: MultisigError) -> Self {
+        Self::ApplicationError(ApplicationError::MultisigError(error))
+    }
+}
@@ -0,0 +1,33 @@
+use radix_engine_common::types::*;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+use radix_engine_interface::blueprints::resource::*;
+use sbor::rust::prelude::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub target: GlobalAddress,
+    pub method: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct ProposalApprovedEvent {
+    pub proposal_id: u64,
+    pub signer: NonFungibleGlobalId,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct SignersUpdatedEvent {
+    pub signers: BTreeSet<NonFungibleGlobalId>,
+    pub threshold: u8,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct DestinationUpdatedEvent {
+    pub destination: ComponentAddress,
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::errors::*;
+use crate::kernel::kernel_api::*;
+use crate::system::system_callback::*;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::multisig::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use radix_engine_interface::types::*;
+use sbor::rust::prelude::*;
+
+pub struct MultisigNativePackage;
+impl MultisigNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = btreemap!(
+            MULTISIG_BLUEPRINT_IDENT.to_string() => MultisigBlueprint::definition(),
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+    {
+        match export_name {
+            MULTISIG_INSTANTIATE_EXPORT_NAME => {
+                let MultisigInstantiateInput {
+                    signers,
+                    threshold,
+                    destination,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = MultisigBlueprint::instantiate(
+                    signers,
+                    threshold,
+                    destination,
+                    address_reservation,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTISIG_PROPOSE_EXPORT_NAME => {
+                let MultisigProposeInput {
+                    signer,
+                    target,
+                    method,
+                    args,
+                    expires_at,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    MultisigBlueprint::propose(signer, target, method, args, expires_at, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTISIG_APPROVE_EXPORT_NAME => {
+                let MultisigApproveInput {
+                    proposal_id,
+                    signer,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = MultisigBlueprint::approve(proposal_id, signer, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTISIG_EXECUTE_EXPORT_NAME => {
+                let MultisigExecuteInput {
+                    proposal_id,
+                    signer,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = MultisigBlueprint::execute(proposal_id, signer, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTISIG_GET_PROPOSAL_EXPORT_NAME => {
+                let MultisigGetProposalInput { proposal_id } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = MultisigBlueprint::get_proposal(proposal_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTISIG_UPDATE_SIGNERS_EXPORT_NAME => {
+                let MultisigUpdateSignersInput { signers, threshold } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = MultisigBlueprint::update_signers(signers, threshold, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            MULTISIG_SET_DESTINATION_EXPORT_NAME => {
+                let MultisigSetDestinationInput { destination } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = MultisigBlueprint::set_destination(destination, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use radix_engine_common::types::*;
+use radix_engine_common::*;
+use radix_engine_interface::blueprints::resource::*;
+use sbor::rust::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct MultisigSubstate {
+    /// The badges of the signers of the multisig, each of which can propose, approve and execute
+    /// proposals.
+    pub signers: BTreeSet<NonFungibleGlobalId>,
+
+    /// The number of distinct signers that must approve a proposal before it can be executed.
+    pub threshold: u8,
+
+    /// The component that any buckets returned by an executed proposal are deposited into.
+    pub destination: ComponentAddress,
+
+    /// The id that the next proposal will be assigned.
+    pub next_proposal_id: u64,
+}
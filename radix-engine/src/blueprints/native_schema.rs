@@ -5,11 +5,13 @@ use crate::blueprints::access_controller::*;
 use crate::blueprints::account::*;
 use crate::blueprints::consensus_manager::*;
 use crate::blueprints::identity::*;
+use crate::blueprints::multisig::MultisigNativePackage;
 use crate::blueprints::package::*;
 use crate::blueprints::pool::*;
 use crate::blueprints::resource::*;
 use crate::blueprints::transaction_processor::*;
 use crate::blueprints::transaction_tracker::TransactionTrackerNativePackage;
+use crate::blueprints::vesting::VestingNativePackage;
 use crate::system::node_modules::metadata::*;
 use crate::system::node_modules::role_assignment::*;
 use crate::system::node_modules::royalty::*;
@@ -24,6 +26,10 @@ lazy_static! {
     pub static ref ACCESS_CONTROLLER_PACKAGE_DEFINITION: PackageDefinition =
         AccessControllerNativePackage::definition();
     pub static ref POOL_PACKAGE_DEFINITION: PackageDefinition = PoolNativePackage::definition();
+    pub static ref VESTING_PACKAGE_DEFINITION: PackageDefinition =
+        VestingNativePackage::definition();
+    pub static ref MULTISIG_PACKAGE_DEFINITION: PackageDefinition =
+        MultisigNativePackage::definition();
     pub static ref TRANSACTION_TRACKER_PACKAGE_DEFINITION: PackageDefinition =
         TransactionTrackerNativePackage::definition();
     pub static ref RESOURCE_PACKAGE_DEFINITION: PackageDefinition =
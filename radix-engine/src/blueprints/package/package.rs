@@ -116,10 +116,12 @@ pub enum PackageError {
         max: RoyaltyAmount,
         actual: RoyaltyAmount,
     },
+    InvalidResourceRoyalty(RoyaltyAmount),
 
     InvalidMetadataKey(String),
 
     RoyaltiesNotEnabled,
+    NoRoyaltyVaultForResource(ResourceAddress),
 }
 
 fn validate_package_schema(
@@ -696,9 +698,25 @@ where
     Y: ClientApi<RuntimeError>,
 {
     let vault = Vault(ResourceManager(XRD).new_empty_vault(api)?);
+    let mut resource_royalty_vaults = index_map_new();
+    for package_royalty in package_structure.package_royalties.values() {
+        if let PackageRoyaltyConfig::Enabled(royalty_amounts) =
+            package_royalty.clone().into_latest()
+        {
+            RoyaltyUtil::create_resource_royalty_vaults(
+                royalty_amounts.values(),
+                &mut resource_royalty_vaults,
+                api,
+            )?;
+        }
+    }
+    let royalty_accumulator = PackageRoyaltyAccumulator {
+        royalty_vault: vault,
+        resource_royalty_vaults,
+    };
 
     let (fields, kv_entries) =
-        PackageNativePackage::init_system_struct(Some(vault), package_structure);
+        PackageNativePackage::init_system_struct(Some(royalty_accumulator), package_structure);
 
     let package_object = api.new_object(
         PACKAGE_BLUEPRINT,
@@ -792,6 +810,21 @@ impl PackageNativePackage {
                 export: PACKAGE_CLAIM_ROYALTIES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageClaimResourceRoyaltiesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageClaimResourceRoyaltiesOutput>(),
+                ),
+                export: PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT.to_string(),
+            },
+        );
 
         let schema = generate_full_schema(aggregator);
         let blueprints = btreemap!(
@@ -829,6 +862,7 @@ impl PackageNativePackage {
                             },
                             methods {
                                 PACKAGE_CLAIM_ROYALTIES_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT => [SECURIFY_OWNER_ROLE];
                             }
                         },
                     ),
@@ -895,6 +929,16 @@ impl PackageNativePackage {
                 let rtn = PackageRoyaltyNativeBlueprint::claim_royalties(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT => {
+                let input: PackageClaimResourceRoyaltiesInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PackageRoyaltyNativeBlueprint::claim_resource_royalties(
+                    input.resource_address,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -902,18 +946,15 @@ impl PackageNativePackage {
     }
 
     fn init_system_struct(
-        royalty_vault: Option<Vault>,
+        royalty_accumulator: Option<PackageRoyaltyAccumulator>,
         package_structure: PackageStructure,
     ) -> (
         BTreeMap<u8, FieldValue>,
         BTreeMap<u8, BTreeMap<Vec<u8>, KVEntry>>,
     ) {
         let mut fields = BTreeMap::new();
-        if let Some(vault) = royalty_vault {
-            let royalty = PackageRoyaltyAccumulator {
-                royalty_vault: vault,
-            }
-            .into_payload();
+        if let Some(royalty_accumulator) = royalty_accumulator {
+            let royalty = royalty_accumulator.into_payload();
             fields.insert(0u8, FieldValue::immutable(&royalty));
         }
 
@@ -1396,7 +1437,19 @@ impl PackageRoyaltyNativeBlueprint {
             let substate: PackageRoyaltyAccumulatorFieldSubstate =
                 api.kernel_read_substate(handle)?.as_typed().unwrap();
 
-            let vault_id = substate.into_payload().into_latest().royalty_vault.0;
+            let royalty_accumulator = substate.into_payload().into_latest();
+            let vault_id = match &royalty_charge {
+                RoyaltyAmount::Resource(resource_address, _) => royalty_accumulator
+                    .resource_royalty_vaults
+                    .get(resource_address)
+                    .map(|vault| vault.0)
+                    .ok_or(RuntimeError::ApplicationError(
+                        ApplicationError::PackageError(PackageError::NoRoyaltyVaultForResource(
+                            *resource_address,
+                        )),
+                    ))?,
+                _ => royalty_accumulator.royalty_vault.0,
+            };
             let package_address = PackageAddress::new_or_panic(receiver.0);
             apply_royalty_cost(
                 api,
@@ -1434,6 +1487,44 @@ impl PackageRoyaltyNativeBlueprint {
 
         Ok(bucket)
     }
+
+    pub(crate) fn claim_resource_royalties<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            PackageFeature::PackageRoyalty.feature_name(),
+        )? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::RoyaltiesNotEnabled),
+            ));
+        }
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            PackageField::RoyaltyAccumulator.into(),
+            LockFlags::read_only(),
+        )?;
+
+        let substate: PackageRoyaltyAccumulatorFieldPayload = api.field_read_typed(handle)?;
+        let mut royalty_vault = substate
+            .into_latest()
+            .resource_royalty_vaults
+            .get(&resource_address)
+            .map(|vault| Vault(vault.0))
+            .ok_or(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::NoRoyaltyVaultForResource(
+                    resource_address,
+                )),
+            ))?;
+        let bucket = royalty_vault.take_all(api)?;
+
+        Ok(bucket)
+    }
 }
 
 pub struct PackageAuthNativeBlueprint;
@@ -14,7 +14,9 @@ declare_native_blueprint_state! {
         royalty:  {
             ident: RoyaltyAccumulator,
             field_type: {
-                kind: StaticSingleVersioned,
+                kind: StaticMultiVersioned,
+                previous_versions: [1 => { updates_to: 2 }],
+                latest_version: 2,
             },
             condition: Condition::if_feature(PackageFeature::PackageRoyalty),
         }
@@ -123,6 +125,24 @@ pub struct PackageRoyaltyAccumulatorV1 {
     pub royalty_vault: Vault,
 }
 
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct PackageRoyaltyAccumulatorV2 {
+    /// The vault for collecting package royalties.
+    pub royalty_vault: Vault,
+    /// The vaults for collecting package royalties charged in resources other than XRD.
+    pub resource_royalty_vaults: IndexMap<ResourceAddress, Vault>,
+}
+
+impl From<PackageRoyaltyAccumulatorV1> for PackageRoyaltyAccumulatorV2 {
+    fn from(value: PackageRoyaltyAccumulatorV1) -> Self {
+        // Royalties of existing packages can't be changed, so they are only ever charged in XRD
+        Self {
+            royalty_vault: value.royalty_vault,
+            resource_royalty_vaults: index_map_new(),
+        }
+    }
+}
+
 //---------------------------------------
 // Collection models - By BlueprintVersion
 //---------------------------------------
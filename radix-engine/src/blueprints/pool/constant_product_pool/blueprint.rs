@@ -0,0 +1,987 @@
+use crate::blueprints::pool::constant_product_pool::*;
+use crate::blueprints::pool::POOL_MANAGER_ROLE;
+use crate::errors::*;
+use crate::internal_prelude::declare_native_blueprint_state;
+use crate::internal_prelude::*;
+use crate::kernel::kernel_api::*;
+use crate::prelude::BlueprintSchemaInit;
+use crate::types::{ReceiverInfo, TypeRef};
+use crate::{event_schema, roles_template};
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_interface::api::node_modules::auth::RoleDefinition;
+use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::package::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+};
+use radix_engine_interface::blueprints::pool::*;
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::prelude::{
+    BlueprintFunctionsSchemaInit, BlueprintHooksInit, BlueprintStateSchemaInit, FunctionSchemaInit,
+};
+use radix_engine_interface::types::*;
+use radix_engine_interface::*;
+
+pub const CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT: &'static str = CONSTANT_PRODUCT_POOL_BLUEPRINT;
+
+declare_native_blueprint_state! {
+    blueprint_ident: ConstantProductPool,
+    blueprint_snake_case: constant_product_pool,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+    }
+}
+
+pub type ConstantProductPoolStateV1 = ConstantProductPoolSubstate;
+
+pub struct ConstantProductPoolBlueprint;
+impl ConstantProductPoolBlueprint {
+    pub fn definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = ConstantProductPoolFeatureSet::all_features();
+        let state = ConstantProductPoolStateSchemaInit::create_schema_init(&mut aggregator);
+
+        let mut functions = BTreeMap::new();
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolInstantiateOutput>(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_CONTRIBUTE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolContributeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolContributeOutput>(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_CONTRIBUTE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_REDEEM_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ConstantProductPoolRedeemInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ConstantProductPoolRedeemOutput>(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_REDEEM_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_SWAP_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ConstantProductPoolSwapInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ConstantProductPoolSwapOutput>(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_SWAP_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_GET_SWAP_OUTPUT_AMOUNT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<
+                        ConstantProductPoolGetSwapOutputAmountInput,
+                    >(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<
+                        ConstantProductPoolGetSwapOutputAmountOutput,
+                    >(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_GET_SWAP_OUTPUT_AMOUNT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_GET_VAULT_AMOUNTS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolGetVaultAmountsInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolGetVaultAmountsOutput>(
+                        ),
+                ),
+                export: CONSTANT_PRODUCT_POOL_GET_VAULT_AMOUNTS_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_GET_PRICE_ACCUMULATORS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<
+                        ConstantProductPoolGetPriceAccumulatorsInput,
+                    >(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<
+                        ConstantProductPoolGetPriceAccumulatorsOutput,
+                    >(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_GET_PRICE_ACCUMULATORS_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_SET_FEES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ConstantProductPoolSetFeesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ConstantProductPoolSetFeesOutput>(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_SET_FEES_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            CONSTANT_PRODUCT_POOL_CLAIM_PROTOCOL_FEES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConstantProductPoolClaimProtocolFeesInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<
+                        ConstantProductPoolClaimProtocolFeesOutput,
+                    >(),
+                ),
+                export: CONSTANT_PRODUCT_POOL_CLAIM_PROTOCOL_FEES_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                ContributionEvent,
+                RedemptionEvent,
+                SwapEvent,
+                FeesUpdatedEvent,
+                ProtocolFeesClaimedEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: btreeset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                functions: BlueprintFunctionsSchemaInit { functions },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    roles {
+                        POOL_MANAGER_ROLE;
+                    },
+                    methods {
+                        // Main Module rules
+                        CONSTANT_PRODUCT_POOL_CONTRIBUTE_IDENT => MethodAccessibility::Public;
+                        CONSTANT_PRODUCT_POOL_REDEEM_IDENT => MethodAccessibility::Public;
+                        CONSTANT_PRODUCT_POOL_SWAP_IDENT => MethodAccessibility::Public;
+                        CONSTANT_PRODUCT_POOL_GET_SWAP_OUTPUT_AMOUNT_IDENT => MethodAccessibility::Public;
+                        CONSTANT_PRODUCT_POOL_GET_VAULT_AMOUNTS_IDENT => MethodAccessibility::Public;
+                        CONSTANT_PRODUCT_POOL_GET_PRICE_ACCUMULATORS_IDENT => MethodAccessibility::Public;
+                        CONSTANT_PRODUCT_POOL_SET_FEES_IDENT => [POOL_MANAGER_ROLE];
+                        CONSTANT_PRODUCT_POOL_CLAIM_PROTOCOL_FEES_IDENT => [POOL_MANAGER_ROLE];
+                    }
+                }),
+            },
+        }
+    }
+
+    pub fn instantiate<Y>(
+        (resource_address1, resource_address2): (ResourceAddress, ResourceAddress),
+        fees: ConstantProductPoolFees,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        // A pool can't be created between the same resources - error out if it's
+        if resource_address1 == resource_address2 {
+            return Err(ConstantProductPoolError::PoolCreationWithSameResource.into());
+        }
+
+        // A pool can't be created where one of the resources is non-fungible - error out if any of
+        // them are
+        for resource_address in [resource_address1, resource_address2] {
+            let resource_manager = ResourceManager(resource_address);
+            if let ResourceType::NonFungible { .. } = resource_manager.resource_type(api)? {
+                return Err(
+                    ConstantProductPoolError::NonFungibleResourcesAreNotAccepted {
+                        resource_address,
+                    }
+                    .into(),
+                );
+            }
+        }
+
+        Self::validate_fees(&fees)?;
+
+        // Allocating the address of the pool - this is going to be needed for the metadata of the
+        // pool unit resource.
+        let (address_reservation, address) = {
+            if let Some(address_reservation) = address_reservation {
+                let address = api.get_reservation_address(address_reservation.0.as_node_id())?;
+                (address_reservation, address)
+            } else {
+                api.allocate_global_address(BlueprintId {
+                    package_address: POOL_PACKAGE,
+                    blueprint_name: CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT.to_string(),
+                })?
+            }
+        };
+
+        // Creating the pool unit resource
+        let pool_unit_resource_manager = {
+            let component_caller_badge = NonFungibleGlobalId::global_caller_badge(address);
+            ResourceManager::new_fungible(
+                owner_role.clone(),
+                true,
+                18,
+                FungibleResourceRoles {
+                    mint_roles: mint_roles! {
+                        minter => rule!(require(component_caller_badge.clone()));
+                        minter_updater => rule!(deny_all);
+                    },
+                    burn_roles: burn_roles! {
+                        burner => rule!(require(component_caller_badge.clone()));
+                        burner_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata_init! {
+                    "pool" => address, locked;
+                },
+                None,
+                api,
+            )?
+        };
+
+        // Creating the pool nodes
+        let role_assignment = RoleAssignment::create(
+            owner_role,
+            btreemap! {
+                ObjectModuleId::Main => roles_init! {
+                    RoleKey { key: POOL_MANAGER_ROLE.to_owned() } => pool_manager_rule;
+                }
+            },
+            api,
+        )?
+        .0;
+
+        let metadata = Metadata::create_with_data(
+            metadata_init! {
+                "pool_vault_number" => 2u8, locked;
+                "pool_resources" => vec![
+                    GlobalAddress::from(resource_address1),
+                    GlobalAddress::from(resource_address2),
+                ], locked;
+                "pool_unit" => GlobalAddress::from(pool_unit_resource_manager.0), locked;
+            },
+            api,
+        )?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let substate = ConstantProductPoolSubstate {
+                vaults: [
+                    (resource_address1, Vault::create(resource_address1, api)?),
+                    (resource_address2, Vault::create(resource_address2, api)?),
+                ],
+                protocol_fee_vaults: [
+                    (resource_address1, Vault::create(resource_address1, api)?),
+                    (resource_address2, Vault::create(resource_address2, api)?),
+                ],
+                pool_unit_resource_manager,
+                fees,
+                price_accumulators: ConstantProductPoolPriceAccumulators {
+                    price1_cumulative: Decimal::ZERO,
+                    price2_cumulative: Decimal::ZERO,
+                    last_update: Runtime::current_time(api, TimePrecision::Minute)?,
+                },
+            };
+            api.new_simple_object(
+                CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT,
+                btreemap! {
+                    ConstantProductPoolField::State.field_index() => FieldValue::new(&ConstantProductPoolStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        api.globalize(
+            object_id,
+            btreemap!(
+                ModuleId::RoleAssignment => role_assignment.0,
+                ModuleId::Metadata => metadata.0,
+                ModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    pub fn contribute<Y>(
+        (bucket1, bucket2): (Bucket, Bucket),
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolContributeOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+
+        // Order the buckets in the same order as the vaults of the pool, ensuring that the two
+        // buckets given as arguments match the two vaults that the pool has.
+        let (bucket1, bucket2) = {
+            let bucket1_resource_address = bucket1.resource_address(api)?;
+            let bucket2_resource_address = bucket2.resource_address(api)?;
+            match (
+                substate.vault_index(bucket1_resource_address),
+                substate.vault_index(bucket2_resource_address),
+            ) {
+                (Some(0), Some(1)) => (bucket1, bucket2),
+                (Some(1), Some(0)) => (bucket2, bucket1),
+                (None, _) | (Some(_), Some(_)) => {
+                    return Err(ConstantProductPoolError::ResourceDoesNotBelongToPool {
+                        resource_address: bucket1_resource_address,
+                    }
+                    .into())
+                }
+                (_, None) => {
+                    return Err(ConstantProductPoolError::ResourceDoesNotBelongToPool {
+                        resource_address: bucket2_resource_address,
+                    }
+                    .into())
+                }
+            }
+        };
+        let (resource_address1, mut vault1) = (substate.vaults[0].0, substate.vault_at(0));
+        let (resource_address2, mut vault2) = (substate.vaults[1].0, substate.vault_at(1));
+
+        let reserves1 = vault1.amount(api)?;
+        let reserves2 = vault2.amount(api)?;
+
+        // The accumulators must be brought up to date with the current reserves before the
+        // reserves change.
+        Self::update_price_accumulators(&mut substate, reserves1, reserves2, api)?;
+
+        // Determine the amount of pool units to mint based on the the current state of the pool.
+        let (pool_units_to_mint, amount1, amount2) = {
+            let pool_unit_total_supply = substate
+                .pool_unit_resource_manager
+                .total_supply(api)?
+                .expect("Total supply is always enabled for pool unit resource.");
+            let contribution1 = bucket1.amount(api)?;
+            let contribution2 = bucket2.amount(api)?;
+            let divisibility1 = Self::divisibility(resource_address1, api)?;
+            let divisibility2 = Self::divisibility(resource_address2, api)?;
+
+            if contribution1 == Decimal::ZERO || contribution2 == Decimal::ZERO {
+                return Err(ConstantProductPoolError::ContributionOfEmptyBucketError.into());
+            }
+
+            match (
+                pool_unit_total_supply > Decimal::ZERO,
+                reserves1 > Decimal::ZERO,
+                reserves2 > Decimal::ZERO,
+            ) {
+                (false, _, _) => Ok((
+                    /*
+                    This is doing the following:
+                    dec(
+                        round(
+                            sqrt(pdec(c1) + pdec(r1)) * sqrt(pdec(c2) + pdec(r2)),
+                            19
+                        )
+                    )
+                     */
+                    PreciseDecimal::from(contribution1)
+                        .safe_add(PreciseDecimal::from(reserves1))
+                        .and_then(|d| d.sqrt())
+                        .and_then(|sqrt_cr1| {
+                            PreciseDecimal::from(contribution2)
+                                .safe_add(PreciseDecimal::from(reserves2))
+                                .and_then(|d| d.sqrt())
+                                .and_then(|sqrt_cr2| sqrt_cr1.safe_mul(sqrt_cr2))
+                        })
+                        .and_then(|d| d.safe_round(19, RoundingMode::ToPositiveInfinity))
+                        .and_then(|d| Decimal::try_from(d).ok())
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?,
+                    contribution1,
+                    contribution2,
+                )),
+                (true, true, true) => {
+                    // Only the part of the contribution which keeps the ratio of the reserves the
+                    // same is accepted; the limiting resource is the one with the smaller ratio of
+                    // contribution to reserves. Whatever remains in the buckets is returned as
+                    // change.
+                    let ratio1 = contribution1
+                        .safe_div(reserves1)
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+                    let ratio2 = contribution2
+                        .safe_div(reserves2)
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+                    let ratio = ratio1.min(ratio2);
+
+                    let amount1 = ratio
+                        .safe_mul(reserves1)
+                        .and_then(|d| d.safe_round(divisibility1, RoundingMode::ToNegativeInfinity))
+                        .map(|d| d.min(contribution1))
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+                    let amount2 = ratio
+                        .safe_mul(reserves2)
+                        .and_then(|d| d.safe_round(divisibility2, RoundingMode::ToNegativeInfinity))
+                        .map(|d| d.min(contribution2))
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+
+                    // The rounding above may have reduced the accepted amounts, the pool units are
+                    // minted according to the smaller of the two actual ratios so that existing
+                    // liquidity providers are never diluted.
+                    let actual_ratio = amount1
+                        .safe_div(reserves1)
+                        .zip(amount2.safe_div(reserves2))
+                        .map(|(ratio1, ratio2)| ratio1.min(ratio2))
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+                    let pool_units_to_mint = actual_ratio
+                        .safe_mul(pool_unit_total_supply)
+                        .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+
+                    Ok((pool_units_to_mint, amount1, amount2))
+                }
+                (true, _, _) => Err(ConstantProductPoolError::NonZeroPoolUnitSupplyButZeroReserves),
+            }
+        }?;
+
+        // Construct the event - this will be emitted once the resources are contributed to the pool
+        let event = ContributionEvent {
+            contributed_resources: btreemap! {
+                resource_address1 => amount1,
+                resource_address2 => amount2,
+            },
+            pool_units_minted: pool_units_to_mint,
+        };
+
+        // Minting the pool unit tokens
+        let pool_units = substate
+            .pool_unit_resource_manager
+            .mint_fungible(pool_units_to_mint, api)?;
+
+        // Deposit the calculated amount of each of the buckets into appropriate vault.
+        bucket1
+            .take(amount1, api)
+            .and_then(|bucket| vault1.put(bucket, api))?;
+        bucket2
+            .take(amount2, api)
+            .and_then(|bucket| vault2.put(bucket, api))?;
+
+        // Determine if there is any change to return back to the caller - if there is not then drop
+        // the empty buckets.
+        let change_bucket = if !bucket1.is_empty(api)? {
+            bucket2.drop_empty(api)?;
+            Some(bucket1)
+        } else if !bucket2.is_empty(api)? {
+            bucket1.drop_empty(api)?;
+            Some(bucket2)
+        } else {
+            bucket1.drop_empty(api)?;
+            bucket2.drop_empty(api)?;
+            None
+        };
+
+        Self::write_and_close(substate, handle, api)?;
+
+        Runtime::emit_event(api, event)?;
+
+        Ok((pool_units, change_bucket))
+    }
+
+    pub fn redeem<Y>(
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolRedeemOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+
+        // Ensure that the passed pool resources are indeed pool resources
+        let bucket_resource_address = bucket.resource_address(api)?;
+        if bucket_resource_address != substate.pool_unit_resource_manager.0 {
+            return Err(ConstantProductPoolError::InvalidPoolUnitResource {
+                expected: substate.pool_unit_resource_manager.0,
+                actual: bucket_resource_address,
+            }
+            .into());
+        }
+
+        let reserves1 = substate.vault_at(0).amount(api)?;
+        let reserves2 = substate.vault_at(1).amount(api)?;
+        Self::update_price_accumulators(&mut substate, reserves1, reserves2, api)?;
+
+        let pool_units_to_redeem = bucket.amount(api)?;
+        let pool_units_total_supply = substate
+            .pool_unit_resource_manager
+            .total_supply(api)?
+            .expect("Total supply is always enabled for pool unit resource.");
+
+        let mut amounts_owed = Vec::new();
+        for (index, reserves) in [(0, reserves1), (1, reserves2)] {
+            let resource_address = substate.vaults[index].0;
+            let divisibility = Self::divisibility(resource_address, api)?;
+            let amount_owed = pool_units_to_redeem
+                .safe_div(pool_units_total_supply)
+                .and_then(|d| d.safe_mul(reserves))
+                .and_then(|d| d.safe_round(divisibility, RoundingMode::ToNegativeInfinity))
+                .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+            amounts_owed.push((resource_address, amount_owed));
+        }
+
+        let event = RedemptionEvent {
+            redeemed_resources: amounts_owed.iter().cloned().collect(),
+            pool_unit_tokens_redeemed: pool_units_to_redeem,
+        };
+
+        let bucket1 = substate.vault_at(0).take(amounts_owed[0].1, api)?;
+        let bucket2 = substate.vault_at(1).take(amounts_owed[1].1, api)?;
+
+        bucket.burn(api)?;
+        Self::write_and_close(substate, handle, api)?;
+
+        Runtime::emit_event(api, event)?;
+
+        Ok((bucket1, bucket2))
+    }
+
+    pub fn swap<Y>(
+        input_bucket: Bucket,
+        min_output_amount: Decimal,
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolSwapOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+
+        let input_resource_address = input_bucket.resource_address(api)?;
+        let input_index = substate.vault_index(input_resource_address).ok_or(
+            ConstantProductPoolError::ResourceDoesNotBelongToPool {
+                resource_address: input_resource_address,
+            },
+        )?;
+        let output_index = 1 - input_index;
+        let output_resource_address = substate.vaults[output_index].0;
+
+        let input_amount = input_bucket.amount(api)?;
+        if input_amount == Decimal::ZERO {
+            return Err(ConstantProductPoolError::SwapOfEmptyBucketError.into());
+        }
+
+        let mut input_vault = substate.vault_at(input_index);
+        let mut output_vault = substate.vault_at(output_index);
+        let input_reserves = input_vault.amount(api)?;
+        let output_reserves = output_vault.amount(api)?;
+
+        // The accumulators must be brought up to date with the current reserves before the
+        // reserves change.
+        if input_index == 0 {
+            Self::update_price_accumulators(&mut substate, input_reserves, output_reserves, api)?;
+        } else {
+            Self::update_price_accumulators(&mut substate, output_reserves, input_reserves, api)?;
+        }
+
+        let quote = Self::quote_swap(
+            input_amount,
+            input_reserves,
+            output_reserves,
+            &substate.fees,
+            Self::divisibility(input_resource_address, api)?,
+            Self::divisibility(output_resource_address, api)?,
+        )?;
+
+        if quote.output_amount < min_output_amount {
+            return Err(ConstantProductPoolError::SlippageToleranceExceeded {
+                min_output_amount,
+                output_amount: quote.output_amount,
+            }
+            .into());
+        }
+
+        // The protocol share of the fee is kept aside in the protocol fee vault while the rest of
+        // the input, including the liquidity provider share of the fee, goes into the reserves.
+        input_bucket
+            .take(quote.protocol_fee_amount, api)
+            .and_then(|bucket| substate.protocol_fee_vault_at(input_index).put(bucket, api))?;
+        input_vault.put(input_bucket, api)?;
+        let output_bucket = output_vault.take(quote.output_amount, api)?;
+
+        Self::write_and_close(substate, handle, api)?;
+
+        Runtime::emit_event(
+            api,
+            SwapEvent {
+                input_resource_address,
+                input_amount,
+                output_resource_address,
+                output_amount: quote.output_amount,
+                lp_fee_amount: quote.lp_fee_amount,
+                protocol_fee_amount: quote.protocol_fee_amount,
+            },
+        )?;
+
+        Ok(output_bucket)
+    }
+
+    pub fn get_swap_output_amount<Y>(
+        input_resource_address: ResourceAddress,
+        input_amount: Decimal,
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolGetSwapOutputAmountOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+
+        let input_index = substate.vault_index(input_resource_address).ok_or(
+            ConstantProductPoolError::ResourceDoesNotBelongToPool {
+                resource_address: input_resource_address,
+            },
+        )?;
+        let output_index = 1 - input_index;
+        let output_resource_address = substate.vaults[output_index].0;
+
+        let quote = Self::quote_swap(
+            input_amount,
+            substate.vault_at(input_index).amount(api)?,
+            substate.vault_at(output_index).amount(api)?,
+            &substate.fees,
+            Self::divisibility(input_resource_address, api)?,
+            Self::divisibility(output_resource_address, api)?,
+        )?;
+
+        api.field_close(handle)?;
+
+        Ok(quote.output_amount)
+    }
+
+    pub fn get_vault_amounts<Y>(
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolGetVaultAmountsOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+        let amounts = substate
+            .vaults
+            .into_iter()
+            .map(|(resource_address, vault)| {
+                vault.amount(api).map(|amount| (resource_address, amount))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+        api.field_close(handle)?;
+        Ok(amounts)
+    }
+
+    pub fn get_price_accumulators<Y>(
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolGetPriceAccumulatorsOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+
+        // The accumulators are projected to the current time without being written back so that
+        // callers always observe up-to-date values.
+        let reserves1 = substate.vault_at(0).amount(api)?;
+        let reserves2 = substate.vault_at(1).amount(api)?;
+        Self::update_price_accumulators(&mut substate, reserves1, reserves2, api)?;
+
+        api.field_close(handle)?;
+
+        Ok(substate.price_accumulators)
+    }
+
+    pub fn set_fees<Y>(
+        fees: ConstantProductPoolFees,
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolSetFeesOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::validate_fees(&fees)?;
+
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+        substate.fees = fees;
+        Self::write_and_close(substate, handle, api)?;
+
+        Runtime::emit_event(api, FeesUpdatedEvent { fees })?;
+
+        Ok(())
+    }
+
+    pub fn claim_protocol_fees<Y>(
+        api: &mut Y,
+    ) -> Result<ConstantProductPoolClaimProtocolFeesOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+
+        let bucket1 = substate.protocol_fee_vault_at(0).take_all(api)?;
+        let bucket2 = substate.protocol_fee_vault_at(1).take_all(api)?;
+
+        api.field_close(handle)?;
+
+        let event = ProtocolFeesClaimedEvent {
+            claimed_resources: btreemap! {
+                substate.protocol_fee_vaults[0].0 => bucket1.amount(api)?,
+                substate.protocol_fee_vaults[1].0 => bucket2.amount(api)?,
+            },
+        };
+        Runtime::emit_event(api, event)?;
+
+        Ok((bucket1, bucket2))
+    }
+
+    //===================
+    // Utility Functions
+    //===================
+
+    fn lock_and_read<Y>(
+        api: &mut Y,
+        lock_flags: LockFlags,
+    ) -> Result<(ConstantProductPoolSubstate, SubstateHandle), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate_key = ConstantProductPoolField::State.into();
+        let handle = api.actor_open_field(ACTOR_STATE_SELF, substate_key, lock_flags)?;
+        let constant_product_pool_substate =
+            api.field_read_typed::<VersionedConstantProductPoolState>(handle)?;
+        let constant_product_pool_substate = match constant_product_pool_substate {
+            VersionedConstantProductPoolState::V1(constant_product_pool_substate) => {
+                constant_product_pool_substate
+            }
+        };
+
+        Ok((constant_product_pool_substate, handle))
+    }
+
+    fn write_and_close<Y>(
+        substate: ConstantProductPoolSubstate,
+        handle: SubstateHandle,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.field_write_typed(
+            handle,
+            &ConstantProductPoolStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)
+    }
+
+    fn validate_fees(fees: &ConstantProductPoolFees) -> Result<(), RuntimeError> {
+        let is_fee_rate_valid = fees.fee_rate >= Decimal::ZERO && fees.fee_rate < Decimal::ONE;
+        let is_protocol_fee_share_valid =
+            fees.protocol_fee_share >= Decimal::ZERO && fees.protocol_fee_share <= Decimal::ONE;
+        if is_fee_rate_valid && is_protocol_fee_share_valid {
+            Ok(())
+        } else {
+            Err(ConstantProductPoolError::InvalidFees { fees: *fees }.into())
+        }
+    }
+
+    fn divisibility<Y>(resource_address: ResourceAddress, api: &mut Y) -> Result<u8, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        ResourceManager(resource_address).resource_type(api).map(|resource_type| {
+            if let ResourceType::Fungible { divisibility } = resource_type {
+                divisibility
+            } else {
+                panic!("Impossible case, we check for this in the constructor and have a test for this.")
+            }
+        })
+    }
+
+    /// Advances the price accumulators of the pool to the current time using the reserves of the
+    /// pool before any change made by the current operation. Nothing is accumulated while either
+    /// of the reserves is empty since the price of the pool is undefined.
+    ///
+    /// The accumulators wrap around on overflow, and the increment of a period whose price is too
+    /// large to be represented saturates, so that extreme reserves never fail the operation of
+    /// the pool.
+    fn update_price_accumulators<Y>(
+        substate: &mut ConstantProductPoolSubstate,
+        reserves1: Decimal,
+        reserves2: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let accumulators = &mut substate.price_accumulators;
+
+        let elapsed_seconds =
+            now.seconds_since_unix_epoch - accumulators.last_update.seconds_since_unix_epoch;
+        if elapsed_seconds <= 0 {
+            return Ok(());
+        }
+
+        if reserves1 > Decimal::ZERO && reserves2 > Decimal::ZERO {
+            let elapsed_seconds = Decimal::from(elapsed_seconds);
+            let increment = |numerator: Decimal, denominator: Decimal| {
+                numerator
+                    .safe_div(denominator)
+                    .and_then(|price| price.safe_mul(elapsed_seconds))
+                    .unwrap_or(Decimal::MAX)
+            };
+            accumulators.price1_cumulative = wrapping_add(
+                accumulators.price1_cumulative,
+                increment(reserves2, reserves1),
+            );
+            accumulators.price2_cumulative = wrapping_add(
+                accumulators.price2_cumulative,
+                increment(reserves1, reserves2),
+            );
+        }
+        accumulators.last_update = now;
+
+        Ok(())
+    }
+
+    /// Computes the output of a swap following the constant product formula. The fee is charged
+    /// on the input and only the remainder of the input is priced against the reserves:
+    ///
+    /// `output = output_reserves * (input - fee) / (input_reserves + input - fee)`
+    fn quote_swap(
+        input_amount: Decimal,
+        input_reserves: Decimal,
+        output_reserves: Decimal,
+        fees: &ConstantProductPoolFees,
+        input_divisibility: u8,
+        output_divisibility: u8,
+    ) -> Result<SwapQuote, RuntimeError> {
+        if input_reserves == Decimal::ZERO || output_reserves == Decimal::ZERO {
+            return Err(ConstantProductPoolError::PoolIsEmpty.into());
+        }
+
+        let fee_amount = input_amount
+            .safe_mul(fees.fee_rate)
+            .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+        let protocol_fee_amount = fee_amount
+            .safe_mul(fees.protocol_fee_share)
+            .and_then(|d| d.safe_round(input_divisibility, RoundingMode::ToNegativeInfinity))
+            .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+        let lp_fee_amount = fee_amount
+            .safe_sub(protocol_fee_amount)
+            .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+
+        let output_amount = input_amount
+            .safe_sub(fee_amount)
+            .and_then(|net_input_amount| {
+                PreciseDecimal::from(output_reserves)
+                    .safe_mul(PreciseDecimal::from(net_input_amount))
+                    .and_then(|numerator| {
+                        PreciseDecimal::from(input_reserves)
+                            .safe_add(PreciseDecimal::from(net_input_amount))
+                            .and_then(|denominator| numerator.safe_div(denominator))
+                    })
+            })
+            .and_then(|d| d.safe_round(output_divisibility, RoundingMode::ToNegativeInfinity))
+            .and_then(|d| Decimal::try_from(d).ok())
+            .ok_or(ConstantProductPoolError::DecimalOverflowError)?;
+
+        Ok(SwapQuote {
+            output_amount,
+            lp_fee_amount,
+            protocol_fee_amount,
+        })
+    }
+}
+
+struct SwapQuote {
+    output_amount: Decimal,
+    lp_fee_amount: Decimal,
+    protocol_fee_amount: Decimal,
+}
+
+/// Adds two decimals, wrapping around at the bounds of [`Decimal`]. The differences between two
+/// observations of a wrapping accumulator stay correct when computed with wrapping arithmetic.
+fn wrapping_add(a: Decimal, b: Decimal) -> Decimal {
+    Decimal(I192(a.0 .0.wrapping_add(b.0 .0)))
+}
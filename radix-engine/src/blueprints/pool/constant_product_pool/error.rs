@@ -0,0 +1,39 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use radix_engine_common::math::*;
+use radix_engine_common::types::*;
+use radix_engine_common::ScryptoSbor;
+use radix_engine_interface::blueprints::pool::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum ConstantProductPoolError {
+    NonFungibleResourcesAreNotAccepted {
+        resource_address: ResourceAddress,
+    },
+    NonZeroPoolUnitSupplyButZeroReserves,
+    InvalidPoolUnitResource {
+        expected: ResourceAddress,
+        actual: ResourceAddress,
+    },
+    ResourceDoesNotBelongToPool {
+        resource_address: ResourceAddress,
+    },
+    PoolCreationWithSameResource,
+    InvalidFees {
+        fees: ConstantProductPoolFees,
+    },
+    ContributionOfEmptyBucketError,
+    SwapOfEmptyBucketError,
+    PoolIsEmpty,
+    SlippageToleranceExceeded {
+        min_output_amount: Decimal,
+        output_amount: Decimal,
+    },
+    DecimalOverflowError,
+}
+
+impl From<ConstantProductPoolError> for RuntimeError {
+    fn from(error: ConstantProductPoolError) -> Self {
+        Self::ApplicationError(ApplicationError::ConstantProductPoolError(error))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::types::*;
+use radix_engine_common::math::Decimal;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+use radix_engine_interface::blueprints::pool::*;
+use sbor::rust::prelude::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ContributionEvent {
+    pub contributed_resources: BTreeMap<ResourceAddress, Decimal>,
+    pub pool_units_minted: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct RedemptionEvent {
+    pub pool_unit_tokens_redeemed: Decimal,
+    pub redeemed_resources: BTreeMap<ResourceAddress, Decimal>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct SwapEvent {
+    pub input_resource_address: ResourceAddress,
+    pub input_amount: Decimal,
+    pub output_resource_address: ResourceAddress,
+    pub output_amount: Decimal,
+    /// The part of the fee which was left in the pool for the liquidity providers.
+    pub lp_fee_amount: Decimal,
+    /// The part of the fee which was deposited into the protocol fee vault.
+    pub protocol_fee_amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct FeesUpdatedEvent {
+    pub fees: ConstantProductPoolFees,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ProtocolFeesClaimedEvent {
+    pub claimed_resources: BTreeMap<ResourceAddress, Decimal>,
+}
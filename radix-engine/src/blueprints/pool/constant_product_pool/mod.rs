@@ -0,0 +1,9 @@
+mod blueprint;
+mod error;
+mod events;
+mod substates;
+
+pub use blueprint::*;
+pub use error::*;
+pub use events::*;
+pub use substates::*;
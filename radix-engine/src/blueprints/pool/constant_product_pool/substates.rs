@@ -0,0 +1,73 @@
+use native_sdk::resource::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+use radix_engine_interface::blueprints::pool::*;
+use radix_engine_interface::blueprints::resource::*;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct ConstantProductPoolSubstate {
+    /// The vaults of the resources of the pool - a single vault for each of the two resources,
+    /// kept in the order the resources were given in when the pool was instantiated.
+    pub vaults: [(ResourceAddress, Vault); 2],
+
+    /// The vaults that the protocol share of the swap fees are deposited into. These are kept
+    /// separate from the pool reserves so that they do not count towards the liquidity of the
+    /// pool and can be claimed by the pool manager.
+    pub protocol_fee_vaults: [(ResourceAddress, Vault); 2],
+
+    /// The resource manager of the pool unit resource that the pool works with.
+    pub pool_unit_resource_manager: ResourceManager,
+
+    /// The fees charged by the pool on swaps.
+    pub fees: ConstantProductPoolFees,
+
+    /// The price accumulators of the pool used for time-weighted average prices.
+    pub price_accumulators: ConstantProductPoolPriceAccumulators,
+}
+
+impl ConstantProductPoolSubstate {
+    pub fn vault(&self, resource_address: ResourceAddress) -> Option<Vault> {
+        self.vault_index(resource_address)
+            .map(|index| self.vault_at(index))
+    }
+
+    pub fn protocol_fee_vault(&self, resource_address: ResourceAddress) -> Option<Vault> {
+        self.vault_index(resource_address)
+            .map(|index| self.protocol_fee_vault_at(index))
+    }
+
+    pub fn vault_at(&self, index: usize) -> Vault {
+        Vault(self.vaults[index].1 .0.clone())
+    }
+
+    pub fn protocol_fee_vault_at(&self, index: usize) -> Vault {
+        Vault(self.protocol_fee_vaults[index].1 .0.clone())
+    }
+
+    /// Returns the index of the vault of the given resource in the vaults array, if the
+    /// resource is one of the resources of the pool.
+    pub fn vault_index(&self, resource_address: ResourceAddress) -> Option<usize> {
+        self.vaults
+            .iter()
+            .position(|(vault_resource_address, _)| resource_address == *vault_resource_address)
+    }
+}
+
+impl Clone for ConstantProductPoolSubstate {
+    fn clone(&self) -> Self {
+        let clone_vaults = |vaults: &[(ResourceAddress, Vault); 2]| {
+            [
+                (vaults[0].0, Vault(vaults[0].1 .0.clone())),
+                (vaults[1].0, Vault(vaults[1].1 .0.clone())),
+            ]
+        };
+
+        Self {
+            vaults: clone_vaults(&self.vaults),
+            protocol_fee_vaults: clone_vaults(&self.protocol_fee_vaults),
+            pool_unit_resource_manager: self.pool_unit_resource_manager.clone(),
+            fees: self.fees,
+            price_accumulators: self.price_accumulators.clone(),
+        }
+    }
+}
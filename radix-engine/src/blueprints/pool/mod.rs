@@ -1,3 +1,4 @@
+pub mod constant_product_pool;
 pub mod multi_resource_pool;
 pub mod one_resource_pool;
 pub mod two_resource_pool;
@@ -26,7 +26,7 @@ use radix_engine_interface::prelude::BlueprintFunctionsSchemaInit;
 use radix_engine_interface::types::*;
 use radix_engine_interface::*;
 
-pub const MULTI_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = "MultiResourcePool";
+pub const MULTI_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = MULTI_RESOURCE_POOL_BLUEPRINT;
 
 declare_native_blueprint_state! {
     blueprint_ident: MultiResourcePool,
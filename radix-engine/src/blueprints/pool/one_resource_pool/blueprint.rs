@@ -26,7 +26,7 @@ use radix_engine_interface::prelude::{
 use radix_engine_interface::types::*;
 use radix_engine_interface::*;
 
-pub const ONE_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = "OneResourcePool";
+pub const ONE_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = ONE_RESOURCE_POOL_BLUEPRINT;
 
 declare_native_blueprint_state! {
     blueprint_ident: OneResourcePool,
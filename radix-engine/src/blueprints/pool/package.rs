@@ -1,3 +1,4 @@
+use super::constant_product_pool::*;
 use super::multi_resource_pool::*;
 use super::one_resource_pool::*;
 use super::two_resource_pool::*;
@@ -19,6 +20,7 @@ impl PoolNativePackage {
             ONE_RESOURCE_POOL_BLUEPRINT_IDENT.to_string() => OneResourcePoolBlueprint::definition(),
             TWO_RESOURCE_POOL_BLUEPRINT_IDENT.to_string() => TwoResourcePoolBlueprint::definition(),
             MULTI_RESOURCE_POOL_BLUEPRINT_IDENT.to_string() => MultiResourcePoolBlueprint::definition(),
+            CONSTANT_PRODUCT_POOL_BLUEPRINT_IDENT.to_string() => ConstantProductPoolBlueprint::definition(),
         );
 
         PackageDefinition { blueprints }
@@ -272,6 +274,105 @@ impl PoolNativePackage {
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
 
+            CONSTANT_PRODUCT_POOL_INSTANTIATE_EXPORT_NAME => {
+                let ConstantProductPoolInstantiateInput {
+                    resource_addresses,
+                    fees,
+                    pool_manager_rule,
+                    owner_role,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConstantProductPoolBlueprint::instantiate(
+                    resource_addresses,
+                    fees,
+                    owner_role,
+                    pool_manager_rule,
+                    address_reservation,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_CONTRIBUTE_EXPORT_NAME => {
+                let ConstantProductPoolContributeInput { buckets } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConstantProductPoolBlueprint::contribute(buckets, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_REDEEM_EXPORT_NAME => {
+                let ConstantProductPoolRedeemInput { bucket } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConstantProductPoolBlueprint::redeem(bucket, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_SWAP_EXPORT_NAME => {
+                let ConstantProductPoolSwapInput {
+                    input_bucket,
+                    min_output_amount,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConstantProductPoolBlueprint::swap(input_bucket, min_output_amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_GET_SWAP_OUTPUT_AMOUNT_EXPORT_NAME => {
+                let ConstantProductPoolGetSwapOutputAmountInput {
+                    input_resource_address,
+                    input_amount,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConstantProductPoolBlueprint::get_swap_output_amount(
+                    input_resource_address,
+                    input_amount,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_GET_VAULT_AMOUNTS_EXPORT_NAME => {
+                let ConstantProductPoolGetVaultAmountsInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConstantProductPoolBlueprint::get_vault_amounts(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_GET_PRICE_ACCUMULATORS_EXPORT_NAME => {
+                let ConstantProductPoolGetPriceAccumulatorsInput {} =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConstantProductPoolBlueprint::get_price_accumulators(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_SET_FEES_EXPORT_NAME => {
+                let ConstantProductPoolSetFeesInput { fees } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConstantProductPoolBlueprint::set_fees(fees, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            CONSTANT_PRODUCT_POOL_CLAIM_PROTOCOL_FEES_EXPORT_NAME => {
+                let ConstantProductPoolClaimProtocolFeesInput {} =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConstantProductPoolBlueprint::claim_protocol_fees(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
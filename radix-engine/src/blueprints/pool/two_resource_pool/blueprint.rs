@@ -28,7 +28,7 @@ use radix_engine_interface::prelude::{
 use radix_engine_interface::types::*;
 use radix_engine_interface::*;
 
-pub const TWO_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = "TwoResourcePool";
+pub const TWO_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = TWO_RESOURCE_POOL_BLUEPRINT;
 
 declare_native_blueprint_state! {
     blueprint_ident: TwoResourcePool,
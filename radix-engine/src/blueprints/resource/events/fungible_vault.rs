@@ -19,7 +19,15 @@ macro_rules! define_events {
 define_events! {
     LockFeeEvent,
     PayFeeEvent,
+    LockRoyaltyPaymentEvent,
+    PayRoyaltyEvent,
     WithdrawEvent,
     DepositEvent,
     RecallEvent
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct WithdrawLimitExceededEvent {
+    pub requested: Decimal,
+    pub remaining: Decimal,
+}
@@ -21,3 +21,9 @@ define_events! {
     DepositEvent,
     RecallEvent
 }
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct WithdrawLimitExceededEvent {
+    pub requested: Decimal,
+    pub remaining: Decimal,
+}
@@ -45,6 +45,10 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        vault_withdraw_limit: {
+            ident: VaultWithdrawLimit,
+            description: "Enabled if withdrawals from vaults of the resource are rate limited",
+        },
     },
     fields: {
         divisibility: {
@@ -60,12 +64,20 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(FungibleResourceManagerFeature::TrackTotalSupply),
         },
+        withdraw_limit: {
+            ident: WithdrawLimit,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(FungibleResourceManagerFeature::VaultWithdrawLimit),
+        },
     },
     collections: {}
 }
 
 pub type FungibleResourceManagerDivisibilityV1 = u8;
 pub type FungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type FungibleResourceManagerWithdrawLimitV1 = WithdrawLimitSubstate;
 
 /// Represents an error when accessing a bucket.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -78,6 +90,7 @@ pub enum FungibleResourceManagerError {
     NotMintable,
     NotBurnable,
     UnexpectedDecimalComputationError,
+    InvalidWithdrawLimit(FungibleWithdrawLimit),
 }
 
 pub fn verify_divisibility(divisibility: u8) -> Result<(), RuntimeError> {
@@ -123,6 +136,7 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        vault_withdraw_limit: false, // Will be set later
     };
 
     roles
@@ -163,6 +177,48 @@ fn to_features_and_roles(
     (features, roles)
 }
 
+fn apply_withdraw_limit(
+    withdraw_limit: Option<FungibleWithdrawLimit>,
+    divisibility: u8,
+    features: &mut FungibleResourceManagerFeatureSet,
+    roles: &mut RoleAssignmentInit,
+    fields: &mut BTreeMap<FieldIndex, FieldValue>,
+) -> Result<(), RuntimeError> {
+    let withdraw_limit = match withdraw_limit {
+        Some(withdraw_limit) => withdraw_limit,
+        None => return Ok(()),
+    };
+
+    let substate = WithdrawLimitSubstate {
+        amount: withdraw_limit.amount,
+        period: withdraw_limit.period,
+    };
+    if !substate.has_valid_period() || !check_fungible_amount(&substate.amount, divisibility) {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::InvalidWithdrawLimit(withdraw_limit),
+            ),
+        ));
+    }
+
+    features.vault_withdraw_limit = true;
+    roles.data.extend(
+        withdraw_limit
+            .bypass_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
+    fields.insert(
+        FungibleResourceManagerField::WithdrawLimit.into(),
+        FieldValue::immutable(
+            &FungibleResourceManagerWithdrawLimitFieldPayload::from_content_source(substate),
+        ),
+    );
+
+    Ok(())
+}
+
 pub struct FungibleResourceManagerBlueprint;
 
 impl FungibleResourceManagerBlueprint {
@@ -198,6 +254,28 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithWithdrawLimitInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithWithdrawLimitOutput>()),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitOutput>()),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_AND_LIMIT_EXPORT_NAME.to_string(),
+            },
+        );
 
         functions.insert(
             FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
@@ -374,6 +452,8 @@ impl FungibleResourceManagerBlueprint {
                         RECALLER_UPDATER_ROLE => updaters: [RECALLER_UPDATER_ROLE];
                         FREEZER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
                         FREEZER_UPDATER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
+                        WITHDRAW_LIMIT_BYPASSER_ROLE => updaters: [WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE];
+                        WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE => updaters: [WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE];
                     },
                     methods {
                         FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT => [MINTER_ROLE];
@@ -396,6 +476,7 @@ impl FungibleResourceManagerBlueprint {
         track_total_supply: bool,
         divisibility: u8,
         resource_roles: FungibleResourceRoles,
+        withdraw_limit: Option<FungibleWithdrawLimit>,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -424,8 +505,15 @@ impl FungibleResourceManagerBlueprint {
                 )
         };
 
-        let (mut features, roles) = to_features_and_roles(resource_roles);
+        let (mut features, mut roles) = to_features_and_roles(resource_roles);
         features.track_total_supply = track_total_supply;
+        apply_withdraw_limit(
+            withdraw_limit,
+            divisibility,
+            &mut features,
+            &mut roles,
+            &mut fields,
+        )?;
 
         if track_total_supply {
             let total_supply_field = if features.mint || features.burn {
@@ -474,6 +562,7 @@ impl FungibleResourceManagerBlueprint {
         divisibility: u8,
         initial_supply: Decimal,
         resource_roles: FungibleResourceRoles,
+        withdraw_limit: Option<FungibleWithdrawLimit>,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -502,8 +591,15 @@ impl FungibleResourceManagerBlueprint {
                 )
         };
 
-        let (mut features, roles) = to_features_and_roles(resource_roles);
+        let (mut features, mut roles) = to_features_and_roles(resource_roles);
         features.track_total_supply = track_total_supply;
+        apply_withdraw_limit(
+            withdraw_limit,
+            divisibility,
+            &mut features,
+            &mut roles,
+            &mut fields,
+        )?;
 
         if track_total_supply {
             let total_supply_field = if features.mint || features.burn {
@@ -731,6 +827,20 @@ impl FungibleResourceManagerBlueprint {
             );
         }
 
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::VaultWithdrawLimit.feature_name(),
+        )? {
+            fields.insert(
+                FungibleVaultField::WithdrawLimitUsage.into(),
+                FieldValue::new(
+                    &FungibleVaultWithdrawLimitUsageFieldPayload::from_content_source(
+                        WithdrawLimitUsage::default(),
+                    ),
+                ),
+            );
+        }
+
         let vault_id = api.new_simple_object(FUNGIBLE_VAULT_BLUEPRINT, fields)?;
 
         Runtime::emit_event(api, VaultCreationEvent { vault_id })?;
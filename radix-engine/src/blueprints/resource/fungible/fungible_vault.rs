@@ -2,7 +2,7 @@ use crate::blueprints::resource::*;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
 use crate::internal_prelude::*;
-use crate::kernel::kernel_api::KernelNodeApi;
+use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::types::*;
 use native_sdk::resource::NativeBucket;
 use native_sdk::runtime::Runtime;
@@ -39,6 +39,13 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_outer_feature(FungibleResourceManagerFeature::VaultFreeze),
         },
+        withdraw_limit_usage: {
+            ident: WithdrawLimitUsage,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_outer_feature(FungibleResourceManagerFeature::VaultWithdrawLimit),
+        },
     },
     collections: {}
 }
@@ -46,6 +53,7 @@ declare_native_blueprint_state! {
 type FungibleVaultBalanceV1 = LiquidFungibleResource;
 type FungibleVaultLockedBalanceV1 = LockedFungibleResource;
 type FungibleVaultFreezeStatusV1 = VaultFrozenFlag;
+type FungibleVaultWithdrawLimitUsageV1 = WithdrawLimitUsage;
 
 pub struct FungibleVaultBlueprint;
 
@@ -120,6 +128,21 @@ impl FungibleVaultBlueprint {
                 export: FUNGIBLE_VAULT_LOCK_FEE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleVaultLockRoyaltyPaymentInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleVaultLockRoyaltyPaymentOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VAULT_RECALL_IDENT.to_string(),
             FunctionSchemaInit {
@@ -233,9 +256,12 @@ impl FungibleVaultBlueprint {
             [
                 fungible_vault::LockFeeEvent,
                 fungible_vault::PayFeeEvent,
+                fungible_vault::LockRoyaltyPaymentEvent,
+                fungible_vault::PayRoyaltyEvent,
                 fungible_vault::WithdrawEvent,
                 fungible_vault::DepositEvent,
-                fungible_vault::RecallEvent
+                fungible_vault::RecallEvent,
+                fungible_vault::WithdrawLimitExceededEvent
             ]
         };
 
@@ -269,6 +295,7 @@ impl FungibleVaultBlueprint {
                         VAULT_TAKE_IDENT => [WITHDRAWER_ROLE];
                         VAULT_TAKE_ADVANCED_IDENT => [WITHDRAWER_ROLE];
                         FUNGIBLE_VAULT_LOCK_FEE_IDENT => [WITHDRAWER_ROLE];
+                        FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT => [WITHDRAWER_ROLE];
                         VAULT_RECALL_IDENT => [RECALLER_ROLE];
                         VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                         VAULT_BURN_IDENT => [BURNER_ROLE];
@@ -296,20 +323,20 @@ impl FungibleVaultBlueprint {
         Ok(divisibility)
     }
 
-    pub fn take<Y>(amount: &Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
+    pub fn take<Y, L: Default>(amount: &Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
     where
-        Y: KernelNodeApi + ClientApi<RuntimeError>,
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         Self::take_advanced(amount, WithdrawStrategy::Exact, api)
     }
 
-    pub fn take_advanced<Y>(
+    pub fn take_advanced<Y, L: Default>(
         amount: &Decimal,
         withdraw_strategy: WithdrawStrategy,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
-        Y: KernelNodeApi + ClientApi<RuntimeError>,
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
 
@@ -370,6 +397,8 @@ impl FungibleVaultBlueprint {
         )?;
         api.field_close(handle)?;
 
+        Self::record_withdraw_against_limit(taken.amount(), api)?;
+
         // Create node
         let bucket = FungibleResourceManagerBlueprint::create_bucket(taken.amount(), api)?;
 
@@ -477,6 +506,68 @@ impl FungibleVaultBlueprint {
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
+    pub fn lock_royalty_payment<Y, L: Default>(
+        receiver: &NodeId,
+        amount: Decimal,
+        max_per_function: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+
+        let resource_address =
+            ResourceAddress::new_or_panic(api.actor_get_node_id(ACTOR_REF_OUTER)?.into());
+        let divisibility = Self::get_divisibility(api)?;
+        if !check_fungible_amount(&amount, divisibility) || max_per_function.is_negative() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::InvalidAmount),
+            ));
+        }
+
+        // Unlike fee locks, the withdrawal isn't force written: the payment is only made
+        // if the transaction succeeds, and is otherwise reverted along with everything else.
+        let vault_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            FungibleVaultField::Balance.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut vault = api
+            .field_read_typed::<FungibleVaultBalanceFieldPayload>(vault_handle)?
+            .into_latest();
+        let payment = vault.take_by_amount(amount).map_err(|_| {
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::ResourceError(
+                ResourceError::InsufficientBalance,
+            )))
+        })?;
+
+        let changes = api.lock_royalty_payment(
+            receiver.clone().into(),
+            resource_address,
+            payment,
+            max_per_function,
+        )?;
+        if !changes.is_empty() {
+            vault.put(changes);
+        }
+
+        api.field_write_typed(
+            vault_handle,
+            &FungibleVaultBalanceFieldPayload::from_content_source(vault),
+        )?;
+        api.field_close(vault_handle)?;
+
+        Self::record_withdraw_against_limit(amount, api)?;
+
+        Runtime::emit_event(
+            api,
+            events::fungible_vault::LockRoyaltyPaymentEvent { amount },
+        )?;
+
+        Ok(())
+    }
+
     pub fn recall<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
@@ -704,6 +795,72 @@ impl FungibleVaultBlueprint {
         Ok(())
     }
 
+    fn record_withdraw_against_limit<Y, L: Default>(
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            FungibleResourceManagerFeature::VaultWithdrawLimit.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        if VaultUtil::can_bypass_withdraw_limit(api)? {
+            return Ok(());
+        }
+
+        let withdraw_limit = {
+            let handle = api.actor_open_field(
+                ACTOR_STATE_OUTER_OBJECT,
+                FungibleResourceManagerField::WithdrawLimit.into(),
+                LockFlags::read_only(),
+            )?;
+            let withdraw_limit = api
+                .field_read_typed::<FungibleResourceManagerWithdrawLimitFieldPayload>(handle)?
+                .into_latest();
+            api.field_close(handle)?;
+            withdraw_limit
+        };
+        let window = withdraw_limit.current_window(api)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            FungibleVaultField::WithdrawLimitUsage.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut usage = api
+            .field_read_typed::<FungibleVaultWithdrawLimitUsageFieldPayload>(handle)?
+            .into_latest();
+        if let Err(error) = usage.record(window, amount, &withdraw_limit) {
+            api.field_close(handle)?;
+            if let VaultError::WithdrawLimitExceeded {
+                requested,
+                remaining,
+            } = &error
+            {
+                Runtime::emit_event_no_revert(
+                    api,
+                    events::fungible_vault::WithdrawLimitExceededEvent {
+                        requested: *requested,
+                        remaining: *remaining,
+                    },
+                )?;
+            }
+            return Err(error.into());
+        }
+        api.field_write_typed(
+            handle,
+            &FungibleVaultWithdrawLimitUsageFieldPayload::from_content_source(usage),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
     fn assert_freezable<Y>(api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -2,14 +2,17 @@ use crate::blueprints::resource::*;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
 use crate::internal_prelude::*;
-use crate::kernel::kernel_api::KernelNodeApi;
+use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
+use crate::system::system_callback::SystemLockData;
+use crate::system::system_substates::KeyValueEntrySubstate;
+use crate::system::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::types::*;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::api::{
-    ClientApi, FieldValue, GenericArgs, KVEntry, ACTOR_REF_GLOBAL, ACTOR_STATE_SELF,
+    ClientApi, FieldValue, GenericArgs, KVEntry, ACTOR_REF_GLOBAL, ACTOR_REF_SELF, ACTOR_STATE_SELF,
 };
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::math::Decimal;
@@ -45,6 +48,10 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        vault_withdraw_limit: {
+            ident: VaultWithdrawLimit,
+            description: "Enabled if withdrawals from vaults of the resource are rate limited",
+        },
     },
     fields: {
         id_type: {
@@ -66,6 +73,13 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(NonFungibleResourceManagerFeature::TrackTotalSupply),
         },
+        withdraw_limit: {
+            ident: WithdrawLimit,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::VaultWithdrawLimit),
+        },
     },
     collections: {
         data: KeyValue {
@@ -85,6 +99,7 @@ declare_native_blueprint_state! {
 
 pub type NonFungibleResourceManagerIdTypeV1 = NonFungibleIdType;
 pub type NonFungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type NonFungibleResourceManagerWithdrawLimitV1 = WithdrawLimitSubstate;
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct NonFungibleResourceManagerMutableFieldsV1 {
     pub mutable_field_index: IndexMap<String, usize>,
@@ -97,6 +112,9 @@ pub enum NonFungibleResourceManagerError {
     NonFungibleNotFound(Box<NonFungibleGlobalId>),
     InvalidRole(String),
     UnknownMutableFieldName(String),
+    UnknownFieldName(String),
+    FieldIsNotAMap(String),
+    MapEntryKindMismatch(String),
     NonFungibleIdTypeDoesNotMatch(NonFungibleIdType, NonFungibleIdType),
     InvalidNonFungibleIdType,
     InvalidNonFungibleSchema(InvalidNonFungibleSchema),
@@ -105,6 +123,7 @@ pub enum NonFungibleResourceManagerError {
     NotMintable,
     NotBurnable,
     UnexpectedDecimalComputationError,
+    InvalidWithdrawLimit(NonFungibleWithdrawLimit),
 }
 
 /// Represents an error when accessing a bucket.
@@ -210,6 +229,28 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithWithdrawLimitInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithWithdrawLimitOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_AND_LIMIT_EXPORT_NAME.to_string(),
+            },
+        );
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT.to_string(),
             FunctionSchemaInit {
@@ -261,6 +302,28 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerUpdateDataFieldsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerUpdateDataFieldsOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetDataFieldsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetDataFieldsOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT.to_string(),
+            },
+        );
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT.to_string(),
             FunctionSchemaInit {
@@ -469,6 +532,8 @@ impl NonFungibleResourceManagerBlueprint {
                         FREEZER_UPDATER_ROLE => updaters: [FREEZER_UPDATER_ROLE];
                         NON_FUNGIBLE_DATA_UPDATER_ROLE => updaters: [NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE];
                         NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE => updaters: [NON_FUNGIBLE_DATA_UPDATER_UPDATER_ROLE];
+                        WITHDRAW_LIMIT_BYPASSER_ROLE => updaters: [WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE];
+                        WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE => updaters: [WITHDRAW_LIMIT_BYPASSER_UPDATER_ROLE];
                     },
                     methods {
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT => [MINTER_ROLE];
@@ -477,6 +542,7 @@ impl NonFungibleResourceManagerBlueprint {
                         RESOURCE_MANAGER_BURN_IDENT => [BURNER_ROLE];
                         RESOURCE_MANAGER_PACKAGE_BURN_IDENT => MethodAccessibility::OwnPackageOnly;
                         NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT => [NON_FUNGIBLE_DATA_UPDATER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT => [NON_FUNGIBLE_DATA_UPDATER_ROLE];
                         RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_CREATE_EMPTY_VAULT_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT => MethodAccessibility::Public;
@@ -484,6 +550,7 @@ impl NonFungibleResourceManagerBlueprint {
                         RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT => MethodAccessibility::Public;
                     }
                 }),
@@ -585,6 +652,7 @@ impl NonFungibleResourceManagerBlueprint {
         track_total_supply: bool,
         non_fungible_schema: NonFungibleDataSchema,
         resource_roles: NonFungibleResourceRoles,
+        withdraw_limit: Option<NonFungibleWithdrawLimit>,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -619,7 +687,7 @@ impl NonFungibleResourceManagerBlueprint {
             ))],
         };
 
-        let (mut features, roles) = to_features_and_roles(resource_roles);
+        let (mut features, mut roles) = to_features_and_roles(resource_roles);
         features.track_total_supply = track_total_supply;
 
         let mut fields = btreemap! {
@@ -632,6 +700,7 @@ impl NonFungibleResourceManagerBlueprint {
                     ),
                 )
         };
+        apply_withdraw_limit(withdraw_limit, &mut features, &mut roles, &mut fields)?;
 
         if track_total_supply {
             let total_supply_field = if features.mint || features.burn {
@@ -679,6 +748,7 @@ impl NonFungibleResourceManagerBlueprint {
         non_fungible_schema: NonFungibleDataSchema,
         entries: BTreeMap<NonFungibleLocalId, (ScryptoValue,)>,
         resource_roles: NonFungibleResourceRoles,
+        withdraw_limit: Option<NonFungibleWithdrawLimit>,
         metadata: ModuleConfig<MetadataInit>,
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -746,13 +816,14 @@ impl NonFungibleResourceManagerBlueprint {
             ))],
         };
 
-        let (mut features, roles) = to_features_and_roles(resource_roles);
+        let (mut features, mut roles) = to_features_and_roles(resource_roles);
         features.track_total_supply = track_total_supply;
 
         let mut fields = btreemap! {
             NonFungibleResourceManagerField::IdType.into() => FieldValue::immutable(&NonFungibleResourceManagerIdTypeFieldPayload::from_content_source(id_type)),
             NonFungibleResourceManagerField::MutableFields.into() => FieldValue::immutable(&NonFungibleResourceManagerMutableFieldsFieldPayload::from_content_source(mutable_fields)),
         };
+        apply_withdraw_limit(withdraw_limit, &mut features, &mut roles, &mut fields)?;
 
         if track_total_supply {
             let total_supply_field = if features.mint || features.burn {
@@ -1140,31 +1211,27 @@ impl NonFungibleResourceManagerBlueprint {
         data: ScryptoValue,
         api: &mut Y,
     ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::update_non_fungible_data_fields(
+            id,
+            vec![NonFungibleDataUpdate::Set { field_name, data }],
+            api,
+        )
+    }
+
+    pub(crate) fn update_non_fungible_data_fields<Y>(
+        id: NonFungibleLocalId,
+        updates: Vec<NonFungibleDataUpdate>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
         let resource_address =
             ResourceAddress::new_or_panic(api.actor_get_node_id(ACTOR_REF_GLOBAL)?.into());
-        let data_schema_handle = api.actor_open_field(
-            ACTOR_STATE_SELF,
-            NonFungibleResourceManagerField::MutableFields.into(),
-            LockFlags::read_only(),
-        )?;
-        let mutable_fields = api
-            .field_read_typed::<NonFungibleResourceManagerMutableFieldsFieldPayload>(
-                data_schema_handle,
-            )?
-            .into_latest();
-
-        let field_index = mutable_fields
-            .mutable_field_index
-            .get(&field_name)
-            .cloned()
-            .ok_or_else(|| {
-                RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
-                    NonFungibleResourceManagerError::UnknownMutableFieldName(field_name),
-                ))
-            })?;
+        let mutable_fields = Self::get_mutable_fields(api)?;
 
         let non_fungible_handle = api.actor_open_key_value_entry(
             ACTOR_STATE_SELF,
@@ -1179,10 +1246,16 @@ impl NonFungibleResourceManagerBlueprint {
             )?;
 
         if let Some(ref mut non_fungible_data_payload) = non_fungible_entry {
-            match non_fungible_data_payload.as_mut() {
-                Value::Tuple { fields } => fields[field_index] = data,
-                _ => panic!("Non-tuple non-fungible created: id = {}", id),
+            {
+                let fields = match non_fungible_data_payload.as_mut() {
+                    Value::Tuple { fields } => fields,
+                    _ => panic!("Non-tuple non-fungible created: id = {}", id),
+                };
+                for update in updates {
+                    Self::apply_non_fungible_data_update(&mutable_fields, fields, update)?;
+                }
             }
+            // The system validates the updated data against the non-fungible data schema.
             let buffer = scrypto_encode(non_fungible_data_payload).unwrap();
             api.key_value_entry_set(non_fungible_handle, buffer)?;
         } else {
@@ -1201,6 +1274,175 @@ impl NonFungibleResourceManagerBlueprint {
         Ok(())
     }
 
+    fn apply_non_fungible_data_update(
+        mutable_fields: &NonFungibleResourceManagerMutableFields,
+        fields: &mut [ScryptoValue],
+        update: NonFungibleDataUpdate,
+    ) -> Result<(), RuntimeError> {
+        let field_name = match &update {
+            NonFungibleDataUpdate::Set { field_name, .. }
+            | NonFungibleDataUpdate::InsertMapEntries { field_name, .. } => field_name.clone(),
+        };
+        let field_index = mutable_fields
+            .mutable_field_index
+            .get(&field_name)
+            .cloned()
+            .ok_or_else(|| {
+                RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::UnknownMutableFieldName(field_name.clone()),
+                ))
+            })?;
+
+        match update {
+            NonFungibleDataUpdate::Set { data, .. } => fields[field_index] = data,
+            NonFungibleDataUpdate::InsertMapEntries { entries, .. } => {
+                match &mut fields[field_index] {
+                    Value::Map {
+                        entries: map_entries,
+                        ..
+                    } => {
+                        for (key, value) in entries {
+                            if let Some(entry) = map_entries.iter_mut().find(|(k, _)| *k == key) {
+                                entry.1 = value;
+                            } else {
+                                map_entries.push((key, value));
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(RuntimeError::ApplicationError(
+                            ApplicationError::NonFungibleResourceManagerError(
+                                NonFungibleResourceManagerError::FieldIsNotAMap(field_name),
+                            ),
+                        ))
+                    }
+                }
+
+                // Entries whose kinds differ from the map's key and value kinds can't be encoded.
+                if scrypto_encode(&fields[field_index]).is_err() {
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::NonFungibleResourceManagerError(
+                            NonFungibleResourceManagerError::MapEntryKindMismatch(field_name),
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn get_non_fungible_data_fields<Y>(
+        id: NonFungibleLocalId,
+        field_names: Vec<String>,
+        api: &mut Y,
+    ) -> Result<ScryptoValue, RuntimeError>
+    where
+        Y: KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+    {
+        let field_index = Self::get_field_index(api)?;
+        let fields = match Self::get_non_fungible(id.clone(), api)? {
+            Value::Tuple { fields } => fields,
+            _ => panic!("Non-tuple non-fungible created: id = {}", id),
+        };
+
+        let requested_fields = field_names
+            .into_iter()
+            .map(|field_name| {
+                field_index
+                    .get(&field_name)
+                    .map(|field_index| fields[*field_index].clone())
+                    .ok_or_else(|| {
+                        RuntimeError::ApplicationError(
+                            ApplicationError::NonFungibleResourceManagerError(
+                                NonFungibleResourceManagerError::UnknownFieldName(field_name),
+                            ),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Value::Tuple {
+            fields: requested_fields,
+        })
+    }
+
+    /// Returns the index of every named field of the non-fungible data, resolved from the
+    /// non-fungible data schema stored with the resource.
+    fn get_field_index<Y>(api: &mut Y) -> Result<IndexMap<String, usize>, RuntimeError>
+    where
+        Y: KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+    {
+        let node_id = api.actor_get_node_id(ACTOR_REF_SELF)?;
+        let type_identifier = match TypeInfoBlueprint::get_type(&node_id, api)? {
+            TypeInfoSubstate::Object(ObjectInfo { blueprint_info, .. }) => {
+                match blueprint_info.generic_substitutions.into_iter().next() {
+                    Some(GenericSubstitution::Local(type_identifier)) => type_identifier,
+                    _ => panic!("Non-fungible data schema not found: {:?}", node_id),
+                }
+            }
+            _ => panic!(
+                "Non-fungible resource manager is not an object: {:?}",
+                node_id
+            ),
+        };
+
+        let handle = api.kernel_open_substate_with_default(
+            &node_id,
+            SCHEMAS_PARTITION,
+            &SubstateKey::Map(scrypto_encode(&type_identifier.0).unwrap()),
+            LockFlags::read_only(),
+            Some(|| {
+                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                IndexedScryptoValue::from_typed(&kv_entry)
+            }),
+            SystemLockData::default(),
+        )?;
+        let schema: KeyValueEntrySubstate<VersionedScryptoSchema> =
+            api.kernel_read_substate(handle)?.as_typed().unwrap();
+        api.kernel_close_substate(handle)?;
+        let schema = schema.into_value().expect(&format!(
+            "Non-fungible data schema not found: {:?}",
+            node_id
+        ));
+
+        let field_index = match schema
+            .v1()
+            .resolve_type_metadata(type_identifier.1)
+            .and_then(|type_metadata| type_metadata.child_names.as_ref())
+        {
+            Some(ChildNames::NamedFields(names)) => names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| (name.to_string(), index))
+                .collect(),
+            _ => index_map_new(),
+        };
+
+        Ok(field_index)
+    }
+
+    fn get_mutable_fields<Y>(
+        api: &mut Y,
+    ) -> Result<NonFungibleResourceManagerMutableFields, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let data_schema_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::MutableFields.into(),
+            LockFlags::read_only(),
+        )?;
+        let mutable_fields = api
+            .field_read_typed::<NonFungibleResourceManagerMutableFieldsFieldPayload>(
+                data_schema_handle,
+            )?
+            .into_latest();
+        api.field_close(data_schema_handle)?;
+
+        Ok(mutable_fields)
+    }
+
     pub(crate) fn non_fungible_exists<Y>(
         id: NonFungibleLocalId,
         api: &mut Y,
@@ -1411,6 +1653,20 @@ impl NonFungibleResourceManagerBlueprint {
             );
         }
 
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::VaultWithdrawLimit.feature_name(),
+        )? {
+            fields.insert(
+                NonFungibleVaultField::WithdrawLimitUsage.into(),
+                FieldValue::new(
+                    &NonFungibleVaultWithdrawLimitUsageFieldPayload::from_content_source(
+                        WithdrawLimitUsage::default(),
+                    ),
+                ),
+            );
+        }
+
         let vault_id = api.new_simple_object(NON_FUNGIBLE_VAULT_BLUEPRINT, fields)?;
 
         Runtime::emit_event(api, VaultCreationEvent { vault_id })?;
@@ -1525,6 +1781,7 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        vault_withdraw_limit: false, // Will be set later
     };
 
     roles
@@ -1571,3 +1828,44 @@ fn to_features_and_roles(
 
     (features, roles)
 }
+
+fn apply_withdraw_limit(
+    withdraw_limit: Option<NonFungibleWithdrawLimit>,
+    features: &mut NonFungibleResourceManagerFeatureSet,
+    roles: &mut RoleAssignmentInit,
+    fields: &mut BTreeMap<FieldIndex, FieldValue>,
+) -> Result<(), RuntimeError> {
+    let withdraw_limit = match withdraw_limit {
+        Some(withdraw_limit) => withdraw_limit,
+        None => return Ok(()),
+    };
+
+    let substate = WithdrawLimitSubstate {
+        amount: Decimal::from(withdraw_limit.count),
+        period: withdraw_limit.period,
+    };
+    if !substate.has_valid_period() {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::InvalidWithdrawLimit(withdraw_limit),
+            ),
+        ));
+    }
+
+    features.vault_withdraw_limit = true;
+    roles.data.extend(
+        withdraw_limit
+            .bypass_roles
+            .unwrap_or_default()
+            .to_role_init()
+            .data,
+    );
+    fields.insert(
+        NonFungibleResourceManagerField::WithdrawLimit.into(),
+        FieldValue::immutable(
+            &NonFungibleResourceManagerWithdrawLimitFieldPayload::from_content_source(substate),
+        ),
+    );
+
+    Ok(())
+}
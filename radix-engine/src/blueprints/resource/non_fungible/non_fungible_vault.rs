@@ -2,7 +2,7 @@ use crate::blueprints::resource::*;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
 use crate::internal_prelude::*;
-use crate::kernel::kernel_api::KernelNodeApi;
+use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::types::*;
 use native_sdk::resource::NativeBucket;
 use native_sdk::runtime::Runtime;
@@ -45,6 +45,13 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_outer_feature(NonFungibleResourceManagerFeature::VaultFreeze),
         },
+        withdraw_limit_usage: {
+            ident: WithdrawLimitUsage,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_outer_feature(NonFungibleResourceManagerFeature::VaultWithdrawLimit),
+        },
     },
     collections: {
         non_fungibles: Index {
@@ -64,6 +71,7 @@ declare_native_blueprint_state! {
 type NonFungibleVaultBalanceV1 = LiquidNonFungibleVault;
 type NonFungibleVaultLockedResourceV1 = LockedNonFungibleResource;
 type NonFungibleVaultFreezeStatusV1 = VaultFrozenFlag;
+type NonFungibleVaultWithdrawLimitUsageV1 = WithdrawLimitUsage;
 type NonFungibleVaultNonFungibleV1 = ();
 
 pub struct NonFungibleVaultBlueprint;
@@ -309,7 +317,8 @@ impl NonFungibleVaultBlueprint {
             [
                 non_fungible_vault::WithdrawEvent,
                 non_fungible_vault::DepositEvent,
-                non_fungible_vault::RecallEvent
+                non_fungible_vault::RecallEvent,
+                non_fungible_vault::WithdrawLimitExceededEvent
             ]
         };
 
@@ -362,20 +371,20 @@ impl NonFungibleVaultBlueprint {
         }
     }
 
-    pub fn take<Y>(amount: &Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
+    pub fn take<Y, L: Default>(amount: &Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
     where
-        Y: KernelNodeApi + ClientApi<RuntimeError>,
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         Self::take_advanced(amount, WithdrawStrategy::Exact, api)
     }
 
-    pub fn take_advanced<Y>(
+    pub fn take_advanced<Y, L: Default>(
         amount: &Decimal,
         withdraw_strategy: WithdrawStrategy,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
-        Y: KernelNodeApi + ClientApi<RuntimeError>,
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
 
@@ -457,6 +466,8 @@ impl NonFungibleVaultBlueprint {
         )?;
         api.field_close(handle)?;
 
+        Self::record_withdraw_against_limit(amount, api)?;
+
         // Create node
         let ids = taken.into_ids();
         let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
@@ -466,17 +477,18 @@ impl NonFungibleVaultBlueprint {
         Ok(bucket)
     }
 
-    pub fn take_non_fungibles<Y>(
+    pub fn take_non_fungibles<Y, L: Default>(
         non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
-        Y: ClientApi<RuntimeError>,
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
         Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
 
         // Take
         let taken = Self::internal_take_non_fungibles(non_fungible_local_ids, api)?;
+        Self::record_withdraw_against_limit(Decimal::from(taken.ids().len()), api)?;
 
         // Create node
         let ids = taken.into_ids();
@@ -818,6 +830,73 @@ impl NonFungibleVaultBlueprint {
         Ok(())
     }
 
+    /// Counts the number of withdrawn non-fungibles against the withdraw limit of the resource.
+    fn record_withdraw_against_limit<Y, L: Default>(
+        count: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_OUTER_OBJECT,
+            NonFungibleResourceManagerFeature::VaultWithdrawLimit.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        if VaultUtil::can_bypass_withdraw_limit(api)? {
+            return Ok(());
+        }
+
+        let withdraw_limit = {
+            let handle = api.actor_open_field(
+                ACTOR_STATE_OUTER_OBJECT,
+                NonFungibleResourceManagerField::WithdrawLimit.into(),
+                LockFlags::read_only(),
+            )?;
+            let withdraw_limit = api
+                .field_read_typed::<NonFungibleResourceManagerWithdrawLimitFieldPayload>(handle)?
+                .into_latest();
+            api.field_close(handle)?;
+            withdraw_limit
+        };
+        let window = withdraw_limit.current_window(api)?;
+
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleVaultField::WithdrawLimitUsage.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut usage = api
+            .field_read_typed::<NonFungibleVaultWithdrawLimitUsageFieldPayload>(handle)?
+            .into_latest();
+        if let Err(error) = usage.record(window, count, &withdraw_limit) {
+            api.field_close(handle)?;
+            if let VaultError::WithdrawLimitExceeded {
+                requested,
+                remaining,
+            } = &error
+            {
+                Runtime::emit_event_no_revert(
+                    api,
+                    events::non_fungible_vault::WithdrawLimitExceededEvent {
+                        requested: *requested,
+                        remaining: *remaining,
+                    },
+                )?;
+            }
+            return Err(error.into());
+        }
+        api.field_write_typed(
+            handle,
+            &NonFungibleVaultWithdrawLimitUsageFieldPayload::from_content_source(usage),
+        )?;
+        api.field_close(handle)?;
+
+        Ok(())
+    }
+
     fn assert_freezable<Y>(api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
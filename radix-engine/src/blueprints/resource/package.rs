@@ -22,6 +22,10 @@ pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str =
     "create_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME: &str =
     "create_with_initial_supply_and_address_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_EXPORT_NAME: &str =
+    "create_with_withdraw_limit_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_AND_LIMIT_EXPORT_NAME: &str =
+    "create_with_initial_supply_and_withdraw_limit_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME: &str = "burn_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME: &str =
     "package_burn_FungibleResourceManager";
@@ -43,6 +47,10 @@ pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str =
     "create_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME: &str =
     "create_with_initial_supply_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_EXPORT_NAME: &str =
+    "create_with_withdraw_limit_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_AND_LIMIT_EXPORT_NAME: &str =
+    "create_with_initial_supply_and_withdraw_limit_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME: &str =
     "burn_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME: &str =
@@ -1157,6 +1165,7 @@ impl ResourceNativePackage {
                     input.track_total_supply,
                     input.divisibility,
                     input.resource_roles,
+                    None,
                     input.metadata,
                     input.address_reservation,
                     api,
@@ -1174,6 +1183,42 @@ impl ResourceNativePackage {
                     input.divisibility,
                     input.initial_supply,
                     input.resource_roles,
+                    None,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_EXPORT_NAME => {
+                let input: FungibleResourceManagerCreateWithWithdrawLimitInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::create(
+                    input.owner_role,
+                    input.track_total_supply,
+                    input.divisibility,
+                    input.resource_roles,
+                    Some(input.withdraw_limit),
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_AND_LIMIT_EXPORT_NAME => {
+                let input: FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::create_with_initial_supply(
+                    input.owner_role,
+                    input.track_total_supply,
+                    input.divisibility,
+                    input.initial_supply,
+                    input.resource_roles,
+                    Some(input.withdraw_limit),
                     input.metadata,
                     input.address_reservation,
                     api,
@@ -1263,6 +1308,7 @@ impl ResourceNativePackage {
                     input.track_total_supply,
                     input.non_fungible_schema,
                     input.resource_roles,
+                    None,
                     input.metadata,
                     input.address_reservation,
                     api,
@@ -1281,6 +1327,44 @@ impl ResourceNativePackage {
                     input.non_fungible_schema,
                     input.entries,
                     input.resource_roles,
+                    None,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_EXPORT_NAME => {
+                let input: NonFungibleResourceManagerCreateWithWithdrawLimitInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create(
+                    input.owner_role,
+                    input.id_type,
+                    input.track_total_supply,
+                    input.non_fungible_schema,
+                    input.resource_roles,
+                    Some(input.withdraw_limit),
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_AND_LIMIT_EXPORT_NAME => {
+                let input: NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create_with_initial_supply(
+                    input.owner_role,
+                    input.id_type,
+                    input.track_total_supply,
+                    input.non_fungible_schema,
+                    input.entries,
+                    input.resource_roles,
+                    Some(input.withdraw_limit),
                     input.metadata,
                     input.address_reservation,
                     api,
@@ -1389,6 +1473,30 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT => {
+                let input: NonFungibleResourceManagerUpdateDataFieldsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::update_non_fungible_data_fields(
+                    input.id,
+                    input.updates,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT => {
+                let input: NonFungibleResourceManagerGetDataFieldsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::get_non_fungible_data_fields(
+                    input.id,
+                    input.field_names,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT => {
                 let input: NonFungibleResourceManagerExistsInput =
                     input.as_typed().map_err(|e| {
@@ -1440,6 +1548,20 @@ impl ResourceNativePackage {
                 })?;
                 FungibleVaultBlueprint::lock_fee(&receiver, input.amount, input.contingent, api)
             }
+            FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT => {
+                let receiver = Runtime::get_node_id(api)?;
+                let input: FungibleVaultLockRoyaltyPaymentInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                FungibleVaultBlueprint::lock_royalty_payment(
+                    &receiver,
+                    input.amount,
+                    input.max_per_function,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&()))
+            }
             FUNGIBLE_VAULT_TAKE_EXPORT_NAME => {
                 let input: VaultTakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1,7 +1,12 @@
 use crate::blueprints::resource::*;
 use crate::errors::RuntimeError;
 use crate::internal_prelude::ApplicationError;
+use crate::kernel::kernel_api::KernelSubstateApi;
+use crate::system::system_modules::auth::{Authorization, AuthorizationCheckResult};
 use crate::types::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::{ClientApi, ACTOR_REF_AUTH_ZONE, ACTOR_REF_OUTER};
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::types::*;
 
@@ -14,6 +19,10 @@ pub enum VaultError {
     NotFreezable,
     NotRecallable,
     VaultIsFrozen,
+    WithdrawLimitExceeded {
+        requested: Decimal,
+        remaining: Decimal,
+    },
 
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance,
@@ -36,3 +45,108 @@ impl VaultUtil {
                 || blueprint.blueprint_name.eq(FUNGIBLE_VAULT_BLUEPRINT))
     }
 }
+
+/// The per-vault withdraw limit of a resource, where the amount of a non-fungible resource is the
+/// number of non-fungibles. The bypass roles of the limit live in the role assignment module.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct WithdrawLimitSubstate {
+    pub amount: Decimal,
+    pub period: WithdrawLimitPeriod,
+}
+
+impl WithdrawLimitSubstate {
+    pub fn has_valid_period(&self) -> bool {
+        match self.period {
+            WithdrawLimitPeriod::Minutes(minutes) => minutes > 0,
+            WithdrawLimitPeriod::Epochs(epochs) => epochs > 0,
+        }
+    }
+
+    /// The window of the current time, which is the current time in minutes or the current epoch
+    /// divided by the limit period. Ledger time is read at minute precision, so a window never
+    /// ends partway through a minute.
+    pub fn current_window<Y>(&self, api: &mut Y) -> Result<u64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let window = match self.period {
+            WithdrawLimitPeriod::Minutes(minutes) => {
+                let now = Runtime::current_time(api, TimePrecision::Minute)?;
+                u64::try_from(now.seconds_since_unix_epoch / 60).unwrap_or(0) / u64::from(minutes)
+            }
+            WithdrawLimitPeriod::Epochs(epochs) => Runtime::current_epoch(api)?.number() / epochs,
+        };
+        Ok(window)
+    }
+}
+
+/// The amount withdrawn from a vault within the current withdraw limit window.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct WithdrawLimitUsage {
+    pub window: u64,
+    pub withdrawn: Decimal,
+}
+
+impl Default for WithdrawLimitUsage {
+    fn default() -> Self {
+        Self {
+            window: 0,
+            withdrawn: Decimal::zero(),
+        }
+    }
+}
+
+impl WithdrawLimitUsage {
+    /// Counts a withdrawal of the given amount against the limit, starting afresh if the usage was
+    /// recorded in an earlier window.
+    pub fn record(
+        &mut self,
+        window: u64,
+        amount: Decimal,
+        limit: &WithdrawLimitSubstate,
+    ) -> Result<(), VaultError> {
+        if self.window != window {
+            self.window = window;
+            self.withdrawn = Decimal::zero();
+        }
+
+        let withdrawn = self
+            .withdrawn
+            .safe_add(amount)
+            .ok_or(VaultError::DecimalOverflow)?;
+        if withdrawn > limit.amount {
+            let remaining = limit
+                .amount
+                .safe_sub(self.withdrawn)
+                .ok_or(VaultError::DecimalOverflow)?;
+            return Err(VaultError::WithdrawLimitExceeded {
+                requested: amount,
+                remaining,
+            });
+        }
+
+        self.withdrawn = withdrawn;
+        Ok(())
+    }
+}
+
+impl VaultUtil {
+    /// Whether the auth zone of the current call satisfies the withdraw limit bypasser role of the
+    /// resource which owns the vault.
+    pub fn can_bypass_withdraw_limit<Y, L: Default>(api: &mut Y) -> Result<bool, RuntimeError>
+    where
+        Y: KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let auth_zone = api.actor_get_node_id(ACTOR_REF_AUTH_ZONE)?;
+        let resource_address =
+            GlobalAddress::new_or_panic(api.actor_get_node_id(ACTOR_REF_OUTER)?.into());
+        let result = Authorization::check_authorization_against_role_key_internal(
+            &auth_zone,
+            &resource_address,
+            &ModuleRoleKey::new(ObjectModuleId::Main, WITHDRAW_LIMIT_BYPASSER_ROLE),
+            api,
+        )?;
+
+        Ok(matches!(result, AuthorizationCheckResult::Authorized))
+    }
+}
@@ -16,6 +16,7 @@ use radix_engine_interface::schema::{
 
 use super::TransactionProcessorBlueprint;
 use super::TransactionProcessorRunInput;
+use super::TransactionProcessorRunSubintentInput;
 
 pub struct TransactionProcessorNativePackage;
 
@@ -39,6 +40,21 @@ impl TransactionProcessorNativePackage {
                 export: TRANSACTION_PROCESSOR_RUN_IDENT.to_string(),
             },
         );
+        functions.insert(
+            TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<TransactionProcessorRunSubintentInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<TransactionProcessorRunSubintentOutput>(),
+                ),
+                export: TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT.to_string(),
+            },
+        );
 
         let schema = generate_full_schema(aggregator);
         let blueprints = btreemap!(
@@ -65,6 +81,8 @@ impl TransactionProcessorNativePackage {
                     /// Only allow the root call frame to call any function in transaction processor.
                     /// This is a safety precaution to reduce surface area of attack. This may be removed
                     /// if/when the transaction processor is verified to be safe.
+                    /// The auth module makes an exception for the transaction processor running
+                    /// the subintents of a transaction.
                     function_auth: FunctionAuth::RootOnly,
                     method_auth: MethodAuthTemplate::AllowAll,
                 },
@@ -93,11 +111,23 @@ impl TransactionProcessorNativePackage {
                     input.global_address_reservations,
                     input.references,
                     input.blobs,
+                    input.subintents,
                     api,
                 )?;
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT => {
+                let input: TransactionProcessorRunSubintentInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+
+                let rtn =
+                    TransactionProcessorBlueprint::run_subintent(input.subintent, input.args, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -28,6 +28,25 @@ pub struct TransactionProcessorRunInput {
     pub global_address_reservations: Vec<GlobalAddressReservation>,
     pub references: Vec<Reference>, // Required so that the kernel passes the references to the processor frame
     pub blobs: IndexMap<Hash, Vec<u8>>,
+    pub subintents: Vec<TransactionProcessorSubintent>,
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct TransactionProcessorSubintent {
+    /// The hash of the subintent, which the auth module grants the subintent signers' proofs by.
+    pub subintent_hash: Hash,
+    pub manifest_encoded_instructions: Vec<u8>,
+    pub references: Vec<Reference>, // Required so that the kernel passes the references to the subintent frame
+    pub blobs: IndexMap<Hash, Vec<u8>>,
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct TransactionProcessorRunSubintentInput {
+    pub child_index: u32,
+    pub subintent: TransactionProcessorSubintent,
+    /// The value yielded to the subintent, whose buckets and proofs are put on its worktop and
+    /// auth zone.
+    pub args: ScryptoValue,
 }
 
 // This needs to match the above, but is easily encodable to avoid cloning from the transaction payload to encode
@@ -37,6 +56,15 @@ pub struct TransactionProcessorRunInputEfficientEncodable<'a> {
     pub global_address_reservations: Vec<GlobalAddressReservation>,
     pub references: &'a IndexSet<Reference>,
     pub blobs: &'a IndexMap<Hash, Vec<u8>>,
+    pub subintents: Vec<TransactionProcessorSubintentEfficientEncodable<'a>>,
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoEncode)]
+pub struct TransactionProcessorSubintentEfficientEncodable<'a> {
+    pub subintent_hash: Hash,
+    pub manifest_encoded_instructions: &'a [u8],
+    pub references: &'a IndexSet<Reference>,
+    pub blobs: &'a IndexMap<Hash, Vec<u8>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -59,6 +87,15 @@ pub enum TransactionProcessorError {
     NotPackageAddress(NodeId),
     NotGlobalAddress(NodeId),
     AuthZoneIsEmpty,
+    InvalidChildIndex(u32),
+    SubintentDidNotYield,
+    /// Yield instructions are only supported in transactions with subintents, which excludes
+    /// every V1 transaction.
+    SubintentInstructionsNotSupported,
+    YieldToParentInRootIntent,
+    /// Subintents may only be run by yielding to them, as the subintent signers' proofs are
+    /// granted to the call which runs them.
+    RunSubintentNotCallable,
 }
 
 pub struct TransactionProcessorBlueprint;
@@ -133,8 +170,73 @@ impl TransactionProcessorBlueprint {
         global_address_reservations: Vec<GlobalAddressReservation>,
         _references: Vec<Reference>, // Required so that the kernel passes the references to the processor frame
         blobs: IndexMap<Hash, Vec<u8>>,
+        subintents: Vec<TransactionProcessorSubintent>,
         api: &mut Y,
     ) -> Result<Vec<InstructionOutput>, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let instructions = Self::decode_instructions(&manifest_encoded_instructions)?;
+        if subintents.is_empty()
+            && instructions.iter().any(|instruction| {
+                matches!(
+                    instruction,
+                    InstructionV1::YieldToChild { .. } | InstructionV1::YieldToParent { .. }
+                )
+            })
+        {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::TransactionProcessorError(
+                    TransactionProcessorError::SubintentInstructionsNotSupported,
+                ),
+            ));
+        }
+
+        let worktop = Self::create_worktop(api)?;
+        let processor = TransactionProcessor::new(blobs, global_address_reservations, subintents);
+        let (outputs, yielded) = Self::execute_instructions(instructions, worktop, processor, api)?;
+        if yielded.is_some() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::TransactionProcessorError(
+                    TransactionProcessorError::YieldToParentInRootIntent,
+                ),
+            ));
+        }
+
+        worktop.drop(api)?;
+
+        Ok(outputs)
+    }
+
+    /// Runs a subintent on its own worktop, until it yields back to its parent.
+    pub(crate) fn run_subintent<Y, L: Default>(
+        subintent: TransactionProcessorSubintent,
+        args: ScryptoValue,
+        api: &mut Y,
+    ) -> Result<ScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
+        let worktop = Self::create_worktop(api)?;
+        let mut processor = TransactionProcessor::new(subintent.blobs, vec![], vec![]);
+        processor.handle_call_return_data(
+            &IndexedScryptoValue::from_typed(&args),
+            &worktop,
+            api,
+        )?;
+        let instructions = Self::decode_instructions(&subintent.manifest_encoded_instructions)?;
+        let (_, yielded) = Self::execute_instructions(instructions, worktop, processor, api)?;
+
+        worktop.drop(api)?;
+
+        yielded.ok_or(RuntimeError::ApplicationError(
+            ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::SubintentDidNotYield,
+            ),
+        ))
+    }
+
+    fn create_worktop<Y, L: Default>(api: &mut Y) -> Result<Worktop, RuntimeError>
     where
         Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
     {
@@ -162,16 +264,33 @@ impl TransactionProcessorBlueprint {
         )?;
         api.kernel_pin_node(worktop_node_id)?;
 
-        let worktop = Worktop(Own(worktop_node_id));
-        let instructions = manifest_decode::<Vec<InstructionV1>>(&manifest_encoded_instructions)
-            .map_err(|e| {
-                // This error should never occur if being called from root since this is constructed
-                // by the transaction executor. This error is more to protect against application
-                // space calling this function if/when possible
-                RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
-            })?;
-        let mut processor = TransactionProcessor::new(blobs, global_address_reservations);
+        Ok(Worktop(Own(worktop_node_id)))
+    }
+
+    fn decode_instructions(
+        manifest_encoded_instructions: &[u8],
+    ) -> Result<Vec<InstructionV1>, RuntimeError> {
+        manifest_decode::<Vec<InstructionV1>>(manifest_encoded_instructions).map_err(|e| {
+            // This error should never occur if being called from root since this is constructed
+            // by the transaction executor. This error is more to protect against application
+            // space calling this function if/when possible
+            RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+        })
+    }
+
+    /// Executes the instructions, and returns their outputs and the value yielded to the parent
+    /// intent, if any.
+    fn execute_instructions<Y, L: Default>(
+        instructions: Vec<InstructionV1>,
+        worktop: Worktop,
+        mut processor: TransactionProcessor,
+        api: &mut Y,
+    ) -> Result<(Vec<InstructionOutput>, Option<ScryptoValue>), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<L> + ClientApi<RuntimeError>,
+    {
         let mut outputs = Vec::new();
+        let mut yielded = None;
         for (index, inst) in instructions.into_iter().enumerate() {
             api.update_instruction_index(index)?;
 
@@ -333,6 +452,15 @@ impl TransactionProcessorBlueprint {
                     processor = processor_with_api.processor;
 
                     let package_address = processor.resolve_package_address(package_address)?;
+                    if package_address == TRANSACTION_PROCESSOR_PACKAGE
+                        && function_name == TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT
+                    {
+                        return Err(RuntimeError::ApplicationError(
+                            ApplicationError::TransactionProcessorError(
+                                TransactionProcessorError::RunSubintentNotCallable,
+                            ),
+                        ));
+                    }
                     let rtn = api.call_function(
                         package_address,
                         &blueprint_name,
@@ -448,13 +576,47 @@ impl TransactionProcessorBlueprint {
 
                     InstructionOutput::None
                 }
+                InstructionV1::YieldToChild { child_index, args } => {
+                    let subintent = processor.take_subintent(child_index)?;
+                    let mut processor_with_api = TransactionProcessorWithApi {
+                        worktop,
+                        processor,
+                        api,
+                    };
+                    let scrypto_value = transform(args, &mut processor_with_api)?;
+                    processor = processor_with_api.processor;
+
+                    let rtn = api.call_function(
+                        TRANSACTION_PROCESSOR_PACKAGE,
+                        TRANSACTION_PROCESSOR_BLUEPRINT,
+                        TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT,
+                        scrypto_encode(&TransactionProcessorRunSubintentInput {
+                            child_index,
+                            subintent,
+                            args: scrypto_value,
+                        })
+                        .unwrap(),
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn).unwrap();
+                    processor.handle_call_return_data(&result, &worktop, api)?;
+                    InstructionOutput::CallReturn(result.into())
+                }
+                InstructionV1::YieldToParent { args } => {
+                    let mut processor_with_api = TransactionProcessorWithApi {
+                        worktop,
+                        processor,
+                        api,
+                    };
+                    yielded = Some(transform(args, &mut processor_with_api)?);
+                    outputs.push(InstructionOutput::None);
+                    break;
+                }
             };
             outputs.push(result);
         }
 
-        worktop.drop(api)?;
-
-        Ok(outputs)
+        Ok((outputs, yielded))
     }
 }
 
@@ -465,15 +627,20 @@ struct TransactionProcessor {
     address_mapping: NonIterMap<u32, NodeId>,
     id_allocator: ManifestIdAllocator,
     blobs_by_hash: IndexMap<Hash, Vec<u8>>,
+    subintents: Vec<Option<TransactionProcessorSubintent>>,
+    next_child_index: u32,
 }
 
 impl TransactionProcessor {
     fn new(
         blobs_by_hash: IndexMap<Hash, Vec<u8>>,
         global_address_reservations: Vec<GlobalAddressReservation>,
+        subintents: Vec<TransactionProcessorSubintent>,
     ) -> Self {
         let mut processor = Self {
             blobs_by_hash,
+            subintents: subintents.into_iter().map(Some).collect(),
+            next_child_index: 0,
             proof_mapping: index_map_new(),
             bucket_mapping: NonIterMap::new(),
             address_reservation_mapping: NonIterMap::new(),
@@ -514,6 +681,27 @@ impl TransactionProcessor {
         Ok(Bucket(Own(real_id)))
     }
 
+    /// Takes the subintent to yield to. Subintents must be yielded to in order, and only once.
+    fn take_subintent(
+        &mut self,
+        child_index: u32,
+    ) -> Result<TransactionProcessorSubintent, RuntimeError> {
+        let subintent = if child_index == self.next_child_index {
+            self.subintents
+                .get_mut(child_index as usize)
+                .and_then(|subintent| subintent.take())
+        } else {
+            None
+        };
+        let subintent = subintent.ok_or(RuntimeError::ApplicationError(
+            ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::InvalidChildIndex(child_index),
+            ),
+        ))?;
+        self.next_child_index += 1;
+        Ok(subintent)
+    }
+
     fn get_blob(&mut self, blob_ref: &ManifestBlobRef) -> Result<&[u8], RuntimeError> {
         let hash = Hash(blob_ref.0);
         self.blobs_by_hash
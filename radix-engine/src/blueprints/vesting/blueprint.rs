@@ -0,0 +1,369 @@
+use crate::blueprints::vesting::*;
+use crate::errors::*;
+use crate::internal_prelude::declare_native_blueprint_state;
+use crate::internal_prelude::*;
+use crate::kernel::kernel_api::*;
+use crate::prelude::BlueprintSchemaInit;
+use crate::types::{ReceiverInfo, TypeRef};
+use crate::{event_schema, roles_template};
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_interface::api::node_modules::auth::RoleDefinition;
+use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::package::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+};
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::blueprints::vesting::*;
+use radix_engine_interface::prelude::{
+    BlueprintFunctionsSchemaInit, BlueprintHooksInit, BlueprintStateSchemaInit, FunctionSchemaInit,
+};
+use radix_engine_interface::types::*;
+use radix_engine_interface::*;
+
+pub const VESTING_BLUEPRINT_IDENT: &'static str = VESTING_BLUEPRINT;
+
+declare_native_blueprint_state! {
+    blueprint_ident: Vesting,
+    blueprint_snake_case: vesting,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+    }
+}
+
+pub type VestingStateV1 = VestingSubstate;
+
+pub struct VestingBlueprint;
+impl VestingBlueprint {
+    pub fn definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let feature_set = VestingFeatureSet::all_features();
+        let state = VestingStateSchemaInit::create_schema_init(&mut aggregator);
+
+        let mut functions = BTreeMap::new();
+
+        functions.insert(
+            VESTING_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingInstantiateOutput>(),
+                ),
+                export: VESTING_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            VESTING_CLAIM_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingClaimInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingClaimOutput>(),
+                ),
+                export: VESTING_CLAIM_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            VESTING_GET_CLAIMABLE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingGetClaimableInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingGetClaimableOutput>(),
+                ),
+                export: VESTING_GET_CLAIMABLE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            VESTING_REVOKE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingRevokeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingRevokeOutput>(),
+                ),
+                export: VESTING_REVOKE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                ClaimEvent,
+                RevocationEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: btreeset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                functions: BlueprintFunctionsSchemaInit { functions },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    roles {
+                        VESTING_BENEFICIARY_ROLE;
+                        VESTING_REVOKER_ROLE;
+                    },
+                    methods {
+                        // Main Module rules
+                        VESTING_CLAIM_IDENT => [VESTING_BENEFICIARY_ROLE];
+                        VESTING_GET_CLAIMABLE_IDENT => MethodAccessibility::Public;
+                        VESTING_REVOKE_IDENT => [VESTING_REVOKER_ROLE];
+                    }
+                }),
+            },
+        }
+    }
+
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        revoker_rule: Option<AccessRule>,
+        schedule: VestingSchedule,
+        bucket: Bucket,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<VestingInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi,
+    {
+        Self::validate_schedule(&schedule)?;
+
+        let total_amount = bucket.amount(api)?;
+        if total_amount == Decimal::ZERO {
+            return Err(VestingError::VestingOfEmptyBucketError.into());
+        }
+
+        let resource_address = bucket.resource_address(api)?;
+        let divisibility = match ResourceManager(resource_address).resource_type(api)? {
+            ResourceType::Fungible { divisibility } => divisibility,
+            ResourceType::NonFungible { .. } => 0,
+        };
+
+        let (address_reservation, address) = {
+            if let Some(address_reservation) = address_reservation {
+                let address = api.get_reservation_address(address_reservation.0.as_node_id())?;
+                (address_reservation, address)
+            } else {
+                api.allocate_global_address(BlueprintId {
+                    package_address: VESTING_PACKAGE,
+                    blueprint_name: VESTING_BLUEPRINT_IDENT.to_string(),
+                })?
+            }
+        };
+
+        // A vesting component without a revoker can never be revoked.
+        let revoker_rule = revoker_rule.unwrap_or(AccessRule::DenyAll);
+        let role_assignment = RoleAssignment::create(
+            owner_role,
+            btreemap! {
+                ObjectModuleId::Main => roles_init! {
+                    RoleKey { key: VESTING_BENEFICIARY_ROLE.to_owned() } => beneficiary_rule;
+                    RoleKey { key: VESTING_REVOKER_ROLE.to_owned() } => revoker_rule;
+                }
+            },
+            api,
+        )?
+        .0;
+
+        let metadata = Metadata::create_with_data(
+            metadata_init! {
+                "vesting_resource" => GlobalAddress::from(resource_address), locked;
+            },
+            api,
+        )?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let mut vault = Vault::create(resource_address, api)?;
+            vault.put(bucket, api)?;
+
+            let substate = VestingSubstate {
+                vault,
+                resource_address,
+                divisibility,
+                total_amount,
+                claimed_amount: Decimal::ZERO,
+                schedule,
+                revoked_at: None,
+            };
+            api.new_simple_object(
+                VESTING_BLUEPRINT_IDENT,
+                btreemap! {
+                    VestingField::State.field_index() => FieldValue::new(&VestingStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        api.globalize(
+            object_id,
+            btreemap!(
+                ModuleId::RoleAssignment => role_assignment.0,
+                ModuleId::Metadata => metadata.0,
+                ModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    pub fn claim<Y>(api: &mut Y) -> Result<VestingClaimOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+
+        let amount = substate
+            .claimable_amount(now)
+            .ok_or(VestingError::DecimalOverflowError)?;
+        let bucket = substate.vault.take(amount, api)?;
+        substate.claimed_amount = substate
+            .claimed_amount
+            .safe_add(amount)
+            .ok_or(VestingError::DecimalOverflowError)?;
+
+        Self::write_and_close(substate, handle, api)?;
+
+        Runtime::emit_event(api, ClaimEvent { amount })?;
+
+        Ok(bucket)
+    }
+
+    pub fn get_claimable<Y>(api: &mut Y) -> Result<VestingGetClaimableOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+        api.field_close(handle)?;
+
+        substate
+            .claimable_amount(now)
+            .ok_or(VestingError::DecimalOverflowError.into())
+    }
+
+    pub fn revoke<Y>(api: &mut Y) -> Result<VestingRevokeOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+
+        if substate.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked.into());
+        }
+
+        // Everything that has vested up to this point remains claimable by the beneficiary, the
+        // rest is returned to the revoker.
+        let unvested_amount = substate
+            .vested_amount(now)
+            .and_then(|vested| substate.total_amount.safe_sub(vested))
+            .ok_or(VestingError::DecimalOverflowError)?;
+        let bucket = substate.vault.take(unvested_amount, api)?;
+        substate.revoked_at = Some(now);
+
+        Self::write_and_close(substate, handle, api)?;
+
+        Runtime::emit_event(api, RevocationEvent { unvested_amount })?;
+
+        Ok(bucket)
+    }
+
+    //===================
+    // Utility Functions
+    //===================
+
+    fn lock_and_read<Y>(
+        api: &mut Y,
+        lock_flags: LockFlags,
+    ) -> Result<(VestingSubstate, SubstateHandle), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate_key = VestingField::State.into();
+        let handle = api.actor_open_field(ACTOR_STATE_SELF, substate_key, lock_flags)?;
+        let vesting_substate = api.field_read_typed::<VersionedVestingState>(handle)?;
+        let vesting_substate = match vesting_substate {
+            VersionedVestingState::V1(vesting_substate) => vesting_substate,
+        };
+
+        Ok((vesting_substate, handle))
+    }
+
+    fn write_and_close<Y>(
+        substate: VestingSubstate,
+        handle: SubstateHandle,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.field_write_typed(
+            handle,
+            &VestingStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)
+    }
+
+    fn validate_schedule(schedule: &VestingSchedule) -> Result<(), RuntimeError> {
+        let is_valid = match schedule {
+            VestingSchedule::Linear { start, cliff, end } => {
+                start <= cliff && cliff <= end && start < end
+            }
+            VestingSchedule::Cliff { .. } => true,
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err(VestingError::InvalidSchedule {
+                schedule: *schedule,
+            }
+            .into())
+        }
+    }
+}
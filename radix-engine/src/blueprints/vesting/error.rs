@@ -0,0 +1,18 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use radix_engine_common::ScryptoSbor;
+use radix_engine_interface::blueprints::vesting::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum VestingError {
+    InvalidSchedule { schedule: VestingSchedule },
+    VestingOfEmptyBucketError,
+    AlreadyRevoked,
+    DecimalOverflowError,
+}
+
+impl From<VestingError> for RuntimeError {
+    fn from(error: VestingError) -> Self {
+        Self::ApplicationError(ApplicationError::VestingError(error))
+    }
+}
@@ -0,0 +1,12 @@
+use radix_engine_common::math::Decimal;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct ClaimEvent {
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct RevocationEvent {
+    pub unvested_amount: Decimal,
+}
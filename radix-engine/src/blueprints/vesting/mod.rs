@@ -0,0 +1,11 @@
+mod blueprint;
+mod error;
+mod events;
+mod package;
+mod substates;
+
+pub use blueprint::*;
+pub use error::*;
+pub use events::*;
+pub use package::*;
+pub use substates::*;
@@ -0,0 +1,86 @@
+use super::*;
+use crate::errors::*;
+use crate::kernel::kernel_api::*;
+use crate::system::system_callback::*;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use radix_engine_interface::blueprints::vesting::*;
+use radix_engine_interface::types::*;
+use sbor::rust::prelude::*;
+
+pub const VESTING_BENEFICIARY_ROLE: &'static str = "beneficiary";
+pub const VESTING_REVOKER_ROLE: &'static str = "revoker";
+
+pub struct VestingNativePackage;
+impl VestingNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = btreemap!(
+            VESTING_BLUEPRINT_IDENT.to_string() => VestingBlueprint::definition(),
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi<SystemLockData> + ClientApi<RuntimeError>,
+    {
+        match export_name {
+            VESTING_INSTANTIATE_EXPORT_NAME => {
+                let VestingInstantiateInput {
+                    owner_role,
+                    beneficiary_rule,
+                    revoker_rule,
+                    schedule,
+                    bucket,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::instantiate(
+                    owner_role,
+                    beneficiary_rule,
+                    revoker_rule,
+                    schedule,
+                    bucket,
+                    address_reservation,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_CLAIM_EXPORT_NAME => {
+                let VestingClaimInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::claim(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_GET_CLAIMABLE_EXPORT_NAME => {
+                let VestingGetClaimableInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::get_claimable(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_REVOKE_EXPORT_NAME => {
+                let VestingRevokeInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::revoke(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
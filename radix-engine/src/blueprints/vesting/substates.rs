@@ -0,0 +1,87 @@
+use native_sdk::resource::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::time::*;
+use radix_engine_common::*;
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::blueprints::vesting::*;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct VestingSubstate {
+    /// The vault holding the resources that have not yet been claimed by the beneficiary.
+    pub vault: Vault,
+
+    /// The address of the resource being vested.
+    pub resource_address: ResourceAddress,
+
+    /// The divisibility of the vested resource, used to round the vested amount so that it can
+    /// always be taken out of the vault. This is zero for non-fungible resources.
+    pub divisibility: u8,
+
+    /// The total amount of resources that the component was instantiated with.
+    pub total_amount: Decimal,
+
+    /// The amount of resources that have been claimed by the beneficiary so far.
+    pub claimed_amount: Decimal,
+
+    /// The schedule according to which the resources are released.
+    pub schedule: VestingSchedule,
+
+    /// The instant the vesting was revoked at, if it was. Nothing vests past this instant.
+    pub revoked_at: Option<Instant>,
+}
+
+impl VestingSubstate {
+    /// Returns the amount of resources that have vested at the given instant, taking the
+    /// revocation instant into account.
+    pub fn vested_amount(&self, now: Instant) -> Option<Decimal> {
+        let now = self
+            .revoked_at
+            .map_or(now, |revoked_at| now.min(revoked_at));
+
+        match self.schedule {
+            VestingSchedule::Cliff { unlock } => {
+                if now.compare(unlock, TimeComparisonOperator::Gte) {
+                    Some(self.total_amount)
+                } else {
+                    Some(Decimal::ZERO)
+                }
+            }
+            VestingSchedule::Linear { start, cliff, end } => {
+                if now.compare(cliff, TimeComparisonOperator::Lt) {
+                    Some(Decimal::ZERO)
+                } else if now.compare(end, TimeComparisonOperator::Gte) {
+                    Some(self.total_amount)
+                } else {
+                    let elapsed = now.seconds_since_unix_epoch - start.seconds_since_unix_epoch;
+                    let duration = end.seconds_since_unix_epoch - start.seconds_since_unix_epoch;
+                    self.total_amount
+                        .safe_mul(elapsed)
+                        .and_then(|d| d.safe_div(duration))
+                        .and_then(|d| d.safe_round(self.divisibility, RoundingMode::ToZero))
+                }
+            }
+        }
+    }
+
+    /// Returns the amount of resources that have vested at the given instant but have not yet
+    /// been claimed.
+    pub fn claimable_amount(&self, now: Instant) -> Option<Decimal> {
+        self.vested_amount(now)
+            .and_then(|vested| vested.safe_sub(self.claimed_amount))
+    }
+}
+
+impl Clone for VestingSubstate {
+    fn clone(&self) -> Self {
+        Self {
+            vault: Vault(self.vault.0.clone()),
+            resource_address: self.resource_address,
+            divisibility: self.divisibility,
+            total_amount: self.total_amount,
+            claimed_amount: self.claimed_amount,
+            schedule: self.schedule,
+            revoked_at: self.revoked_at,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::blueprints::access_controller::AccessControllerError;
 use crate::blueprints::account::AccountError;
 use crate::blueprints::consensus_manager::{ConsensusManagerError, ValidatorError};
+use crate::blueprints::multisig::MultisigError;
 use crate::blueprints::package::PackageError;
+use crate::blueprints::pool::constant_product_pool::ConstantProductPoolError;
 use crate::blueprints::pool::multi_resource_pool::MultiResourcePoolError;
 use crate::blueprints::pool::one_resource_pool::OneResourcePoolError;
 use crate::blueprints::pool::two_resource_pool::TwoResourcePoolError;
@@ -11,6 +13,7 @@ use crate::blueprints::resource::{
     VaultError, WorktopError,
 };
 use crate::blueprints::transaction_processor::TransactionProcessorError;
+use crate::blueprints::vesting::VestingError;
 use crate::kernel::call_frame::{
     CallFrameDrainSubstatesError, CallFrameRemoveSubstateError, CallFrameScanKeysError,
     CallFrameScanSortedSubstatesError, CallFrameSetSubstateError, CloseSubstateError,
@@ -27,7 +30,7 @@ use crate::system::system_modules::limits::TransactionLimitsError;
 use crate::system::system_type_checker::TypeCheckError;
 use crate::transaction::AbortReason;
 use crate::types::*;
-use crate::vm::wasm::WasmRuntimeError;
+use crate::vm::wasm::{WasmBacktrace, WasmRuntimeError};
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::api::{ActorStateHandle, ModuleId};
 use radix_engine_interface::blueprints::package::{BlueprintPartitionType, CanonicalBlueprintId};
@@ -293,6 +296,11 @@ pub enum SystemUpstreamError {
 pub enum VmError {
     Native(NativeRuntimeError),
     Wasm(WasmRuntimeError),
+    /// A trap or panic of Scrypto code executed in debug mode, with the backtrace at the time.
+    WithBacktrace {
+        error: Box<RuntimeError>,
+        backtrace: WasmBacktrace,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -470,6 +478,12 @@ pub enum ApplicationError {
     TwoResourcePoolError(TwoResourcePoolError),
 
     MultiResourcePoolError(MultiResourcePoolError),
+
+    ConstantProductPoolError(ConstantProductPoolError),
+
+    VestingError(VestingError),
+
+    MultisigError(MultisigError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
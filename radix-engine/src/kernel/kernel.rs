@@ -3,7 +3,9 @@ use super::call_frame::{CallFrame, NodeVisibility, OpenSubstateError};
 use super::heap::Heap;
 use super::id_allocator::IdAllocator;
 use crate::blueprints::resource::*;
-use crate::blueprints::transaction_processor::TransactionProcessorRunInputEfficientEncodable;
+use crate::blueprints::transaction_processor::{
+    TransactionProcessorRunInputEfficientEncodable, TransactionProcessorSubintentEfficientEncodable,
+};
 use crate::errors::RuntimeError;
 use crate::errors::*;
 use crate::internal_prelude::*;
@@ -35,7 +37,7 @@ use radix_engine_interface::blueprints::transaction_processor::{
 use radix_engine_store_interface::db_key_mapper::SubstateKeyContent;
 use resources_tracker_macro::trace_resources;
 use sbor::rust::mem;
-use transaction::prelude::PreAllocatedAddress;
+use transaction::prelude::{ExecutableSubintent, PreAllocatedAddress};
 
 /// Organizes the radix engine stack to make a function entrypoint available for execution
 pub struct KernelBoot<'g, V: SystemCallbackObject, S: CommitableSubstateStore> {
@@ -69,6 +71,7 @@ impl<'g, 'h, V: SystemCallbackObject, S: CommitableSubstateStore> KernelBoot<'g,
         pre_allocated_addresses: &'a Vec<PreAllocatedAddress>,
         references: &'a IndexSet<Reference>,
         blobs: &'a IndexMap<Hash, Vec<u8>>,
+        subintents: &'a [ExecutableSubintent<'a>],
     ) -> Result<Vec<u8>, RuntimeError> {
         #[cfg(feature = "resource_tracker")]
         radix_engine_profiling::QEMU_PLUGIN_CALIBRATOR.with(|v| {
@@ -178,6 +181,17 @@ impl<'g, 'h, V: SystemCallbackObject, S: CommitableSubstateStore> KernelBoot<'g,
                 global_address_reservations,
                 references,
                 blobs,
+                subintents: subintents
+                    .iter()
+                    .map(
+                        |subintent| TransactionProcessorSubintentEfficientEncodable {
+                            subintent_hash: subintent.intent_hash.to_hash(),
+                            manifest_encoded_instructions: subintent.encoded_instructions,
+                            references: subintent.references,
+                            blobs: subintent.blobs,
+                        },
+                    )
+                    .collect(),
             })
             .unwrap(),
         )?;
@@ -2,6 +2,7 @@ use crate::blueprints::access_controller::*;
 use crate::blueprints::account::{AccountNativePackage, AccountOwnerBadgeData};
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::{IdentityNativePackage, IdentityOwnerBadgeData};
+use crate::blueprints::multisig::MultisigNativePackage;
 use crate::blueprints::package::{
     create_bootstrap_package_partitions, PackageCollection, PackageNativePackage,
     PackageOwnerBadgeData, SystemInstruction,
@@ -13,6 +14,7 @@ use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
 use crate::blueprints::transaction_tracker::{
     TransactionTrackerNativePackage, TRANSACTION_TRACKER_CREATE_IDENT,
 };
+use crate::blueprints::vesting::VestingNativePackage;
 use crate::internal_prelude::*;
 use crate::system::node_modules::metadata::MetadataNativePackage;
 use crate::system::node_modules::role_assignment::RoleAssignmentNativePackage;
@@ -1008,6 +1010,50 @@ pub fn create_system_bootstrap_transaction(
         });
     }
 
+    // Vesting Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(VESTING_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: VestingNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Vesting Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of vesting components.".to_owned(), locked;
+                },
+                native_package_code_id: VESTING_CODE_ID,
+            }),
+        });
+    }
+
+    // Multisig Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(MULTISIG_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: MultisigNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Multisig Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of multisig components.".to_owned(), locked;
+                },
+                native_package_code_id: MULTISIG_CODE_ID,
+            }),
+        });
+    }
+
     // ECDSA Secp256k1
     {
         pre_allocated_addresses.push((
@@ -2111,7 +2111,8 @@ where
             RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
         })?;
         let blueprint_id = BlueprintId::new(&package_address, blueprint_name);
-        let auth_zone = SystemModuleMixer::on_call_function(self, &blueprint_id, function_name)?;
+        let auth_zone =
+            SystemModuleMixer::on_call_function(self, &blueprint_id, function_name, &args)?;
 
         let rtn = self
             .api
@@ -2196,6 +2197,27 @@ where
             .credit_cost_units(vault_id, locked_fee, contingent)
     }
 
+    #[trace_resources]
+    fn lock_royalty_payment(
+        &mut self,
+        vault_id: NodeId,
+        resource_address: ResourceAddress,
+        payment: LiquidFungibleResource,
+        max_per_function: Decimal,
+    ) -> Result<LiquidFungibleResource, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::LockRoyaltyPayment)?;
+
+        self.api.kernel_get_system().modules.lock_royalty_payment(
+            vault_id,
+            resource_address,
+            payment,
+            max_per_function,
+        )
+    }
+
     fn execution_cost_unit_limit(&mut self) -> Result<u32, RuntimeError> {
         self.api
             .kernel_get_system()
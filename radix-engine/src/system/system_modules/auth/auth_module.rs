@@ -1,6 +1,7 @@
 use super::Authorization;
 use crate::blueprints::package::PackageAuthNativeBlueprint;
 use crate::blueprints::resource::AuthZone;
+use crate::blueprints::transaction_processor::TransactionProcessorRunSubintentInput;
 use crate::errors::*;
 use crate::internal_prelude::*;
 use crate::kernel::actor::Actor;
@@ -19,7 +20,9 @@ use radix_engine_interface::blueprints::package::{
     BlueprintVersion, BlueprintVersionKey, MethodAuthTemplate, RoleSpecification,
 };
 use radix_engine_interface::blueprints::resource::*;
-use radix_engine_interface::blueprints::transaction_processor::TRANSACTION_PROCESSOR_BLUEPRINT;
+use radix_engine_interface::blueprints::transaction_processor::{
+    TRANSACTION_PROCESSOR_BLUEPRINT, TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT,
+};
 use radix_engine_interface::types::*;
 use transaction::model::AuthZoneParams;
 
@@ -75,11 +78,14 @@ impl AuthModule {
         api: &mut SystemService<Y, V>,
         blueprint_id: &BlueprintId,
         ident: &str,
+        args: &IndexedScryptoValue,
     ) -> Result<NodeId, RuntimeError>
     where
         V: SystemCallbackObject,
         Y: KernelApi<SystemConfig<V>>,
     {
+        let mut is_subintent = false;
+
         // Create AuthZone
         let auth_zone = {
             // TODO: Remove special casing use of transaction processor and just have virtual resources
@@ -98,6 +104,22 @@ impl AuthModule {
                         auth_module.params.virtual_resources.clone(),
                         auth_module.params.initial_proofs.clone(),
                     )
+                } else if let Some(subintent_initial_proofs) = Self::take_subintent_initial_proofs(
+                    api,
+                    is_transaction_processor_blueprint,
+                    ident,
+                    args,
+                )? {
+                    is_subintent = true;
+                    (
+                        api.kernel_get_system()
+                            .modules
+                            .auth
+                            .params
+                            .virtual_resources
+                            .clone(),
+                        subintent_initial_proofs,
+                    )
                 } else {
                     (BTreeSet::new(), BTreeSet::new())
                 };
@@ -106,7 +128,7 @@ impl AuthModule {
         };
 
         // Check authorization
-        {
+        if !is_subintent {
             // Step 1: Resolve method to permission
             let permission = PackageAuthNativeBlueprint::resolve_function_permission(
                 blueprint_id.package_address.as_node_id(),
@@ -126,6 +148,42 @@ impl AuthModule {
         Ok(auth_zone)
     }
 
+    /// Returns the initial proofs of the subintent being yielded into, if this is the root
+    /// transaction processor running a subintent of the transaction. The proofs are bound to the
+    /// hash of the subintent, and are only given out once, so a subintent which is not part of
+    /// the transaction, or is run again, is left to fail the function auth check. The transaction
+    /// processor only calls the function when yielding to a child, and refuses to call it for a
+    /// `CallFunction` instruction.
+    fn take_subintent_initial_proofs<V, Y>(
+        api: &mut SystemService<Y, V>,
+        is_transaction_processor_blueprint: bool,
+        ident: &str,
+        args: &IndexedScryptoValue,
+    ) -> Result<Option<BTreeSet<NonFungibleGlobalId>>, RuntimeError>
+    where
+        V: SystemCallbackObject,
+        Y: KernelApi<SystemConfig<V>>,
+    {
+        if !is_transaction_processor_blueprint
+            || !ident.eq(TRANSACTION_PROCESSOR_RUN_SUBINTENT_IDENT)
+            || api.kernel_get_current_depth() != 1
+            || !api.current_actor()?.is_transaction_processor_blueprint()
+        {
+            return Ok(None);
+        }
+
+        let Ok(input) = args.as_typed::<TransactionProcessorRunSubintentInput>() else {
+            return Ok(None);
+        };
+        Ok(api
+            .kernel_get_system()
+            .modules
+            .auth
+            .params
+            .subintent_initial_proofs
+            .swap_remove(&input.subintent.subintent_hash))
+    }
+
     pub fn on_call_function_finish<V, Y>(
         api: &mut SystemService<Y, V>,
         auth_zone: NodeId,
@@ -11,7 +11,9 @@ use crate::system::system_substates::FieldSubstate;
 use crate::system::system_substates::KeyValueEntrySubstate;
 use crate::types::*;
 use native_sdk::resource::{NativeNonFungibleProof, NativeProof};
+use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::{ClientObjectApi, LockFlags, ObjectModuleId};
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
 use radix_engine_interface::blueprints::resource::*;
 use sbor::rust::ops::Fn;
 
@@ -283,6 +285,38 @@ impl Authorization {
         }
     }
 
+    pub fn verify_time_rule<Y: ClientObjectApi<RuntimeError>>(
+        time_rule: &TimeRule,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError> {
+        match time_rule {
+            TimeRule::AfterInstant(instant) => Runtime::compare_against_current_time(
+                api,
+                *instant,
+                TimePrecision::Minute,
+                TimeComparisonOperator::Gte,
+            ),
+            TimeRule::BeforeInstant(instant) => Runtime::compare_against_current_time(
+                api,
+                *instant,
+                TimePrecision::Minute,
+                TimeComparisonOperator::Lt,
+            ),
+            TimeRule::BetweenInstants(start, end) => {
+                if !Self::verify_time_rule(&TimeRule::AfterInstant(*start), api)? {
+                    return Ok(false);
+                }
+                Self::verify_time_rule(&TimeRule::BeforeInstant(*end), api)
+            }
+            TimeRule::AfterEpoch(epoch) => Ok(Runtime::current_epoch(api)? >= *epoch),
+            TimeRule::BeforeEpoch(epoch) => Ok(Runtime::current_epoch(api)? < *epoch),
+            TimeRule::BetweenEpochs(start, end) => {
+                let current_epoch = Runtime::current_epoch(api)?;
+                Ok(current_epoch >= *start && current_epoch < *end)
+            }
+        }
+    }
+
     pub fn verify_auth_rule<Y: KernelSubstateApi<L> + ClientObjectApi<RuntimeError>, L: Default>(
         auth_zone: &NodeId,
         auth_rule: &AccessRuleNode,
@@ -315,6 +349,13 @@ impl Authorization {
 
                 return Ok(AuthorizationCheckResult::Authorized);
             }
+            AccessRuleNode::TimeRule(rule) => {
+                if Self::verify_time_rule(rule, api)? {
+                    Ok(AuthorizationCheckResult::Authorized)
+                } else {
+                    Ok(AuthorizationCheckResult::Failed(vec![]))
+                }
+            }
         }
     }
 
@@ -97,6 +97,7 @@ pub enum ExecutionCostingEntry<'a> {
 
     /* system */
     LockFee,
+    LockRoyaltyPayment,
     QueryFeeReserve,
     QueryActor,
     QueryTransactionHash,
@@ -165,6 +166,7 @@ impl<'a> ExecutionCostingEntry<'a> {
                 ft.scan_sorted_substates_cost(event)
             }
             ExecutionCostingEntry::LockFee => ft.lock_fee_cost(),
+            ExecutionCostingEntry::LockRoyaltyPayment => ft.lock_royalty_payment_cost(),
             ExecutionCostingEntry::QueryFeeReserve => ft.query_fee_reserve_cost(),
             ExecutionCostingEntry::QueryActor => ft.query_actor_cost(),
             ExecutionCostingEntry::QueryTransactionHash => ft.query_transaction_hash_cost(),
@@ -157,6 +157,22 @@ impl CostingModule {
                 ))
             })
     }
+
+    pub fn lock_royalty_payment(
+        &mut self,
+        vault_id: NodeId,
+        resource_address: ResourceAddress,
+        payment: LiquidFungibleResource,
+        max_per_function: Decimal,
+    ) -> Result<LiquidFungibleResource, RuntimeError> {
+        self.fee_reserve
+            .lock_royalty_payment(vault_id, resource_address, payment, max_per_function)
+            .map_err(|e| {
+                RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                    CostingError::FeeReserveError(e),
+                ))
+            })
+    }
 }
 
 pub fn apply_royalty_cost<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
@@ -6,6 +6,7 @@ use crate::{
 };
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use sbor::rust::cmp::min;
+use sbor::rust::mem;
 use transaction::prelude::TransactionCostingParameters;
 
 // Note: for performance reason, `u128` is used to represent decimal in this file.
@@ -25,6 +26,16 @@ pub enum FeeReserveError {
     LoanRepaymentFailed {
         xrd_owed: Decimal,
     },
+    InsufficientRoyaltyPayment {
+        resource_address: ResourceAddress,
+        required: Decimal,
+        remaining: Decimal,
+    },
+    RoyaltyAmountIsGreaterThanAllowed {
+        resource_address: ResourceAddress,
+        max: Decimal,
+        actual: Decimal,
+    },
     Abort(AbortReason),
 }
 
@@ -72,6 +83,14 @@ pub trait ExecutionFeeReserve {
         fee: LiquidFungibleResource,
         contingent: bool,
     ) -> Result<LiquidFungibleResource, FeeReserveError>;
+
+    fn lock_royalty_payment(
+        &mut self,
+        vault_id: NodeId,
+        resource_address: ResourceAddress,
+        payment: LiquidFungibleResource,
+        max_per_function: Decimal,
+    ) -> Result<LiquidFungibleResource, FeeReserveError>;
 }
 
 pub trait FinalizingFeeReserve {
@@ -133,12 +152,24 @@ pub struct SystemLoanFeeReserve {
     /// Royalty costs
     royalty_cost: u128,
     royalty_cost_breakdown: BTreeMap<RoyaltyRecipient, u128>,
+    /// Royalty costs charged in resources other than XRD, keyed by the recipient's vault for
+    /// the resource
+    resource_royalty_cost_breakdown: BTreeMap<RoyaltyRecipient, (ResourceAddress, Decimal)>,
+    /// The payments taken from the locked royalty payments for each recipient, along with the
+    /// vault each payment was locked from
+    resource_royalty_payments: BTreeMap<RoyaltyRecipient, Vec<(NodeId, LiquidFungibleResource)>>,
 
     /// Storage Costs
     storage_cost: u128,
 
     /// Payments made during the execution of a transaction.
     locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
+    /// Payments locked for royalties charged in resources other than XRD, which haven't been
+    /// charged yet.
+    locked_royalty_payments: Vec<(NodeId, ResourceAddress, LiquidFungibleResource)>,
+    /// The most a single function or method may charge in each resource, being the smallest
+    /// maximum stated by the payers locking payments in the resource.
+    max_royalty_per_function: BTreeMap<ResourceAddress, Decimal>,
 }
 
 impl Default for SystemLoanFeeReserve {
@@ -261,10 +292,14 @@ impl SystemLoanFeeReserve {
 
             royalty_cost_breakdown: BTreeMap::new(),
             royalty_cost: 0,
+            resource_royalty_cost_breakdown: BTreeMap::new(),
+            resource_royalty_payments: BTreeMap::new(),
 
             storage_cost: 0,
 
             locked_fees: Vec::new(),
+            locked_royalty_payments: Vec::new(),
+            max_royalty_per_function: BTreeMap::new(),
         }
     }
 
@@ -308,6 +343,15 @@ impl SystemLoanFeeReserve {
             .collect()
     }
 
+    pub fn resource_royalty_cost_breakdown(
+        &self,
+    ) -> IndexMap<RoyaltyRecipient, (ResourceAddress, Decimal)> {
+        self.resource_royalty_cost_breakdown
+            .clone()
+            .into_iter()
+            .collect()
+    }
+
     fn check_execution_cost_unit_limit(&self, cost_units: u32) -> Result<(), FeeReserveError> {
         if checked_add(self.execution_cost_units_committed, cost_units)?
             > self.execution_cost_unit_limit
@@ -372,6 +416,9 @@ impl SystemLoanFeeReserve {
         recipient: RoyaltyRecipient,
     ) -> Result<(), FeeReserveError> {
         let amount = match royalty_amount {
+            RoyaltyAmount::Resource(resource_address, amount) => {
+                return self.consume_resource_royalty_internal(resource_address, amount, recipient);
+            }
             RoyaltyAmount::Xrd(xrd_amount) => transmute_decimal_as_u128(xrd_amount)?,
             RoyaltyAmount::Usd(usd_amount) => {
                 transmute_decimal_as_u128(usd_amount)?
@@ -397,6 +444,84 @@ impl SystemLoanFeeReserve {
         }
     }
 
+    fn consume_resource_royalty_internal(
+        &mut self,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        recipient: RoyaltyRecipient,
+    ) -> Result<(), FeeReserveError> {
+        if amount.is_negative() {
+            return Err(FeeReserveError::Overflow);
+        }
+
+        if let Some(max) = self.max_royalty_per_function.get(&resource_address) {
+            if amount > *max {
+                return Err(FeeReserveError::RoyaltyAmountIsGreaterThanAllowed {
+                    resource_address,
+                    max: *max,
+                    actual: amount,
+                });
+            }
+        }
+
+        let remaining = self.remaining_royalty_payment(resource_address)?;
+        if remaining < amount {
+            return Err(FeeReserveError::InsufficientRoyaltyPayment {
+                resource_address,
+                required: amount,
+                remaining,
+            });
+        }
+
+        // Match the charge against the locked payments up front, so that the payments can be
+        // handed over to the recipient as they are on finalization.
+        let mut payments = Vec::new();
+        let mut to_take = amount;
+        for (vault_id, locked_resource_address, payment) in &mut self.locked_royalty_payments {
+            if to_take.is_zero() {
+                break;
+            }
+            if *locked_resource_address != resource_address || payment.is_empty() {
+                continue;
+            }
+            let taken = payment
+                .take_by_amount(min(to_take, payment.amount()))
+                .map_err(|_| FeeReserveError::Overflow)?;
+            to_take = to_take
+                .safe_sub(taken.amount())
+                .ok_or(FeeReserveError::Overflow)?;
+            payments.push((*vault_id, taken));
+        }
+
+        let (_, charged) = self
+            .resource_royalty_cost_breakdown
+            .entry(recipient.clone())
+            .or_insert((resource_address, Decimal::ZERO));
+        *charged = charged.safe_add(amount).ok_or(FeeReserveError::Overflow)?;
+        self.resource_royalty_payments
+            .entry(recipient)
+            .or_default()
+            .extend(payments);
+
+        Ok(())
+    }
+
+    /// The amount of the resource locked for royalty payments that hasn't been charged yet.
+    fn remaining_royalty_payment(
+        &self,
+        resource_address: ResourceAddress,
+    ) -> Result<Decimal, FeeReserveError> {
+        let mut remaining = Decimal::ZERO;
+        for (_, locked_resource_address, payment) in &self.locked_royalty_payments {
+            if *locked_resource_address == resource_address {
+                remaining = remaining
+                    .safe_add(payment.amount())
+                    .ok_or(FeeReserveError::Overflow)?;
+            }
+        }
+        Ok(remaining)
+    }
+
     pub fn repay_all(&mut self) -> Result<(), FeeReserveError> {
         // Apply deferred execution cost
         self.consume_execution_internal(self.execution_cost_units_deferred)?;
@@ -427,6 +552,21 @@ impl SystemLoanFeeReserve {
         self.xrd_balance += self.royalty_cost_breakdown.values().sum::<u128>();
         self.royalty_cost_breakdown.clear();
         self.royalty_cost = 0;
+        self.resource_royalty_cost_breakdown.clear();
+        // Give the matched payments back, so that they are refunded to the vaults they were
+        // locked from
+        for (vault_id, payment) in mem::take(&mut self.resource_royalty_payments)
+            .into_values()
+            .flatten()
+        {
+            if let Some((_, _, locked)) = self
+                .locked_royalty_payments
+                .iter_mut()
+                .find(|(locked_vault_id, _, _)| *locked_vault_id == vault_id)
+            {
+                locked.put(payment);
+            }
+        }
     }
 
     #[inline]
@@ -529,6 +669,28 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
 
         Ok(fee)
     }
+
+    fn lock_royalty_payment(
+        &mut self,
+        vault_id: NodeId,
+        resource_address: ResourceAddress,
+        mut payment: LiquidFungibleResource,
+        max_per_function: Decimal,
+    ) -> Result<LiquidFungibleResource, FeeReserveError> {
+        if max_per_function.is_negative() {
+            return Err(FeeReserveError::Overflow);
+        }
+
+        let max = self
+            .max_royalty_per_function
+            .entry(resource_address)
+            .or_insert(max_per_function);
+        *max = min(*max, max_per_function);
+        self.locked_royalty_payments
+            .push((vault_id, resource_address, payment.take_all()));
+
+        Ok(payment)
+    }
 }
 
 impl FinalizingFeeReserve for SystemLoanFeeReserve {
@@ -556,6 +718,7 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             )
             .unwrap();
         let royalty_cost_breakdown = self.royalty_cost_breakdown();
+        let resource_royalty_cost_breakdown = self.resource_royalty_cost_breakdown();
 
         FeeReserveFinalizationSummary {
             total_execution_cost_units_consumed: self.execution_cost_units_committed,
@@ -569,6 +732,9 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             total_bad_debt_in_xrd: transmute_u128_as_decimal(self.xrd_owed),
             locked_fees: self.locked_fees,
             royalty_cost_breakdown,
+            locked_royalty_payments: self.locked_royalty_payments,
+            resource_royalty_cost_breakdown,
+            resource_royalty_payments: self.resource_royalty_payments.into_iter().collect(),
         }
     }
 }
@@ -583,6 +749,8 @@ mod tests {
         component_address(EntityType::GlobalGenericComponent, 5);
     const TEST_VAULT_ID: NodeId = NodeId([0u8; NodeId::LENGTH]);
     const TEST_VAULT_ID_2: NodeId = NodeId([1u8; NodeId::LENGTH]);
+    const TEST_RESOURCE: ResourceAddress =
+        resource_address(EntityType::GlobalFungibleResourceManager, 5);
 
     fn xrd<T: Into<Decimal>>(amount: T) -> LiquidFungibleResource {
         LiquidFungibleResource::new(amount.into())
@@ -760,4 +928,102 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn test_resource_royalty() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve
+            .lock_royalty_payment(TEST_VAULT_ID, TEST_RESOURCE, xrd(10), dec!(10))
+            .unwrap();
+        fee_reserve
+            .consume_royalty(
+                RoyaltyAmount::Resource(TEST_RESOURCE, 4.into()),
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2),
+            )
+            .unwrap();
+        assert_eq!(
+            fee_reserve.consume_royalty(
+                RoyaltyAmount::Resource(TEST_RESOURCE, 7.into()),
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2),
+            ),
+            Err(FeeReserveError::InsufficientRoyaltyPayment {
+                resource_address: TEST_RESOURCE,
+                required: dec!("7"),
+                remaining: dec!("6"),
+            }),
+        );
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.total_royalty_cost_in_xrd, dec!("0"));
+        assert_eq!(
+            summary.resource_royalty_cost_breakdown,
+            indexmap!(
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2) => (TEST_RESOURCE, dec!("4"))
+            )
+        );
+        assert_eq!(
+            summary.resource_royalty_payments,
+            indexmap!(
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2) => vec![(TEST_VAULT_ID, xrd(4))]
+            )
+        );
+        assert_eq!(
+            summary.locked_royalty_payments,
+            vec![(TEST_VAULT_ID, TEST_RESOURCE, xrd(6))]
+        );
+    }
+
+    #[test]
+    fn test_resource_royalty_revert() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve
+            .lock_royalty_payment(TEST_VAULT_ID, TEST_RESOURCE, xrd(10), dec!(10))
+            .unwrap();
+        fee_reserve
+            .consume_royalty(
+                RoyaltyAmount::Resource(TEST_RESOURCE, 4.into()),
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2),
+            )
+            .unwrap();
+        fee_reserve.revert_royalty();
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert!(summary.resource_royalty_cost_breakdown.is_empty());
+        assert!(summary.resource_royalty_payments.is_empty());
+        assert_eq!(
+            summary.locked_royalty_payments,
+            vec![(TEST_VAULT_ID, TEST_RESOURCE, xrd(10))]
+        );
+    }
+
+    #[test]
+    fn test_resource_royalty_above_stated_max() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve
+            .lock_royalty_payment(TEST_VAULT_ID, TEST_RESOURCE, xrd(10), dec!(5))
+            .unwrap();
+        fee_reserve
+            .lock_royalty_payment(TEST_VAULT_ID_2, TEST_RESOURCE, xrd(10), dec!(3))
+            .unwrap();
+        fee_reserve
+            .consume_royalty(
+                RoyaltyAmount::Resource(TEST_RESOURCE, 3.into()),
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2),
+            )
+            .unwrap();
+        assert_eq!(
+            fee_reserve.consume_royalty(
+                RoyaltyAmount::Resource(TEST_RESOURCE, 4.into()),
+                RoyaltyRecipient::Component(TEST_COMPONENT, TEST_VAULT_ID_2),
+            ),
+            Err(FeeReserveError::RoyaltyAmountIsGreaterThanAllowed {
+                resource_address: TEST_RESOURCE,
+                max: dec!("3"),
+                actual: dec!("4"),
+            }),
+        );
+    }
 }
@@ -27,6 +27,16 @@ pub struct FeeReserveFinalizationSummary {
     pub locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
     /// The royalty cost breakdown
     pub royalty_cost_breakdown: IndexMap<RoyaltyRecipient, Decimal>,
+    /// The vaults locked for royalty payments in resources other than XRD, with what is left
+    /// of the payments after the royalties have been charged
+    pub locked_royalty_payments: Vec<(NodeId, ResourceAddress, LiquidFungibleResource)>,
+    /// The breakdown of royalties charged in resources other than XRD, keyed by the
+    /// recipient's vault for the resource
+    pub resource_royalty_cost_breakdown: IndexMap<RoyaltyRecipient, (ResourceAddress, Decimal)>,
+    /// The payments for royalties charged in resources other than XRD, already matched to each
+    /// recipient, along with the vault each payment was locked from
+    pub resource_royalty_payments:
+        IndexMap<RoyaltyRecipient, Vec<(NodeId, LiquidFungibleResource)>>,
 }
 
 impl FeeReserveFinalizationSummary {
@@ -356,6 +356,11 @@ impl FeeTable {
         500
     }
 
+    #[inline]
+    pub fn lock_royalty_payment_cost(&self) -> u32 {
+        500
+    }
+
     #[inline]
     pub fn query_fee_reserve_cost(&self) -> u32 {
         500
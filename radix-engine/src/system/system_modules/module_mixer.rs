@@ -440,6 +440,7 @@ impl SystemModuleMixer {
         api: &mut SystemService<Y, V>,
         blueprint_id: &BlueprintId,
         ident: &str,
+        args: &IndexedScryptoValue,
     ) -> Result<NodeId, RuntimeError>
     where
         V: SystemCallbackObject,
@@ -452,7 +453,7 @@ impl SystemModuleMixer {
             .enabled_modules
             .contains(EnabledModules::AUTH)
         {
-            AuthModule::on_call_function(api, blueprint_id, ident)?
+            AuthModule::on_call_function(api, blueprint_id, ident, args)?
         } else {
             AuthModule::create_mock(api, None, btreeset!(), btreeset!())?
         };
@@ -656,6 +657,21 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn lock_royalty_payment(
+        &mut self,
+        vault_id: NodeId,
+        resource_address: ResourceAddress,
+        payment: LiquidFungibleResource,
+        max_per_function: Decimal,
+    ) -> Result<LiquidFungibleResource, RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::COSTING) {
+            self.costing
+                .lock_royalty_payment(vault_id, resource_address, payment, max_per_function)
+        } else {
+            Ok(payment)
+        }
+    }
+
     pub fn events(&self) -> &Vec<Event> {
         &self.transaction_runtime.events
     }
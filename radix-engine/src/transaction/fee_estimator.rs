@@ -0,0 +1,138 @@
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_modules::costing::FeeTable;
+use crate::transaction::*;
+use crate::types::*;
+use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::interface::*;
+use transaction::errors::TransactionValidationError;
+use transaction::model::*;
+use transaction::validation::ValidationConfig;
+
+#[derive(Debug, Clone)]
+pub enum FeeEstimationError {
+    TransactionValidationError(TransactionValidationError),
+    PreviewError(PreviewError),
+    /// The preview was rejected or failed, so its fees don't estimate those of the transaction.
+    PreviewNotCommittedSuccessfully(Box<TransactionReceipt>),
+    /// The estimated cost doesn't fit in a `Decimal`.
+    CostOverflow,
+}
+
+/// A fee estimate of a transaction, made before its intent is signed and notarized.
+#[derive(Debug, Clone)]
+pub struct TransactionFeeEstimate {
+    pub payload: NotarizedPayloadEstimate,
+    /// The receipt of the preview of the intent, which was committed successfully.
+    pub preview_receipt: TransactionReceipt,
+    /// The cost units for verifying the signatures, which a preview doesn't charge.
+    pub signature_verification_cost_units: u32,
+    /// The cost units for validating the signatures part of the payload, which a preview
+    /// doesn't charge.
+    pub additional_payload_validation_cost_units: u32,
+    /// The estimated total cost in XRD.
+    pub total_cost_in_xrd: Decimal,
+    /// The estimated total cost in XRD, with the safety margin added.
+    pub total_cost_with_margin_in_xrd: Decimal,
+}
+
+/// Estimates the fee of a transaction, by previewing its intent and adding the costs of the
+/// signatures, which a preview doesn't charge.
+///
+/// The signer public keys are used both as the signer proofs of the preview, and for the size of
+/// the intent signatures. The margin is a percentage of the estimated total cost.
+pub fn estimate_transaction_fee<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    network: &NetworkDefinition,
+    intent: IntentV1,
+    signer_public_keys: Vec<PublicKey>,
+    flags: PreviewFlags,
+    margin_percentage: u16,
+) -> Result<TransactionFeeEstimate, FeeEstimationError> {
+    let signer_curves = signer_public_keys
+        .iter()
+        .map(|public_key| match public_key {
+            PublicKey::Secp256k1(_) => CurveType::Secp256k1,
+            PublicKey::Ed25519(_) => CurveType::Ed25519,
+        })
+        .collect::<Vec<_>>();
+    let payload = intent
+        .estimate_notarized_payload(&signer_curves)
+        .map_err(FeeEstimationError::TransactionValidationError)?;
+    payload
+        .validate_payload_size(&ValidationConfig::default(network.id))
+        .map_err(FeeEstimationError::TransactionValidationError)?;
+    let previewed_payload_size = intent
+        .prepare()
+        .map_err(|e| FeeEstimationError::TransactionValidationError(e.into()))?
+        .summary
+        .effective_length;
+
+    let preview_receipt = execute_preview(
+        substate_db,
+        vm,
+        network,
+        PreviewIntentV1 {
+            intent,
+            signer_public_keys,
+            flags,
+        },
+        false,
+    )
+    .map_err(FeeEstimationError::PreviewError)?;
+    if !preview_receipt.is_commit_success() {
+        return Err(FeeEstimationError::PreviewNotCommittedSuccessfully(
+            Box::new(preview_receipt),
+        ));
+    }
+
+    let fee_table = FeeTable::new();
+    let signature_verification_cost_units =
+        fee_table.verify_tx_signatures_cost(payload.num_of_signature_validations);
+    let additional_payload_size = payload
+        .effective_payload_size
+        .saturating_sub(previewed_payload_size);
+    let additional_payload_validation_cost_units =
+        fee_table.validate_tx_payload_cost(additional_payload_size);
+
+    let costing_parameters = &preview_receipt.costing_parameters;
+    let tip_percentage = preview_receipt
+        .transaction_costing_parameters
+        .tip_percentage;
+    let additional_execution_cost_in_xrd = signature_verification_cost_units
+        .checked_add(additional_payload_validation_cost_units)
+        .and_then(|cost_units| {
+            costing_parameters
+                .execution_cost_unit_price
+                .safe_mul(cost_units)?
+                .safe_mul(add_percentage(Decimal::ONE, tip_percentage.into())?)
+        })
+        .ok_or(FeeEstimationError::CostOverflow)?;
+    let additional_storage_cost_in_xrd = costing_parameters
+        .archive_storage_price
+        .safe_mul(additional_payload_size)
+        .ok_or(FeeEstimationError::CostOverflow)?;
+
+    let total_cost_in_xrd = preview_receipt
+        .fee_summary
+        .total_cost()
+        .safe_add(additional_execution_cost_in_xrd)
+        .and_then(|cost| cost.safe_add(additional_storage_cost_in_xrd))
+        .ok_or(FeeEstimationError::CostOverflow)?;
+    let total_cost_with_margin_in_xrd = add_percentage(total_cost_in_xrd, margin_percentage.into())
+        .ok_or(FeeEstimationError::CostOverflow)?;
+
+    Ok(TransactionFeeEstimate {
+        payload,
+        preview_receipt,
+        signature_verification_cost_units,
+        additional_payload_validation_cost_units,
+        total_cost_in_xrd,
+        total_cost_with_margin_in_xrd,
+    })
+}
+
+/// Adds the given percentage to an amount, returning `None` on overflow.
+fn add_percentage(amount: Decimal, percentage: u32) -> Option<Decimal> {
+    amount.safe_mul(Decimal::ONE.safe_add(Decimal::from(percentage).safe_div(100)?)?)
+}
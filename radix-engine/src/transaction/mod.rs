@@ -1,9 +1,11 @@
+mod fee_estimator;
 mod preview_executor;
 mod state_update_summary;
 mod system_structure;
 mod transaction_executor;
 mod transaction_receipt;
 
+pub use fee_estimator::*;
 pub use preview_executor::*;
 pub use state_update_summary::*;
 pub use system_structure::*;
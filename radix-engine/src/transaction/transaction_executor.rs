@@ -5,7 +5,7 @@ use crate::blueprints::consensus_manager::{
 use crate::blueprints::models::FieldPayload;
 use crate::blueprints::resource::{
     BurnFungibleResourceEvent, DepositEvent, FungibleVaultBalanceFieldPayload,
-    FungibleVaultBalanceFieldSubstate, FungibleVaultField, PayFeeEvent,
+    FungibleVaultBalanceFieldSubstate, FungibleVaultField, PayFeeEvent, PayRoyaltyEvent,
 };
 use crate::blueprints::transaction_processor::TransactionProcessorError;
 use crate::blueprints::transaction_tracker::{
@@ -241,7 +241,7 @@ where
         // We are assuming that intent hash store is ready when epoch manager is ready.
         let current_epoch = Self::read_epoch(&mut track);
         let validation_result = if let Some(current_epoch) = current_epoch {
-            if let Some(range) = executable.epoch_range() {
+            let intent_validation_result = if let Some(range) = executable.epoch_range() {
                 Self::validate_epoch_range(
                     current_epoch,
                     range.start_epoch_inclusive,
@@ -256,7 +256,22 @@ where
                 })
             } else {
                 Ok(())
-            }
+            };
+            // Subintents are replay protected the same way as the transaction intent
+            intent_validation_result.and_then(|_| {
+                executable.subintents().iter().try_for_each(|subintent| {
+                    Self::validate_epoch_range(
+                        current_epoch,
+                        subintent.epoch_range.start_epoch_inclusive,
+                        subintent.epoch_range.end_epoch_exclusive,
+                    )?;
+                    Self::validate_intent_hash(
+                        &mut track,
+                        subintent.intent_hash.to_hash(),
+                        subintent.epoch_range.end_epoch_exclusive,
+                    )
+                })
+            })
         } else {
             Ok(())
         };
@@ -343,14 +358,25 @@ where
                             to_royalty_recipients: fee_reserve_finalization
                                 .royalty_cost_breakdown
                                 .clone(),
+                            to_resource_royalty_recipients: fee_reserve_finalization
+                                .resource_royalty_cost_breakdown
+                                .clone(),
                         };
 
                         // Update intent hash status
                         if let Some(next_epoch) = Self::read_epoch(&mut track) {
+                            let intent_hashes = core::iter::once(executable.intent_hash())
+                                .chain(
+                                    executable
+                                        .subintents()
+                                        .iter()
+                                        .map(|subintent| &subintent.intent_hash),
+                                )
+                                .collect();
                             Self::update_transaction_tracker(
                                 &mut track,
                                 next_epoch,
-                                executable.intent_hash(),
+                                intent_hashes,
                                 is_success,
                             );
                         }
@@ -593,6 +619,7 @@ where
                 executable.pre_allocated_addresses(),
                 executable.references(),
                 executable.blobs(),
+                executable.subintents(),
             )
             .and_then(|x| {
                 // Note that if a transactions fails during this phase, the costing is
@@ -706,6 +733,41 @@ where
         TransactionResultType::Commit(interpretation_result)
     }
 
+    fn put_into_fungible_vault(
+        track: &mut Track<S, SpreadPrefixKeyMapper>,
+        vault_id: &NodeId,
+        resource: LiquidFungibleResource,
+    ) {
+        // NOTE: safe unwraps.
+        // Only the royalty vaults created by the royalty modules and the fungible vaults which
+        // locked royalty payments are passed in, and neither can be dropped.
+        // Freezing doesn't get in the way either: freeze flags are enforced by the vault
+        // blueprint, while the balance is written here directly, the same way locked fees are
+        // collected. A refund only gives back what was withdrawn from the vault in this
+        // transaction, after the withdraw freeze check had passed.
+        let substate_key = FungibleVaultField::Balance.into();
+        let mut vault_balance = track
+            .read_substate(vault_id, MAIN_BASE_PARTITION, &substate_key)
+            .unwrap()
+            .as_typed::<FungibleVaultBalanceFieldSubstate>()
+            .unwrap()
+            .into_payload()
+            .into_latest();
+        vault_balance.put(resource);
+        let updated_substate_content =
+            FungibleVaultBalanceFieldPayload::from_content_source(vault_balance)
+                .into_mutable_substate();
+        track
+            .set_substate(
+                *vault_id,
+                MAIN_BASE_PARTITION,
+                substate_key,
+                IndexedScryptoValue::from_typed(&updated_substate_content),
+                &mut |_| -> Result<(), ()> { Ok(()) },
+            )
+            .unwrap();
+    }
+
     fn finalize_fees(
         track: &mut Track<S, SpreadPrefixKeyMapper>,
         fee_reserve: SystemLoanFeeReserve,
@@ -753,6 +815,47 @@ where
 
         // Take fee payments
         let fee_reserve_finalization = fee_reserve.finalize();
+
+        // Distribute royalties charged in other resources. The royalty payment locks are
+        // regular state changes, so for a failed transaction they've already been reverted.
+        // The fee reserve has already matched the payments to the recipients, so they are
+        // deposited as they are and what is left of the locked payments is refunded.
+        if is_success {
+            for (recipient, payments) in &fee_reserve_finalization.resource_royalty_payments {
+                let mut collected = LiquidFungibleResource::new(Decimal::ZERO);
+                for (vault_id, payment) in payments {
+                    events.push((
+                        EventTypeIdentifier(
+                            Emitter::Method(*vault_id, ObjectModuleId::Main),
+                            PayRoyaltyEvent::event_name().to_string(),
+                        ),
+                        scrypto_encode(&PayRoyaltyEvent {
+                            amount: payment.amount(),
+                        })
+                        .unwrap(),
+                    ));
+                    collected.put(payment.clone());
+                }
+
+                let node_id = recipient.vault_id();
+                let amount = collected.amount();
+                Self::put_into_fungible_vault(track, &node_id, collected);
+                events.push((
+                    EventTypeIdentifier(
+                        Emitter::Method(node_id, ObjectModuleId::Main),
+                        DepositEvent::event_name().to_string(),
+                    ),
+                    scrypto_encode(&DepositEvent { amount }).unwrap(),
+                ));
+            }
+
+            // Refund what wasn't charged
+            for (vault_id, _, payment) in &fee_reserve_finalization.locked_royalty_payments {
+                if !payment.is_empty() {
+                    Self::put_into_fungible_vault(track, vault_id, payment.clone());
+                }
+            }
+        }
         let mut fee_payments: IndexMap<NodeId, Decimal> = index_map_new();
         let mut required = fee_reserve_finalization.total_cost();
         let mut collected_fees = LiquidFungibleResource::new(Decimal::ZERO);
@@ -951,7 +1054,7 @@ where
     fn update_transaction_tracker(
         track: &mut Track<S, SpreadPrefixKeyMapper>,
         next_epoch: Epoch,
-        intent_hash: &TransactionIntentHash,
+        intent_hashes: Vec<&TransactionIntentHash>,
         is_success: bool,
     ) {
         // Read the intent hash store
@@ -968,36 +1071,38 @@ where
 
         let mut transaction_tracker = transaction_tracker.into_v1();
 
-        // Update the status of the intent hash
-        if let TransactionIntentHash::ToCheck {
-            expiry_epoch,
-            intent_hash,
-        } = intent_hash
-        {
-            if let Some(partition_number) =
-                transaction_tracker.partition_for_expiry_epoch(*expiry_epoch)
+        // Update the status of the intent hashes
+        for intent_hash in intent_hashes {
+            if let TransactionIntentHash::ToCheck {
+                expiry_epoch,
+                intent_hash,
+            } = intent_hash
             {
-                track
-                    .set_substate(
-                        TRANSACTION_TRACKER.into_node_id(),
-                        PartitionNumber(partition_number),
-                        SubstateKey::Map(scrypto_encode(intent_hash).unwrap()),
-                        IndexedScryptoValue::from_typed(&KeyValueEntrySubstate::V1(
-                            KeyValueEntrySubstateV1 {
-                                value: Some(if is_success {
-                                    TransactionStatus::V1(TransactionStatusV1::CommittedSuccess)
-                                } else {
-                                    TransactionStatus::V1(TransactionStatusV1::CommittedFailure)
-                                }),
-                                // TODO: maybe make it immutable, but how does this affect partition deletion?
-                                mutability: SubstateMutability::Mutable,
-                            },
-                        )),
-                        &mut |_| -> Result<(), ()> { Ok(()) },
-                    )
-                    .unwrap();
-            } else {
-                panic!("No partition for an expiry epoch")
+                if let Some(partition_number) =
+                    transaction_tracker.partition_for_expiry_epoch(*expiry_epoch)
+                {
+                    track
+                        .set_substate(
+                            TRANSACTION_TRACKER.into_node_id(),
+                            PartitionNumber(partition_number),
+                            SubstateKey::Map(scrypto_encode(intent_hash).unwrap()),
+                            IndexedScryptoValue::from_typed(&KeyValueEntrySubstate::V1(
+                                KeyValueEntrySubstateV1 {
+                                    value: Some(if is_success {
+                                        TransactionStatus::V1(TransactionStatusV1::CommittedSuccess)
+                                    } else {
+                                        TransactionStatus::V1(TransactionStatusV1::CommittedFailure)
+                                    }),
+                                    // TODO: maybe make it immutable, but how does this affect partition deletion?
+                                    mutability: SubstateMutability::Mutable,
+                                },
+                            )),
+                            &mut |_| -> Result<(), ()> { Ok(()) },
+                        )
+                        .unwrap();
+                } else {
+                    panic!("No partition for an expiry epoch")
+                }
             }
         }
 
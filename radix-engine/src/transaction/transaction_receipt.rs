@@ -105,6 +105,9 @@ pub struct FeeDestination {
     pub to_validator_set: Decimal,
     pub to_burn: Decimal,
     pub to_royalty_recipients: IndexMap<RoyaltyRecipient, Decimal>,
+    /// Royalties charged in resources other than XRD, keyed by the recipient's vault for
+    /// the resource.
+    pub to_resource_royalty_recipients: IndexMap<RoyaltyRecipient, (ResourceAddress, Decimal)>,
 }
 
 /// Captures whether a transaction's commit outcome is Success or Failure
@@ -0,0 +1,118 @@
+use crate::types::*;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The environment variable through which `scrypto coverage` passes the directory that
+/// coverage builds and data are placed in.
+pub const COVERAGE_DIRECTORY_ENV: &str = "COVERAGE_DIRECTORY";
+
+/// The environment variable through which `scrypto coverage` passes the toolchain that packages
+/// are built with for coverage. It has to be a nightly one.
+pub const COVERAGE_TOOLCHAIN_ENV: &str = "COVERAGE_TOOLCHAIN";
+
+/// The toolchain coverage builds use if none is passed in.
+pub const DEFAULT_COVERAGE_TOOLCHAIN: &str = "nightly";
+
+/// Where and with which toolchain packages are built for coverage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageConfig {
+    /// The directory that coverage builds and data are placed in.
+    pub directory: PathBuf,
+    /// The toolchain channel the packages are built with.
+    pub toolchain: String,
+}
+
+/// Returns the coverage directory, if run by `scrypto coverage`.
+pub fn coverage_directory() -> Option<PathBuf> {
+    std::env::var_os(COVERAGE_DIRECTORY_ENV).map(PathBuf::from)
+}
+
+/// Returns the coverage configuration, if run by `scrypto coverage`.
+pub fn coverage_config() -> Option<CoverageConfig> {
+    coverage_directory().map(|directory| CoverageConfig {
+        directory,
+        toolchain: std::env::var(COVERAGE_TOOLCHAIN_ENV)
+            .unwrap_or(DEFAULT_COVERAGE_TOOLCHAIN.to_owned()),
+    })
+}
+
+/// Returns the target directory that coverage builds are placed in.
+pub fn coverage_target_directory(coverage_directory: &Path) -> PathBuf {
+    coverage_directory.join("target")
+}
+
+/// Creates the `cargo build` command with which the test runners compile a Scrypto package.
+///
+/// With a coverage configuration, the package is built with the configured toolchain and
+/// coverage instrumentation, into the [`coverage_target_directory`].
+pub fn cargo_build_command(package_dir: &Path, coverage: Option<&CoverageConfig>) -> Command {
+    let mut command = Command::new("cargo");
+    command.current_dir(package_dir);
+    if let Some(coverage) = coverage {
+        command.arg(format!("+{}", coverage.toolchain));
+    }
+    command.args(["build", "--target", "wasm32-unknown-unknown", "--release"]);
+    if let Some(coverage) = coverage {
+        command
+            .args(["--features", "scrypto/coverage", "--target-dir"])
+            .arg(coverage_target_directory(&coverage.directory))
+            .env(
+                "CARGO_ENCODED_RUSTFLAGS",
+                "-Cinstrument-coverage\x1f-Zno-profiler-runtime\x1f--emit=llvm-ir",
+            );
+    }
+    command
+}
+
+/// Saves the coverage counters dumped from a Scrypto instance as a `.profraw` file, under the
+/// blueprint's directory within the coverage directory. Returns the path of the file.
+pub fn save_coverage_data(
+    coverage_directory: &Path,
+    blueprint_name: &str,
+    coverage_data: &[u8],
+) -> io::Result<PathBuf> {
+    let mut file_path = coverage_directory.join("data");
+    file_path.push(blueprint_name);
+    std::fs::create_dir_all(&file_path)?;
+
+    // Name the file after the content, so that identical dumps are only stored once
+    let file_name = hash(coverage_data).to_string();
+    file_path.push(format!("{}.profraw", &file_name[..32]));
+    std::fs::write(&file_path, coverage_data)?;
+    Ok(file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_coverage_data() {
+        let dir = std::env::temp_dir().join(format!(
+            "coverage-test-{}",
+            hash(format!("{:?}", std::time::SystemTime::now()))
+        ));
+
+        let path = save_coverage_data(&dir, "Hello", &[1, 2, 3]).unwrap();
+
+        assert!(path.starts_with(dir.join("data").join("Hello")));
+        assert_eq!(path.extension().unwrap(), "profraw");
+        assert_eq!(std::fs::read(&path).unwrap(), vec![1, 2, 3]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_build_command_uses_configured_toolchain() {
+        let coverage = CoverageConfig {
+            directory: PathBuf::from("/coverage"),
+            toolchain: "nightly-2023-08-01".to_owned(),
+        };
+
+        let command = cargo_build_command(Path::new("/package"), Some(&coverage));
+
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args[0], "+nightly-2023-08-01");
+        assert!(args.contains(&std::ffi::OsStr::new("/coverage/target")));
+    }
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "std")]
+mod coverage;
 mod macros;
 mod native_blueprint_call_validator;
 mod package_extractor;
 mod panics;
 
+#[cfg(feature = "std")]
+pub use coverage::*;
 pub use macros::*;
 pub use native_blueprint_call_validator::*;
 pub use package_extractor::*;
@@ -171,6 +171,14 @@ fn get_arguments_schema<'s>(
         Invocation::Function(package_address @ POOL_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(&POOL_PACKAGE_DEFINITION, package_address, blueprint).map(Some)?
         }
+        Invocation::Function(package_address @ VESTING_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(&VESTING_PACKAGE_DEFINITION, package_address, blueprint)
+                .map(Some)?
+        }
+        Invocation::Function(package_address @ MULTISIG_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(&MULTISIG_PACKAGE_DEFINITION, package_address, blueprint)
+                .map(Some)?
+        }
         Invocation::Function(package_address @ TRANSACTION_PROCESSOR_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
@@ -2,12 +2,14 @@ use crate::blueprints::access_controller::AccessControllerNativePackage;
 use crate::blueprints::account::AccountNativePackage;
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::IdentityNativePackage;
+use crate::blueprints::multisig::MultisigNativePackage;
 use crate::blueprints::package::PackageNativePackage;
 use crate::blueprints::pool::PoolNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
 use crate::blueprints::test_utils::TestUtilsNativePackage;
 use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
 use crate::blueprints::transaction_tracker::TransactionTrackerNativePackage;
+use crate::blueprints::vesting::VestingNativePackage;
 use crate::errors::{NativeRuntimeError, RuntimeError, VmError};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::system::node_modules::metadata::MetadataNativePackage;
@@ -130,6 +132,10 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                         RoleAssignmentNativePackage::invoke_export(export_name, input, api)
                     }
                     POOL_CODE_ID => PoolNativePackage::invoke_export(export_name, input, api),
+                    VESTING_CODE_ID => VestingNativePackage::invoke_export(export_name, input, api),
+                    MULTISIG_CODE_ID => {
+                        MultisigNativePackage::invoke_export(export_name, input, api)
+                    }
                     TRANSACTION_TRACKER_CODE_ID => {
                         TransactionTrackerNativePackage::invoke_export(export_name, input, api)
                     }
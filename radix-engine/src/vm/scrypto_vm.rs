@@ -1,15 +1,32 @@
+#[cfg(feature = "coverage")]
+use crate::errors::{InvokeError, VmError};
 use crate::errors::{RuntimeError, SystemUpstreamError};
+#[cfg(feature = "coverage")]
+use crate::system::system_modules::costing::SystemLoanFeeReserve;
+#[cfg(feature = "coverage")]
+use crate::transaction::CostingParameters;
 use crate::types::*;
+#[cfg(feature = "coverage")]
+use crate::utils::{coverage_directory, save_coverage_data};
 use crate::vm::vm::VmInvoke;
 use crate::vm::wasm::*;
+#[cfg(feature = "coverage")]
+use crate::vm::wasm_runtime::NoOpWasmRuntime;
 use crate::vm::wasm_runtime::ScryptoRuntime;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::CodeHash;
 use resources_tracker_macro::trace_resources;
+use sbor::rust::sync::Arc;
+#[cfg(feature = "coverage")]
+use transaction::prelude::TransactionCostingParameters;
 
 pub struct ScryptoVm<W: WasmEngine> {
     pub wasm_engine: W,
     pub wasm_validator_config: WasmValidatorConfigV1,
+    /// Whether to execute Scrypto code instrumented for debugging, which attaches backtraces to
+    /// traps and panics. The code is re-prepared from the original code, once per code hash.
+    pub debug_mode: bool,
+    pub debug_code_cache: WasmDebugCodeCache,
 }
 
 impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
@@ -17,6 +34,8 @@ impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
         Self {
             wasm_engine: W::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            debug_mode: false,
+            debug_code_cache: WasmDebugCodeCache::default(),
         }
     }
 }
@@ -31,6 +50,35 @@ impl<W: WasmEngine> ScryptoVm<W> {
         ScryptoVmInstance {
             instance: self.wasm_engine.instantiate(code_hash, instrumented_code),
             package_address: *package_address,
+            debug_code: None,
+        }
+    }
+
+    pub fn prepare_debug_code(&self, original_code: &[u8]) -> Result<WasmDebugCode, PrepareError> {
+        let debug_code = WasmValidator {
+            instrumenter_config: self.wasm_validator_config.clone(),
+            ..Default::default()
+        }
+        .validate_for_debug(original_code)?;
+
+        Ok(WasmDebugCode {
+            code_hash: CodeHash::from(hash(&debug_code)),
+            code: debug_code,
+            debug_info: WasmDebugInfo::new(original_code),
+        })
+    }
+
+    pub fn create_debug_instance(
+        &self,
+        package_address: &PackageAddress,
+        debug_code: Arc<WasmDebugCode>,
+    ) -> ScryptoVmInstance<W::WasmInstance> {
+        ScryptoVmInstance {
+            instance: self
+                .wasm_engine
+                .instantiate(debug_code.code_hash, &debug_code.code),
+            package_address: *package_address,
+            debug_code: Some(debug_code),
         }
     }
 }
@@ -38,6 +86,7 @@ impl<W: WasmEngine> ScryptoVm<W> {
 pub struct ScryptoVmInstance<I: WasmInstance> {
     instance: I,
     package_address: PackageAddress,
+    debug_code: Option<Arc<WasmDebugCode>>,
 }
 
 impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
@@ -51,6 +100,9 @@ impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
     where
         Y: ClientApi<RuntimeError>,
     {
+        #[cfg(feature = "coverage")]
+        let blueprint_id = api.actor_get_blueprint_id()?;
+
         let rtn = {
             let mut runtime: Box<dyn WasmRuntime> = Box::new(ScryptoRuntime::new(
                 api,
@@ -64,10 +116,27 @@ impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
                     .allocate_buffer(args.as_slice().to_vec())
                     .expect("Failed to allocate buffer"),
             );
-            self.instance
-                .invoke_export(export_name, input, &mut runtime)?
+            match self
+                .instance
+                .invoke_export(export_name, input, &mut runtime)
+            {
+                Ok(rtn) => rtn,
+                Err(error) => {
+                    return Err(match &self.debug_code {
+                        Some(debug_code) => debug_code.debug_info.attach_backtrace(
+                            error,
+                            &self.package_address,
+                            &runtime.debug_call_stack(),
+                        ),
+                        None => error.into(),
+                    })
+                }
+            }
         };
 
+        #[cfg(feature = "coverage")]
+        self.dump_coverage(&blueprint_id.blueprint_name)?;
+
         let output = IndexedScryptoValue::from_vec(rtn).map_err(|e| {
             RuntimeError::SystemUpstreamError(SystemUpstreamError::OutputDecodeError(e))
         })?;
@@ -76,6 +145,50 @@ impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
     }
 }
 
+#[cfg(feature = "coverage")]
+impl<I: WasmInstance> ScryptoVmInstance<I> {
+    /// Saves the coverage counters of the instance, if run by `scrypto coverage`.
+    ///
+    /// The counters are dumped through a no-op runtime with a fee reserve of its own (as when
+    /// extracting a package definition), so that coverage runs are costed like regular ones.
+    fn dump_coverage(&mut self, blueprint_name: &str) -> Result<(), RuntimeError> {
+        let coverage_directory = match coverage_directory() {
+            Some(coverage_directory) => coverage_directory,
+            None => return Ok(()),
+        };
+
+        let fee_reserve = SystemLoanFeeReserve::new(
+            &CostingParameters::default(),
+            &TransactionCostingParameters {
+                tip_percentage: 0,
+                free_credit_in_xrd: Decimal::try_from(PREVIEW_CREDIT_IN_XRD).unwrap(),
+            },
+            false,
+        );
+        let mut wasm_execution_units_consumed = 0;
+        let mut runtime: Box<dyn WasmRuntime> = Box::new(NoOpWasmRuntime::new(
+            fee_reserve,
+            &mut wasm_execution_units_consumed,
+        ));
+        let coverage_data = match self
+            .instance
+            .invoke_export("dump_coverage", vec![], &mut runtime)
+        {
+            Ok(coverage_data) => coverage_data,
+            // Packages not built with coverage instrumentation don't have the export
+            Err(InvokeError::SelfError(WasmRuntimeError::UnknownExport(..))) => return Ok(()),
+            Err(error) => return Err(error.into()),
+        };
+
+        save_coverage_data(&coverage_directory, blueprint_name, &coverage_data).map_err(|err| {
+            RuntimeError::VmError(VmError::Wasm(WasmRuntimeError::CoverageDataSaveError(
+                format!("{:?}", err),
+            )))
+        })?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const _: () = {
@@ -9,6 +9,7 @@ use crate::vm::wasm::{WasmEngine, WasmValidator};
 use crate::vm::{NativeVm, NativeVmExtension, ScryptoVm};
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
+use sbor::rust::sync::Arc;
 
 pub struct Vm<'g, W: WasmEngine, E: NativeVmExtension> {
     pub scrypto_vm: &'g ScryptoVm<W>,
@@ -104,44 +105,92 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                 output
             }
             VmType::ScryptoV1 => {
-                let instrumented_code = {
-                    let handle = api.kernel_open_substate_with_default(
-                        address.as_node_id(),
-                        MAIN_BASE_PARTITION
-                            .at_offset(PACKAGE_INSTRUMENTED_CODE_PARTITION_OFFSET)
-                            .unwrap(),
-                        &SubstateKey::Map(scrypto_encode(&export.code_hash).unwrap()),
-                        LockFlags::read_only(),
-                        Some(|| {
-                            let kv_entry = KeyValueEntrySubstate::<()>::default();
-                            IndexedScryptoValue::from_typed(&kv_entry)
-                        }),
-                        SystemLockData::default(),
-                    )?;
-                    let instrumented_code = api.kernel_read_substate(handle)?;
-                    let instrumented_code: PackageCodeInstrumentedCodeEntrySubstate =
-                        instrumented_code.as_typed().unwrap();
-                    api.kernel_close_substate(handle)?;
-                    instrumented_code
-                        .into_value()
-                        .expect(&format!("Instrumented code not found: {:?}", export))
-                        .into_latest()
-                };
+                let scrypto_vm = api.kernel_get_system().callback_obj.scrypto_vm;
+                let mut scrypto_vm_instance = if scrypto_vm.debug_mode {
+                    let debug_code = match scrypto_vm.debug_code_cache.get(&export.code_hash) {
+                        Some(debug_code) => debug_code,
+                        None => {
+                            let original_code = {
+                                let handle = api.kernel_open_substate_with_default(
+                                    address.as_node_id(),
+                                    MAIN_BASE_PARTITION
+                                        .at_offset(PACKAGE_ORIGINAL_CODE_PARTITION_OFFSET)
+                                        .unwrap(),
+                                    &SubstateKey::Map(scrypto_encode(&export.code_hash).unwrap()),
+                                    LockFlags::read_only(),
+                                    Some(|| {
+                                        let kv_entry = KeyValueEntrySubstate::<()>::default();
+                                        IndexedScryptoValue::from_typed(&kv_entry)
+                                    }),
+                                    SystemLockData::default(),
+                                )?;
+                                let original_code = api.kernel_read_substate(handle)?;
+                                let original_code: PackageCodeOriginalCodeEntrySubstate =
+                                    original_code.as_typed().unwrap();
+                                api.kernel_close_substate(handle)?;
+                                original_code
+                                    .into_value()
+                                    .expect(&format!("Original code not found: {:?}", export))
+                                    .into_latest()
+                            };
 
-                let mut scrypto_vm_instance = {
-                    api.kernel_get_system()
-                        .callback_obj
-                        .scrypto_vm
-                        .create_instance(
-                            address,
-                            export.code_hash,
-                            &instrumented_code.instrumented_code,
-                        )
-                };
+                            let debug_code = scrypto_vm
+                                .prepare_debug_code(&original_code.code)
+                                .map_err(|e| {
+                                    RuntimeError::ApplicationError(ApplicationError::PackageError(
+                                        PackageError::InvalidWasm(e),
+                                    ))
+                                })?;
+                            let debug_code = Arc::new(debug_code);
+                            scrypto_vm
+                                .debug_code_cache
+                                .insert(export.code_hash, debug_code.clone());
+                            debug_code
+                        }
+                    };
 
-                api.consume_cost_units(ClientCostingEntry::PrepareWasmCode {
-                    size: instrumented_code.instrumented_code.len(),
-                })?;
+                    api.consume_cost_units(ClientCostingEntry::PrepareWasmCode {
+                        size: debug_code.code.len(),
+                    })?;
+
+                    scrypto_vm.create_debug_instance(address, debug_code)
+                } else {
+                    let instrumented_code = {
+                        let handle = api.kernel_open_substate_with_default(
+                            address.as_node_id(),
+                            MAIN_BASE_PARTITION
+                                .at_offset(PACKAGE_INSTRUMENTED_CODE_PARTITION_OFFSET)
+                                .unwrap(),
+                            &SubstateKey::Map(scrypto_encode(&export.code_hash).unwrap()),
+                            LockFlags::read_only(),
+                            Some(|| {
+                                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                                IndexedScryptoValue::from_typed(&kv_entry)
+                            }),
+                            SystemLockData::default(),
+                        )?;
+                        let instrumented_code = api.kernel_read_substate(handle)?;
+                        let instrumented_code: PackageCodeInstrumentedCodeEntrySubstate =
+                            instrumented_code.as_typed().unwrap();
+                        api.kernel_close_substate(handle)?;
+                        instrumented_code
+                            .into_value()
+                            .expect(&format!("Instrumented code not found: {:?}", export))
+                            .into_latest()
+                    };
+
+                    let scrypto_vm_instance = scrypto_vm.create_instance(
+                        address,
+                        export.code_hash,
+                        &instrumented_code.instrumented_code,
+                    );
+
+                    api.consume_cost_units(ClientCostingEntry::PrepareWasmCode {
+                        size: instrumented_code.instrumented_code.len(),
+                    })?;
+
+                    scrypto_vm_instance
+                };
 
                 let output =
                     { scrypto_vm_instance.invoke(export.export_name.as_str(), input, api)? };
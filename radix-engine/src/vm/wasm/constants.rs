@@ -75,6 +75,13 @@ pub const SYS_GET_TRANSACTION_HASH_FUNCTION_NAME: &str = "sys_get_transaction_ha
 pub const SYS_GENERATE_RUID_FUNCTION_NAME: &str = "sys_generate_ruid";
 pub const SYS_PANIC_FUNCTION_NAME: &str = "sys_panic";
 
+//=================
+// Debug
+//=================
+pub const DEBUG_ENTER_FUNCTION_NAME: &str = "debug_enter";
+pub const DEBUG_EXIT_FUNCTION_NAME: &str = "debug_exit";
+pub const DEBUG_LOCATION_FUNCTION_NAME: &str = "debug_location";
+
 //=================
 // WASM Shim
 //=================
@@ -0,0 +1,952 @@
+//! Debug execution support for Scrypto modules.
+//!
+//! In debug mode, every defined function is instrumented to report its entry and exit to the
+//! host, which maintains a shadow call stack, and every call and trap site reports its code
+//! offset to the host before executing. When the execution traps or panics, the frames still on
+//! the stack form the backtrace. Each frame is symbolised with the function name from the `name`
+//! section and the source file and line from the DWARF line info of the original code, both kept
+//! by `scrypto build --debug`.
+
+use crate::errors::{ApplicationError, InvokeError, RuntimeError, VmError};
+use crate::types::*;
+use crate::vm::wasm::constants::*;
+use crate::vm::wasm::errors::*;
+use gimli::{EndianSlice, LittleEndian};
+use radix_engine_interface::blueprints::package::CodeHash;
+use sbor::rust::sync::Arc;
+use wasm_encoder::{
+    BlockType, CodeSection, ConstExpr, ElementSection, Elements, EntityType, ExportKind,
+    ExportSection, Function, ImportSection, Instruction, Module, RawSection, RefType, StartSection,
+    TypeSection,
+};
+use wasmparser::{
+    ElementItems, ElementKind, ExternalKind, Name, NameSectionReader, Operator, Parser, Payload,
+    Type, TypeRef, ValType,
+};
+
+/// A frame of the shadow call stack kept in debug mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmDebugFrame {
+    /// The index of the function in the original code.
+    pub function_index: u32,
+    /// The code offset of the call or trap site the function last reached, relative to the start
+    /// of the code section of the original code, as used by the DWARF line info.
+    pub code_offset: Option<u32>,
+}
+
+/// A row of the DWARF line table, mapping code offsets starting at `address` to a source line.
+/// A line of zero marks the end of a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LineRow {
+    address: u64,
+    file: usize,
+    line: u32,
+}
+
+/// Function names and source lines of a Scrypto module, used for symbolising backtraces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmDebugInfo {
+    function_names: IndexMap<u32, String>,
+    files: Vec<String>,
+    /// The line table, sorted by address.
+    lines: Vec<LineRow>,
+}
+
+impl WasmDebugInfo {
+    /// Reads the function names from the `name` section and the line table from the DWARF
+    /// sections of the original code. Code without them (e.g. stripped by `wasm-opt`) yields
+    /// frames with function indices and no source lines.
+    pub fn new(code: &[u8]) -> Self {
+        let mut debug_info = Self::default();
+        let mut dwarf_sections = index_map_new::<&str, &[u8]>();
+        for payload in Parser::new(0).parse_all(code) {
+            let reader = match payload {
+                Ok(Payload::CustomSection(reader)) => reader,
+                Ok(_) => continue,
+                Err(_) => break,
+            };
+            if reader.name() == "name" {
+                for name in NameSectionReader::new(reader.data(), reader.data_offset()) {
+                    if let Ok(Name::Function(names)) = name {
+                        for naming in names.into_iter().flatten() {
+                            debug_info
+                                .function_names
+                                .insert(naming.index, demangle(naming.name));
+                        }
+                    }
+                }
+            } else if reader.name().starts_with(".debug_") {
+                dwarf_sections.insert(reader.name(), reader.data());
+            }
+        }
+
+        // Malformed DWARF only loses the source lines, not the function names
+        if debug_info.read_line_table(&dwarf_sections).is_err() {
+            debug_info.files.clear();
+            debug_info.lines.clear();
+        }
+        debug_info
+    }
+
+    fn read_line_table(
+        &mut self,
+        dwarf_sections: &IndexMap<&str, &[u8]>,
+    ) -> Result<(), gimli::Error> {
+        let dwarf = gimli::Dwarf::load(|id| -> Result<_, gimli::Error> {
+            let data = dwarf_sections.get(id.name()).copied().unwrap_or(&[]);
+            Ok(EndianSlice::new(data, LittleEndian))
+        })?;
+
+        let mut file_indices = index_map_new::<String, usize>();
+        let mut units = dwarf.units();
+        while let Some(header) = units.next()? {
+            let unit = dwarf.unit(header)?;
+            let program = match unit.line_program.clone() {
+                Some(program) => program,
+                None => continue,
+            };
+            let mut rows = program.rows();
+            while let Some((header, row)) = rows.next_row()? {
+                if row.end_sequence() {
+                    self.lines.push(LineRow {
+                        address: row.address(),
+                        file: 0,
+                        line: 0,
+                    });
+                    continue;
+                }
+                let file = match row.file(header) {
+                    Some(file) => file,
+                    None => continue,
+                };
+                let mut path = String::new();
+                if let Some(directory) = file.directory(header) {
+                    path.push_str(&dwarf.attr_string(&unit, directory)?.to_string_lossy());
+                }
+                let name = dwarf.attr_string(&unit, file.path_name())?;
+                let name = name.to_string_lossy();
+                if path.is_empty() || name.starts_with('/') {
+                    path = name.into_owned();
+                } else {
+                    path = format!("{}/{}", path.trim_end_matches('/'), name);
+                }
+
+                let next_index = file_indices.len();
+                let file = *file_indices.entry(path).or_insert(next_index);
+                self.lines.push(LineRow {
+                    address: row.address(),
+                    file,
+                    line: row.line().map(|line| line.get() as u32).unwrap_or(0),
+                });
+            }
+        }
+
+        self.files = file_indices.into_iter().map(|(path, _)| path).collect();
+        self.lines.sort_by_key(|row| row.address);
+        Ok(())
+    }
+
+    pub fn function_name(&self, function_index: u32) -> Option<&str> {
+        self.function_names
+            .get(&function_index)
+            .map(|name| name.as_str())
+    }
+
+    /// Resolves a code offset (relative to the start of the code section) to a source file and
+    /// line, if covered by the line table.
+    pub fn source_location(&self, code_offset: u32) -> Option<(&str, u32)> {
+        let index = self
+            .lines
+            .partition_point(|row| row.address <= code_offset as u64)
+            .checked_sub(1)?;
+        let row = &self.lines[index];
+        if row.line == 0 {
+            return None;
+        }
+        Some((self.files[row.file].as_str(), row.line))
+    }
+
+    /// Symbolises the backtrace of the given shadow call stack, most recent call first.
+    pub fn backtrace(
+        &self,
+        package_address: &PackageAddress,
+        call_stack: &[WasmDebugFrame],
+    ) -> WasmBacktrace {
+        WasmBacktrace {
+            package_address: *package_address,
+            frames: call_stack
+                .iter()
+                .rev()
+                .map(|frame| WasmBacktraceFrame {
+                    function_index: frame.function_index,
+                    function_name: self
+                        .function_name(frame.function_index)
+                        .map(|name| name.to_owned()),
+                    location: frame
+                        .code_offset
+                        .and_then(|code_offset| self.source_location(code_offset))
+                        .map(|(file, line)| (file.to_owned(), line)),
+                })
+                .collect(),
+        }
+    }
+
+    /// Attaches the backtrace to traps and panics, leaving all other errors untouched.
+    ///
+    /// Errors raised by nested invocations already carry the backtrace of the code which raised
+    /// them, so they are not wrapped again.
+    pub fn attach_backtrace(
+        &self,
+        error: InvokeError<WasmRuntimeError>,
+        package_address: &PackageAddress,
+        call_stack: &[WasmDebugFrame],
+    ) -> RuntimeError {
+        let error: RuntimeError = error.into();
+        match error {
+            RuntimeError::VmError(VmError::Wasm(WasmRuntimeError::ExecutionError(..)))
+            | RuntimeError::ApplicationError(ApplicationError::PanicMessage(..)) => {
+                RuntimeError::VmError(VmError::WithBacktrace {
+                    error: Box::new(error),
+                    backtrace: self.backtrace(package_address, call_stack),
+                })
+            }
+            error => error,
+        }
+    }
+}
+
+/// A backtrace of Scrypto code executed in debug mode, most recent call first.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct WasmBacktrace {
+    pub package_address: PackageAddress,
+    pub frames: Vec<WasmBacktraceFrame>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct WasmBacktraceFrame {
+    /// The index of the function in the original code.
+    pub function_index: u32,
+    /// The demangled function name, if kept in the `name` section.
+    pub function_name: Option<String>,
+    /// The source file and line of the call or trap site, if covered by the DWARF line info.
+    pub location: Option<(String, u32)>,
+}
+
+impl fmt::Display for WasmBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "backtrace of {}:", self.package_address.to_hex())?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            match &frame.function_name {
+                Some(name) => write!(f, "\n  {}: {}", i, name)?,
+                None => write!(f, "\n  {}: func[{}]", i, frame.function_index)?,
+            }
+            if let Some((file, line)) = &frame.location {
+                write!(f, "\n        at {}:{}", file, line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The code of a package prepared for debug execution, with the debug info of its original code.
+#[derive(Debug)]
+pub struct WasmDebugCode {
+    /// The hash of the debug code, under which its module is cached apart from the instrumented
+    /// code of the same package.
+    pub code_hash: CodeHash,
+    pub code: Vec<u8>,
+    pub debug_info: WasmDebugInfo,
+}
+
+/// A cache of debug code by the hash of the original code, which saves reading, re-preparing
+/// and symbolising the original code on every invocation in debug mode.
+pub struct WasmDebugCodeCache {
+    #[cfg(not(feature = "moka"))]
+    cache: RefCell<lru::LruCache<CodeHash, Arc<WasmDebugCode>>>,
+    #[cfg(feature = "moka")]
+    cache: moka::sync::Cache<CodeHash, Arc<WasmDebugCode>>,
+}
+
+impl Default for WasmDebugCodeCache {
+    fn default() -> Self {
+        Self::new(WASM_ENGINE_CACHE_SIZE)
+    }
+}
+
+impl WasmDebugCodeCache {
+    pub fn new(max_cache_size: usize) -> Self {
+        #[cfg(not(feature = "moka"))]
+        let cache = RefCell::new(lru::LruCache::new(
+            NonZeroUsize::new(max_cache_size).unwrap(),
+        ));
+        #[cfg(feature = "moka")]
+        let cache = moka::sync::Cache::builder()
+            .weigher(|_key: &CodeHash, _value: &Arc<WasmDebugCode>| -> u32 {
+                // No sophisticated weighing mechanism, just keep a fixed size cache
+                1u32
+            })
+            .max_capacity(max_cache_size as u64)
+            .build();
+
+        Self { cache }
+    }
+
+    pub fn get(&self, code_hash: &CodeHash) -> Option<Arc<WasmDebugCode>> {
+        #[cfg(not(feature = "moka"))]
+        return self.cache.borrow_mut().get(code_hash).cloned();
+        #[cfg(feature = "moka")]
+        return self.cache.get(code_hash);
+    }
+
+    pub fn insert(&self, code_hash: CodeHash, debug_code: Arc<WasmDebugCode>) {
+        #[cfg(not(feature = "moka"))]
+        self.cache.borrow_mut().put(code_hash, debug_code);
+        #[cfg(feature = "moka")]
+        self.cache.insert(code_hash, debug_code);
+    }
+}
+
+/// Demangles a symbol in the legacy Rust mangling scheme (the default of rustc), dropping the
+/// trailing hash. Other symbols are returned as they are.
+pub fn demangle(symbol: &str) -> String {
+    let mangled = symbol
+        .strip_prefix("_ZN")
+        .or_else(|| symbol.strip_prefix("__ZN"))
+        .and_then(|rest| rest.strip_suffix('E'));
+    let mut rest = match mangled {
+        Some(rest) => rest,
+        None => return symbol.to_string(),
+    };
+
+    let mut path = Vec::new();
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+        let len = match rest[..digits].parse::<usize>() {
+            Ok(len) if digits + len <= rest.len() => len,
+            _ => return symbol.to_string(),
+        };
+        path.push(&rest[digits..digits + len]);
+        rest = &rest[digits + len..];
+    }
+    if let Some(last) = path.last() {
+        if last.len() == 17
+            && last.starts_with('h')
+            && last[1..].bytes().all(|b| b.is_ascii_hexdigit())
+        {
+            path.pop();
+        }
+    }
+
+    let mut demangled = String::new();
+    for (i, component) in path.iter().enumerate() {
+        if i > 0 {
+            demangled.push_str("::");
+        }
+        // A leading underscore escapes a component starting with `$`
+        let mut component = if component.starts_with("_$") {
+            &component[1..]
+        } else {
+            *component
+        };
+        while !component.is_empty() {
+            if let Some(rest) = component.strip_prefix("..") {
+                demangled.push_str("::");
+                component = rest;
+            } else if component.starts_with('$') {
+                let end = match component[1..].find('$') {
+                    Some(end) => end + 1,
+                    None => {
+                        demangled.push_str(component);
+                        break;
+                    }
+                };
+                match &component[1..end] {
+                    "SP" => demangled.push('@'),
+                    "BP" => demangled.push('*'),
+                    "RF" => demangled.push('&'),
+                    "LT" => demangled.push('<'),
+                    "GT" => demangled.push('>'),
+                    "LP" => demangled.push('('),
+                    "RP" => demangled.push(')'),
+                    "C" => demangled.push(','),
+                    escape => match escape
+                        .strip_prefix('u')
+                        .and_then(|code| u32::from_str_radix(code, 16).ok())
+                        .and_then(char::from_u32)
+                    {
+                        Some(c) => demangled.push(c),
+                        None => demangled.push_str(&component[..=end]),
+                    },
+                }
+                component = &component[end + 1..];
+            } else {
+                let end = component
+                    .find(|c| c == '$' || c == '.')
+                    .filter(|end| *end > 0)
+                    .unwrap_or(component.len());
+                demangled.push_str(&component[..end]);
+                component = &component[end..];
+            }
+        }
+    }
+    demangled
+}
+
+/// Instruments the code for debug execution, with the debug functions imported from the host:
+/// - every defined function calls `debug_enter(function_index)` on entry and `debug_exit()` on
+///   every return, where `function_index` is the index in the original code;
+/// - every call and `unreachable` is preceded by `debug_location(code_offset)`, where
+///   `code_offset` is the offset of the instruction in the code section of the original code.
+///
+/// The module is parsed with `wasmparser` and re-encoded with `wasm-encoder`. As the debug
+/// functions are imported, every reference to a defined function (calls, `ref.func`, exports,
+/// the start function and element segments) is shifted. The body of each function is wrapped in
+/// a block, so that `return` can be rewritten into a branch to the end of the block, and
+/// branches to the function label keep their depth. Traps skip the exit call, which leaves the
+/// trapping frames on the shadow call stack. Custom sections are dropped, as they refer to the
+/// original code.
+pub fn inject_debug_call_tracing(code: &[u8]) -> Result<Vec<u8>, PrepareError> {
+    let error = |reason: &str| PrepareError::RejectedByDebugInstrumentation {
+        reason: reason.to_string(),
+    };
+    let parser_error = |err: wasmparser::BinaryReaderError| error(&err.to_string());
+
+    // Collect the function types and imports, which determine the function index space
+    let mut function_types = Vec::<(Vec<ValType>, Vec<ValType>)>::new();
+    let mut imported_functions = 0u32;
+    let mut function_type_indices = Vec::<u32>::new();
+    for payload in Parser::new(0).parse_all(code) {
+        match payload.map_err(parser_error)? {
+            Payload::TypeSection(reader) => {
+                for ty in reader {
+                    match ty.map_err(parser_error)? {
+                        Type::Func(ty) => {
+                            function_types.push((ty.params().to_vec(), ty.results().to_vec()))
+                        }
+                        _ => return Err(error("only function types are supported")),
+                    }
+                }
+            }
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if let TypeRef::Func(_) = import.map_err(parser_error)?.ty {
+                        imported_functions += 1;
+                    }
+                }
+            }
+            Payload::FunctionSection(reader) => {
+                for type_index in reader {
+                    function_type_indices.push(type_index.map_err(parser_error)?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let enter_type_index = function_types.len() as u32;
+    let enter_function_index = imported_functions;
+    let exit_function_index = imported_functions + 1;
+    let location_function_index = imported_functions + 2;
+    let shift = |function_index: u32| {
+        if function_index >= imported_functions {
+            function_index + 3
+        } else {
+            function_index
+        }
+    };
+
+    let mut module = Module::new();
+    let mut types_written = false;
+    let mut imports_written = false;
+    let mut code_section_start = 0usize;
+    let mut code_section = CodeSection::new();
+    let mut defined_functions = 0u32;
+    for payload in Parser::new(0).parse_all(code) {
+        let payload = payload.map_err(parser_error)?;
+
+        // The debug types and imports are appended to the existing ones, or added in sections
+        // of their own if the code has none
+        if let Some((id, _)) = payload.as_section() {
+            let is_custom = matches!(payload, Payload::CustomSection(_));
+            if !is_custom && id > 1 && !types_written {
+                module.section(&debug_types(&[])?);
+                types_written = true;
+            }
+            if !is_custom && id > 2 && !imports_written {
+                module.section(&debug_imports(ImportSection::new(), enter_type_index));
+                imports_written = true;
+            }
+        }
+
+        match payload {
+            Payload::TypeSection(_) => {
+                module.section(&debug_types(&function_types)?);
+                types_written = true;
+            }
+            Payload::ImportSection(reader) => {
+                let mut section = ImportSection::new();
+                for import in reader {
+                    let import = import.map_err(parser_error)?;
+                    match import.ty {
+                        TypeRef::Func(type_index) => {
+                            section.import(
+                                import.module,
+                                import.name,
+                                EntityType::Function(type_index),
+                            );
+                        }
+                        _ => return Err(error("only function imports are supported")),
+                    }
+                }
+                module.section(&debug_imports(section, enter_type_index));
+                imports_written = true;
+            }
+            Payload::ExportSection(reader) => {
+                let mut section = ExportSection::new();
+                for export in reader {
+                    let export = export.map_err(parser_error)?;
+                    let (kind, index) = match export.kind {
+                        ExternalKind::Func => (ExportKind::Func, shift(export.index)),
+                        ExternalKind::Table => (ExportKind::Table, export.index),
+                        ExternalKind::Memory => (ExportKind::Memory, export.index),
+                        ExternalKind::Global => (ExportKind::Global, export.index),
+                        ExternalKind::Tag => (ExportKind::Tag, export.index),
+                    };
+                    section.export(export.name, kind, index);
+                }
+                module.section(&section);
+            }
+            Payload::StartSection { func, .. } => {
+                module.section(&StartSection {
+                    function_index: shift(func),
+                });
+            }
+            Payload::ElementSection(reader) => {
+                let mut section = ElementSection::new();
+                for element in reader {
+                    let element = element.map_err(parser_error)?;
+                    let functions = match element.items {
+                        ElementItems::Functions(reader) => reader
+                            .into_iter()
+                            .map(|index| index.map(shift))
+                            .collect::<Result<Vec<u32>, _>>()
+                            .map_err(parser_error)?,
+                        ElementItems::Expressions(reader) => {
+                            let mut functions = Vec::new();
+                            for expr in reader {
+                                match expr
+                                    .map_err(parser_error)?
+                                    .get_operators_reader()
+                                    .read()
+                                    .map_err(parser_error)?
+                                {
+                                    Operator::RefFunc { function_index } => {
+                                        functions.push(shift(function_index))
+                                    }
+                                    _ => return Err(error("unsupported element expression")),
+                                }
+                            }
+                            functions
+                        }
+                    };
+                    match element.kind {
+                        ElementKind::Active {
+                            table_index,
+                            offset_expr,
+                        } => {
+                            let offset = match offset_expr
+                                .get_operators_reader()
+                                .read()
+                                .map_err(parser_error)?
+                            {
+                                Operator::I32Const { value } => ConstExpr::i32_const(value),
+                                Operator::GlobalGet { global_index } => {
+                                    ConstExpr::global_get(global_index)
+                                }
+                                _ => return Err(error("unsupported element offset")),
+                            };
+                            // Table 0 is encoded implicitly, as required by the MVP
+                            let table_index: Option<u32> = table_index.into();
+                            section.active(
+                                table_index.filter(|index| *index != 0),
+                                &offset,
+                                RefType::FUNCREF,
+                                Elements::Functions(&functions),
+                            );
+                        }
+                        _ => return Err(error("only active element segments are supported")),
+                    }
+                }
+                module.section(&section);
+            }
+            Payload::CodeSectionStart { range, .. } => {
+                code_section_start = range.start;
+            }
+            Payload::CodeSectionEntry(body) => {
+                let function_index = imported_functions + defined_functions;
+                let results = function_type_indices
+                    .get(defined_functions as usize)
+                    .and_then(|type_index| function_types.get(*type_index as usize))
+                    .map(|(_, results)| results.as_slice())
+                    .ok_or(error("function without type"))?;
+                let block_type = match results {
+                    [] => BlockType::Empty,
+                    [value_type] => BlockType::Result(encode_val_type(*value_type)?),
+                    _ => return Err(error("multi-value functions are not supported")),
+                };
+                defined_functions += 1;
+
+                let mut locals = Vec::new();
+                for local in body.get_locals_reader().map_err(parser_error)? {
+                    let (count, value_type) = local.map_err(parser_error)?;
+                    locals.push((count, encode_val_type(value_type)?));
+                }
+                let mut function = Function::new(locals);
+                function
+                    .instruction(&Instruction::I32Const(function_index as i32))
+                    .instruction(&Instruction::Call(enter_function_index))
+                    .instruction(&Instruction::Block(block_type));
+
+                // The number of blocks opened within the function body
+                let mut depth = 0u32;
+                let mut operators = body.get_operators_reader().map_err(parser_error)?;
+                while !operators.eof() {
+                    let start = operators.original_position();
+                    let operator = operators.read().map_err(parser_error)?;
+                    let end = operators.original_position();
+                    let code_offset = (start - code_section_start) as i32;
+                    match operator {
+                        Operator::Block { .. } | Operator::Loop { .. } | Operator::If { .. } => {
+                            depth += 1;
+                            function.raw(code[start..end].iter().copied());
+                        }
+                        Operator::End if depth == 0 => {
+                            // End of the function, which closes the injected block
+                            function
+                                .instruction(&Instruction::End)
+                                .instruction(&Instruction::Call(exit_function_index))
+                                .instruction(&Instruction::End);
+                        }
+                        Operator::End => {
+                            depth -= 1;
+                            function.raw(code[start..end].iter().copied());
+                        }
+                        Operator::Return => {
+                            function.instruction(&Instruction::Br(depth));
+                        }
+                        Operator::Call { function_index } => {
+                            function
+                                .instruction(&Instruction::I32Const(code_offset))
+                                .instruction(&Instruction::Call(location_function_index))
+                                .instruction(&Instruction::Call(shift(function_index)));
+                        }
+                        Operator::CallIndirect { .. } | Operator::Unreachable => {
+                            function
+                                .instruction(&Instruction::I32Const(code_offset))
+                                .instruction(&Instruction::Call(location_function_index))
+                                .raw(code[start..end].iter().copied());
+                        }
+                        Operator::RefFunc { function_index } => {
+                            function.instruction(&Instruction::RefFunc(shift(function_index)));
+                        }
+                        _ => {
+                            function.raw(code[start..end].iter().copied());
+                        }
+                    }
+                }
+                code_section.function(&function);
+
+                if defined_functions as usize == function_type_indices.len() {
+                    module.section(&code_section);
+                }
+            }
+            // Custom sections are dropped, as they refer to the original code
+            Payload::CustomSection(_) => {}
+            Payload::Version { .. } | Payload::End(_) => {}
+            payload => match payload.as_section() {
+                Some((id, range)) => {
+                    module.section(&RawSection {
+                        id,
+                        data: &code[range],
+                    });
+                }
+                None => return Err(error("unsupported payload")),
+            },
+        }
+    }
+    if !types_written {
+        module.section(&debug_types(&[])?);
+    }
+    if !imports_written {
+        module.section(&debug_imports(ImportSection::new(), enter_type_index));
+    }
+
+    Ok(module.finish())
+}
+
+/// Encodes the type section with the types of the debug imports appended to the given types.
+fn debug_types(
+    function_types: &[(Vec<ValType>, Vec<ValType>)],
+) -> Result<TypeSection, PrepareError> {
+    let mut section = TypeSection::new();
+    for (params, results) in function_types {
+        section.function(
+            params
+                .iter()
+                .map(|ty| encode_val_type(*ty))
+                .collect::<Result<Vec<_>, _>>()?,
+            results
+                .iter()
+                .map(|ty| encode_val_type(*ty))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    // debug_enter and debug_location: (i32) -> ()
+    section.function([wasm_encoder::ValType::I32], []);
+    // debug_exit: () -> ()
+    section.function([], []);
+    Ok(section)
+}
+
+/// Appends the debug imports to the given imports, given the index of the first debug type.
+fn debug_imports(mut section: ImportSection, enter_type_index: u32) -> ImportSection {
+    section
+        .import(
+            MODULE_ENV_NAME,
+            DEBUG_ENTER_FUNCTION_NAME,
+            EntityType::Function(enter_type_index),
+        )
+        .import(
+            MODULE_ENV_NAME,
+            DEBUG_EXIT_FUNCTION_NAME,
+            EntityType::Function(enter_type_index + 1),
+        )
+        .import(
+            MODULE_ENV_NAME,
+            DEBUG_LOCATION_FUNCTION_NAME,
+            EntityType::Function(enter_type_index),
+        );
+    section
+}
+
+fn encode_val_type(value_type: ValType) -> Result<wasm_encoder::ValType, PrepareError> {
+    match value_type {
+        ValType::I32 => Ok(wasm_encoder::ValType::I32),
+        ValType::I64 => Ok(wasm_encoder::ValType::I64),
+        ValType::F32 => Ok(wasm_encoder::ValType::F32),
+        ValType::F64 => Ok(wasm_encoder::ValType::F64),
+        _ => Err(PrepareError::RejectedByDebugInstrumentation {
+            reason: format!("unsupported value type {:?}", value_type),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wabt::{wasm2wat, wat2wasm};
+
+    #[test]
+    fn test_inject_debug_call_tracing() {
+        let code = wat2wasm(
+            r#"
+            (module
+                (import "env" "sys_panic" (func $panic (param i32 i32)))
+                (func $f (param i64) (result i64)
+                    (if (i64.eqz (local.get 0))
+                        (then (return (i64.const 1)))
+                    )
+                    (call $g (local.get 0))
+                )
+                (func $g (param i64) (result i64)
+                    (local.get 0)
+                )
+                (memory $0 1)
+                (export "memory" (memory $0))
+                (export "Test_f" (func $f))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let instrumented = wasm2wat(inject_debug_call_tracing(&code).unwrap()).unwrap();
+
+        // The call to $g is at offset 14 of the code section
+        assert_eq!(
+            instrumented,
+            r#"(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i64) (result i64)))
+  (type (;2;) (func (param i32)))
+  (type (;3;) (func))
+  (import "env" "sys_panic" (func (;0;) (type 0)))
+  (import "env" "debug_enter" (func (;1;) (type 2)))
+  (import "env" "debug_exit" (func (;2;) (type 3)))
+  (import "env" "debug_location" (func (;3;) (type 2)))
+  (func (;4;) (type 1) (param i64) (result i64)
+    i32.const 1
+    call 1
+    block (result i64)  ;; label = @1
+      local.get 0
+      i64.eqz
+      if  ;; label = @2
+        i64.const 1
+        br 1 (;@1;)
+      end
+      local.get 0
+      i32.const 14
+      call 3
+      call 5
+    end
+    call 2)
+  (func (;5;) (type 1) (param i64) (result i64)
+    i32.const 2
+    call 1
+    block (result i64)  ;; label = @1
+      local.get 0
+    end
+    call 2)
+  (memory (;0;) 1)
+  (export "memory" (memory 0))
+  (export "Test_f" (func 4)))
+"#
+        );
+    }
+
+    #[test]
+    fn test_inject_debug_call_tracing_shifts_element_segments() {
+        let code = wat2wasm(
+            r#"
+            (module
+                (import "env" "sys_panic" (func $panic (param i32 i32)))
+                (type $t (func (param i64) (result i64)))
+                (table 2 funcref)
+                (elem (i32.const 0) $f $g)
+                (func $f (param i64) (result i64)
+                    (call_indirect (type $t) (local.get 0) (i32.const 1))
+                )
+                (func $g (param i64) (result i64)
+                    (local.get 0)
+                )
+                (memory $0 1)
+                (export "memory" (memory $0))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let instrumented = inject_debug_call_tracing(&code).unwrap();
+
+        let mut elements = Vec::new();
+        for payload in Parser::new(0).parse_all(&instrumented) {
+            if let Payload::ElementSection(reader) = payload.unwrap() {
+                for element in reader {
+                    if let ElementItems::Functions(functions) = element.unwrap().items {
+                        for function_index in functions {
+                            elements.push(function_index.unwrap());
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(elements, vec![4, 5]);
+        crate::vm::wasm::WasmModule::init(&instrumented).unwrap();
+    }
+
+    #[test]
+    fn test_demangle() {
+        assert_eq!(
+            demangle("_ZN5hello5Hello10free_token17h2f5d4b8e1c0a9e3fE"),
+            "hello::Hello::free_token"
+        );
+        assert_eq!(
+            demangle("_ZN4core3ptr36drop_in_place$LT$scrypto..Bucket$GT$17h0123456789abcdefE"),
+            "core::ptr::drop_in_place<scrypto::Bucket>"
+        );
+        assert_eq!(
+            demangle("_ZN5hello5Hello3new28_$u7b$$u7b$closure$u7d$$u7d$17h0123456789abcdefE"),
+            "hello::Hello::new::{{closure}}"
+        );
+        assert_eq!(demangle("Hello_new"), "Hello_new");
+    }
+
+    #[test]
+    fn test_debug_info_without_name_section() {
+        let code = wat2wasm("(module (func $f))").unwrap();
+        let debug_info = WasmDebugInfo::new(&code);
+        let package_address = PackageAddress::new_or_panic([13u8; NodeId::LENGTH]);
+
+        let backtrace = debug_info.backtrace(
+            &package_address,
+            &[
+                WasmDebugFrame {
+                    function_index: 0,
+                    code_offset: None,
+                },
+                WasmDebugFrame {
+                    function_index: 3,
+                    code_offset: Some(20),
+                },
+            ],
+        );
+
+        assert_eq!(
+            backtrace.frames,
+            vec![
+                WasmBacktraceFrame {
+                    function_index: 3,
+                    function_name: None,
+                    location: None,
+                },
+                WasmBacktraceFrame {
+                    function_index: 0,
+                    function_name: None,
+                    location: None,
+                },
+            ]
+        );
+        assert_eq!(
+            backtrace.to_string(),
+            format!(
+                "backtrace of {}:\n  0: func[3]\n  1: func[0]",
+                package_address.to_hex()
+            )
+        );
+    }
+
+    #[test]
+    fn test_source_location() {
+        let debug_info = WasmDebugInfo {
+            function_names: index_map_new(),
+            files: vec!["/package/src/lib.rs".to_owned()],
+            lines: vec![
+                LineRow {
+                    address: 10,
+                    file: 0,
+                    line: 15,
+                },
+                LineRow {
+                    address: 20,
+                    file: 0,
+                    line: 17,
+                },
+                LineRow {
+                    address: 30,
+                    file: 0,
+                    line: 0,
+                },
+            ],
+        };
+
+        assert_eq!(debug_info.source_location(5), None);
+        assert_eq!(
+            debug_info.source_location(10),
+            Some(("/package/src/lib.rs", 15))
+        );
+        assert_eq!(
+            debug_info.source_location(29),
+            Some(("/package/src/lib.rs", 17))
+        );
+        assert_eq!(debug_info.source_location(30), None);
+    }
+}
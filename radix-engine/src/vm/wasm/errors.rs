@@ -46,6 +46,8 @@ pub enum PrepareError {
     RejectedByInstructionMetering { reason: String },
     /// Failed to inject stack metering
     RejectedByStackMetering { reason: String },
+    /// Failed to inject debug call tracing
+    RejectedByDebugInstrumentation { reason: String },
     /// Not instantiatable
     NotInstantiatable { reason: String },
     /// Not compilable
@@ -149,6 +151,9 @@ pub enum WasmRuntimeError {
     FeeReserveError(FeeReserveError),
 
     InvalidEventFlags(u32),
+
+    /// Failed to save the dumped coverage counters (coverage runs only!)
+    CoverageDataSaveError(String),
 }
 
 impl SelfError for WasmRuntimeError {
@@ -1,4 +1,5 @@
 mod constants;
+mod debug;
 mod errors;
 mod prepare;
 mod traits;
@@ -13,6 +14,7 @@ mod weights;
 pub use self::wasmer::*;
 pub use self::wasmi::*;
 pub use constants::*;
+pub use debug::*;
 pub use errors::*;
 pub use prepare::*;
 pub use traits::*;
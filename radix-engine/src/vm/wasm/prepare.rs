@@ -1,5 +1,5 @@
 use crate::types::*;
-use crate::vm::wasm::{constants::*, errors::*, PrepareError};
+use crate::vm::wasm::{constants::*, debug, errors::*, PrepareError};
 use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
 use syn::Ident;
 use wasm_instrument::{
@@ -7,7 +7,9 @@ use wasm_instrument::{
     inject_stack_limiter,
     utils::module_info::ModuleInfo,
 };
-use wasmparser::{ExternalKind, FuncType, Operator, Type, TypeRef, ValType, WasmFeatures};
+use wasmparser::{
+    ExternalKind, FuncType, Operator, Parser, Payload, Type, TypeRef, ValType, WasmFeatures,
+};
 
 use super::WasmiModule;
 #[derive(Debug)]
@@ -1032,6 +1034,12 @@ impl WasmModule {
         Ok(self)
     }
 
+    pub fn inject_debug_call_tracing(mut self) -> Result<Self, PrepareError> {
+        let code = debug::inject_debug_call_tracing(&self.module.bytes())?;
+        self.module = ModuleInfo::new(&code).map_err(|_| PrepareError::DeserializationError)?;
+        Ok(self)
+    }
+
     pub fn ensure_instantiatable(self) -> Result<Self, PrepareError> {
         // During instantiation time, the following procedures are applied:
 
@@ -1087,6 +1095,69 @@ impl WasmModule {
         Ok((code, function_exports))
     }
 
+    /// Returns the number of imported functions, which precede the defined functions in the
+    /// function index space.
+    pub fn num_imported_functions(&self) -> u32 {
+        self.module.function_map.len() as u32 - self.module.num_local_functions()
+    }
+
+    /// Returns the body size of each defined function, in order of definition.
+    pub fn function_body_sizes(&self) -> Result<Vec<usize>, PrepareError> {
+        Ok(self
+            .module
+            .code_section()
+            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
+            .unwrap_or(vec![])
+            .iter()
+            .map(|body| body.range().len())
+            .collect())
+    }
+
+    /// Returns the name and function index of each function export.
+    pub fn function_exports(&self) -> Result<Vec<(String, u32)>, PrepareError> {
+        Ok(self
+            .module
+            .export_section()
+            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter(|export| export.kind == ExternalKind::Func)
+            .map(|export| (export.name.to_string(), export.index))
+            .collect())
+    }
+
+    /// Returns the name and content size of each section of the given code, in order of
+    /// appearance. Unlike [`WasmModule::init`], custom sections are included.
+    pub fn section_sizes(code: &[u8]) -> Result<Vec<(String, usize)>, PrepareError> {
+        let mut sections = Vec::new();
+        for payload in Parser::new(0).parse_all(code) {
+            let payload = payload.map_err(|err| PrepareError::WasmParserError(err.to_string()))?;
+            let name = match &payload {
+                Payload::CustomSection(reader) => format!("custom \"{}\"", reader.name()),
+                Payload::TypeSection(_) => "type".to_owned(),
+                Payload::ImportSection(_) => "import".to_owned(),
+                Payload::FunctionSection(_) => "function".to_owned(),
+                Payload::TableSection(_) => "table".to_owned(),
+                Payload::MemorySection(_) => "memory".to_owned(),
+                Payload::GlobalSection(_) => "global".to_owned(),
+                Payload::ExportSection(_) => "export".to_owned(),
+                Payload::StartSection { .. } => "start".to_owned(),
+                Payload::ElementSection(_) => "element".to_owned(),
+                Payload::DataCountSection { .. } => "data count".to_owned(),
+                Payload::DataSection(_) => "data".to_owned(),
+                Payload::CodeSectionStart { .. } => "code".to_owned(),
+                _ => match payload.as_section() {
+                    Some((id, _)) => format!("unknown ({})", id),
+                    None => continue,
+                },
+            };
+            if let Some((_, range)) = payload.as_section() {
+                sections.push((name, range.len()));
+            }
+        }
+        Ok(sections)
+    }
+
     fn function_matches(
         module: &ModuleInfo,
         func_index: usize,
@@ -1,6 +1,7 @@
 use crate::errors::InvokeError;
 use crate::types::*;
 use crate::vm::wasm::errors::*;
+use crate::vm::wasm::WasmDebugFrame;
 use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::api::ActorRefHandle;
 use radix_engine_interface::blueprints::package::CodeHash;
@@ -192,6 +193,24 @@ pub trait WasmRuntime {
     fn sys_generate_ruid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn sys_panic(&mut self, message: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    /// Records the entry into a function, only called by code instrumented for debugging.
+    fn debug_enter_function(
+        &mut self,
+        function_index: u32,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    /// Records the exit from the most recently entered function.
+    fn debug_exit_function(&mut self) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    /// Records the call or trap site the most recently entered function is about to execute.
+    fn debug_set_location(
+        &mut self,
+        code_offset: u32,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    /// Returns the frames of the functions entered but not exited, outermost first.
+    fn debug_call_stack(&self) -> Vec<WasmDebugFrame>;
 }
 
 /// Represents an instantiated, invokable Scrypto module.
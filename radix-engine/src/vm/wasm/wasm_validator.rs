@@ -54,6 +54,33 @@ impl WasmValidator {
             .ensure_compilable()?
             .to_bytes()
     }
+
+    /// Prepares already published code for debug execution, which additionally reports every
+    /// function entry and exit to the host (see [`inject_debug_call_tracing`]).
+    ///
+    /// The export constraints are not checked, as the code has been validated when published.
+    pub fn validate_for_debug(&self, code: &[u8]) -> Result<Vec<u8>, PrepareError> {
+        WasmModule::init(code)?
+            .enforce_no_start_function()?
+            .enforce_import_limit()?
+            .enforce_export_names()?
+            .enforce_memory_limit_and_inject_max(self.max_memory_size_in_pages)?
+            .enforce_table_limit(self.max_initial_table_size)?
+            .enforce_br_table_limit(self.max_number_of_br_table_targets)?
+            .enforce_function_limit(
+                self.max_number_of_functions,
+                self.max_number_of_function_params,
+                self.max_number_of_function_locals,
+            )?
+            .enforce_global_limit(self.max_number_of_globals)?
+            .inject_debug_call_tracing()?
+            .inject_instruction_metering(&self.instrumenter_config)?
+            .inject_stack_metering(self.instrumenter_config.max_stack_size())?
+            .ensure_instantiatable()?
+            .ensure_compilable()?
+            .to_bytes()
+            .map(|(code, _)| code)
+    }
 }
 
 #[cfg(test)]
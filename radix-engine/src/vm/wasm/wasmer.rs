@@ -706,6 +706,30 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        fn debug_enter(
+            env: &WasmerInstanceEnv,
+            function_index: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime.debug_enter_function(function_index)
+        }
+
+        fn debug_exit(env: &WasmerInstanceEnv) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime.debug_exit_function()
+        }
+
+        fn debug_location(
+            env: &WasmerInstanceEnv,
+            code_offset: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime.debug_set_location(code_offset)
+        }
+
         // native functions ends
 
         // env
@@ -754,6 +778,9 @@ impl WasmerModule {
                 SYS_GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_get_transaction_hash),
                 SYS_GENERATE_RUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_generate_ruid),
                 BUFFER_CONSUME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), buffer_consume),
+                DEBUG_ENTER_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), debug_enter),
+                DEBUG_EXIT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), debug_exit),
+                DEBUG_LOCATION_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), debug_location),
             }
         };
 
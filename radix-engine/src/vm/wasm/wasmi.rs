@@ -601,6 +601,30 @@ fn panic(
 
     runtime.sys_panic(message)
 }
+
+fn debug_enter(
+    caller: Caller<'_, HostState>,
+    function_index: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (_, runtime) = grab_runtime!(caller);
+
+    runtime.debug_enter_function(function_index)
+}
+
+fn debug_exit(caller: Caller<'_, HostState>) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (_, runtime) = grab_runtime!(caller);
+
+    runtime.debug_exit_function()
+}
+
+fn debug_location(
+    caller: Caller<'_, HostState>,
+    code_offset: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (_, runtime) = grab_runtime!(caller);
+
+    runtime.debug_set_location(code_offset)
+}
 // native functions ends
 
 macro_rules! linker_define {
@@ -1079,6 +1103,27 @@ impl WasmiModule {
             },
         );
 
+        let host_debug_enter = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, function_index: u32| -> Result<(), Trap> {
+                debug_enter(caller, function_index).map_err(|e| e.into())
+            },
+        );
+
+        let host_debug_exit = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>| -> Result<(), Trap> {
+                debug_exit(caller).map_err(|e| e.into())
+            },
+        );
+
+        let host_debug_location = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, code_offset: u32| -> Result<(), Trap> {
+                debug_location(caller, code_offset).map_err(|e| e.into())
+            },
+        );
+
         let mut linker = <Linker<HostState>>::new();
 
         linker_define!(linker, BUFFER_CONSUME_FUNCTION_NAME, host_consume_buffer);
@@ -1231,6 +1276,9 @@ impl WasmiModule {
         );
 
         linker_define!(linker, SYS_GENERATE_RUID_FUNCTION_NAME, host_generate_ruid);
+        linker_define!(linker, DEBUG_ENTER_FUNCTION_NAME, host_debug_enter);
+        linker_define!(linker, DEBUG_EXIT_FUNCTION_NAME, host_debug_exit);
+        linker_define!(linker, DEBUG_LOCATION_FUNCTION_NAME, host_debug_location);
 
         let global_value = Global::new(store.as_context_mut(), Value::I32(-1), Mutability::Var);
         linker_define!(linker, "test_global_mutable_value", global_value);
@@ -1347,7 +1395,7 @@ impl WasmInstance for WasmiInstance {
                 .write(runtime as *mut _ as usize as *mut _);
         }
 
-        let func = self.get_export_func(func_name).unwrap();
+        let func = self.get_export_func(func_name)?;
         let input: Vec<Value> = args
             .into_iter()
             .map(|buffer| Value::I64(buffer.as_i64()))
@@ -21,6 +21,7 @@ where
     package_address: PackageAddress,
     export_name: String,
     wasm_execution_units_buffer: u32,
+    debug_call_stack: Vec<WasmDebugFrame>,
 }
 
 impl<'y, Y> ScryptoRuntime<'y, Y>
@@ -35,6 +36,7 @@ where
             package_address,
             export_name,
             wasm_execution_units_buffer: 0,
+            debug_call_stack: Vec::new(),
         }
     }
 }
@@ -447,6 +449,36 @@ where
         Ok(())
     }
 
+    fn debug_enter_function(
+        &mut self,
+        function_index: u32,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.debug_call_stack.push(WasmDebugFrame {
+            function_index,
+            code_offset: None,
+        });
+        Ok(())
+    }
+
+    fn debug_exit_function(&mut self) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.debug_call_stack.pop();
+        Ok(())
+    }
+
+    fn debug_set_location(
+        &mut self,
+        code_offset: u32,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        if let Some(frame) = self.debug_call_stack.last_mut() {
+            frame.code_offset = Some(code_offset);
+        }
+        Ok(())
+    }
+
+    fn debug_call_stack(&self) -> Vec<WasmDebugFrame> {
+        self.debug_call_stack.clone()
+    }
+
     fn sys_get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let hash = self.api.get_transaction_hash()?;
 
@@ -262,6 +262,13 @@ implement_client_api! {
             locked_fee: LiquidFungibleResource,
             contingent: bool,
         ) -> Result<LiquidFungibleResource, RuntimeError>,
+        lock_royalty_payment: (
+            &mut self,
+            vault_id: NodeId,
+            resource_address: ResourceAddress,
+            payment: LiquidFungibleResource,
+            max_per_function: Decimal,
+        ) -> Result<LiquidFungibleResource, RuntimeError>,
         execution_cost_unit_limit: (&mut self) -> Result<u32, RuntimeError>,
         execution_cost_unit_price: (&mut self) -> Result<Decimal, RuntimeError>,
         finalization_cost_unit_limit: (&mut self) -> Result<u32, RuntimeError>,
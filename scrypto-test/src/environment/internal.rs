@@ -82,6 +82,7 @@ impl EncapsulatedRadixEngine {
                 AuthZoneParams {
                     initial_proofs: Default::default(),
                     virtual_resources: Default::default(),
+                    subintent_initial_proofs: Default::default(),
                 },
                 SystemLoanFeeReserve::default(),
                 FeeTable::new(),
@@ -83,12 +83,10 @@ struct Compile;
 
 impl Compile {
     pub fn compile<P: AsRef<Path>>(package_dir: P) -> (Vec<u8>, PackageDefinition) {
-        // Build
-        let status = Command::new("cargo")
-            .current_dir(package_dir.as_ref())
-            .args(["build", "--target", "wasm32-unknown-unknown", "--release"])
-            .status()
-            .unwrap();
+        // Build, with coverage instrumentation when run by `scrypto coverage`
+        let coverage = coverage_config();
+        let mut command = cargo_build_command(package_dir.as_ref(), coverage.as_ref());
+        let status = command.status().unwrap();
         if !status.success() {
             panic!("Failed to compile package: {:?}", package_dir.as_ref());
         }
@@ -113,7 +111,10 @@ impl Compile {
                 .to_owned()
                 .replace("-", "_")
         };
-        let mut path = PathBuf::from_str(&Self::get_cargo_target_directory(&cargo)).unwrap(); // Infallible;
+        let mut path = match coverage {
+            Some(coverage) => coverage_target_directory(&coverage.directory),
+            None => PathBuf::from_str(&Self::get_cargo_target_directory(&cargo)).unwrap(), // Infallible;
+        };
         path.push("wasm32-unknown-unknown");
         path.push("release");
         path.push(wasm_name);
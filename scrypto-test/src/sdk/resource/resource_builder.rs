@@ -111,6 +111,7 @@ pub trait AnyResourceType: Default {
 
 pub struct FungibleResourceType {
     divisibility: u8,
+    withdraw_limit: Option<FungibleWithdrawLimit>,
 }
 impl AnyResourceType for FungibleResourceType {
     type ResourceRoles = FungibleResourceRoles;
@@ -119,6 +120,7 @@ impl Default for FungibleResourceType {
     fn default() -> Self {
         Self {
             divisibility: DIVISIBILITY_MAXIMUM,
+            withdraw_limit: None,
         }
     }
 }
@@ -477,25 +479,43 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 owner_role,
                 divisibility,
                 resource_roles,
+                withdraw_limit,
                 metadata,
                 address_reservation,
             } => {
                 let metadata = metadata.unwrap_or_else(|| Default::default());
 
-                let bytes = env.call_function(
-                    RESOURCE_PACKAGE,
-                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-                    FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
-                    scrypto_encode(&FungibleResourceManagerCreateInput {
-                        owner_role,
-                        divisibility,
-                        track_total_supply: true,
-                        metadata,
-                        resource_roles,
-                        address_reservation,
-                    })
-                    .unwrap(),
-                )?;
+                let bytes = match withdraw_limit {
+                    Some(withdraw_limit) => env.call_function(
+                        RESOURCE_PACKAGE,
+                        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                        FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT,
+                        scrypto_encode(&FungibleResourceManagerCreateWithWithdrawLimitInput {
+                            owner_role,
+                            divisibility,
+                            track_total_supply: true,
+                            metadata,
+                            resource_roles,
+                            withdraw_limit,
+                            address_reservation,
+                        })
+                        .unwrap(),
+                    )?,
+                    None => env.call_function(
+                        RESOURCE_PACKAGE,
+                        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                        FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
+                        scrypto_encode(&FungibleResourceManagerCreateInput {
+                            owner_role,
+                            divisibility,
+                            track_total_supply: true,
+                            metadata,
+                            resource_roles,
+                            address_reservation,
+                        })
+                        .unwrap(),
+                    )?,
+                };
                 Ok(scrypto_decode(&bytes).unwrap())
             }
             private::CreateWithNoSupply::NonFungible {
@@ -552,7 +572,64 @@ impl InProgressResourceBuilder<FungibleResourceType> {
     /// ```
     pub fn divisibility(mut self, divisibility: u8) -> Self {
         assert!(divisibility <= 18);
-        self.resource_type = FungibleResourceType { divisibility };
+        self.resource_type.divisibility = divisibility;
+        self
+    }
+
+    /// Caps the amount which may be withdrawn from each vault of the resource within a period.
+    ///
+    /// Windows are aligned to multiples of the period, and withdrawals authorized by the
+    /// withdraw limit bypasser role are neither limited nor counted.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto_test::prelude::*;
+    ///
+    /// // Permits withdrawing at most 100 tokens from each vault per epoch.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .withdraw_limit(100, WithdrawLimitPeriod::Epochs(1));
+    /// ```
+    pub fn withdraw_limit<T: Into<Decimal>>(
+        mut self,
+        amount: T,
+        period: WithdrawLimitPeriod,
+    ) -> Self {
+        self.resource_type.withdraw_limit = Some(FungibleWithdrawLimit {
+            amount: amount.into(),
+            period,
+            bypass_roles: None,
+        });
+        self
+    }
+
+    /// Sets who may withdraw from vaults of the resource without being limited by the
+    /// [`withdraw_limit`](Self::withdraw_limit), which must be set first.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto_test::prelude::*;
+    ///
+    /// # let resource_address = XRD;
+    /// // Withdrawals authorized with a proof of a specific resource are not limited.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .withdraw_limit(100, WithdrawLimitPeriod::Minutes(60))
+    ///    .withdraw_limit_bypass_roles(withdraw_limit_bypass_roles! {
+    ///        withdraw_limit_bypasser => rule!(require(resource_address));
+    ///        withdraw_limit_bypasser_updater => rule!(deny_all);
+    ///    });
+    /// ```
+    pub fn withdraw_limit_bypass_roles(
+        mut self,
+        bypass_roles: Option<WithdrawLimitBypassRoles<RoleDefinition>>,
+    ) -> Self {
+        let withdraw_limit = self
+            .resource_type
+            .withdraw_limit
+            .as_mut()
+            .expect("The withdraw limit must be set before its bypass roles");
+        withdraw_limit.bypass_roles = bypass_roles;
         self
     }
 }
@@ -582,21 +659,41 @@ impl InProgressResourceBuilder<FungibleResourceType> {
             .take()
             .unwrap_or_else(|| Default::default());
 
-        let bytes = env.call_function(
-            RESOURCE_PACKAGE,
-            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyInput {
-                owner_role: self.owner_role,
-                track_total_supply: true,
-                divisibility: self.resource_type.divisibility,
-                resource_roles: self.resource_roles,
-                metadata,
-                initial_supply: amount.into(),
-                address_reservation: self.address_reservation,
-            })
-            .unwrap(),
-        )?;
+        let bytes = match self.resource_type.withdraw_limit {
+            Some(withdraw_limit) => env.call_function(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT,
+                scrypto_encode(
+                    &FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitInput {
+                        owner_role: self.owner_role,
+                        track_total_supply: true,
+                        divisibility: self.resource_type.divisibility,
+                        resource_roles: self.resource_roles,
+                        withdraw_limit,
+                        metadata,
+                        initial_supply: amount.into(),
+                        address_reservation: self.address_reservation,
+                    },
+                )
+                .unwrap(),
+            )?,
+            None => env.call_function(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+                scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyInput {
+                    owner_role: self.owner_role,
+                    track_total_supply: true,
+                    divisibility: self.resource_type.divisibility,
+                    resource_roles: self.resource_roles,
+                    metadata,
+                    initial_supply: amount.into(),
+                    address_reservation: self.address_reservation,
+                })
+                .unwrap(),
+            )?,
+        };
 
         Ok(scrypto_decode::<(
             ResourceAddress,
@@ -895,6 +992,7 @@ impl private::CanCreateWithNoSupply for InProgressResourceBuilder<FungibleResour
             owner_role: self.owner_role,
             divisibility: self.resource_type.divisibility,
             resource_roles: self.resource_roles,
+            withdraw_limit: self.resource_type.withdraw_limit,
             metadata: self.metadata_config,
             address_reservation: self.address_reservation,
         }
@@ -975,6 +1073,7 @@ mod private {
             owner_role: OwnerRole,
             divisibility: u8,
             resource_roles: FungibleResourceRoles,
+            withdraw_limit: Option<FungibleWithdrawLimit>,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
         },
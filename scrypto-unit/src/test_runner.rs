@@ -15,12 +15,13 @@ use radix_engine::system::system_db_reader::{
 };
 use radix_engine::system::type_info::TypeInfoSubstate;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction, BalanceChange, CommitResult, CostingParameters,
-    ExecutionConfig, PreviewError, TransactionReceipt, TransactionResult,
+    estimate_transaction_fee, execute_preview, execute_transaction, BalanceChange, CommitResult,
+    CostingParameters, ExecutionConfig, FeeEstimationError, PreviewError, TransactionFeeEstimate,
+    TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
-use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmDebugCodeCache, WasmValidatorConfigV1};
 use radix_engine::vm::{NativeVm, NativeVmExtension, NoExtension, ScryptoVm, Vm};
 use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
 use radix_engine_interface::api::node_modules::auth::*;
@@ -63,19 +64,52 @@ pub struct Compile;
 
 impl Compile {
     pub fn compile<P: AsRef<Path>>(package_dir: P) -> (Vec<u8>, PackageDefinition) {
-        // Build
-        let status = Command::new("cargo")
-            .current_dir(package_dir.as_ref())
-            .args(["build", "--target", "wasm32-unknown-unknown", "--release"])
-            .status()
-            .unwrap();
+        Self::compile_with_options(package_dir, false)
+    }
+
+    /// Compiles a package keeping the function names and the DWARF line info, which the engine
+    /// uses to symbolise backtraces in debug mode.
+    pub fn compile_with_debug_info<P: AsRef<Path>>(package_dir: P) -> (Vec<u8>, PackageDefinition) {
+        Self::compile_with_options(package_dir, true)
+    }
+
+    fn compile_with_options<P: AsRef<Path>>(
+        package_dir: P,
+        debug_info: bool,
+    ) -> (Vec<u8>, PackageDefinition) {
+        let mut cargo = package_dir.as_ref().to_owned();
+        cargo.push("Cargo.toml");
+
+        // Build, with coverage instrumentation when run by `scrypto coverage`
+        let coverage = coverage_config().filter(|_| !debug_info);
+        let mut command = cargo_build_command(package_dir.as_ref(), coverage.as_ref());
+
+        // Debug builds are placed in a target directory of their own, to not replace the
+        // regular build of the package
+        let debug_target_directory = if debug_info {
+            let target_directory = get_cargo_target_directory(&cargo);
+            Some(
+                PathBuf::from_str(&target_directory)
+                    .unwrap()
+                    .join("debug-info"),
+            )
+        } else {
+            None
+        };
+        if let Some(debug_target_directory) = &debug_target_directory {
+            command
+                .arg("--target-dir")
+                .arg(debug_target_directory)
+                .env("CARGO_PROFILE_RELEASE_STRIP", "none")
+                .env("CARGO_PROFILE_RELEASE_DEBUG", "line-tables-only");
+        }
+
+        let status = command.status().unwrap();
         if !status.success() {
             panic!("Failed to compile package: {:?}", package_dir.as_ref());
         }
 
         // Find wasm path
-        let mut cargo = package_dir.as_ref().to_owned();
-        cargo.push("Cargo.toml");
         let wasm_name = if cargo.exists() {
             let content = fs::read_to_string(&cargo).expect("Failed to read the Cargo.toml file");
             Self::extract_crate_name(&content)
@@ -92,7 +126,11 @@ impl Compile {
                 .to_owned()
                 .replace("-", "_")
         };
-        let mut path = PathBuf::from_str(&get_cargo_target_directory(&cargo)).unwrap(); // Infallible;
+        let mut path = match (debug_target_directory, coverage) {
+            (Some(debug_target_directory), _) => debug_target_directory,
+            (None, Some(coverage)) => coverage_target_directory(&coverage.directory),
+            (None, None) => PathBuf::from_str(&get_cargo_target_directory(&cargo)).unwrap(), // Infallible;
+        };
         path.push("wasm32-unknown-unknown");
         path.push("release");
         path.push(wasm_name);
@@ -235,6 +273,7 @@ pub struct TestRunnerBuilder<E, D> {
     trace: bool,
     state_hashing: bool,
     collect_events: bool,
+    debug_mode: bool,
 }
 
 impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
@@ -246,6 +285,7 @@ impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
             trace: true,
             state_hashing: false,
             collect_events: false,
+            debug_mode: false,
         }
     }
 }
@@ -266,6 +306,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    /// Executes Scrypto code instrumented for debugging, which attaches a backtrace to every
+    /// trap and panic. Packages should be built with `scrypto build --debug` (or
+    /// [`Compile::compile_with_debug_info`]) to keep the function names and line info.
+    pub fn with_debug_mode(mut self) -> Self {
+        self.debug_mode = true;
+        self
+    }
+
     pub fn with_custom_genesis(mut self, genesis: CustomGenesis) -> Self {
         self.custom_genesis = Some(genesis);
         self
@@ -282,6 +330,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             trace: self.trace,
             state_hashing: self.state_hashing,
             collect_events: self.collect_events,
+            debug_mode: self.debug_mode,
         }
     }
 
@@ -293,6 +342,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             trace: self.trace,
             state_hashing: self.state_hashing,
             collect_events: self.collect_events,
+            debug_mode: self.debug_mode,
         }
     }
 
@@ -309,6 +359,8 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         let scrypto_vm = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            debug_mode: self.debug_mode,
+            debug_code_cache: WasmDebugCodeCache::default(),
         };
         let native_vm = NativeVm::new_with_extension(self.custom_extension);
         let vm = Vm::new(&scrypto_vm, native_vm.clone());
@@ -573,6 +625,62 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         Some(balance.amount())
     }
 
+    pub fn inspect_component_resource_royalty(
+        &mut self,
+        component_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> Option<Decimal> {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let accumulator = reader
+            .read_typed_object_field::<ComponentRoyaltyAccumulatorFieldPayload>(
+                component_address.as_node_id(),
+                ObjectModuleId::Royalty,
+                ComponentRoyaltyField::Accumulator.field_index(),
+            )
+            .ok()?
+            .into_latest();
+        let vault = accumulator.resource_royalty_vaults.get(&resource_address)?;
+
+        let balance = reader
+            .read_typed_object_field::<FungibleVaultBalanceFieldPayload>(
+                vault.0.as_node_id(),
+                ObjectModuleId::Main,
+                FungibleVaultField::Balance.field_index(),
+            )
+            .unwrap()
+            .into_latest();
+
+        Some(balance.amount())
+    }
+
+    pub fn inspect_package_resource_royalty(
+        &mut self,
+        package_address: PackageAddress,
+        resource_address: ResourceAddress,
+    ) -> Option<Decimal> {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let accumulator = reader
+            .read_typed_object_field::<PackageRoyaltyAccumulatorFieldPayload>(
+                package_address.as_node_id(),
+                ObjectModuleId::Main,
+                PackageField::RoyaltyAccumulator.field_index(),
+            )
+            .ok()?
+            .into_latest();
+        let vault = accumulator.resource_royalty_vaults.get(&resource_address)?;
+
+        let balance = reader
+            .read_typed_object_field::<FungibleVaultBalanceFieldPayload>(
+                vault.0.as_node_id(),
+                ObjectModuleId::Main,
+                FungibleVaultField::Balance.field_index(),
+            )
+            .unwrap()
+            .into_latest();
+
+        Some(balance.amount())
+    }
+
     pub fn find_all_nodes(&self) -> IndexSet<NodeId> {
         let mut node_ids = index_set_new();
         for pk in self.database.list_partition_keys() {
@@ -836,12 +944,15 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         (pub_key, priv_key, account)
     }
 
-    pub fn get_active_validator_info_by_key(&self, key: &Secp256k1PublicKey) -> ValidatorSubstate {
+    pub fn get_active_validator_info_by_key(
+        &self,
+        key: &Secp256k1PublicKey,
+    ) -> ValidatorSubstateV2 {
         let address = self.get_active_validator_with_key(key);
         self.get_validator_info(address)
     }
 
-    pub fn get_validator_info(&self, address: ComponentAddress) -> ValidatorSubstate {
+    pub fn get_validator_info(&self, address: ComponentAddress) -> ValidatorSubstateV2 {
         let reader = SystemDatabaseReader::new(&self.database);
         let substate = reader
             .read_typed_object_field::<ValidatorStateFieldPayload>(
@@ -1374,6 +1485,30 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         execute_preview(&self.database, vm, network, preview_intent, self.trace)
     }
 
+    pub fn estimate_transaction_fee(
+        &mut self,
+        intent: IntentV1,
+        signer_public_keys: Vec<PublicKey>,
+        flags: PreviewFlags,
+        network: &NetworkDefinition,
+        margin_percentage: u16,
+    ) -> Result<TransactionFeeEstimate, FeeEstimationError> {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        estimate_transaction_fee(
+            &self.database,
+            vm,
+            network,
+            intent,
+            signer_public_keys,
+            flags,
+            margin_percentage,
+        )
+    }
+
     pub fn preview_manifest(
         &mut self,
         manifest: TransactionManifestV1,
@@ -2,7 +2,8 @@ use super::HasTypeInfo;
 use crate::prelude::{Global, HasStub, ObjectStub, ObjectStubHandle};
 use radix_engine_common::prelude::PACKAGE_PACKAGE;
 use radix_engine_interface::blueprints::package::{
-    PackageClaimRoyaltiesInput, PACKAGE_BLUEPRINT, PACKAGE_CLAIM_ROYALTIES_IDENT,
+    PackageClaimResourceRoyaltiesInput, PackageClaimRoyaltiesInput, PACKAGE_BLUEPRINT,
+    PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT, PACKAGE_CLAIM_ROYALTIES_IDENT,
 };
 use radix_engine_interface::blueprints::resource::Bucket;
 use radix_engine_interface::types::*;
@@ -47,6 +48,13 @@ impl PackageStub {
             &PackageClaimRoyaltiesInput {},
         )
     }
+
+    pub fn claim_resource_royalties(&self, resource_address: ResourceAddress) -> Bucket {
+        self.call(
+            PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT,
+            &PackageClaimResourceRoyaltiesInput { resource_address },
+        )
+    }
 }
 
 impl From<PackageAddress> for Package {
@@ -1,6 +1,11 @@
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::multisig::MultisigProposal;
+use radix_engine_interface::blueprints::pool::{
+    ConstantProductPoolFees, ConstantProductPoolPriceAccumulators,
+};
+use radix_engine_interface::blueprints::vesting::VestingSchedule;
 
 use crate::prelude::*;
 
@@ -13,6 +18,8 @@ use crate::prelude::*;
 //  4. Access Controller
 //  5. Consensus Manager
 //  6. Validator
+//  7. Vesting
+//  8. Multisig
 // ================================================================================================
 
 extern_blueprint_internal! {
@@ -126,6 +133,83 @@ extern_blueprint_internal! {
     }
 }
 
+extern_blueprint_internal! {
+    POOL_PACKAGE,
+    ConstantProductPool,
+    "ConstantProductPool",
+    "OwnedConstantProductPool",
+    "GlobalConstantProductPool",
+    ConstantProductPoolFunctions
+    {
+        fn instantiate(
+            owner_role: OwnerRole,
+            pool_manager_rule: AccessRule,
+            resource_addresses: (ResourceAddress, ResourceAddress),
+            fees: ConstantProductPoolFees,
+            address_reservation: Option<GlobalAddressReservation>
+        ) -> Global<ConstantProductPool>;
+    },
+    {
+        fn contribute(&mut self, buckets: (Bucket, Bucket)) -> (Bucket, Option<Bucket>);
+        fn redeem(&mut self, bucket: Bucket) -> (Bucket, Bucket);
+        fn swap(&mut self, input_bucket: Bucket, min_output_amount: Decimal) -> Bucket;
+        fn get_swap_output_amount(&self, input_resource_address: ResourceAddress, input_amount: Decimal) -> Decimal;
+        fn get_vault_amounts(&self) -> BTreeMap<ResourceAddress, Decimal>;
+        fn get_price_accumulators(&self) -> ConstantProductPoolPriceAccumulators;
+        fn set_fees(&mut self, fees: ConstantProductPoolFees);
+        fn claim_protocol_fees(&mut self) -> (Bucket, Bucket);
+    }
+}
+
+extern_blueprint_internal! {
+    VESTING_PACKAGE,
+    Vesting,
+    "Vesting",
+    "OwnedVesting",
+    "GlobalVesting",
+    VestingFunctions
+    {
+        fn instantiate(
+            owner_role: OwnerRole,
+            beneficiary_rule: AccessRule,
+            revoker_rule: Option<AccessRule>,
+            schedule: VestingSchedule,
+            bucket: Bucket,
+            address_reservation: Option<GlobalAddressReservation>
+        ) -> Global<Vesting>;
+    },
+    {
+        fn claim(&mut self) -> Bucket;
+        fn get_claimable(&self) -> Decimal;
+        fn revoke(&mut self) -> Bucket;
+    }
+}
+
+extern_blueprint_internal! {
+    MULTISIG_PACKAGE,
+    Multisig,
+    "Multisig",
+    "OwnedMultisig",
+    "GlobalMultisig",
+    MultisigFunctions
+    {
+        fn instantiate(
+            signers: BTreeSet<NonFungibleGlobalId>,
+            threshold: u8,
+            destination: ComponentAddress,
+            address_reservation: Option<GlobalAddressReservation>
+        ) -> Global<Multisig>;
+    },
+    {
+        fn propose(&mut self, signer: NonFungibleGlobalId, target: GlobalAddress, method: String, args: ScryptoValue, expires_at: Instant) -> u64;
+        fn approve(&mut self, proposal_id: u64, signer: NonFungibleGlobalId);
+        fn execute(&mut self, proposal_id: u64, signer: NonFungibleGlobalId);
+        fn get_proposal(&self, proposal_id: u64) -> Option<MultisigProposal>;
+        fn update_signers(&mut self, signers: BTreeSet<NonFungibleGlobalId>, threshold: u8);
+        fn set_destination(&mut self, destination: ComponentAddress);
+    }
+}
+
 extern_blueprint_internal! {
     ACCOUNT_PACKAGE,
     Account,
@@ -140,7 +224,10 @@ extern_blueprint_internal! {
     {
         fn burn(&mut self, resource_address: ResourceAddress, amount: Decimal);
         fn burn_non_fungibles(&mut self, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>);
+        fn get_vaults(&self, cursor: u32, limit: u32) -> AccountVaultsPage;
+        fn balance_of(&self, resource_address: ResourceAddress) -> Decimal;
         fn set_default_deposit_rule(&self, default: DefaultDepositRule);
+        fn set_deposit_rule(&self, deposit_rule: Option<DepositRule>);
         fn set_resource_preference(&self, resource_address: ResourceAddress, resource_preference: ResourcePreference);
         fn remove_resource_preference(&self, resource_address: ResourceAddress);
         fn create_proof(&self, resource_address: ResourceAddress) -> Proof;
@@ -150,6 +237,7 @@ extern_blueprint_internal! {
         fn deposit_batch(&mut self, buckets: Vec<Bucket>);
         fn lock_contingent_fee(&mut self, amount: Decimal);
         fn lock_fee(&mut self, amount: Decimal);
+        fn lock_royalty_payment(&mut self, resource_address: ResourceAddress, amount: Decimal, max_per_function: Decimal);
         fn lock_fee_and_withdraw(&mut self, amount_to_lock: Decimal, resource_address: ResourceAddress, amount: Decimal) -> Bucket;
         fn lock_fee_and_withdraw_non_fungibles(&mut self, amount_to_lock: Decimal, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>) -> Bucket;
         fn securify(&mut self) -> Bucket;
@@ -246,6 +334,7 @@ extern_blueprint_internal! {
         fn apply_reward(&mut self, xrd_bucket: Bucket, epoch: Epoch);
         fn claim_xrd(&mut self, bucket: Bucket) -> Bucket;
         fn finish_unlock_owner_stake_units(&mut self) -> Bucket;
+        fn get_pending_unstakes(&self, claimant: ComponentAddress) -> Vec<ValidatorPendingUnstake>;
         fn get_redemption_value(&self, amount_of_stake_units: Decimal) -> Decimal;
         fn lock_owner_stake_units(&mut self, stake_unit_bucket: Bucket);
         fn register(&mut self);
@@ -256,8 +345,11 @@ extern_blueprint_internal! {
         fn total_stake_xrd_amount(&self) -> Decimal;
         fn unregister(&mut self);
         fn unstake(&mut self, stake_unit_bucket: Bucket) -> Bucket;
+        fn unstake_for_claimant(&mut self, stake_unit_bucket: Bucket, claimant: ComponentAddress) -> Bucket;
         fn update_accept_delegated_stake(&mut self, accept_delegated_stake: bool);
+        fn update_commission_tiers(&mut self, tiers: Vec<ValidatorCommissionTier>);
         fn update_fee(&mut self, new_fee_factor: Decimal);
         fn update_key(&mut self, key: Secp256k1PublicKey);
+        fn update_max_stake(&mut self, max_stake_xrd: Option<Decimal>);
     }
 }
@@ -26,6 +26,18 @@ pub fn forget_vec(vec: Vec<u8>) -> Slice {
     Slice::new(ptr as u32, len as u32)
 }
 
+/// Captures the coverage counters of this instance, to be dumped by the engine after each
+/// invocation.
+#[cfg(feature = "coverage")]
+#[no_mangle]
+pub extern "C" fn dump_coverage() -> Slice {
+    let mut coverage = Vec::new();
+    unsafe {
+        minicov::capture_coverage(&mut coverage).expect("Failed to capture coverage");
+    }
+    forget_vec(coverage)
+}
+
 /// Api make blueprint function calls
 pub mod blueprint {
     pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
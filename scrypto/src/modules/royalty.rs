@@ -4,19 +4,20 @@ use crate::runtime::*;
 use crate::*;
 use radix_engine_common::types::RoyaltyAmount;
 use radix_engine_interface::api::node_modules::royalty::{
-    ComponentClaimRoyaltiesInput, ComponentRoyaltyCreateInput, ComponentRoyaltyLockInput,
-    ComponentRoyaltySetInput, COMPONENT_ROYALTY_BLUEPRINT, COMPONENT_ROYALTY_CLAIMER_ROLE,
-    COMPONENT_ROYALTY_CLAIMER_UPDATER_ROLE, COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT,
-    COMPONENT_ROYALTY_CREATE_IDENT, COMPONENT_ROYALTY_LOCKER_ROLE,
-    COMPONENT_ROYALTY_LOCKER_UPDATER_ROLE, COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT,
-    COMPONENT_ROYALTY_SETTER_ROLE, COMPONENT_ROYALTY_SETTER_UPDATER_ROLE,
-    COMPONENT_ROYALTY_SET_ROYALTY_IDENT,
+    ComponentClaimResourceRoyaltiesInput, ComponentClaimRoyaltiesInput,
+    ComponentRoyaltyCreateInput, ComponentRoyaltyLockInput, ComponentRoyaltySetInput,
+    COMPONENT_ROYALTY_BLUEPRINT, COMPONENT_ROYALTY_CLAIMER_ROLE,
+    COMPONENT_ROYALTY_CLAIMER_UPDATER_ROLE, COMPONENT_ROYALTY_CLAIM_RESOURCE_ROYALTIES_IDENT,
+    COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT, COMPONENT_ROYALTY_CREATE_IDENT,
+    COMPONENT_ROYALTY_LOCKER_ROLE, COMPONENT_ROYALTY_LOCKER_UPDATER_ROLE,
+    COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT, COMPONENT_ROYALTY_SETTER_ROLE,
+    COMPONENT_ROYALTY_SETTER_UPDATER_ROLE, COMPONENT_ROYALTY_SET_ROYALTY_IDENT,
 };
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::blueprints::resource::Bucket;
 use radix_engine_interface::constants::ROYALTY_MODULE_PACKAGE;
 use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode};
-use radix_engine_interface::types::ComponentRoyaltyConfig;
+use radix_engine_interface::types::{ComponentRoyaltyConfig, ResourceAddress};
 use sbor::rust::string::ToString;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
@@ -87,6 +88,13 @@ impl Royalty {
             &ComponentClaimRoyaltiesInput {},
         )
     }
+
+    pub fn claim_resource_royalties(&self, resource_address: ResourceAddress) -> Bucket {
+        self.call(
+            COMPONENT_ROYALTY_CLAIM_RESOURCE_ROYALTIES_IDENT,
+            &ComponentClaimResourceRoyaltiesInput { resource_address },
+        )
+    }
 }
 
 pub struct RoyaltyRoles<T> {
@@ -261,6 +261,45 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Applies several updates to the mutable part of a non-fungible unit in a single call.
+    ///
+    /// # Panics
+    /// Panics if this is not a non-fungible resource, the specified non-fungible is not found,
+    /// or the updated data doesn't match the resource's non-fungible data schema.
+    pub fn update_non_fungible_data_fields(
+        &self,
+        id: &NonFungibleLocalId,
+        updates: Vec<NonFungibleDataUpdate>,
+    ) {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT,
+            &NonFungibleResourceManagerUpdateDataFieldsInput {
+                id: id.clone(),
+                updates,
+            },
+        )
+    }
+
+    /// Returns the values of the named fields of a non-fungible unit as a tuple, in the given
+    /// order, e.g. `let (level, tags): (u8, Vec<String>) = ...`.
+    ///
+    /// # Panics
+    /// Panics if this is not a non-fungible resource, the specified non-fungible is not found,
+    /// or a field name isn't part of the resource's non-fungible data schema.
+    pub fn get_non_fungible_data_fields<T: ScryptoDecode>(
+        &self,
+        id: &NonFungibleLocalId,
+        field_names: &[&str],
+    ) -> T {
+        self.call(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT,
+            &NonFungibleResourceManagerGetDataFieldsInput {
+                id: id.clone(),
+                field_names: field_names.iter().map(|name| name.to_string()).collect(),
+            },
+        )
+    }
+
     pub fn amount_for_withdrawal(
         &self,
         request_amount: Decimal,
@@ -56,6 +56,12 @@ pub trait ScryptoFungibleVault {
 
     fn lock_contingent_fee<A: Into<Decimal>>(&mut self, amount: A);
 
+    fn lock_royalty_payment<A: Into<Decimal>, M: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        max_per_function: M,
+    );
+
     fn create_proof_of_amount<A: Into<Decimal>>(&self, amount: A) -> FungibleProof;
 
     fn authorize_with_amount<A: Into<Decimal>, F: FnOnce() -> O, O>(&self, amount: A, f: F) -> O;
@@ -310,6 +316,27 @@ impl ScryptoFungibleVault for FungibleVault {
         );
     }
 
+    /// Locks the given amount of resource as a contingent royalty payment.
+    ///
+    /// Royalties denominated in this resource are charged from the locked amount if the
+    /// transaction succeeds; any unused amount is refunded to the original vault. No single
+    /// function or method may charge more than `max_per_function`.
+    fn lock_royalty_payment<A: Into<Decimal>, M: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        max_per_function: M,
+    ) {
+        let _rtn = ScryptoVmV1Api::object_call(
+            self.0 .0.as_node_id(),
+            FUNGIBLE_VAULT_LOCK_ROYALTY_PAYMENT_IDENT,
+            scrypto_encode(&FungibleVaultLockRoyaltyPaymentInput {
+                amount: amount.into(),
+                max_per_function: max_per_function.into(),
+            })
+            .unwrap(),
+        );
+    }
+
     fn create_proof_of_amount<A: Into<Decimal>>(&self, amount: A) -> FungibleProof {
         let rtn = ScryptoVmV1Api::object_call(
             self.0 .0.as_node_id(),
@@ -0,0 +1,9 @@
+#[cfg(windows)]
+use colored::*;
+use simulator::rtms;
+
+pub fn main() -> Result<(), rtms::Error> {
+    #[cfg(windows)]
+    control::set_virtual_terminal(true).unwrap();
+    rtms::run()
+}
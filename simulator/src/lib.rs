@@ -6,6 +6,8 @@ pub mod resim;
 pub mod rtmc;
 /// Radix transaction manifest decompiler CLI.
 pub mod rtmd;
+/// Radix transaction multi-party signing CLI.
+pub mod rtms;
 /// Scrypto CLI.
 pub mod scrypto;
 /// Utility functions.
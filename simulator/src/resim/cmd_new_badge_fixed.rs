@@ -39,7 +39,7 @@ pub struct NewBadgeFixed {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys used for signing, or `remote:<command>` signers, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
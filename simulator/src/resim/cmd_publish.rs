@@ -54,8 +54,15 @@ impl Publish {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         // Load wasm code
         let (code_path, definition_path) = if self.path.extension() != Some(OsStr::new("wasm")) {
-            build_package(&self.path, false, false, self.disable_wasm_opt)
-                .map_err(Error::BuildError)?
+            build_package(
+                &self.path,
+                false,
+                false,
+                self.disable_wasm_opt,
+                false,
+                &CargoBuildArgs::default(),
+            )
+            .map_err(Error::BuildError)?
         } else {
             let code_path = self.path.clone();
             let schema_path = code_path.with_extension("schema");
@@ -3,9 +3,11 @@ use radix_engine::utils::validate_call_arguments_to_native_components;
 use regex::{Captures, Regex};
 use std::env;
 use std::path::PathBuf;
-use transaction::manifest::BlobProvider;
+use transaction::manifest::{compile_with_parameters, BlobProvider};
+use transaction::validation::validate_manifest_lifecycle;
 
 use crate::resim::*;
+use crate::utils::*;
 
 /// Compiles, signs and runs a transaction manifest
 #[derive(Parser, Debug)]
@@ -21,7 +23,15 @@ pub struct Run {
     #[clap(short, long, multiple = true)]
     pub blobs: Option<Vec<String>>,
 
-    /// The private keys used for signing, separated by comma
+    /// The values of the manifest parameters, as `<name>=<value>`
+    #[clap(short = 'p', long = "param", multiple = true)]
+    pub params: Option<Vec<String>>,
+
+    /// The path to a file with the values of the manifest parameters, one `<name>=<value>` per line
+    #[clap(long)]
+    pub params_file: Option<PathBuf>,
+
+    /// The private keys used for signing, or `remote:<command>` signers, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
@@ -52,15 +62,23 @@ impl Run {
                 blobs.push(std::fs::read(path).map_err(Error::IOError)?);
             }
         }
-        let compiled_manifest = transaction::manifest::compile(
+        let parameters = load_manifest_parameters(
+            self.params_file.as_deref(),
+            self.params.as_deref().unwrap_or_default(),
+        )
+        .map_err(Error::ManifestParametersError)?;
+        let compiled_manifest = compile_with_parameters(
             &pre_processed_manifest,
             &network,
             BlobProvider::new_with_blobs(blobs),
+            parameters,
         )
         .map_err(Error::CompileError)?;
 
         validate_call_arguments_to_native_components(&compiled_manifest.instructions)
             .map_err(Error::InstructionSchemaValidationError)?;
+        validate_manifest_lifecycle(&compiled_manifest.instructions)
+            .map_err(Error::ManifestLifecycleError)?;
 
         handle_manifest(
             compiled_manifest,
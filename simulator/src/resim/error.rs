@@ -61,12 +61,16 @@ pub enum Error {
 
     CompileError(transaction::manifest::CompileError),
 
+    ManifestParametersError(ManifestParametersError),
+
     DecompileError(transaction::manifest::DecompileError),
 
     InvalidId(String),
 
     InvalidPrivateKey,
 
+    SigningError(SigningError),
+
     NonFungibleGlobalIdError(ParseNonFungibleGlobalIdError),
 
     FailedToBuildArguments(BuildCallArgumentError),
@@ -77,5 +81,7 @@ pub enum Error {
 
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
 
+    ManifestLifecycleError(Vec<transaction::errors::LocatedManifestLifecycleError>),
+
     InvalidResourceSpecifier(String),
 }
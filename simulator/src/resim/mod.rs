@@ -52,6 +52,7 @@ pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
 
+use crate::utils::RemoteSigner;
 use clap::{Parser, Subcommand};
 use radix_engine::blueprints::consensus_manager::{
     ConsensusManagerSubstate, ProposerMilliTimestampSubstate, ProposerMinuteTimestampSubstate,
@@ -262,12 +263,9 @@ pub fn handle_manifest<O: std::io::Write>(
             .bootstrap_test_default();
 
             let sks = get_signing_keys(signing_keys)?;
-            let initial_proofs = sks
-                .into_iter()
-                .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
-                .collect::<BTreeSet<NonFungibleGlobalId>>();
             let nonce = get_nonce()?;
             let transaction = TestTransaction::new_from_nonce(manifest, nonce);
+            let initial_proofs = get_signature_proofs(&sks, &transaction.hash)?;
 
             let receipt = execute_and_commit_transaction(
                 &mut substate_db,
@@ -316,27 +314,52 @@ pub fn process_receipt(receipt: TransactionReceipt) -> Result<TransactionReceipt
     }
 }
 
-pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Secp256k1PrivateKey>, Error> {
+/// Parses the comma-separated signers: hex-encoded Secp256k1 private keys, or `remote:<command>`
+/// for a signer process speaking the protocol of [`RemoteSigner`].
+pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Box<dyn Signer>>, Error> {
     let private_keys = if let Some(keys) = signing_keys {
         keys.split(",")
             .map(str::trim)
             .filter(|s| !s.is_empty())
-            .map(|key| {
+            .map(|key| -> Result<Box<dyn Signer>, Error> {
+                if let Some(command) = key.strip_prefix("remote:") {
+                    return RemoteSigner::spawn_command(command)
+                        .map(|signer| Box::new(signer) as Box<dyn Signer>)
+                        .map_err(Error::SigningError);
+                }
                 hex::decode(key)
                     .map_err(|_| Error::InvalidPrivateKey)
                     .and_then(|bytes| {
                         Secp256k1PrivateKey::from_bytes(&bytes)
                             .map_err(|_| Error::InvalidPrivateKey)
                     })
+                    .map(|private_key| Box::new(private_key) as Box<dyn Signer>)
             })
-            .collect::<Result<Vec<Secp256k1PrivateKey>, Error>>()?
+            .collect::<Result<Vec<Box<dyn Signer>>, Error>>()?
     } else {
-        vec![get_default_private_key()?]
+        vec![Box::new(get_default_private_key()?) as Box<dyn Signer>]
     };
 
     Ok(private_keys)
 }
 
+/// Has every signer sign the transaction hash, and returns the signature proofs of the signers.
+/// A signer which refuses to sign, or returns an invalid signature, fails the transaction.
+pub fn get_signature_proofs(
+    signers: &[Box<dyn Signer>],
+    transaction_hash: &Hash,
+) -> Result<BTreeSet<NonFungibleGlobalId>, Error> {
+    signers
+        .iter()
+        .map(|signer| {
+            signer
+                .try_sign_with_public_key(transaction_hash)
+                .map(|_| NonFungibleGlobalId::from_public_key(&signer.public_key()))
+                .map_err(Error::SigningError)
+        })
+        .collect()
+}
+
 pub fn export_package_schema(
     package_address: PackageAddress,
 ) -> Result<BTreeMap<BlueprintVersionKey, BlueprintDefinition>, Error> {
@@ -1,8 +1,10 @@
+use crate::utils::*;
 use clap::Parser;
 use radix_engine::{types::*, utils::*};
 use std::path::PathBuf;
 use std::str::FromStr;
-use transaction::manifest::{compile, BlobProvider};
+use transaction::manifest::{compile_with_parameters, BlobProvider};
+use transaction::validation::validate_manifest_lifecycle;
 
 /// Radix transaction manifest compiler
 #[derive(Parser, Debug)]
@@ -20,6 +22,14 @@ pub struct Args {
     #[clap(short, long, multiple = true)]
     blobs: Option<Vec<String>>,
 
+    /// The values of the manifest parameters, as `<name>=<value>`
+    #[clap(short = 'p', long = "param", multiple = true)]
+    params: Option<Vec<String>>,
+
+    /// The path to a file with the values of the manifest parameters, one `<name>=<value>` per line
+    #[clap(long)]
+    params_file: Option<PathBuf>,
+
     /// Input file
     #[clap(required = true)]
     input: PathBuf,
@@ -30,8 +40,10 @@ pub enum Error {
     IoError(std::io::Error),
     EncodeError(sbor::EncodeError),
     CompileError(transaction::manifest::CompileError),
+    ManifestParametersError(ManifestParametersError),
     ParseNetworkError(ParseNetworkError),
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
+    ManifestLifecycleError(Vec<transaction::errors::LocatedManifestLifecycleError>),
 }
 
 pub fn run() -> Result<(), Error> {
@@ -48,10 +60,22 @@ pub fn run() -> Result<(), Error> {
             blobs.push(std::fs::read(path).map_err(Error::IoError)?);
         }
     }
-    let transaction = compile(&content, &network, BlobProvider::new_with_blobs(blobs))
-        .map_err(Error::CompileError)?;
+    let parameters = load_manifest_parameters(
+        args.params_file.as_deref(),
+        args.params.as_deref().unwrap_or_default(),
+    )
+    .map_err(Error::ManifestParametersError)?;
+    let transaction = compile_with_parameters(
+        &content,
+        &network,
+        BlobProvider::new_with_blobs(blobs),
+        parameters,
+    )
+    .map_err(Error::CompileError)?;
     validate_call_arguments_to_native_components(&transaction.instructions)
         .map_err(Error::InstructionSchemaValidationError)?;
+    validate_manifest_lifecycle(&transaction.instructions)
+        .map_err(Error::ManifestLifecycleError)?;
     std::fs::write(
         args.output,
         manifest_encode(&transaction).map_err(Error::EncodeError)?,
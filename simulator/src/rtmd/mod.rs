@@ -1,3 +1,4 @@
+use crate::utils::display_public_key;
 use clap::Parser;
 use radix_engine::types::*;
 use radix_engine::utils::validate_call_arguments_to_native_components;
@@ -7,6 +8,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use transaction::manifest::decompile;
 use transaction::prelude::*;
+use transaction::validation::{recover, verify};
 
 /// Radix transaction manifest decompiler
 #[derive(Parser, Debug)]
@@ -24,39 +26,54 @@ pub struct Args {
     #[clap(short, long, action)]
     export_blobs: bool,
 
-    /// Input file
+    /// Input file, containing a compiled manifest or a transaction payload (raw or hex-encoded),
+    /// or a hex-encoded transaction payload
     #[clap(required = true)]
-    input: PathBuf,
+    input: String,
 }
 
 #[derive(Debug)]
 pub enum Error {
     IoError(std::io::Error),
+    HexDecodeError(hex::FromHexError),
     DecodeError(sbor::DecodeError),
     DecompileError(transaction::manifest::DecompileError),
     ParseNetworkError(ParseNetworkError),
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
+    PrepareError(transaction::model::PrepareError),
+    HashEncodeError(TransactionHashBech32EncodeError),
 }
 
 pub fn run() -> Result<(), Error> {
     let args = Args::parse();
 
-    let content = std::fs::read(&args.input).map_err(Error::IoError)?;
+    let content = read_input(&args.input)?;
     let network = match args.network {
         Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
         None => NetworkDefinition::simulator(),
     };
-    let manifest =
-        manifest_decode::<TransactionManifestV1>(&content).map_err(Error::DecodeError)?;
-    validate_call_arguments_to_native_components(&manifest.instructions)
+
+    // A compiled manifest is a tuple, while a transaction payload is a versioned enum
+    let (instructions, blobs) = match manifest_decode::<TransactionManifestV1>(&content) {
+        Ok(manifest) => (
+            manifest.instructions,
+            manifest.blobs.into_values().collect(),
+        ),
+        Err(manifest_error) => {
+            let payload = manifest_decode::<VersionedTransactionPayload>(&content)
+                .map_err(|_| Error::DecodeError(manifest_error))?;
+            dump_transaction_payload(payload, &network, &mut std::io::stdout())?
+        }
+    };
+    validate_call_arguments_to_native_components(&instructions)
         .map_err(Error::InstructionSchemaValidationError)?;
 
-    let result = decompile(&manifest.instructions, &network).map_err(Error::DecompileError)?;
+    let result = decompile(&instructions, &network).map_err(Error::DecompileError)?;
     std::fs::write(&args.output, &result).map_err(Error::IoError)?;
 
     if args.export_blobs {
         let directory = args.output.parent().unwrap();
-        for blob in manifest.blobs.values() {
+        for blob in blobs {
             let blob_hash = hash(&blob);
             std::fs::write(directory.join(format!("{}.blob", blob_hash)), &blob)
                 .map_err(Error::IoError)?;
@@ -65,3 +82,253 @@ pub fn run() -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Reads the input bytes from a file (raw or hex-encoded), or from the hex-encoded argument.
+fn read_input(input: &str) -> Result<Vec<u8>, Error> {
+    let path = PathBuf::from(input);
+    if path.is_file() {
+        let content = std::fs::read(&path).map_err(Error::IoError)?;
+        match std::str::from_utf8(&content) {
+            Ok(text) if is_hex(text.trim()) => {
+                hex::decode(text.trim()).map_err(Error::HexDecodeError)
+            }
+            _ => Ok(content),
+        }
+    } else {
+        hex::decode(input.trim()).map_err(Error::HexDecodeError)
+    }
+}
+
+fn is_hex(text: &str) -> bool {
+    !text.is_empty() && text.len() % 2 == 0 && text.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Prints the header, message, signatures and hashes of a transaction payload, and returns its
+/// instructions and blobs.
+pub fn dump_transaction_payload<O: std::io::Write>(
+    payload: VersionedTransactionPayload,
+    network: &NetworkDefinition,
+    out: &mut O,
+) -> Result<(Vec<InstructionV1>, Vec<Vec<u8>>), Error> {
+    let encoder = TransactionHashBech32Encoder::new(network);
+    let encode = |result: Result<String, TransactionHashBech32EncodeError>| {
+        result.map_err(Error::HashEncodeError)
+    };
+
+    let (intent, intent_signatures, notary_signature) = match payload {
+        VersionedTransactionPayload::IntentV1 {
+            header,
+            instructions,
+            blobs,
+            message,
+        } => (
+            IntentV1 {
+                header,
+                instructions,
+                blobs,
+                message,
+            },
+            None,
+            None,
+        ),
+        VersionedTransactionPayload::SignedIntentV1 {
+            intent,
+            intent_signatures,
+        } => (intent, Some(intent_signatures), None),
+        VersionedTransactionPayload::NotarizedTransactionV1 {
+            signed_intent,
+            notary_signature,
+        } => (
+            signed_intent.intent,
+            Some(signed_intent.intent_signatures),
+            Some(notary_signature),
+        ),
+        VersionedTransactionPayload::SystemTransactionV1 {
+            instructions,
+            blobs,
+            pre_allocated_addresses,
+            hash_for_execution,
+        } => {
+            let transaction = SystemTransactionV1 {
+                instructions,
+                blobs,
+                pre_allocated_addresses,
+                hash_for_execution,
+            };
+            let prepared = transaction.prepare().map_err(Error::PrepareError)?;
+            writeln!(out, "Payload: System Transaction V1").map_err(Error::IoError)?;
+            writeln!(
+                out,
+                "System Transaction Hash: {}",
+                encode(encoder.encode(&prepared.system_transaction_hash()))?
+            )
+            .map_err(Error::IoError)?;
+            writeln!(
+                out,
+                "Hash For Execution: {}",
+                transaction.hash_for_execution
+            )
+            .map_err(Error::IoError)?;
+            let address_encoder = AddressBech32Encoder::new(network);
+            for address in &transaction.pre_allocated_addresses {
+                writeln!(
+                    out,
+                    "Pre-allocated Address: {} ({}:{})",
+                    address.address.display(&address_encoder),
+                    address
+                        .blueprint_id
+                        .package_address
+                        .display(&address_encoder),
+                    address.blueprint_id.blueprint_name
+                )
+                .map_err(Error::IoError)?;
+            }
+            return Ok((
+                transaction.instructions.0,
+                transaction
+                    .blobs
+                    .blobs
+                    .into_iter()
+                    .map(|blob| blob.0)
+                    .collect(),
+            ));
+        }
+    };
+
+    let is_signed = intent_signatures.is_some();
+    let kind = match (is_signed, &notary_signature) {
+        (false, _) => "Intent V1",
+        (true, None) => "Signed Intent V1",
+        (true, Some(_)) => "Notarized Transaction V1",
+    };
+    writeln!(out, "Payload: {}", kind).map_err(Error::IoError)?;
+
+    let header = &intent.header;
+    writeln!(out, "Header:").map_err(Error::IoError)?;
+    writeln!(out, "  Network Id: {}", header.network_id).map_err(Error::IoError)?;
+    writeln!(
+        out,
+        "  Epoch Range: [{}, {})",
+        header.start_epoch_inclusive.number(),
+        header.end_epoch_exclusive.number()
+    )
+    .map_err(Error::IoError)?;
+    writeln!(out, "  Nonce: {}", header.nonce).map_err(Error::IoError)?;
+    writeln!(
+        out,
+        "  Notary Public Key: {}",
+        display_public_key(&header.notary_public_key)
+    )
+    .map_err(Error::IoError)?;
+    writeln!(out, "  Notary Is Signatory: {}", header.notary_is_signatory)
+        .map_err(Error::IoError)?;
+    writeln!(out, "  Tip Percentage: {}", header.tip_percentage).map_err(Error::IoError)?;
+
+    match &intent.message {
+        MessageV1::None => writeln!(out, "Message: None"),
+        MessageV1::Plaintext(message) => match &message.message {
+            MessageContentsV1::String(text) => {
+                writeln!(out, "Message ({}): {:?}", message.mime_type, text)
+            }
+            MessageContentsV1::Bytes(bytes) => writeln!(
+                out,
+                "Message ({}): 0x{}",
+                message.mime_type,
+                hex::encode(bytes)
+            ),
+        },
+        MessageV1::Encrypted(message) => writeln!(
+            out,
+            "Message: Encrypted ({} bytes) for {} decryptor(s)",
+            message.encrypted.0.len(),
+            message
+                .decryptors_by_curve
+                .values()
+                .map(|decryptors| decryptors.number_of_decryptors())
+                .sum::<usize>()
+        ),
+    }
+    .map_err(Error::IoError)?;
+
+    let signed_intent = SignedIntentV1 {
+        intent,
+        intent_signatures: intent_signatures.unwrap_or(IntentSignaturesV1 { signatures: vec![] }),
+    };
+    let prepared_intent = signed_intent
+        .intent
+        .prepare()
+        .map_err(Error::PrepareError)?;
+    let intent_hash = prepared_intent.intent_hash();
+    writeln!(
+        out,
+        "Intent Hash: {}",
+        encode(encoder.encode(&intent_hash))?
+    )
+    .map_err(Error::IoError)?;
+
+    if is_signed {
+        writeln!(
+            out,
+            "Intent Signatures: {}",
+            signed_intent.intent_signatures.signatures.len()
+        )
+        .map_err(Error::IoError)?;
+        for signature in &signed_intent.intent_signatures.signatures {
+            let signer = recover(&intent_hash.into_hash(), &signature.0).filter(|public_key| {
+                verify(
+                    &intent_hash.into_hash(),
+                    public_key,
+                    &signature.0.signature(),
+                )
+            });
+            match signer {
+                Some(public_key) => {
+                    writeln!(out, "  Signer: {}", display_public_key(&public_key))
+                }
+                None => writeln!(out, "  Signer: <invalid signature>"),
+            }
+            .map_err(Error::IoError)?;
+        }
+
+        let prepared_signed_intent = signed_intent.prepare().map_err(Error::PrepareError)?;
+        let signed_intent_hash = prepared_signed_intent.signed_intent_hash();
+        writeln!(
+            out,
+            "Signed Intent Hash: {}",
+            encode(encoder.encode(&signed_intent_hash))?
+        )
+        .map_err(Error::IoError)?;
+
+        if let Some(notary_signature) = notary_signature {
+            let is_valid = verify(
+                &signed_intent_hash.into_hash(),
+                &signed_intent.intent.header.notary_public_key,
+                &notary_signature.0,
+            );
+            writeln!(
+                out,
+                "Notary Signature: {}",
+                if is_valid { "valid" } else { "invalid" }
+            )
+            .map_err(Error::IoError)?;
+
+            let transaction = NotarizedTransactionV1 {
+                signed_intent: signed_intent.clone(),
+                notary_signature,
+            };
+            let prepared = transaction.prepare().map_err(Error::PrepareError)?;
+            writeln!(
+                out,
+                "Notarized Transaction Hash: {}",
+                encode(encoder.encode(&prepared.notarized_transaction_hash()))?
+            )
+            .map_err(Error::IoError)?;
+        }
+    }
+
+    let intent = signed_intent.intent;
+    Ok((
+        intent.instructions.0,
+        intent.blobs.blobs.into_iter().map(|blob| blob.0).collect(),
+    ))
+}
@@ -0,0 +1,306 @@
+use crate::utils::display_public_key;
+use clap::{Parser, Subcommand};
+use radix_engine::types::*;
+use radix_engine::utils::validate_call_arguments_to_native_components;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use transaction::manifest::{compile, BlobProvider};
+use transaction::prelude::*;
+use transaction::validation::validate_manifest_lifecycle;
+
+/// Radix transaction multi-party signing tool
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "rtms")]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    Create(Create),
+    Sign(Sign),
+    Merge(Merge),
+    Inspect(Inspect),
+    Notarize(Notarize),
+}
+
+/// Create a partially signed transaction from a manifest, without any signatures
+#[derive(Parser, Debug)]
+pub struct Create {
+    /// Path to the transaction manifest
+    manifest: PathBuf,
+
+    /// Path to the output file
+    #[clap(short, long)]
+    output: PathBuf,
+
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    blobs: Option<Vec<String>>,
+
+    /// The hex-encoded public key of the notary
+    #[clap(long)]
+    notary_public_key: String,
+
+    /// Whether the notary counts as a signatory
+    #[clap(long, action)]
+    notary_is_signatory: bool,
+
+    /// The hex-encoded public keys expected to sign the intent
+    #[clap(long, multiple = true)]
+    signers: Option<Vec<String>>,
+
+    /// The first epoch in which the transaction can be committed
+    #[clap(long)]
+    start_epoch: u64,
+
+    /// The epoch from which the transaction can no longer be committed
+    #[clap(long)]
+    end_epoch: u64,
+
+    /// The nonce of the intent
+    #[clap(long)]
+    nonce: u32,
+
+    /// The tip percentage
+    #[clap(long, default_value = "0")]
+    tip_percentage: u16,
+}
+
+/// Sign a partially signed transaction with the private key in a keystore
+#[derive(Parser, Debug)]
+pub struct Sign {
+    /// Path to the partially signed transaction, which is updated in place
+    transaction: PathBuf,
+
+    /// Path to the keystore file, containing a hex-encoded Secp256k1 private key
+    #[clap(short, long)]
+    keystore: PathBuf,
+}
+
+/// Merge the signatures of partially signed transactions of the same intent
+#[derive(Parser, Debug)]
+pub struct Merge {
+    /// Paths to the partially signed transactions
+    #[clap(required = true, min_values = 2)]
+    transactions: Vec<PathBuf>,
+
+    /// Path to the output file
+    #[clap(short, long)]
+    output: PathBuf,
+}
+
+/// Show the intent hash, the signers and the missing signers of a partially signed transaction
+#[derive(Parser, Debug)]
+pub struct Inspect {
+    /// Path to the partially signed transaction
+    transaction: PathBuf,
+
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+}
+
+/// Notarize a fully signed transaction, writing the hex-encoded notarized transaction payload
+#[derive(Parser, Debug)]
+pub struct Notarize {
+    /// Path to the partially signed transaction
+    transaction: PathBuf,
+
+    /// Path to the keystore file of the notary, containing a hex-encoded Secp256k1 private key
+    #[clap(short, long)]
+    keystore: PathBuf,
+
+    /// Path to the output file
+    #[clap(short, long)]
+    output: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IoError(std::io::Error),
+    HexDecodeError(hex::FromHexError),
+    EncodeError(sbor::EncodeError),
+    DecodeError(sbor::DecodeError),
+    CompileError(transaction::manifest::CompileError),
+    ParseNetworkError(ParseNetworkError),
+    InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
+    ManifestLifecycleError(Vec<transaction::errors::LocatedManifestLifecycleError>),
+    InvalidPublicKey(String),
+    InvalidPrivateKey,
+    PartialSigningError(transaction::errors::PartialSigningError),
+    HashEncodeError(TransactionHashBech32EncodeError),
+}
+
+pub fn run() -> Result<(), Error> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Create(cmd) => cmd.run(),
+        Command::Sign(cmd) => cmd.run(),
+        Command::Merge(cmd) => cmd.run(),
+        Command::Inspect(cmd) => cmd.run(),
+        Command::Notarize(cmd) => cmd.run(),
+    }
+}
+
+impl Create {
+    pub fn run(&self) -> Result<(), Error> {
+        let network = parse_network(&self.network)?;
+        let content = std::fs::read_to_string(&self.manifest).map_err(Error::IoError)?;
+        let mut blobs = Vec::new();
+        if let Some(paths) = &self.blobs {
+            for path in paths {
+                blobs.push(std::fs::read(path).map_err(Error::IoError)?);
+            }
+        }
+        let manifest = compile(&content, &network, BlobProvider::new_with_blobs(blobs))
+            .map_err(Error::CompileError)?;
+        validate_call_arguments_to_native_components(&manifest.instructions)
+            .map_err(Error::InstructionSchemaValidationError)?;
+        validate_manifest_lifecycle(&manifest.instructions)
+            .map_err(Error::ManifestLifecycleError)?;
+
+        let (instructions, blobs) = manifest.for_intent();
+        let intent = IntentV1 {
+            header: TransactionHeaderV1 {
+                network_id: network.id,
+                start_epoch_inclusive: Epoch::of(self.start_epoch),
+                end_epoch_exclusive: Epoch::of(self.end_epoch),
+                nonce: self.nonce,
+                notary_public_key: parse_public_key(&self.notary_public_key)?,
+                notary_is_signatory: self.notary_is_signatory,
+                tip_percentage: self.tip_percentage,
+            },
+            instructions,
+            blobs,
+            message: MessageV1::None,
+        };
+        let required_signers = self
+            .signers
+            .iter()
+            .flatten()
+            .map(|public_key| parse_public_key(public_key))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        write_transaction(
+            &self.output,
+            &PartiallySignedTransactionV1::new(intent, required_signers),
+        )
+    }
+}
+
+impl Sign {
+    pub fn run(&self) -> Result<(), Error> {
+        let mut transaction = read_transaction(&self.transaction)?;
+        let private_key = read_keystore(&self.keystore)?;
+        transaction
+            .sign(&private_key)
+            .map_err(Error::PartialSigningError)?;
+        write_transaction(&self.transaction, &transaction)
+    }
+}
+
+impl Merge {
+    pub fn run(&self) -> Result<(), Error> {
+        let mut transactions = self.transactions.iter().map(|path| read_transaction(path));
+        let mut merged = transactions.next().unwrap()?;
+        for transaction in transactions {
+            merged
+                .merge(transaction?)
+                .map_err(Error::PartialSigningError)?;
+        }
+        write_transaction(&self.output, &merged)
+    }
+}
+
+impl Inspect {
+    pub fn run(&self) -> Result<(), Error> {
+        let network = parse_network(&self.network)?;
+        let transaction = read_transaction(&self.transaction)?;
+        let intent_hash = transaction
+            .intent_hash()
+            .map_err(Error::PartialSigningError)?;
+        let signers = transaction.signers().map_err(Error::PartialSigningError)?;
+        let missing_signers = transaction
+            .missing_signers()
+            .map_err(Error::PartialSigningError)?;
+
+        println!(
+            "Intent Hash: {}",
+            TransactionHashBech32Encoder::new(&network)
+                .encode(&intent_hash)
+                .map_err(Error::HashEncodeError)?
+        );
+        println!(
+            "Notary Public Key: {}",
+            display_public_key(&transaction.intent.header.notary_public_key)
+        );
+        println!("Signers: {}", signers.len());
+        for public_key in &signers {
+            println!("  {}", display_public_key(public_key));
+        }
+        println!("Missing Signers: {}", missing_signers.len());
+        for public_key in &missing_signers {
+            println!("  {}", display_public_key(public_key));
+        }
+        Ok(())
+    }
+}
+
+impl Notarize {
+    pub fn run(&self) -> Result<(), Error> {
+        let transaction = read_transaction(&self.transaction)?;
+        let private_key = read_keystore(&self.keystore)?;
+        let notarized = transaction
+            .notarize(&private_key)
+            .map_err(Error::PartialSigningError)?;
+        let payload = notarized.to_payload_bytes().map_err(Error::EncodeError)?;
+        std::fs::write(&self.output, hex::encode(payload)).map_err(Error::IoError)
+    }
+}
+
+fn parse_network(network: &Option<String>) -> Result<NetworkDefinition, Error> {
+    match network {
+        Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError),
+        None => Ok(NetworkDefinition::simulator()),
+    }
+}
+
+/// Parses a hex-encoded public key, telling the curves apart by the key length.
+fn parse_public_key(public_key: &str) -> Result<PublicKey, Error> {
+    let invalid = || Error::InvalidPublicKey(public_key.to_owned());
+    let bytes = hex::decode(public_key.trim()).map_err(|_| invalid())?;
+    match bytes.len() {
+        Secp256k1PublicKey::LENGTH => Secp256k1PublicKey::try_from(bytes.as_slice())
+            .map(Into::into)
+            .map_err(|_| invalid()),
+        Ed25519PublicKey::LENGTH => Ed25519PublicKey::try_from(bytes.as_slice())
+            .map(Into::into)
+            .map_err(|_| invalid()),
+        _ => Err(invalid()),
+    }
+}
+
+fn read_keystore(path: &Path) -> Result<Secp256k1PrivateKey, Error> {
+    let content = std::fs::read_to_string(path).map_err(Error::IoError)?;
+    let bytes = hex::decode(content.trim()).map_err(|_| Error::InvalidPrivateKey)?;
+    Secp256k1PrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)
+}
+
+/// Partially signed transactions are stored as hex, so they can be passed around as text.
+fn read_transaction(path: &Path) -> Result<PartiallySignedTransactionV1, Error> {
+    let content = std::fs::read_to_string(path).map_err(Error::IoError)?;
+    let bytes = hex::decode(content.trim()).map_err(Error::HexDecodeError)?;
+    manifest_decode(&bytes).map_err(Error::DecodeError)
+}
+
+fn write_transaction(path: &Path, transaction: &PartiallySignedTransactionV1) -> Result<(), Error> {
+    let bytes = manifest_encode(transaction).map_err(Error::EncodeError)?;
+    std::fs::write(path, hex::encode(bytes)).map_err(Error::IoError)
+}
@@ -1,10 +1,16 @@
 use clap::Parser;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
 use std::env::current_dir;
+use std::fs;
 use std::path::PathBuf;
 
 use crate::scrypto::*;
 use crate::utils::*;
 
+/// The number of largest functions listed in the size report.
+const SIZE_REPORT_LARGEST_FUNCTIONS: usize = 10;
+
 /// Build a Scrypto package
 #[derive(Parser, Debug)]
 pub struct Build {
@@ -19,17 +25,90 @@ pub struct Build {
     /// When passed, this argument disables wasm-opt from running on the built wasm.
     #[clap(long)]
     disable_wasm_opt: bool,
+
+    /// Build reproducibly, by pinning the toolchain and remapping machine-specific paths.
+    /// The toolchain is pinned in the package's `rust-toolchain.toml`, if it doesn't pin one yet.
+    #[clap(long)]
+    reproducible: bool,
+
+    /// Space or comma separated list of features to activate
+    #[clap(short = 'F', long)]
+    features: Vec<String>,
+
+    /// Do not activate the `default` feature
+    #[clap(long)]
+    no_default_features: bool,
+
+    /// Build artifacts with the specified profile
+    #[clap(long)]
+    profile: Option<String>,
+
+    /// Set an environment variable for the cargo invocation, as KEY=VALUE
+    #[clap(short, long, parse(try_from_str = parse_env_var))]
+    env: Vec<(String, String)>,
+
+    /// The toolchain channel to build with in reproducible mode, if not pinned by the package
+    #[clap(long, requires = "reproducible")]
+    toolchain: Option<String>,
+
+    /// Keep the function names and line info in the built WASM, for debug execution backtraces
+    #[clap(long)]
+    debug: bool,
+
+    /// Do not print the size report of the built WASM
+    #[clap(long)]
+    no_size_report: bool,
 }
 
 impl Build {
     pub fn run(&self) -> Result<(), Error> {
-        build_package(
-            self.path.clone().unwrap_or(current_dir().unwrap()),
+        let base_path = self.path.clone().unwrap_or(current_dir().unwrap());
+        if self.reproducible {
+            let (channel, written) =
+                pin_toolchain(&base_path, self.toolchain.as_deref()).map_err(Error::BuildError)?;
+            if written {
+                println!(
+                    "Pinned the toolchain {} in {}, commit it along with the package sources",
+                    channel,
+                    base_path.join(TOOLCHAIN_FILE_NAME).display()
+                );
+            }
+        }
+
+        let (wasm_path, definition_path) = build_package(
+            base_path,
             self.trace,
             false,
             self.disable_wasm_opt,
+            self.reproducible,
+            &CargoBuildArgs {
+                features: self.features.clone(),
+                no_default_features: self.no_default_features,
+                profile: self.profile.clone(),
+                env: self.env.clone(),
+                debug: self.debug,
+                toolchain: self.toolchain.clone(),
+            },
         )
-        .map(|_| ())
-        .map_err(Error::BuildError)
+        .map_err(Error::BuildError)?;
+
+        if !self.no_size_report {
+            let code = fs::read(&wasm_path).map_err(Error::IOError)?;
+            let definition: PackageDefinition =
+                manifest_decode(&fs::read(&definition_path).map_err(Error::IOError)?)
+                    .map_err(Error::SborDecodeError)?;
+            WasmSizeReport::new(&code, &definition)
+                .map_err(Error::SizeReportError)?
+                .print(SIZE_REPORT_LARGEST_FUNCTIONS, &mut std::io::stdout())
+                .map_err(Error::IOError)?;
+        }
+
+        Ok(())
     }
 }
+
+fn parse_env_var(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .ok_or(format!("invalid KEY=VALUE: no `=` found in `{}`", s))
+}
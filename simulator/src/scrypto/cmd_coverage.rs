@@ -0,0 +1,35 @@
+use clap::Parser;
+use std::env::current_dir;
+use std::path::PathBuf;
+
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// Run Scrypto tests with coverage instrumentation and write an lcov report
+#[derive(Parser, Debug)]
+pub struct Coverage {
+    /// The arguments to be passed to the test executable
+    arguments: Vec<String>,
+
+    /// The package directory
+    #[clap(long)]
+    path: Option<PathBuf>,
+
+    /// The nightly toolchain channel to build the instrumented package with, if not pinned by the
+    /// package
+    #[clap(long)]
+    toolchain: Option<String>,
+}
+
+impl Coverage {
+    pub fn run(&self) -> Result<(), Error> {
+        let report_path = coverage_package(
+            self.path.clone().unwrap_or(current_dir().unwrap()),
+            self.arguments.clone(),
+            self.toolchain.as_deref(),
+        )
+        .map_err(Error::CoverageError)?;
+        println!("Coverage report written to {}", report_path.display());
+        Ok(())
+    }
+}
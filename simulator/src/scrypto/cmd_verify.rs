@@ -0,0 +1,97 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::CodeHash;
+use radix_engine_queries::typed_substate_layout::*;
+use radix_engine_store_interface::db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper};
+use radix_engine_stores::rocks_db::RocksdbSubstateStore;
+use std::env::current_dir;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::resim::{get_data_dir, SimulatorPackageAddress};
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// Rebuild a Scrypto package reproducibly and verify its code hash against a published package
+#[derive(Parser, Debug)]
+pub struct Verify {
+    /// The address of the published package, looked up in the simulator ledger
+    #[clap(long, required_unless_present = "code", conflicts_with = "code")]
+    package_address: Option<SimulatorPackageAddress>,
+
+    /// The published code, as a .wasm file, to verify against instead of a ledger package
+    #[clap(long)]
+    code: Option<PathBuf>,
+
+    /// The package directory
+    #[clap(long)]
+    path: Option<PathBuf>,
+
+    /// The build descriptor (`.build.json`) recorded by the build of the published code, whose
+    /// toolchain, features, profile and environment the package is rebuilt with
+    #[clap(long)]
+    descriptor: PathBuf,
+
+    /// The toolchain channel to rebuild with, overriding the one recorded in the descriptor
+    #[clap(long)]
+    toolchain: Option<String>,
+}
+
+impl Verify {
+    pub fn run(&self) -> Result<(), Error> {
+        let expected = self.published_code_hashes()?;
+
+        let descriptor = BuildDescriptor::read(&self.descriptor).map_err(Error::BuildError)?;
+        let mut args = descriptor.cargo_build_args();
+        if self.toolchain.is_some() {
+            args.toolchain = self.toolchain.clone();
+        }
+        let (wasm_path, definition_path) = build_package(
+            self.path.clone().unwrap_or(current_dir().unwrap()),
+            descriptor.trace,
+            false,
+            descriptor.wasm_opt_version.is_none(),
+            true,
+            &args,
+        )
+        .map_err(Error::BuildError)?;
+        let code = fs::read(&wasm_path).map_err(Error::IOError)?;
+        let actual = CodeHash::from(hash(&code));
+
+        if expected.contains(&actual) {
+            println!(
+                "{} Code hash {} matches the published code (build descriptor: {})",
+                "Verified!".green().bold(),
+                actual.0,
+                build_descriptor_path(&definition_path).display()
+            );
+            Ok(())
+        } else {
+            Err(Error::CodeHashMismatch { expected, actual })
+        }
+    }
+
+    fn published_code_hashes(&self) -> Result<Vec<CodeHash>, Error> {
+        if let Some(code_path) = &self.code {
+            let code = fs::read(code_path).map_err(Error::IOError)?;
+            return Ok(vec![CodeHash::from(hash(&code))]);
+        }
+
+        let package_address = self.package_address.clone().unwrap().0;
+        let substate_db =
+            RocksdbSubstateStore::standard(get_data_dir().map_err(Error::ResimError)?);
+        let code_hashes: Vec<CodeHash> = substate_db
+            .list_mapped::<SpreadPrefixKeyMapper, PackageCodeOriginalCodeEntrySubstate, MapKey>(
+                package_address.as_node_id(),
+                PackagePartitionOffset::CodeOriginalCodeKeyValue.as_main_partition(),
+            )
+            .filter_map(|(_, substate)| substate.into_value())
+            .map(|code| CodeHash::from(hash(&code.into_latest().code)))
+            .collect();
+        if code_hashes.is_empty() {
+            return Err(Error::PackageNotFound(package_address));
+        }
+        Ok(code_hashes)
+    }
+}
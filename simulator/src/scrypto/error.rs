@@ -1,5 +1,9 @@
 use std::io;
 
+use radix_engine::vm::wasm::PrepareError;
+use radix_engine_interface::blueprints::package::CodeHash;
+use radix_engine_interface::types::PackageAddress;
+
 use crate::utils::*;
 
 #[derive(Debug)]
@@ -10,7 +14,22 @@ pub enum Error {
 
     TestError(TestError),
 
+    CoverageError(CoverageError),
+
     FormatError(FormatError),
 
+    SborDecodeError(sbor::DecodeError),
+
+    SizeReportError(PrepareError),
+
     PackageAlreadyExists,
+
+    ResimError(crate::resim::Error),
+
+    PackageNotFound(PackageAddress),
+
+    CodeHashMismatch {
+        expected: Vec<CodeHash>,
+        actual: CodeHash,
+    },
 }
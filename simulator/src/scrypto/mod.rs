@@ -1,13 +1,17 @@
 mod cmd_build;
+mod cmd_coverage;
 mod cmd_fmt;
 mod cmd_new_package;
 mod cmd_test;
+mod cmd_verify;
 mod error;
 
 pub use cmd_build::*;
+pub use cmd_coverage::*;
 pub use cmd_fmt::*;
 pub use cmd_new_package::*;
 pub use cmd_test::*;
+pub use cmd_verify::*;
 pub use error::*;
 
 use clap::{Parser, Subcommand};
@@ -23,9 +27,11 @@ pub struct ScryptoCli {
 #[derive(Subcommand, Debug)]
 pub enum Command {
     Build(Build),
+    Coverage(Coverage),
     Fmt(Fmt),
     NewPackage(NewPackage),
     Test(Test),
+    Verify(Verify),
 }
 
 pub fn run() -> Result<(), Error> {
@@ -33,8 +39,10 @@ pub fn run() -> Result<(), Error> {
 
     match cli.command {
         Command::Build(cmd) => cmd.run(),
+        Command::Coverage(cmd) => cmd.run(),
         Command::Fmt(cmd) => cmd.run(),
         Command::NewPackage(cmd) => cmd.run(),
         Command::Test(cmd) => cmd.run(),
+        Command::Verify(cmd) => cmd.run(),
     }
 }
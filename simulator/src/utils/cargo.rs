@@ -9,8 +9,22 @@ use std::process::ExitStatus;
 use cargo_toml::Manifest;
 use radix_engine::types::*;
 use radix_engine::utils::*;
+use radix_engine_interface::blueprints::package::CodeHash;
+use serde::{Deserialize, Serialize};
 use wasm_opt::OptimizationError;
 
+/// The version of the `wasm-opt` crate used for optimizing the built WASM.
+/// Must be kept in sync with the `wasm-opt` dependency in `Cargo.toml`.
+pub const WASM_OPT_VERSION: &'static str = "0.114.1";
+
+/// The toolchain file a package may pin its compiler with.
+pub const TOOLCHAIN_FILE_NAME: &'static str = "rust-toolchain.toml";
+
+/// The path prefixes are remapped to in reproducible builds.
+const REMAPPED_PACKAGE_PATH: &'static str = "/package";
+const REMAPPED_TARGET_PATH: &'static str = "/target";
+const REMAPPED_CARGO_HOME_PATH: &'static str = "/cargo";
+
 #[derive(Debug)]
 pub enum BuildError {
     NotCargoPackage(PathBuf),
@@ -32,6 +46,94 @@ pub enum BuildError {
     InvalidManifestFile(PathBuf),
 
     OptimizationError(OptimizationError),
+
+    ToolchainResolutionError,
+
+    DescriptorEncodeError(serde_json::Error),
+
+    DescriptorDecodeError(serde_json::Error),
+}
+
+/// Describes how a package was built, so that the build can be repeated and its
+/// output compared against the code of a published package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildDescriptor {
+    pub package_name: String,
+    pub reproducible: bool,
+    /// The output of `rustc --version`.
+    pub rustc_version: String,
+    /// The toolchain channel the package was built with, if pinned.
+    pub toolchain_channel: Option<String>,
+    /// The features of the package activated on top of the ones required by the build.
+    pub features: Vec<String>,
+    pub no_default_features: bool,
+    pub profile: String,
+    /// Whether tracing was turned on.
+    #[serde(default)]
+    pub trace: bool,
+    /// The environment variables set for the cargo invocation.
+    #[serde(default)]
+    pub env: Vec<(String, String)>,
+    pub wasm_opt_version: Option<String>,
+    /// Whether the function names were kept for debugging.
+    #[serde(default)]
+    pub debug: bool,
+    /// The hex-encoded code hash, as stored by the engine for the published code.
+    pub code_hash: String,
+}
+
+impl BuildDescriptor {
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, BuildError> {
+        let path = path.as_ref();
+        let content =
+            fs::read(path).map_err(|err| BuildError::IOErrorAtPath(err, path.to_owned()))?;
+        serde_json::from_slice(&content).map_err(BuildError::DescriptorDecodeError)
+    }
+
+    /// Returns the cargo arguments the package was built with, so that the build can be repeated.
+    pub fn cargo_build_args(&self) -> CargoBuildArgs {
+        CargoBuildArgs {
+            features: self.features.clone(),
+            no_default_features: self.no_default_features,
+            profile: Some(self.profile.clone()),
+            env: self.env.clone(),
+            debug: self.debug,
+            toolchain: self.toolchain_channel.clone(),
+        }
+    }
+}
+
+/// Additional arguments passed through to `cargo build`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CargoBuildArgs {
+    /// Features of the package to activate, in addition to the ones required by the build.
+    pub features: Vec<String>,
+    pub no_default_features: bool,
+    /// The cargo profile to build with, `release` if not specified.
+    pub profile: Option<String>,
+    /// Environment variables to set for the cargo invocation.
+    pub env: Vec<(String, String)>,
+    /// Keeps the function names (the `name` section) and the DWARF line info in the built WASM,
+    /// which the engine uses to symbolise backtraces in debug mode.
+    pub debug: bool,
+    /// The toolchain channel to build with in reproducible mode, overriding the one pinned in
+    /// the package's toolchain file.
+    pub toolchain: Option<String>,
+}
+
+impl CargoBuildArgs {
+    pub fn profile(&self) -> &str {
+        self.profile.as_deref().unwrap_or("release")
+    }
+
+    /// Returns the name of the directory cargo places the artifacts of the profile in.
+    pub fn profile_dir(&self) -> &str {
+        match self.profile() {
+            "dev" | "test" => "debug",
+            "bench" => "release",
+            profile => profile,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -45,6 +147,23 @@ pub enum TestError {
     CargoFailure(ExitStatus),
 }
 
+#[derive(Debug)]
+pub enum CoverageError {
+    NotCargoPackage,
+
+    BuildError(BuildError),
+
+    IOError(io::Error),
+
+    CargoFailure(ExitStatus),
+
+    NoCoverageData,
+
+    LlvmIrNotFound(PathBuf),
+
+    ToolFailure(String, ExitStatus),
+}
+
 #[derive(Debug)]
 pub enum FormatError {
     BuildError(BuildError),
@@ -54,30 +173,74 @@ pub enum FormatError {
     CargoFailure(ExitStatus),
 }
 
-fn run_cargo_build(
-    manifest_path: impl AsRef<OsStr>,
-    target_path: impl AsRef<OsStr>,
-    trace: bool,
-    no_schema_gen: bool,
-) -> Result<(), BuildError> {
-    let mut features = Vec::<String>::new();
+/// Splits the space or comma separated lists of features passed in by the user.
+fn package_features(args: &CargoBuildArgs) -> Vec<String> {
+    args.features
+        .iter()
+        .flat_map(|features| features.split(|c| c == ',' || c == ' '))
+        .filter(|feature| !feature.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+fn build_features(trace: bool, no_schema_gen: bool, args: &CargoBuildArgs) -> Vec<String> {
+    let mut features = package_features(args);
     if trace {
         features.push("scrypto/trace".to_owned());
     }
     if no_schema_gen {
         features.push("scrypto/no-schema".to_owned());
     }
+    features
+}
+
+fn run_cargo_build(
+    base_path: &Path,
+    manifest_path: impl AsRef<OsStr>,
+    target_path: &Path,
+    trace: bool,
+    no_schema_gen: bool,
+    toolchain_channel: Option<&str>,
+    args: &CargoBuildArgs,
+) -> Result<(), BuildError> {
+    let mut features = build_features(trace, no_schema_gen, args);
     if !features.is_empty() {
-        features.insert(0, "--features".to_owned());
+        features = vec!["--features".to_owned(), features.join(",")];
+    }
+    if args.no_default_features {
+        features.push("--no-default-features".to_owned());
     }
 
-    let status = Command::new("cargo")
+    let mut command = Command::new("cargo");
+    if let Some(channel) = toolchain_channel {
+        // Select the toolchain through the rustup proxy, rather than through a toolchain file
+        command.arg(format!("+{}", channel));
+    }
+    if args.debug {
+        let profile = args.profile().to_uppercase().replace('-', "_");
+        command
+            .env(format!("CARGO_PROFILE_{}_STRIP", profile), "none")
+            .env(
+                format!("CARGO_PROFILE_{}_DEBUG", profile),
+                "line-tables-only",
+            );
+    }
+    command.envs(args.env.iter().map(|(key, value)| (key, value)));
+    if toolchain_channel.is_some() {
+        command.env(
+            "CARGO_ENCODED_RUSTFLAGS",
+            reproducible_rustflags(base_path, target_path, &args.env),
+        );
+    }
+
+    let status = command
         .arg("build")
         .arg("--target")
         .arg("wasm32-unknown-unknown")
-        .arg("--release")
+        .arg("--profile")
+        .arg(args.profile())
         .arg("--target-dir")
-        .arg(target_path.as_ref())
+        .arg(target_path)
         .arg("--manifest-path")
         .arg(manifest_path.as_ref())
         .args(features)
@@ -90,6 +253,152 @@ fn run_cargo_build(
     }
 }
 
+/// Returns the rustflags (encoded as expected by `CARGO_ENCODED_RUSTFLAGS`) which remap
+/// all machine-specific path prefixes, so that they don't end up in the built WASM.
+/// The `RUSTFLAGS` passed in through the cargo environment variables take precedence over the
+/// ones of the process, as cargo would otherwise ignore them in favour of the encoded flags.
+fn reproducible_rustflags(
+    base_path: &Path,
+    target_path: &Path,
+    env: &[(String, String)],
+) -> String {
+    let mut flags = Vec::<String>::new();
+    let existing = env
+        .iter()
+        .rev()
+        .find(|(key, _)| key == "RUSTFLAGS")
+        .map(|(_, value)| value.clone())
+        .or_else(|| std::env::var("RUSTFLAGS").ok());
+    if let Some(existing) = existing {
+        flags.extend(existing.split_whitespace().map(ToOwned::to_owned));
+    }
+    let mut remap = |from: &Path, to: &str| {
+        let from = fs::canonicalize(from).unwrap_or(from.to_owned());
+        flags.push(format!("--remap-path-prefix={}={}", from.display(), to));
+    };
+    remap(target_path, REMAPPED_TARGET_PATH);
+    remap(base_path, REMAPPED_PACKAGE_PATH);
+    if let Some(cargo_home) = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".cargo")))
+    {
+        remap(&cargo_home, REMAPPED_CARGO_HOME_PATH);
+    }
+    flags.join("\x1f")
+}
+
+/// Gets the verbose `rustc` version info (`rustc -vV`) as key-value pairs, either of the given
+/// toolchain or of the one resolved from the given directory.
+fn get_rustc_version_info(
+    base_path: &Path,
+    toolchain_channel: Option<&str>,
+) -> Result<IndexMap<String, String>, BuildError> {
+    let mut command = Command::new("rustc");
+    if let Some(channel) = toolchain_channel {
+        command.arg(format!("+{}", channel));
+    }
+    let output = command
+        .current_dir(base_path)
+        .arg("-vV")
+        .output()
+        .map_err(BuildError::IOError)?;
+    if !output.status.success() {
+        return Err(BuildError::ToolchainResolutionError);
+    }
+    let stdout =
+        String::from_utf8(output.stdout).map_err(|_| BuildError::ToolchainResolutionError)?;
+
+    let mut info = index_map_new();
+    for (i, line) in stdout.lines().enumerate() {
+        if i == 0 {
+            info.insert("version".to_owned(), line.trim().to_owned());
+        } else if let Some((key, value)) = line.split_once(':') {
+            info.insert(key.trim().to_owned(), value.trim().to_owned());
+        }
+    }
+    Ok(info)
+}
+
+/// Reads the toolchain channel pinned in the package's toolchain file.
+fn read_pinned_toolchain(base_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(base_path.join(TOOLCHAIN_FILE_NAME))
+        .or_else(|_| fs::read_to_string(base_path.join("rust-toolchain")))
+        .ok()?;
+    parse_toolchain_channel(&content)
+}
+
+/// Parses the toolchain channel from either a `rust-toolchain.toml` or a legacy single-line
+/// `rust-toolchain` file.
+fn parse_toolchain_channel(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let line = line.trim();
+        if !line.contains('=') && !line.is_empty() && !line.starts_with('[') {
+            return Some(line.to_owned());
+        }
+        let (key, value) = line.split_once('=')?;
+        if key.trim() == "channel" {
+            Some(value.trim().trim_matches('"').to_owned())
+        } else {
+            None
+        }
+    })
+}
+
+/// Resolves the toolchain channel to build with reproducibly: the given override, else the one
+/// pinned in the package's toolchain file, else the currently active one. The package sources
+/// are never modified, so that a build can be verified against an untouched source tree.
+/// See [`pin_toolchain`] for pinning the resolved toolchain in the package.
+pub fn resolve_toolchain<P: AsRef<Path>>(
+    base_path: P,
+    toolchain_override: Option<&str>,
+) -> Result<String, BuildError> {
+    let base_path = base_path.as_ref();
+    if let Some(channel) = toolchain_override {
+        return Ok(channel.to_owned());
+    }
+    if let Some(channel) = read_pinned_toolchain(base_path) {
+        return Ok(channel);
+    }
+
+    let info = get_rustc_version_info(base_path, None)?;
+    let release = info
+        .get("release")
+        .ok_or(BuildError::ToolchainResolutionError)?;
+    if release.contains("nightly") {
+        let date = info
+            .get("commit-date")
+            .ok_or(BuildError::ToolchainResolutionError)?;
+        Ok(format!("nightly-{}", date))
+    } else {
+        Ok(release.clone())
+    }
+}
+
+/// Pins the toolchain resolved by [`resolve_toolchain`] in the package's toolchain file, unless
+/// the package already pins one, so that it can be committed along with the sources. Returns the
+/// resolved channel and whether the toolchain file was written.
+pub fn pin_toolchain<P: AsRef<Path>>(
+    base_path: P,
+    toolchain_override: Option<&str>,
+) -> Result<(String, bool), BuildError> {
+    let base_path = base_path.as_ref();
+    let channel = resolve_toolchain(base_path, toolchain_override)?;
+    if read_pinned_toolchain(base_path).is_some() {
+        return Ok((channel, false));
+    }
+
+    let toolchain_path = base_path.join(TOOLCHAIN_FILE_NAME);
+    fs::write(
+        &toolchain_path,
+        format!(
+            "[toolchain]\nchannel = \"{}\"\ntargets = [\"wasm32-unknown-unknown\"]\n",
+            channel
+        ),
+    )
+    .map_err(|err| BuildError::IOErrorAtPath(err, toolchain_path))?;
+    Ok((channel, true))
+}
+
 /// Gets the default cargo directory for the given crate.
 /// This respects whether the crate is in a workspace.
 pub fn get_default_target_directory(
@@ -119,11 +428,17 @@ pub fn get_default_target_directory(
 }
 
 /// Builds a package.
+///
+/// In reproducible mode, cargo is invoked with an explicit `+<channel>` toolchain and all
+/// machine-specific path prefixes are remapped, so that the same source produces the same code
+/// hash on any machine. In either mode, a build descriptor is written next to the definition.
 pub fn build_package<P: AsRef<Path>>(
     base_path: P,
     trace: bool,
     force_local_target: bool,
     disable_wasm_opt: bool,
+    reproducible: bool,
+    args: &CargoBuildArgs,
 ) -> Result<(PathBuf, PathBuf), BuildError> {
     let base_path = base_path.as_ref().to_owned();
 
@@ -134,6 +449,12 @@ pub fn build_package<P: AsRef<Path>>(
         return Err(BuildError::NotCargoPackage(manifest_path));
     }
 
+    let reproducible_channel = if reproducible {
+        Some(resolve_toolchain(&base_path, args.toolchain.as_deref())?)
+    } else {
+        None
+    };
+
     // Use the scrypto directory as a target, even if the scrypto crate is part of a workspace
     // This allows us to find where the WASM and SCHEMA ends up deterministically.
     let target_path = if force_local_target {
@@ -147,22 +468,33 @@ pub fn build_package<P: AsRef<Path>>(
 
     let mut out_path = target_path.clone();
     out_path.push("wasm32-unknown-unknown");
-    out_path.push("release");
+    out_path.push(args.profile_dir());
 
     // Build with SCHEMA
-    run_cargo_build(&manifest_path, &target_path, trace, false)?;
+    run_cargo_build(
+        &base_path,
+        &manifest_path,
+        &target_path,
+        trace,
+        false,
+        reproducible_channel.as_deref(),
+        args,
+    )?;
 
     // Find the binary paths
     let manifest = Manifest::from_path(&manifest_path)
         .map_err(|_| BuildError::InvalidManifestFile(manifest_path.clone()))?;
+    let package_name = manifest
+        .package
+        .as_ref()
+        .map(|pkg| pkg.name.clone())
+        .ok_or(BuildError::MissingPackageName)?;
     let mut wasm_name = None;
     if let Some(lib) = manifest.lib {
         wasm_name = lib.name.clone();
     }
     if wasm_name == None {
-        if let Some(pkg) = manifest.package {
-            wasm_name = Some(pkg.name.replace("-", "_"));
-        }
+        wasm_name = Some(package_name.replace("-", "_"));
     }
     let mut bin_path = out_path.clone();
     bin_path.push(wasm_name.ok_or(BuildError::InvalidManifestFile(manifest_path.clone()))?);
@@ -181,30 +513,89 @@ pub fn build_package<P: AsRef<Path>>(
     .map_err(|err| BuildError::IOErrorAtPath(err, definition_path.clone()))?;
 
     // Build without SCHEMA
-    run_cargo_build(&manifest_path, &target_path, trace, true)?;
+    run_cargo_build(
+        &base_path,
+        &manifest_path,
+        &target_path,
+        trace,
+        true,
+        reproducible_channel.as_deref(),
+        args,
+    )?;
 
     // Optimizes the built wasm using Binaryen's wasm-opt tool. The code that follows is equivalent
     // to running the following commands in the CLI:
     // wasm-opt -0z --strip-debug --strip-dwarf --strip-procedures $some_path $some_path
+    // In debug mode, the names and DWARF are kept instead: wasm-opt -0z -g $some_path $some_path
     if !disable_wasm_opt {
-        wasm_opt::OptimizationOptions::new_optimize_for_size_aggressively()
-            .add_pass(wasm_opt::Pass::StripDebug)
-            .add_pass(wasm_opt::Pass::StripDwarf)
-            .add_pass(wasm_opt::Pass::StripProducers)
+        let mut options = wasm_opt::OptimizationOptions::new_optimize_for_size_aggressively();
+        if args.debug {
+            options.debug_info(true);
+        } else {
+            options
+                .add_pass(wasm_opt::Pass::StripDebug)
+                .add_pass(wasm_opt::Pass::StripDwarf)
+                .add_pass(wasm_opt::Pass::StripProducers);
+        }
+        options
             .run(&wasm_path, &wasm_path)
             .map_err(BuildError::OptimizationError)?;
     }
 
+    // Record the build descriptor
+    let code =
+        fs::read(&wasm_path).map_err(|err| BuildError::IOErrorAtPath(err, wasm_path.clone()))?;
+    let rustc_version = get_rustc_version_info(&base_path, reproducible_channel.as_deref())?
+        .remove("version")
+        .ok_or(BuildError::ToolchainResolutionError)?;
+    let descriptor = BuildDescriptor {
+        package_name,
+        reproducible,
+        rustc_version,
+        toolchain_channel: reproducible_channel.or_else(|| read_pinned_toolchain(&base_path)),
+        features: package_features(args),
+        no_default_features: args.no_default_features,
+        profile: args.profile().to_owned(),
+        trace,
+        env: args.env.clone(),
+        wasm_opt_version: if disable_wasm_opt {
+            None
+        } else {
+            Some(WASM_OPT_VERSION.to_owned())
+        },
+        debug: args.debug,
+        code_hash: CodeHash::from(hash(&code)).0.to_string(),
+    };
+    let descriptor_path = build_descriptor_path(&definition_path);
+    fs::write(
+        &descriptor_path,
+        serde_json::to_string_pretty(&descriptor).map_err(BuildError::DescriptorEncodeError)?,
+    )
+    .map_err(|err| BuildError::IOErrorAtPath(err, descriptor_path.clone()))?;
+
     Ok((wasm_path, definition_path))
 }
 
+/// Returns the path of the build descriptor written next to the given package definition.
+pub fn build_descriptor_path<P: AsRef<Path>>(definition_path: P) -> PathBuf {
+    definition_path.as_ref().with_extension("build.json")
+}
+
 /// Runs tests within a package.
 pub fn test_package<P: AsRef<Path>, I, S>(path: P, args: I) -> Result<(), TestError>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<OsStr>,
 {
-    build_package(&path, false, false, false).map_err(TestError::BuildError)?;
+    build_package(
+        &path,
+        false,
+        false,
+        false,
+        false,
+        &CargoBuildArgs::default(),
+    )
+    .map_err(TestError::BuildError)?;
 
     let mut cargo = path.as_ref().to_owned();
     cargo.push("Cargo.toml");
@@ -227,6 +618,162 @@ where
     }
 }
 
+/// Runs tests within a package with coverage instrumentation, and writes an lcov report of the
+/// blueprint source lines exercised by the tests. Returns the path of the report.
+///
+/// The WASM is built by the test runner with a nightly toolchain: the given one, else the one
+/// pinned by the package if it's a nightly one, else the default nightly. The engine dumps the
+/// coverage counters after each invocation. Requires `clang`, `llvm-profdata` and `llvm-cov`
+/// matching the LLVM version of the nightly toolchain.
+pub fn coverage_package<P: AsRef<Path>, I, S>(
+    path: P,
+    args: I,
+    toolchain: Option<&str>,
+) -> Result<PathBuf, CoverageError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let mut manifest_path = path.as_ref().to_owned();
+    manifest_path.push("Cargo.toml");
+    if !manifest_path.exists() {
+        return Err(CoverageError::NotCargoPackage);
+    }
+    let manifest = Manifest::from_path(&manifest_path).map_err(|_| {
+        CoverageError::BuildError(BuildError::InvalidManifestFile(manifest_path.clone()))
+    })?;
+    let wasm_name = manifest
+        .lib
+        .and_then(|lib| lib.name)
+        .or(manifest.package.map(|pkg| pkg.name.replace("-", "_")))
+        .ok_or(CoverageError::BuildError(BuildError::MissingPackageName))?;
+
+    let mut coverage_path = PathBuf::from_str(
+        &get_default_target_directory(&manifest_path).map_err(CoverageError::BuildError)?,
+    )
+    .unwrap(); // Infallible
+    coverage_path.push("coverage");
+    let data_path = coverage_path.join("data");
+    if data_path.exists() {
+        fs::remove_dir_all(&data_path).map_err(CoverageError::IOError)?;
+    }
+
+    let toolchain = toolchain
+        .map(ToOwned::to_owned)
+        .or_else(|| {
+            read_pinned_toolchain(path.as_ref()).filter(|channel| channel.starts_with("nightly"))
+        })
+        .unwrap_or(DEFAULT_COVERAGE_TOOLCHAIN.to_owned());
+
+    // Run the tests, which build the instrumented WASM and dump the counters into the data path
+    let status = Command::new("cargo")
+        .env(COVERAGE_DIRECTORY_ENV, &coverage_path)
+        .env(COVERAGE_TOOLCHAIN_ENV, &toolchain)
+        .arg("test")
+        .arg("--release")
+        .arg("--features")
+        .arg("radix-engine/coverage")
+        .arg("--manifest-path")
+        .arg(&manifest_path)
+        .arg("--")
+        .args(args)
+        .status()
+        .map_err(CoverageError::IOError)?;
+    if !status.success() {
+        return Err(CoverageError::CargoFailure(status));
+    }
+
+    // Merge the raw profiles of all blueprints
+    let profraw_files = walk_files(&data_path, "profraw").map_err(CoverageError::IOError)?;
+    if profraw_files.is_empty() {
+        return Err(CoverageError::NoCoverageData);
+    }
+    let profdata_path = coverage_path.join(format!("{}.profdata", wasm_name));
+    run_tool(
+        Command::new("llvm-profdata")
+            .arg("merge")
+            .arg("-sparse")
+            .args(&profraw_files)
+            .arg("-o")
+            .arg(&profdata_path),
+    )?;
+
+    // Compile the emitted LLVM IR into an object file, which carries the coverage mapping
+    let deps_path = coverage_target_directory(&coverage_path)
+        .join("wasm32-unknown-unknown")
+        .join("release")
+        .join("deps");
+    let ir_path = walk_files(&deps_path, "ll")
+        .map_err(CoverageError::IOError)?
+        .into_iter()
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with(&format!("{}-", wasm_name)))
+                .unwrap_or(false)
+        })
+        .max_by_key(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .ok_or(CoverageError::LlvmIrNotFound(deps_path))?;
+    let object_path = coverage_path.join(format!("{}.o", wasm_name));
+    run_tool(
+        Command::new("clang")
+            .arg(&ir_path)
+            .arg("-Wno-override-module")
+            .arg("-c")
+            .arg("-o")
+            .arg(&object_path),
+    )?;
+
+    // Export the report, limited to the blueprint source
+    let output = Command::new("llvm-cov")
+        .arg("export")
+        .arg("--format=lcov")
+        .arg(format!("--instr-profile={}", profdata_path.display()))
+        .arg("--ignore-filename-regex=(\\.cargo|rustc)/")
+        .arg(&object_path)
+        .output()
+        .map_err(CoverageError::IOError)?;
+    if !output.status.success() {
+        return Err(CoverageError::ToolFailure(
+            "llvm-cov".to_owned(),
+            output.status,
+        ));
+    }
+    let report_path = coverage_path.join("lcov.info");
+    fs::write(&report_path, output.stdout).map_err(CoverageError::IOError)?;
+
+    Ok(report_path)
+}
+
+fn run_tool(command: &mut Command) -> Result<(), CoverageError> {
+    let status = command.status().map_err(CoverageError::IOError)?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(CoverageError::ToolFailure(
+            command.get_program().to_string_lossy().into_owned(),
+            status,
+        ))
+    }
+}
+
+/// Recursively lists all files with the given extension under a directory.
+fn walk_files(dir: &Path, extension: &str) -> Result<Vec<PathBuf>, io::Error> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(walk_files(&path, extension)?);
+        } else if path.extension() == Some(OsStr::new(extension)) {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
 /// Format a package.
 pub fn fmt_package<P: AsRef<Path>>(path: P, check: bool, quiet: bool) -> Result<(), FormatError> {
     let mut cargo = path.as_ref().to_owned();
@@ -258,3 +805,36 @@ pub fn fmt_package<P: AsRef<Path>>(path: P, check: bool, quiet: bool) -> Result<
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_toolchain_channel() {
+        assert_eq!(
+            parse_toolchain_channel(
+                "[toolchain]\nchannel = \"1.72.0\"\ntargets = [\"wasm32-unknown-unknown\"]\n"
+            ),
+            Some("1.72.0".to_owned())
+        );
+        assert_eq!(
+            parse_toolchain_channel("nightly-2023-08-01\n"),
+            Some("nightly-2023-08-01".to_owned())
+        );
+        assert_eq!(parse_toolchain_channel("[toolchain]\n"), None);
+    }
+
+    #[test]
+    fn test_reproducible_rustflags_keep_env_rustflags() {
+        let flags = reproducible_rustflags(
+            Path::new("/nonexistent/package"),
+            Path::new("/nonexistent/target"),
+            &[("RUSTFLAGS".to_owned(), "-C opt-level=z".to_owned())],
+        );
+        let flags: Vec<&str> = flags.split('\x1f').collect();
+        assert_eq!(&flags[..2], &["-C", "opt-level=z"]);
+        assert!(flags.contains(&"--remap-path-prefix=/nonexistent/target=/target"));
+        assert!(flags.contains(&"--remap-path-prefix=/nonexistent/package=/package"));
+    }
+}
@@ -1,3 +1,5 @@
+use radix_engine::types::*;
+
 pub fn list_item_prefix(last: bool) -> &'static str {
     if last {
         "└─"
@@ -5,3 +7,11 @@ pub fn list_item_prefix(last: bool) -> &'static str {
         "├─"
     }
 }
+
+/// Displays a public key along with its curve, e.g. `Ed25519(<hex>)`.
+pub fn display_public_key(public_key: &PublicKey) -> String {
+    match public_key {
+        PublicKey::Secp256k1(public_key) => format!("Secp256k1({})", public_key),
+        PublicKey::Ed25519(public_key) => format!("Ed25519({})", public_key),
+    }
+}
@@ -0,0 +1,74 @@
+use radix_engine::types::*;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum ManifestParametersError {
+    IOError(std::io::Error),
+    /// A parameter which isn't of the `<name>=<value>` form
+    InvalidParameter(String),
+}
+
+/// Collects the values of the parameters declared by a manifest with `PARAM`.
+///
+/// The values are read from an optional key/value file, with a `<name>=<value>` entry per line
+/// (empty lines and lines starting with `#` are ignored), and from `<name>=<value>` command line
+/// flags, which take precedence over the file.
+pub fn load_manifest_parameters(
+    file: Option<&Path>,
+    flags: &[String],
+) -> Result<IndexMap<String, String>, ManifestParametersError> {
+    let mut parameters = index_map_new();
+
+    if let Some(file) = file {
+        let content = std::fs::read_to_string(file).map_err(ManifestParametersError::IOError)?;
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, value) = parse_manifest_parameter(line)?;
+            parameters.insert(name, value);
+        }
+    }
+
+    for flag in flags {
+        let (name, value) = parse_manifest_parameter(flag)?;
+        parameters.insert(name, value);
+    }
+
+    Ok(parameters)
+}
+
+fn parse_manifest_parameter(s: &str) -> Result<(String, String), ManifestParametersError> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(ManifestParametersError::InvalidParameter(s.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_manifest_parameters() {
+        let file = std::env::temp_dir().join("test_load_manifest_parameters.env");
+        std::fs::write(&file, "# Stokenet\namount = 10\naccount=account_tdx_1\n\n").unwrap();
+
+        let parameters =
+            load_manifest_parameters(Some(file.as_path()), &["amount=20".to_string()]).unwrap();
+
+        assert_eq!(
+            parameters,
+            indexmap!(
+                "amount".to_string() => "20".to_string(),
+                "account".to_string() => "account_tdx_1".to_string(),
+            )
+        );
+        assert!(matches!(
+            load_manifest_parameters(None, &["amount".to_string()]),
+            Err(ManifestParametersError::InvalidParameter(_))
+        ));
+    }
+}
@@ -2,10 +2,16 @@ mod cargo;
 mod common_instructions;
 mod display;
 mod iter;
+mod manifest_parameters;
+mod remote_signer;
 mod resource_specifier;
+mod size_report;
 
 pub use cargo::*;
 pub use common_instructions::*;
-pub use display::list_item_prefix;
+pub use display::{display_public_key, list_item_prefix};
 pub use iter::{IdentifyLast, Iter};
+pub use manifest_parameters::*;
+pub use remote_signer::*;
 pub use resource_specifier::*;
+pub use size_report::*;
@@ -0,0 +1,224 @@
+//! A [`Signer`] which delegates signing to a local process, such as a signing daemon or a bridge
+//! to a hardware security module, so that the private key never enters the simulator.
+//!
+//! The signer process is spoken to over its standard input and output, with one JSON object per
+//! line. Every request has a `method`, and every response has either a hex-encoded `result` or an
+//! `error` message:
+//!
+//! ```text
+//! > {"method":"public_key"}
+//! < {"result":"<33 byte Secp256k1 or 32 byte Ed25519 public key>"}
+//! > {"method":"sign","hash":"<32 byte hash>"}
+//! < {"result":"<65 byte recoverable Secp256k1 or 64 byte Ed25519 signature>"}
+//! < {"error":"<reason the signature was refused>"}
+//! ```
+
+use radix_engine::types::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use transaction::errors::SigningError;
+use transaction::prelude::*;
+use transaction::validation::verify;
+
+#[derive(Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum Request {
+    PublicKey,
+    Sign { hash: String },
+}
+
+#[derive(Deserialize)]
+struct Response {
+    result: Option<String>,
+    error: Option<String>,
+}
+
+struct SignerProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl SignerProcess {
+    fn call(&mut self, request: &Request) -> Result<Vec<u8>, SigningError> {
+        let mut line = serde_json::to_string(request).expect("Request can be serialized");
+        line.push('\n');
+        self.stdin
+            .write_all(line.as_bytes())
+            .and_then(|_| self.stdin.flush())
+            .map_err(|e| SigningError::SignerUnavailable(e.to_string()))?;
+
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) => Err(SigningError::SignerUnavailable(
+                "Signer process closed its output".to_owned(),
+            )),
+            Ok(_) => Ok(()),
+            Err(e) => Err(SigningError::SignerUnavailable(e.to_string())),
+        }?;
+
+        let response: Response = serde_json::from_str(&line)
+            .map_err(|e| SigningError::InvalidResponse(e.to_string()))?;
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(SigningError::SigningRejected(error)),
+            (Some(result), None) => {
+                hex::decode(result).map_err(|e| SigningError::InvalidResponse(e.to_string()))
+            }
+            (None, None) => Err(SigningError::InvalidResponse(
+                "Response has neither a result nor an error".to_owned(),
+            )),
+        }
+    }
+}
+
+impl Drop for SignerProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// A signer backed by a local process, speaking the JSON-lines protocol described in the
+/// [module documentation](self).
+pub struct RemoteSigner {
+    process: RefCell<SignerProcess>,
+    public_key: PublicKey,
+}
+
+impl RemoteSigner {
+    /// Spawns the signer process and asks it for its public key.
+    pub fn spawn(program: &str, args: &[&str]) -> Result<Self, SigningError> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| SigningError::SignerUnavailable(e.to_string()))?;
+        let stdin = child.stdin.take().expect("Stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("Stdout is piped"));
+        let mut process = SignerProcess {
+            child,
+            stdin,
+            stdout,
+        };
+
+        let bytes = process.call(&Request::PublicKey)?;
+        let invalid = || SigningError::InvalidResponse(format!("Invalid public key {:?}", bytes));
+        let public_key = match bytes.len() {
+            Secp256k1PublicKey::LENGTH => Secp256k1PublicKey::try_from(bytes.as_slice())
+                .map(Into::into)
+                .map_err(|_| invalid())?,
+            Ed25519PublicKey::LENGTH => Ed25519PublicKey::try_from(bytes.as_slice())
+                .map(Into::into)
+                .map_err(|_| invalid())?,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            process: RefCell::new(process),
+            public_key,
+        })
+    }
+
+    /// Spawns a signer from a command line, with the program and its arguments separated by
+    /// whitespace.
+    pub fn spawn_command(command: &str) -> Result<Self, SigningError> {
+        let mut parts = command.split_whitespace();
+        let program = parts
+            .next()
+            .ok_or_else(|| SigningError::SignerUnavailable("Empty signer command".to_owned()))?;
+        Self::spawn(program, &parts.collect::<Vec<_>>())
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn try_sign_without_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureV1, SigningError> {
+        self.try_sign_with_public_key(message_hash)
+            .map(|signature| signature.signature())
+    }
+
+    fn try_sign_with_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureWithPublicKeyV1, SigningError> {
+        let bytes = self.process.borrow_mut().call(&Request::Sign {
+            hash: hex::encode(message_hash.as_slice()),
+        })?;
+        let invalid = || SigningError::InvalidResponse(format!("Invalid signature {:?}", bytes));
+        let signature: SignatureWithPublicKeyV1 = match self.public_key {
+            PublicKey::Secp256k1(_) => Secp256k1Signature::try_from(bytes.as_slice())
+                .map(Into::into)
+                .map_err(|_| invalid())?,
+            PublicKey::Ed25519(public_key) => Ed25519Signature::try_from(bytes.as_slice())
+                .map(|signature| (public_key, signature).into())
+                .map_err(|_| invalid())?,
+        };
+
+        // The signer process is not trusted to sign the given hash with the expected key
+        if !verify(message_hash, &self.public_key, &signature.signature()) {
+            return Err(SigningError::InvalidResponse(format!(
+                "Signature {:?} does not match the public key {:?}",
+                bytes, self.public_key
+            )));
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_signer_reports_public_key_and_rejection() {
+        let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+        let script = format!(
+            "read request; echo '{{\"result\":\"{}\"}}'; read request; echo '{{\"error\":\"Denied\"}}'",
+            public_key
+        );
+        let signer = RemoteSigner::spawn("sh", &["-c", &script]).unwrap();
+
+        assert_eq!(Signer::public_key(&signer), public_key.into());
+        assert_eq!(
+            signer.try_sign_with_public_key(&hash("message")),
+            Err(SigningError::SigningRejected("Denied".to_owned()))
+        );
+        assert!(matches!(
+            signer.try_sign_with_public_key(&hash("message")),
+            Err(SigningError::SignerUnavailable(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_signer_verifies_signatures() {
+        let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let message_hash = hash("message");
+        let script = format!(
+            "read request; echo '{{\"result\":\"{}\"}}'; \
+             read request; echo '{{\"result\":\"{}\"}}'; \
+             read request; echo '{{\"result\":\"{}\"}}'",
+            private_key.public_key(),
+            hex::encode(private_key.sign(&message_hash).to_vec()),
+            hex::encode(private_key.sign(&hash("another message")).to_vec()),
+        );
+        let signer = RemoteSigner::spawn("sh", &["-c", &script]).unwrap();
+
+        assert_eq!(
+            signer.try_sign_with_public_key(&message_hash),
+            Ok(private_key.sign_with_public_key(&message_hash))
+        );
+        assert!(matches!(
+            signer.try_sign_with_public_key(&message_hash),
+            Err(SigningError::InvalidResponse(_))
+        ));
+    }
+}
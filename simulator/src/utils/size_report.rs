@@ -0,0 +1,212 @@
+//! This module implements a size report for built WASM packages, breaking down the code size by
+//! section and function, comparing the exports against the package definition and warning when
+//! the code approaches the size limits enforced by the engine.
+
+use colored::*;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+
+/// The percentage of a limit above which a warning is emitted.
+const SIZE_WARNING_THRESHOLD_PERCENTAGE: usize = 80;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmSizeReport {
+    pub code_size: usize,
+    /// The content size of each section, in order of appearance.
+    pub sections: Vec<(String, usize)>,
+    /// The body size of each function defined in the module, largest first.
+    pub functions: Vec<(String, usize)>,
+    pub exports: Vec<String>,
+    /// Exports required by the package definition, but missing from the code.
+    pub missing_exports: Vec<String>,
+    /// Function exports present in the code, but not referenced by the package definition.
+    pub unreferenced_exports: Vec<String>,
+    /// The error the engine would reject the code with when publishing, if any.
+    pub validation_error: Option<PrepareError>,
+}
+
+impl WasmSizeReport {
+    /// Creates the report from the module as parsed by the engine, so that it reflects exactly
+    /// what the engine validates when the package is published.
+    pub fn new(code: &[u8], definition: &PackageDefinition) -> Result<Self, PrepareError> {
+        let module = WasmModule::init(code)?;
+        let sections = WasmModule::section_sizes(code)?;
+        let imported_functions = module.num_imported_functions();
+        let function_exports = module.function_exports()?;
+        let debug_info = WasmDebugInfo::new(code);
+
+        let mut functions: Vec<(String, usize)> = module
+            .function_body_sizes()?
+            .into_iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let index = imported_functions + i as u32;
+                let name = debug_info
+                    .function_name(index)
+                    .map(ToOwned::to_owned)
+                    .or_else(|| {
+                        function_exports
+                            .iter()
+                            .find(|(_, export_index)| *export_index == index)
+                            .map(|(name, _)| name.clone())
+                    })
+                    .unwrap_or_else(|| format!("func[{}]", index));
+                (name, size)
+            })
+            .collect();
+        functions.sort_by(|a, b| b.1.cmp(&a.1));
+
+        let exports: Vec<String> = function_exports.into_iter().map(|(name, _)| name).collect();
+        let expected_exports: BTreeSet<String> = definition
+            .blueprints
+            .values()
+            .flat_map(|blueprint| blueprint.schema.exports())
+            .collect();
+        let missing_exports = expected_exports
+            .iter()
+            .filter(|export| !exports.contains(export))
+            .cloned()
+            .collect();
+        let unreferenced_exports = exports
+            .iter()
+            .filter(|export| !expected_exports.contains(*export))
+            .cloned()
+            .collect();
+
+        let validation_error = WasmValidator::default()
+            .validate(code, definition.blueprints.values())
+            .err();
+
+        Ok(Self {
+            code_size: code.len(),
+            sections,
+            functions,
+            exports,
+            missing_exports,
+            unreferenced_exports,
+            validation_error,
+        })
+    }
+
+    /// Returns warnings for every engine limit the code is close to or above.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let mut check = |name: &str, value: usize, limit: usize| {
+            if value * 100 >= limit * SIZE_WARNING_THRESHOLD_PERCENTAGE {
+                warnings.push(format!(
+                    "{} is {} of the limit of {} ({}%)",
+                    name,
+                    value,
+                    limit,
+                    value * 100 / limit
+                ));
+            }
+        };
+        // The code is published within a transaction and stored in a single substate
+        check("Code size", self.code_size, MAX_TRANSACTION_SIZE);
+        check("Code size", self.code_size, MAX_SUBSTATE_VALUE_SIZE);
+        check(
+            "Number of functions",
+            self.functions.len(),
+            WasmValidator::default().max_number_of_functions as usize,
+        );
+        warnings
+    }
+
+    pub fn print<O: std::io::Write>(
+        &self,
+        largest_functions: usize,
+        output: &mut O,
+    ) -> std::io::Result<()> {
+        writeln!(
+            output,
+            "{}: {} bytes",
+            "Code size".green().bold(),
+            self.code_size
+        )?;
+        writeln!(output, "{}:", "Sections".green().bold())?;
+        for (name, size) in &self.sections {
+            writeln!(output, "  {:<24} {:>10} bytes", name, size)?;
+        }
+        writeln!(
+            output,
+            "{} ({} total):",
+            "Largest functions".green().bold(),
+            self.functions.len()
+        )?;
+        for (name, size) in self.functions.iter().take(largest_functions) {
+            writeln!(output, "  {:<48} {:>10} bytes", name, size)?;
+        }
+        writeln!(
+            output,
+            "{}: {}",
+            "Exports".green().bold(),
+            self.exports.len()
+        )?;
+        for export in &self.missing_exports {
+            writeln!(output, "  {} {}", "missing:".red(), export)?;
+        }
+        for export in &self.unreferenced_exports {
+            writeln!(output, "  {} {}", "not in definition:".yellow(), export)?;
+        }
+        for warning in self.warnings() {
+            writeln!(output, "{} {}", "Warning:".yellow().bold(), warning)?;
+        }
+        if let Some(error) = &self.validation_error {
+            writeln!(
+                output,
+                "{} the engine rejects the code: {:?}",
+                "Error:".red().bold(),
+                error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_report_of_minimal_module() {
+        // (module (func (export "hello_schema")) (func (export "Hello_new") (param i64) (result i64) local.get 0))
+        let code = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic & version
+            0x01, 0x09, 0x02, 0x60, 0x00, 0x00, 0x60, 0x01, 0x7e, 0x01, 0x7e, // type
+            0x03, 0x03, 0x02, 0x00, 0x01, // function
+            0x07, 0x1c, 0x02, 0x0c, b'h', b'e', b'l', b'l', b'o', b'_', b's', b'c', b'h', b'e',
+            b'm', b'a', 0x00, 0x00, 0x09, b'H', b'e', b'l', b'l', b'o', b'_', b'n', b'e', b'w',
+            0x00, 0x01, // export
+            0x0a, 0x09, 0x02, 0x02, 0x00, 0x0b, 0x04, 0x00, 0x20, 0x00, 0x0b, // code
+        ];
+
+        let report = WasmSizeReport::new(&code, &PackageDefinition::default()).unwrap();
+
+        assert_eq!(report.code_size, code.len());
+        assert_eq!(
+            report
+                .sections
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["type", "function", "export", "code"]
+        );
+        assert_eq!(report.functions[0], ("Hello_new".to_owned(), 4));
+        assert_eq!(report.functions[1], ("hello_schema".to_owned(), 2));
+        assert!(report.missing_exports.is_empty());
+        assert_eq!(
+            report.unreferenced_exports,
+            vec!["hello_schema".to_owned(), "Hello_new".to_owned()]
+        );
+        assert!(report.warnings().is_empty());
+        // Without a memory, the module parses but the engine rejects it
+        assert_eq!(
+            report.validation_error,
+            Some(PrepareError::InvalidMemory(
+                InvalidMemory::MissingMemorySection
+            ))
+        );
+    }
+}
@@ -14,6 +14,13 @@ $scrypto new-package hello-world --path $test_pkg --local
 
 # Build
 $scrypto build --path $test_pkg
+$scrypto build --path $test_pkg --reproducible --no-default-features
+
+# Verify
+test_out="$test_pkg/target/wasm32-unknown-unknown/release"
+cp $test_out/hello_world.wasm $test_pkg/published.wasm
+cp $test_out/hello_world.build.json $test_pkg/published.build.json
+$scrypto verify --path $test_pkg --code $test_pkg/published.wasm --descriptor $test_pkg/published.build.json
 
 # Test
 $scrypto test --path $test_pkg
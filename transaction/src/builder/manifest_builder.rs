@@ -10,8 +10,11 @@ use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
+use radix_engine_interface::blueprints::multisig::*;
 use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::pool::*;
 use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::blueprints::vesting::*;
 
 /// A manifest builder for use in tests.
 ///
@@ -177,6 +180,30 @@ impl ManifestBuilder {
         self.registrar.object_names()
     }
 
+    /// Resolves a component address against the named addresses of this builder.
+    /// This is intended for builder extension methods, such as the ones generated by
+    /// [`generate_manifest_builder_extensions`].
+    pub fn resolve_component_address(
+        &self,
+        component_address: impl ResolvableComponentAddress,
+    ) -> DynamicComponentAddress {
+        component_address.resolve(&self.registrar)
+    }
+
+    /// Resolves a bucket and marks it as consumed by the next instruction.
+    /// This is intended for builder extension methods, such as the ones generated by
+    /// [`generate_manifest_builder_extensions`].
+    pub fn consume_bucket(&self, bucket: impl ExistingManifestBucket) -> ManifestBucket {
+        bucket.mark_consumed(&self.registrar)
+    }
+
+    /// Resolves a proof and marks it as consumed by the next instruction.
+    /// This is intended for builder extension methods, such as the ones generated by
+    /// [`generate_manifest_builder_extensions`].
+    pub fn consume_proof(&self, proof: impl ExistingManifestProof) -> ManifestProof {
+        proof.mark_consumed(&self.registrar)
+    }
+
     /// Example usage:
     /// ```
     /// # use transaction::prelude::*;
@@ -502,6 +529,26 @@ impl ManifestBuilder {
         self.add_instruction(InstructionV1::DropNamedProofs)
     }
 
+    /// Runs the subintent at the given index until it yields back, passing it the arguments.
+    ///
+    /// Only valid in the root intent of a transaction with subintents, where each subintent
+    /// must be yielded to exactly once, in order.
+    pub fn yield_to_child(self, child_index: u32, arguments: impl ResolvableArguments) -> Self {
+        self.add_instruction(InstructionV1::YieldToChild {
+            child_index,
+            args: arguments.resolve(),
+        })
+    }
+
+    /// Returns control to the parent intent, passing it the arguments.
+    ///
+    /// Only valid as the last instruction of a subintent.
+    pub fn yield_to_parent(self, arguments: impl ResolvableArguments) -> Self {
+        self.add_instruction(InstructionV1::YieldToParent {
+            args: arguments.resolve(),
+        })
+    }
+
     /// Drops auth zone signature proofs.
     pub fn drop_auth_zone_signature_proofs(self) -> Self {
         self.add_instruction(InstructionV1::DropAuthZoneSignatureProofs)
@@ -563,6 +610,59 @@ impl ManifestBuilder {
         self.add_instruction(instruction)
     }
 
+    /// Creates a fungible resource whose vaults are subject to a withdraw limit
+    pub fn create_fungible_resource_with_withdraw_limit(
+        self,
+        owner_role: OwnerRole,
+        track_total_supply: bool,
+        divisibility: u8,
+        resource_roles: FungibleResourceRoles,
+        withdraw_limit: FungibleWithdrawLimit,
+        metadata: ModuleConfig<MetadataInit>,
+        initial_supply: Option<Decimal>,
+    ) -> Self {
+        let instruction = if let Some(initial_supply) = initial_supply {
+            InstructionV1::CallFunction {
+                package_address: RESOURCE_PACKAGE.into(),
+                blueprint_name: FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                function_name:
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT
+                        .to_string(),
+                args: to_manifest_value_and_unwrap!(
+                    &FungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitManifestInput {
+                        owner_role,
+                        divisibility,
+                        track_total_supply,
+                        metadata,
+                        resource_roles,
+                        withdraw_limit,
+                        initial_supply,
+                        address_reservation: None,
+                    }
+                ),
+            }
+        } else {
+            InstructionV1::CallFunction {
+                package_address: RESOURCE_PACKAGE.into(),
+                blueprint_name: FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                function_name: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT
+                    .to_string(),
+                args: to_manifest_value_and_unwrap!(
+                    &FungibleResourceManagerCreateWithWithdrawLimitManifestInput {
+                        owner_role,
+                        divisibility,
+                        track_total_supply,
+                        metadata,
+                        resource_roles,
+                        withdraw_limit,
+                        address_reservation: None,
+                    }
+                ),
+            }
+        };
+        self.add_instruction(instruction)
+    }
+
     /// Creates a new non-fungible resource
     pub fn create_non_fungible_resource<T, V>(
         self,
@@ -623,6 +723,71 @@ impl ManifestBuilder {
         self.add_instruction(instruction)
     }
 
+    /// Creates a new non-fungible resource whose vaults are subject to a withdraw limit
+    pub fn create_non_fungible_resource_with_withdraw_limit<T, V>(
+        self,
+        owner_role: OwnerRole,
+        id_type: NonFungibleIdType,
+        track_total_supply: bool,
+        resource_roles: NonFungibleResourceRoles,
+        withdraw_limit: NonFungibleWithdrawLimit,
+        metadata: ModuleConfig<MetadataInit>,
+        initial_supply: Option<T>,
+    ) -> Self
+    where
+        T: IntoIterator<Item = (NonFungibleLocalId, V)>,
+        V: ManifestEncode + NonFungibleData,
+    {
+        let instruction = if let Some(initial_supply) = initial_supply {
+            let entries = initial_supply
+                .into_iter()
+                .map(|(id, e)| (id, (to_manifest_value_and_unwrap!(&e),)))
+                .collect();
+
+            InstructionV1::CallFunction {
+                package_address: RESOURCE_PACKAGE.into(),
+                blueprint_name: NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                function_name:
+                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_AND_WITHDRAW_LIMIT_IDENT
+                        .to_string(),
+                args: to_manifest_value_and_unwrap!(
+                    &NonFungibleResourceManagerCreateWithInitialSupplyAndWithdrawLimitManifestInput {
+                        owner_role,
+                        id_type,
+                        track_total_supply,
+                        non_fungible_schema: NonFungibleDataSchema::new_schema::<V>(),
+                        resource_roles,
+                        withdraw_limit,
+                        metadata,
+                        entries,
+                        address_reservation: None,
+                    }
+                ),
+            }
+        } else {
+            InstructionV1::CallFunction {
+                package_address: RESOURCE_PACKAGE.into(),
+                blueprint_name: NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                function_name: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_WITHDRAW_LIMIT_IDENT
+                    .to_string(),
+                args: to_manifest_value_and_unwrap!(
+                    &NonFungibleResourceManagerCreateWithWithdrawLimitManifestInput {
+                        owner_role,
+                        id_type,
+                        track_total_supply,
+                        non_fungible_schema: NonFungibleDataSchema::new_schema::<V>(),
+                        resource_roles,
+                        withdraw_limit,
+                        metadata,
+                        address_reservation: None,
+                    }
+                ),
+            }
+        };
+
+        self.add_instruction(instruction)
+    }
+
     pub fn create_ruid_non_fungible_resource<T, V>(
         self,
         owner_role: OwnerRole,
@@ -700,6 +865,34 @@ impl ManifestBuilder {
         )
     }
 
+    pub fn update_non_fungible_data_fields(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        id: NonFungibleLocalId,
+        updates: Vec<NonFungibleDataManifestUpdate>,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_FIELDS_IDENT,
+            NonFungibleResourceManagerUpdateDataFieldsManifestInput { id, updates },
+        )
+    }
+
+    pub fn get_non_fungible_data_fields(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        id: NonFungibleLocalId,
+        field_names: Vec<String>,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_DATA_FIELDS_IDENT,
+            NonFungibleResourceManagerGetDataFieldsInput { id, field_names },
+        )
+    }
+
     pub fn create_identity_advanced(self, owner_role: OwnerRole) -> Self {
         self.add_instruction(InstructionV1::CallFunction {
             package_address: IDENTITY_PACKAGE.into(),
@@ -786,6 +979,21 @@ impl ManifestBuilder {
         self.call_method(address, VALIDATOR_UNSTAKE_IDENT, (bucket,))
     }
 
+    pub fn unstake_validator_for_claimant(
+        self,
+        validator_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+        claimant: ComponentAddress,
+    ) -> Self {
+        let address = validator_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT,
+            (bucket, claimant),
+        )
+    }
+
     pub fn claim_xrd(
         self,
         validator_address: impl ResolvableComponentAddress,
@@ -1124,6 +1332,22 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn claim_package_resource_royalties(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_CLAIM_RESOURCE_ROYALTIES_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageClaimResourceRoyaltiesInput {
+                resource_address
+            }),
+        })
+    }
+
     pub fn set_component_royalty(
         self,
         component_address: impl ResolvableComponentAddress,
@@ -1168,6 +1392,22 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn claim_component_resource_royalties(
+        self,
+        component_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> Self {
+        let address = component_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.add_instruction(InstructionV1::CallRoyaltyMethod {
+            address: address.into(),
+            method_name: COMPONENT_ROYALTY_CLAIM_RESOURCE_ROYALTIES_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ComponentClaimResourceRoyaltiesInput {
+                resource_address
+            }),
+        })
+    }
+
     pub fn set_metadata(
         self,
         address: impl ResolvableGlobalAddress,
@@ -1684,6 +1924,32 @@ impl ManifestBuilder {
         })
     }
 
+    /// Locks a contingent royalty payment from the given resource's vault of an account, of which
+    /// no single function or method may charge more than `max_per_function`.
+    pub fn lock_royalty_payment(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+        max_per_function: impl ResolvableDecimal,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+        let max_per_function = max_per_function.resolve();
+        let args = to_manifest_value_and_unwrap!(&AccountLockRoyaltyPaymentInput {
+            resource_address,
+            amount,
+            max_per_function,
+        });
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT.to_string(),
+            args,
+        })
+    }
+
     /// Locks a large fee from the faucet.
     pub fn get_free_xrd_from_faucet(self) -> Self {
         self.call_method(FAUCET, "free", ())
@@ -1979,47 +2245,708 @@ impl ManifestBuilder {
         )
     }
 
-    pub fn create_access_controller(
+    pub fn securify_account(self, account_address: impl ResolvableComponentAddress) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(address, ACCOUNT_SECURIFY_IDENT, AccountSecurifyInput {})
+    }
+
+    pub fn get_account_vaults(
         self,
-        controlled_asset: impl ExistingManifestBucket,
-        primary_role: AccessRule,
-        recovery_role: AccessRule,
-        confirmation_role: AccessRule,
-        timed_recovery_delay_in_minutes: Option<u32>,
+        account_address: impl ResolvableComponentAddress,
+        cursor: u32,
+        limit: u32,
     ) -> Self {
-        let controlled_asset = controlled_asset.mark_consumed(&self.registrar);
-        self.call_function(
-            ACCESS_CONTROLLER_PACKAGE,
-            ACCESS_CONTROLLER_BLUEPRINT,
-            ACCESS_CONTROLLER_CREATE_IDENT,
-            (
-                controlled_asset,
-                RuleSet {
-                    primary_role,
-                    recovery_role,
-                    confirmation_role,
-                },
-                timed_recovery_delay_in_minutes,
-                Option::<()>::None,
-            ),
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_GET_VAULTS_IDENT,
+            AccountGetVaultsInput { cursor, limit },
         )
     }
 
-    /// Builds a transaction manifest.
-    pub fn build(self) -> TransactionManifestV1 {
-        let manifest = TransactionManifestV1 {
-            instructions: self.instructions,
-            blobs: self.blobs,
-        };
-        #[cfg(feature = "dump_manifest_to_file")]
-        {
-            let bytes = manifest_encode(&manifest).unwrap();
-            let manifest_hash = hash(&bytes);
-            let path = format!("manifest_{:?}.raw", manifest_hash);
-            std::fs::write(&path, bytes).unwrap();
-            println!("manifest dumped to file {}", &path);
-        }
-        manifest
+    pub fn get_account_balance(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_BALANCE_OF_IDENT,
+            AccountBalanceOfInput { resource_address },
+        )
+    }
+
+    pub fn set_default_deposit_rule(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        default: DefaultDepositRule,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleInput { default },
+        )
+    }
+
+    pub fn set_deposit_rule(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        deposit_rule: Option<DepositRule>,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_SET_DEPOSIT_RULE_IDENT,
+            AccountSetDepositRuleInput { deposit_rule },
+        )
+    }
+
+    pub fn set_resource_preference(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        resource_preference: ResourcePreference,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
+            AccountSetResourcePreferenceInput {
+                resource_address,
+                resource_preference,
+            },
+        )
+    }
+
+    pub fn remove_resource_preference(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT,
+            AccountRemoveResourcePreferenceInput { resource_address },
+        )
+    }
+
+    pub fn add_authorized_depositor(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        badge: ResourceOrNonFungible,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR,
+            AccountAddAuthorizedDepositorInput { badge },
+        )
+    }
+
+    pub fn remove_authorized_depositor(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        badge: ResourceOrNonFungible,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR,
+            AccountRemoveAuthorizedDepositorInput { badge },
+        )
+    }
+
+    pub fn create_access_controller(
+        self,
+        controlled_asset: impl ExistingManifestBucket,
+        primary_role: AccessRule,
+        recovery_role: AccessRule,
+        confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let controlled_asset = controlled_asset.mark_consumed(&self.registrar);
+        self.call_function(
+            ACCESS_CONTROLLER_PACKAGE,
+            ACCESS_CONTROLLER_BLUEPRINT,
+            ACCESS_CONTROLLER_CREATE_IDENT,
+            (
+                controlled_asset,
+                RuleSet {
+                    primary_role,
+                    recovery_role,
+                    confirmation_role,
+                },
+                timed_recovery_delay_in_minutes,
+                Option::<()>::None,
+            ),
+        )
+    }
+
+    pub fn create_proof_from_access_controller(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+            AccessControllerCreateProofInput {},
+        )
+    }
+
+    pub fn initiate_recovery_as_primary(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+            AccessControllerInitiateRecoveryAsPrimaryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn initiate_recovery_as_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn initiate_badge_withdraw_attempt_as_primary(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT,
+            AccessControllerInitiateBadgeWithdrawAttemptAsPrimaryInput,
+        )
+    }
+
+    pub fn initiate_badge_withdraw_attempt_as_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT,
+            AccessControllerInitiateBadgeWithdrawAttemptAsRecoveryInput,
+        )
+    }
+
+    /// Confirms the recovery proposal of the primary role, which must match the proposal exactly.
+    pub fn quick_confirm_primary_role_recovery_proposal(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Confirms the recovery proposal of the recovery role, which must match the proposal exactly.
+    pub fn quick_confirm_recovery_role_recovery_proposal(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    /// Confirms the badge withdraw attempt of the primary role, putting the badge on the worktop.
+    pub fn quick_confirm_primary_role_badge_withdraw_attempt(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+            AccessControllerQuickConfirmPrimaryRoleBadgeWithdrawAttemptInput,
+        )
+    }
+
+    /// Confirms the badge withdraw attempt of the recovery role, putting the badge on the worktop.
+    pub fn quick_confirm_recovery_role_badge_withdraw_attempt(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+            AccessControllerQuickConfirmRecoveryRoleBadgeWithdrawAttemptInput,
+        )
+    }
+
+    pub fn timed_confirm_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+            AccessControllerTimedConfirmRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn cancel_primary_role_recovery_proposal(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            AccessControllerCancelPrimaryRoleRecoveryProposalInput,
+        )
+    }
+
+    pub fn cancel_recovery_role_recovery_proposal(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            AccessControllerCancelRecoveryRoleRecoveryProposalInput,
+        )
+    }
+
+    pub fn cancel_primary_role_badge_withdraw_attempt(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+            AccessControllerCancelPrimaryRoleBadgeWithdrawAttemptInput,
+        )
+    }
+
+    pub fn cancel_recovery_role_badge_withdraw_attempt(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+            AccessControllerCancelRecoveryRoleBadgeWithdrawAttemptInput,
+        )
+    }
+
+    pub fn lock_primary_role(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
+            AccessControllerLockPrimaryRoleInput,
+        )
+    }
+
+    pub fn unlock_primary_role(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT,
+            AccessControllerUnlockPrimaryRoleInput,
+        )
+    }
+
+    pub fn stop_timed_recovery(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT,
+            AccessControllerStopTimedRecoveryInput {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            },
+        )
+    }
+
+    pub fn mint_recovery_badges(
+        self,
+        access_controller_address: impl ResolvableComponentAddress,
+        non_fungible_local_ids: impl IntoIterator<Item = NonFungibleLocalId>,
+    ) -> Self {
+        let address = access_controller_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT,
+            AccessControllerMintRecoveryBadgesInput {
+                non_fungible_local_ids: non_fungible_local_ids.into_iter().collect(),
+            },
+        )
+    }
+
+    pub fn create_one_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> Self {
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.call_function(
+            POOL_PACKAGE,
+            ONE_RESOURCE_POOL_BLUEPRINT,
+            ONE_RESOURCE_POOL_INSTANTIATE_IDENT,
+            OneResourcePoolInstantiateManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_address,
+                address_reservation: None,
+            },
+        )
+    }
+
+    pub fn create_two_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: (ResourceAddress, ResourceAddress),
+    ) -> Self {
+        self.call_function(
+            POOL_PACKAGE,
+            TWO_RESOURCE_POOL_BLUEPRINT,
+            TWO_RESOURCE_POOL_INSTANTIATE_IDENT,
+            TwoResourcePoolInstantiateManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_addresses,
+                address_reservation: None,
+            },
+        )
+    }
+
+    pub fn create_multi_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: impl IntoIterator<Item = ResourceAddress>,
+    ) -> Self {
+        self.call_function(
+            POOL_PACKAGE,
+            MULTI_RESOURCE_POOL_BLUEPRINT,
+            MULTI_RESOURCE_POOL_INSTANTIATE_IDENT,
+            MultiResourcePoolInstantiateManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_addresses: resource_addresses.into_iter().collect(),
+                address_reservation: None,
+            },
+        )
+    }
+
+    pub fn contribute_to_one_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            OneResourcePoolContributeManifestInput { bucket },
+        )
+    }
+
+    pub fn contribute_to_two_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        buckets: (impl ExistingManifestBucket, impl ExistingManifestBucket),
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let buckets = (
+            buckets.0.mark_consumed(&self.registrar),
+            buckets.1.mark_consumed(&self.registrar),
+        );
+        self.call_method(
+            address,
+            TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            TwoResourcePoolContributeManifestInput { buckets },
+        )
+    }
+
+    pub fn contribute_to_multi_resource_pool<B: ExistingManifestBucket>(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        buckets: impl IntoIterator<Item = B>,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let buckets = buckets
+            .into_iter()
+            .map(|bucket| bucket.mark_consumed(&self.registrar))
+            .collect();
+        self.call_method(
+            address,
+            MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            MultiResourcePoolContributeManifestInput { buckets },
+        )
+    }
+
+    /// Redeems pool units from any of the native pools.
+    pub fn redeem_pool_units(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            ONE_RESOURCE_POOL_REDEEM_IDENT,
+            OneResourcePoolRedeemManifestInput { bucket },
+        )
+    }
+
+    /// Deposits into any of the native pools, without minting pool units.
+    pub fn protected_deposit_to_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_method(
+            address,
+            ONE_RESOURCE_POOL_PROTECTED_DEPOSIT_IDENT,
+            OneResourcePoolProtectedDepositManifestInput { bucket },
+        )
+    }
+
+    pub fn protected_withdraw_from_one_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        amount: impl ResolvableDecimal,
+        withdraw_strategy: WithdrawStrategy,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            ONE_RESOURCE_POOL_PROTECTED_WITHDRAW_IDENT,
+            OneResourcePoolProtectedWithdrawManifestInput {
+                amount: amount.resolve(),
+                withdraw_strategy,
+            },
+        )
+    }
+
+    /// Withdraws one of the resources of a two or multi resource pool, without burning pool units.
+    pub fn protected_withdraw_from_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+        withdraw_strategy: WithdrawStrategy,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        self.call_method(
+            address,
+            TWO_RESOURCE_POOL_PROTECTED_WITHDRAW_IDENT,
+            TwoResourcePoolProtectedWithdrawManifestInput {
+                resource_address,
+                amount: amount.resolve(),
+                withdraw_strategy,
+            },
+        )
+    }
+
+    pub fn create_vesting(
+        self,
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        revoker_rule: Option<AccessRule>,
+        schedule: VestingSchedule,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_function(
+            VESTING_PACKAGE,
+            VESTING_BLUEPRINT,
+            VESTING_INSTANTIATE_IDENT,
+            VestingInstantiateManifestInput {
+                owner_role,
+                beneficiary_rule,
+                revoker_rule,
+                schedule,
+                bucket,
+                address_reservation: None,
+            },
+        )
+    }
+
+    pub fn claim_vested(self, vesting_address: impl ResolvableComponentAddress) -> Self {
+        let address = vesting_address.resolve(&self.registrar);
+        self.call_method(address, VESTING_CLAIM_IDENT, VestingClaimManifestInput {})
+    }
+
+    pub fn revoke_vesting(self, vesting_address: impl ResolvableComponentAddress) -> Self {
+        let address = vesting_address.resolve(&self.registrar);
+        self.call_method(address, VESTING_REVOKE_IDENT, VestingRevokeManifestInput {})
+    }
+
+    pub fn create_multisig(
+        self,
+        signers: impl IntoIterator<Item = NonFungibleGlobalId>,
+        threshold: u8,
+        destination: ComponentAddress,
+    ) -> Self {
+        self.call_function(
+            MULTISIG_PACKAGE,
+            MULTISIG_BLUEPRINT,
+            MULTISIG_INSTANTIATE_IDENT,
+            MultisigInstantiateManifestInput {
+                signers: signers.into_iter().collect(),
+                threshold,
+                destination,
+                address_reservation: None,
+            },
+        )
+    }
+
+    pub fn propose_to_multisig(
+        self,
+        multisig_address: impl ResolvableComponentAddress,
+        signer: NonFungibleGlobalId,
+        target: impl Into<GlobalAddress>,
+        method: impl Into<String>,
+        args: impl ResolvableArguments,
+        expires_at: Instant,
+    ) -> Self {
+        let address = multisig_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            MULTISIG_PROPOSE_IDENT,
+            MultisigProposeManifestInput {
+                signer,
+                target: target.into(),
+                method: method.into(),
+                args: args.resolve(),
+                expires_at,
+            },
+        )
+    }
+
+    pub fn approve_multisig_proposal(
+        self,
+        multisig_address: impl ResolvableComponentAddress,
+        proposal_id: u64,
+        signer: NonFungibleGlobalId,
+    ) -> Self {
+        let address = multisig_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            MULTISIG_APPROVE_IDENT,
+            MultisigApproveManifestInput {
+                proposal_id,
+                signer,
+            },
+        )
+    }
+
+    pub fn execute_multisig_proposal(
+        self,
+        multisig_address: impl ResolvableComponentAddress,
+        proposal_id: u64,
+        signer: NonFungibleGlobalId,
+    ) -> Self {
+        let address = multisig_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            MULTISIG_EXECUTE_IDENT,
+            MultisigExecuteManifestInput {
+                proposal_id,
+                signer,
+            },
+        )
+    }
+
+    /// Builds a transaction manifest.
+    pub fn build(self) -> TransactionManifestV1 {
+        let manifest = TransactionManifestV1 {
+            instructions: self.instructions,
+            blobs: self.blobs,
+        };
+        #[cfg(feature = "dump_manifest_to_file")]
+        {
+            let bytes = manifest_encode(&manifest).unwrap();
+            let manifest_hash = hash(&bytes);
+            let path = format!("manifest_{:?}.raw", manifest_hash);
+            std::fs::write(&path, bytes).unwrap();
+            println!("manifest dumped to file {}", &path);
+        }
+        manifest
+    }
+
+    /// Builds the manifest, after checking the lifetimes of its buckets, proofs, address
+    /// reservations and named addresses with [`validate_manifest_lifecycle`].
+    pub fn try_build(self) -> Result<TransactionManifestV1, Vec<LocatedManifestLifecycleError>> {
+        validate_manifest_lifecycle(&self.instructions)?;
+        Ok(self.build())
     }
 
     pub fn to_canonical_string(
@@ -0,0 +1,630 @@
+//! Generates typed [`ManifestBuilder`] extension methods from the schema of a package, so that
+//! manifests built against a blueprint stop compiling when the blueprint interface changes.
+//!
+//! The generated code is meant to be written from a build script, for example from the
+//! `PackageDefinition` exported by `scrypto build` next to the package code:
+//!
+//! ```ignore
+//! // build.rs
+//! let definition: PackageDefinition = manifest_decode(&std::fs::read("radiswap.rpd")?)?;
+//! let code = generate_manifest_builder_extensions(&definition);
+//! std::fs::write(Path::new(&env::var("OUT_DIR")?).join("radiswap.rs"), code)?;
+//!
+//! // lib.rs
+//! use transaction::prelude::*;
+//! include!(concat!(env!("OUT_DIR"), "/radiswap.rs"));
+//! ```
+//!
+//! For each blueprint, a `<Blueprint>ManifestBuilderExt` trait is generated, with a
+//! `<blueprint>_<function>` method per function and method of the blueprint. Buckets and proofs,
+//! and vectors of them, are taken by name or as [`ManifestBucket`] / [`ManifestProof`] and are
+//! marked as consumed, and the return type of the call is given in the method documentation.
+//! Types without a manifest equivalent, such as named structs and enums, are taken as a
+//! [`ManifestValue`]. Arguments named like the address parameters of the methods, or like a Rust
+//! keyword, are suffixed with `_`.
+
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::package::{PackageDefinition, PACKAGE_BLUEPRINT};
+use radix_engine_interface::schema::{FunctionSchemaInit, Receiver, RefTypes, TypeRef};
+use sbor::rust::fmt::Write;
+
+type ScryptoSchema = SchemaV1<ScryptoCustomSchema>;
+
+/// Generates the Rust source of typed [`ManifestBuilder`] extension traits for all the blueprints
+/// of a package. The generated code expects `transaction::prelude::*` to be in scope.
+pub fn generate_manifest_builder_extensions(package_definition: &PackageDefinition) -> String {
+    let mut code = String::new();
+    writeln!(
+        code,
+        "// Generated by `generate_manifest_builder_extensions`. Do not edit."
+    )
+    .unwrap();
+    for (blueprint_name, definition) in &package_definition.blueprints {
+        let schema = definition.schema.schema.v1();
+        let functions = definition
+            .schema
+            .functions
+            .functions
+            .iter()
+            .filter_map(|(name, function)| {
+                generate_function(schema, blueprint_name, name, function)
+            })
+            .collect::<Vec<_>>();
+        if functions.is_empty() {
+            continue;
+        }
+
+        let trait_name = format!("{}ManifestBuilderExt", to_upper_camel_case(blueprint_name));
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "/// Typed manifest builder methods for the `{}` blueprint.",
+            blueprint_name
+        )
+        .unwrap();
+        writeln!(code, "pub trait {}: Sized {{", trait_name).unwrap();
+        for function in &functions {
+            writeln!(code, "    /// {}", function.doc).unwrap();
+            writeln!(code, "    fn {};", function.signature).unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+        writeln!(code).unwrap();
+        writeln!(code, "impl {} for ManifestBuilder {{", trait_name).unwrap();
+        for function in &functions {
+            writeln!(code, "    fn {} {{", function.signature).unwrap();
+            for line in &function.body {
+                writeln!(code, "        {}", line).unwrap();
+            }
+            writeln!(code, "    }}").unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+    }
+    code
+}
+
+struct GeneratedFunction {
+    doc: String,
+    signature: String,
+    body: Vec<String>,
+}
+
+/// How a top-level argument is taken by a generated method, and resolved in its body.
+enum Parameter {
+    Bucket,
+    Proof,
+    Buckets,
+    Proofs,
+    Decimal,
+    Value(String),
+}
+
+fn generate_function(
+    schema: &ScryptoSchema,
+    blueprint_name: &str,
+    function_name: &str,
+    function: &FunctionSchemaInit,
+) -> Option<GeneratedFunction> {
+    // Direct access methods can only be called on internal vaults, which a manifest can't name
+    if let Some(receiver) = &function.receiver {
+        if !receiver.ref_types.contains(RefTypes::NORMAL) {
+            return None;
+        }
+    }
+    let TypeRef::Static(input) = function.input else {
+        return None;
+    };
+    let Some(TypeKind::Tuple { field_types }) = schema.resolve_type_kind(input) else {
+        return None;
+    };
+    let field_names = schema
+        .resolve_type_metadata(input)
+        .and_then(|metadata| metadata.get_field_names());
+
+    let (address_name, address_type) = match function.receiver {
+        Some(_) => ("component_address", "impl ResolvableComponentAddress"),
+        None => ("package_address", "impl ResolvablePackageAddress"),
+    };
+    let mut parameters = vec![format!("{}: {}", address_name, address_type)];
+    let mut body = vec![];
+    let mut arguments = vec![];
+    // Both address names are reserved, so that the arguments of functions and methods are
+    // renamed alike
+    let mut used_names = ["package_address", "component_address"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<BTreeSet<_>>();
+    for (i, field_type) in field_types.iter().enumerate() {
+        let mut name = match field_names.and_then(|names| names.get(i)) {
+            Some(name) => name.to_string(),
+            None => format!("arg{}", i),
+        };
+        while is_rust_keyword(&name) || !used_names.insert(name.clone()) {
+            name.push('_');
+        }
+        match parameter(schema, *field_type) {
+            Parameter::Bucket => {
+                parameters.push(format!("{}: impl ExistingManifestBucket", name));
+                body.push(format!("let {0} = self.consume_bucket({0});", name));
+            }
+            Parameter::Proof => {
+                parameters.push(format!("{}: impl ExistingManifestProof", name));
+                body.push(format!("let {0} = self.consume_proof({0});", name));
+            }
+            Parameter::Buckets => {
+                parameters.push(format!("{}: Vec<impl ExistingManifestBucket>", name));
+                body.push(format!(
+                    "let {0}: Vec<ManifestBucket> = {0}.into_iter().map(|bucket| self.consume_bucket(bucket)).collect();",
+                    name
+                ));
+            }
+            Parameter::Proofs => {
+                parameters.push(format!("{}: Vec<impl ExistingManifestProof>", name));
+                body.push(format!(
+                    "let {0}: Vec<ManifestProof> = {0}.into_iter().map(|proof| self.consume_proof(proof)).collect();",
+                    name
+                ));
+            }
+            Parameter::Decimal => {
+                parameters.push(format!("{}: impl ResolvableDecimal", name));
+                body.push(format!("let {0} = ResolvableDecimal::resolve({0});", name));
+            }
+            Parameter::Value(rust_type) => {
+                parameters.push(format!("{}: {}", name, rust_type));
+            }
+        }
+        arguments.push(name);
+    }
+
+    let returns = match function.output {
+        TypeRef::Static(output) => format!("`{}`", type_name(schema, output)),
+        TypeRef::Generic(index) => format!("the blueprint generic {}", index),
+    };
+    let (doc, call) = match &function.receiver {
+        Some(receiver) => {
+            let receiver = match receiver.receiver {
+                Receiver::SelfRef => "`&self`",
+                Receiver::SelfRefMut => "`&mut self`",
+            };
+            body.insert(
+                0,
+                "let component_address = self.resolve_component_address(component_address);"
+                    .to_string(),
+            );
+            (
+                format!(
+                    "Calls the {} method `{}` of a `{}` component, which returns {}.",
+                    receiver, function_name, blueprint_name, returns
+                ),
+                format!(
+                    "self.call_method(component_address, \"{}\", manifest_args!({}))",
+                    function_name,
+                    arguments.join(", ")
+                ),
+            )
+        }
+        None => (
+            format!(
+                "Calls the function `{}` of the `{}` blueprint, which returns {}.",
+                function_name, blueprint_name, returns
+            ),
+            format!(
+                "self.call_function(package_address, \"{}\", \"{}\", manifest_args!({}))",
+                blueprint_name,
+                function_name,
+                arguments.join(", ")
+            ),
+        ),
+    };
+    body.push(call);
+
+    Some(GeneratedFunction {
+        doc,
+        signature: format!(
+            "{}_{}(self, {}) -> Self",
+            to_snake_case(blueprint_name),
+            function_name,
+            parameters.join(", ")
+        ),
+        body,
+    })
+}
+
+fn parameter(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> Parameter {
+    match own_kind(schema, type_index) {
+        Some(OwnKind::Bucket) => return Parameter::Bucket,
+        Some(OwnKind::Proof) => return Parameter::Proof,
+        _ => {}
+    }
+    match schema.resolve_type_kind(type_index) {
+        Some(TypeKind::Custom(ScryptoCustomTypeKind::Decimal)) => Parameter::Decimal,
+        Some(TypeKind::Array { element_type }) => match own_kind(schema, *element_type) {
+            Some(OwnKind::Bucket) => Parameter::Buckets,
+            Some(OwnKind::Proof) => Parameter::Proofs,
+            _ => Parameter::Value(manifest_type(schema, type_index)),
+        },
+        _ => Parameter::Value(manifest_type(schema, type_index)),
+    }
+}
+
+enum OwnKind {
+    Bucket,
+    Proof,
+    AddressReservation,
+    Other(String),
+}
+
+fn own_kind(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> Option<OwnKind> {
+    let Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Own(validation))) =
+        schema.resolve_type_validation(type_index)
+    else {
+        return None;
+    };
+    Some(match validation {
+        OwnValidation::IsBucket => OwnKind::Bucket,
+        OwnValidation::IsProof => OwnKind::Proof,
+        OwnValidation::IsGlobalAddressReservation => OwnKind::AddressReservation,
+        OwnValidation::IsVault => OwnKind::Other("Vault".to_string()),
+        OwnValidation::IsKeyValueStore => OwnKind::Other("KeyValueStore".to_string()),
+        OwnValidation::IsTypedObject(_, blueprint_name) => match blueprint_name.as_str() {
+            FUNGIBLE_BUCKET_BLUEPRINT | NON_FUNGIBLE_BUCKET_BLUEPRINT => OwnKind::Bucket,
+            FUNGIBLE_PROOF_BLUEPRINT | NON_FUNGIBLE_PROOF_BLUEPRINT => OwnKind::Proof,
+            _ => OwnKind::Other(blueprint_name.clone()),
+        },
+    })
+}
+
+/// The address type of a reference, or `None` for internal references.
+fn reference_type(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> Option<&'static str> {
+    let Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(validation))) =
+        schema.resolve_type_validation(type_index)
+    else {
+        return Some("GlobalAddress");
+    };
+    match validation {
+        ReferenceValidation::IsGlobal => Some("GlobalAddress"),
+        ReferenceValidation::IsGlobalPackage => Some("PackageAddress"),
+        ReferenceValidation::IsGlobalComponent => Some("ComponentAddress"),
+        ReferenceValidation::IsGlobalResourceManager => Some("ResourceAddress"),
+        ReferenceValidation::IsGlobalTyped(_, blueprint_name) => match blueprint_name.as_str() {
+            PACKAGE_BLUEPRINT => Some("PackageAddress"),
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT | NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT => {
+                Some("ResourceAddress")
+            }
+            _ => Some("ComponentAddress"),
+        },
+        ReferenceValidation::IsInternal | ReferenceValidation::IsInternalTyped(_, _) => None,
+    }
+}
+
+/// The Rust type which encodes to a manifest value matching the given type.
+fn manifest_type(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> String {
+    let Some(type_kind) = schema.resolve_type_kind(type_index) else {
+        return "ManifestValue".to_string();
+    };
+    let type_name = schema.resolve_type_name_from_metadata(type_index);
+    match type_kind {
+        TypeKind::Bool => "bool".to_string(),
+        TypeKind::I8 => "i8".to_string(),
+        TypeKind::I16 => "i16".to_string(),
+        TypeKind::I32 => "i32".to_string(),
+        TypeKind::I64 => "i64".to_string(),
+        TypeKind::I128 => "i128".to_string(),
+        TypeKind::U8 => "u8".to_string(),
+        TypeKind::U16 => "u16".to_string(),
+        TypeKind::U32 => "u32".to_string(),
+        TypeKind::U64 => "u64".to_string(),
+        TypeKind::U128 => "u128".to_string(),
+        TypeKind::String => "String".to_string(),
+        TypeKind::Array { element_type } => {
+            format!("Vec<{}>", manifest_type(schema, *element_type))
+        }
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "BTreeMap<{}, {}>",
+            manifest_type(schema, *key_type),
+            manifest_type(schema, *value_type)
+        ),
+        TypeKind::Tuple { field_types } if type_name.is_none() => format!(
+            "({})",
+            field_types
+                .iter()
+                .map(|field_type| format!("{},", manifest_type(schema, *field_type)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+        TypeKind::Enum { variants } if type_name == Some("Option") => match variants.get(&1) {
+            Some(fields) if fields.len() == 1 => {
+                format!("Option<{}>", manifest_type(schema, fields[0]))
+            }
+            _ => "ManifestValue".to_string(),
+        },
+        TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => "Decimal".to_string(),
+        TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => "PreciseDecimal".to_string(),
+        TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+            "NonFungibleLocalId".to_string()
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Reference) => reference_type(schema, type_index)
+            .unwrap_or("ManifestValue")
+            .to_string(),
+        TypeKind::Custom(ScryptoCustomTypeKind::Own) => match own_kind(schema, type_index) {
+            Some(OwnKind::Bucket) => "ManifestBucket".to_string(),
+            Some(OwnKind::Proof) => "ManifestProof".to_string(),
+            Some(OwnKind::AddressReservation) => "ManifestAddressReservation".to_string(),
+            _ => "ManifestValue".to_string(),
+        },
+        TypeKind::Any | TypeKind::Tuple { .. } | TypeKind::Enum { .. } => {
+            "ManifestValue".to_string()
+        }
+    }
+}
+
+/// A Scrypto-side name of the given type, for documentation.
+fn type_name(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> String {
+    let Some(type_kind) = schema.resolve_type_kind(type_index) else {
+        return "Any".to_string();
+    };
+    let name = schema.resolve_type_name_from_metadata(type_index);
+    match type_kind {
+        TypeKind::Array { element_type } => format!("Vec<{}>", type_name(schema, *element_type)),
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "BTreeMap<{}, {}>",
+            type_name(schema, *key_type),
+            type_name(schema, *value_type)
+        ),
+        TypeKind::Tuple { field_types } if name.is_none() || field_types.is_empty() => format!(
+            "({})",
+            field_types
+                .iter()
+                .map(|field_type| type_name(schema, *field_type))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeKind::Enum { variants } if name == Some("Option") => match variants.get(&1) {
+            Some(fields) if fields.len() == 1 => {
+                format!("Option<{}>", type_name(schema, fields[0]))
+            }
+            _ => "Option".to_string(),
+        },
+        TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+            match reference_type(schema, type_index) {
+                Some(address_type) => address_type.to_string(),
+                None => "InternalAddress".to_string(),
+            }
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Own) => match own_kind(schema, type_index) {
+            Some(OwnKind::Bucket) => "Bucket".to_string(),
+            Some(OwnKind::Proof) => "Proof".to_string(),
+            Some(OwnKind::AddressReservation) => "GlobalAddressReservation".to_string(),
+            Some(OwnKind::Other(name)) => name,
+            None => "Own".to_string(),
+        },
+        TypeKind::Bool => "bool".to_string(),
+        TypeKind::I8 => "i8".to_string(),
+        TypeKind::I16 => "i16".to_string(),
+        TypeKind::I32 => "i32".to_string(),
+        TypeKind::I64 => "i64".to_string(),
+        TypeKind::I128 => "i128".to_string(),
+        TypeKind::U8 => "u8".to_string(),
+        TypeKind::U16 => "u16".to_string(),
+        TypeKind::U32 => "u32".to_string(),
+        TypeKind::U64 => "u64".to_string(),
+        TypeKind::U128 => "u128".to_string(),
+        _ => name.unwrap_or("Any").to_string(),
+    }
+}
+
+fn to_upper_camel_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            core::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    let mut previous_is_lowercase = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if previous_is_lowercase {
+                snake_case.push('_');
+            }
+            snake_case.push(c.to_ascii_lowercase());
+            previous_is_lowercase = false;
+        } else if c.is_ascii_alphanumeric() {
+            snake_case.push(c);
+            previous_is_lowercase = true;
+        } else {
+            snake_case.push('_');
+            previous_is_lowercase = false;
+        }
+    }
+    snake_case
+}
+
+fn is_rust_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "as" | "async"
+            | "await"
+            | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "Self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
+    use radix_engine_interface::schema::{
+        BlueprintFunctionsSchemaInit, BlueprintSchemaInit, ReceiverInfo,
+    };
+    use radix_engine_interface::ScryptoSbor;
+
+    #[derive(ScryptoSbor)]
+    struct InstantiateInput {
+        pool_units: ResourceAddress,
+        fee: Decimal,
+        name: Option<String>,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct SwapInput {
+        input: Bucket,
+        badges: Vec<Proof>,
+        min_output: Option<Decimal>,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct TransferInput {
+        component_address: ComponentAddress,
+        component_address_: u32,
+        package_address: PackageAddress,
+    }
+
+    #[test]
+    fn generates_typed_methods_for_functions_and_methods() {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let mut functions = BTreeMap::new();
+        functions.insert(
+            "instantiate".to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<InstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<(ComponentAddress, Bucket)>(),
+                ),
+                export: "instantiate".to_string(),
+            },
+        );
+        functions.insert(
+            "swap".to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator.add_child_type_and_descendents::<SwapInput>()),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<Bucket>()),
+                export: "swap".to_string(),
+            },
+        );
+        let definition = PackageDefinition {
+            blueprints: btreemap!(
+                "SwapPool".to_string() => BlueprintDefinitionInit {
+                    schema: BlueprintSchemaInit {
+                        schema: generate_full_schema(aggregator),
+                        functions: BlueprintFunctionsSchemaInit { functions },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            ),
+        };
+
+        let code = generate_manifest_builder_extensions(&definition);
+
+        assert!(code.contains("pub trait SwapPoolManifestBuilderExt: Sized {"));
+        assert!(code.contains("impl SwapPoolManifestBuilderExt for ManifestBuilder {"));
+        assert!(code.contains(
+            "/// Calls the function `instantiate` of the `SwapPool` blueprint, which returns `(ComponentAddress, Bucket)`."
+        ));
+        assert!(code.contains(
+            "fn swap_pool_instantiate(self, package_address: impl ResolvablePackageAddress, pool_units: ResourceAddress, fee: impl ResolvableDecimal, name: Option<String>) -> Self"
+        ));
+        assert!(code.contains(
+            "self.call_function(package_address, \"SwapPool\", \"instantiate\", manifest_args!(pool_units, fee, name))"
+        ));
+        assert!(code.contains(
+            "/// Calls the `&mut self` method `swap` of a `SwapPool` component, which returns `Bucket`."
+        ));
+        assert!(code.contains(
+            "fn swap_pool_swap(self, component_address: impl ResolvableComponentAddress, input: impl ExistingManifestBucket, badges: Vec<impl ExistingManifestProof>, min_output: Option<Decimal>) -> Self"
+        ));
+        assert!(code.contains("let input = self.consume_bucket(input);"));
+        assert!(code.contains(
+            "let badges: Vec<ManifestProof> = badges.into_iter().map(|proof| self.consume_proof(proof)).collect();"
+        ));
+        assert!(code.contains(
+            "self.call_method(component_address, \"swap\", manifest_args!(input, badges, min_output))"
+        ));
+    }
+
+    #[test]
+    fn renames_arguments_colliding_with_address_parameters() {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let mut functions = BTreeMap::new();
+        functions.insert(
+            "transfer".to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<TransferInput>(),
+                ),
+                output: TypeRef::Static(aggregator.add_child_type_and_descendents::<()>()),
+                export: "transfer".to_string(),
+            },
+        );
+        let definition = PackageDefinition {
+            blueprints: btreemap!(
+                "Vault".to_string() => BlueprintDefinitionInit {
+                    schema: BlueprintSchemaInit {
+                        schema: generate_full_schema(aggregator),
+                        functions: BlueprintFunctionsSchemaInit { functions },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            ),
+        };
+
+        let code = generate_manifest_builder_extensions(&definition);
+
+        assert!(code.contains(
+            "fn vault_transfer(self, component_address: impl ResolvableComponentAddress, component_address_: ComponentAddress, component_address__: u32, package_address_: PackageAddress) -> Self"
+        ));
+        assert!(code.contains(
+            "self.call_method(component_address, \"transfer\", manifest_args!(component_address_, component_address__, package_address_))"
+        ));
+    }
+}
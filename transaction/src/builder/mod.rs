@@ -1,7 +1,11 @@
 mod manifest_builder;
+mod manifest_builder_codegen;
 mod manifest_namer;
 mod transaction_builder;
+mod transaction_v2_builder;
 
 pub use manifest_builder::*;
+pub use manifest_builder_codegen::*;
 pub use manifest_namer::*;
 pub use transaction_builder::*;
+pub use transaction_v2_builder::*;
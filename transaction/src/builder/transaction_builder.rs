@@ -1,5 +1,4 @@
-use crate::model::*;
-use crate::signing::Signer;
+use crate::internal_prelude::*;
 
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifestV1>,
@@ -35,22 +34,43 @@ impl TransactionBuilder {
         self
     }
 
-    pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
+    /// Sets a message encrypted for the given decryptors, see [`EncryptedMessageV1::encrypt`].
+    pub fn encrypted_message(
+        self,
+        message: &PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        entropy: &[u8; 32],
+    ) -> Result<Self, MessageEncryptionError> {
+        Ok(self.message(MessageV1::encrypted(message, decryptors, entropy)?))
+    }
+
+    pub fn sign<S: Signer + ?Sized>(self, signer: &S) -> Self {
+        self.try_sign(signer).expect("Signer failed to sign")
+    }
+
+    pub fn try_sign<S: Signer + ?Sized>(mut self, signer: &S) -> Result<Self, SigningError> {
         let intent = self.transaction_intent();
         let prepared = intent.prepare().expect("Intent could be prepared");
         self.intent_signatures
-            .push(signer.sign_with_public_key(&prepared.intent_hash()));
-        self
+            .push(signer.try_sign_with_public_key(prepared.intent_hash().as_hash())?);
+        Ok(self)
+    }
+
+    pub fn multi_sign<S: Signer + ?Sized>(self, signers: &[&S]) -> Self {
+        self.try_multi_sign(signers).expect("Signer failed to sign")
     }
 
-    pub fn multi_sign<S: Signer>(mut self, signers: &[&S]) -> Self {
+    pub fn try_multi_sign<S: Signer + ?Sized>(
+        mut self,
+        signers: &[&S],
+    ) -> Result<Self, SigningError> {
         let intent = self.transaction_intent();
         let prepared = intent.prepare().expect("Intent could be prepared");
         for signer in signers {
             self.intent_signatures
-                .push(signer.sign_with_public_key(&prepared.intent_hash()));
+                .push(signer.try_sign_with_public_key(prepared.intent_hash().as_hash())?);
         }
-        self
+        Ok(self)
     }
 
     pub fn signer_signatures(mut self, sigs: Vec<SignatureWithPublicKeyV1>) -> Self {
@@ -58,17 +78,18 @@ impl TransactionBuilder {
         self
     }
 
-    pub fn notarize<S: Signer>(mut self, signer: &S) -> Self {
+    pub fn notarize<S: Signer + ?Sized>(self, signer: &S) -> Self {
+        self.try_notarize(signer).expect("Signer failed to sign")
+    }
+
+    pub fn try_notarize<S: Signer + ?Sized>(mut self, signer: &S) -> Result<Self, SigningError> {
         let signed_intent = self.signed_transaction_intent();
         let prepared = signed_intent
             .prepare()
             .expect("Signed intent could be prepared");
-        self.notary_signature = Some(
-            signer
-                .sign_with_public_key(&prepared.signed_intent_hash())
-                .signature(),
-        );
-        self
+        self.notary_signature =
+            Some(signer.try_sign_without_public_key(prepared.signed_intent_hash().as_hash())?);
+        Ok(self)
     }
 
     pub fn notary_signature(mut self, signature: SignatureV1) -> Self {
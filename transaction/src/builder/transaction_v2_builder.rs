@@ -0,0 +1,188 @@
+use crate::internal_prelude::*;
+
+/// Builds a signed subintent, which can be added to a [`TransactionV2Builder`].
+pub struct SubintentBuilder {
+    manifest: Option<TransactionManifestV1>,
+    header: Option<SubintentHeaderV2>,
+    message: Option<MessageV1>,
+    subintent_signatures: Vec<SignatureWithPublicKeyV1>,
+}
+
+impl SubintentBuilder {
+    pub fn new() -> Self {
+        Self {
+            manifest: None,
+            header: None,
+            message: None,
+            subintent_signatures: vec![],
+        }
+    }
+
+    /// Sets the manifest, which must end with a `YieldToParent` instruction.
+    pub fn manifest(mut self, manifest: TransactionManifestV1) -> Self {
+        self.manifest = Some(manifest);
+        self
+    }
+
+    pub fn header(mut self, header: SubintentHeaderV2) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn message(mut self, message: MessageV1) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    pub fn sign<S: Signer + ?Sized>(self, signer: &S) -> Self {
+        self.try_sign(signer).expect("Signer failed to sign")
+    }
+
+    pub fn try_sign<S: Signer + ?Sized>(mut self, signer: &S) -> Result<Self, SigningError> {
+        let prepared = self
+            .subintent()
+            .prepare()
+            .expect("Subintent could be prepared");
+        self.subintent_signatures
+            .push(signer.try_sign_with_public_key(prepared.subintent_hash().as_hash())?);
+        Ok(self)
+    }
+
+    pub fn build(&self) -> SignedSubintentV2 {
+        SignedSubintentV2 {
+            subintent: self.subintent(),
+            subintent_signatures: IntentSignaturesV1 {
+                signatures: self
+                    .subintent_signatures
+                    .iter()
+                    .cloned()
+                    .map(IntentSignatureV1)
+                    .collect(),
+            },
+        }
+    }
+
+    fn subintent(&self) -> SubintentV2 {
+        let (instructions, blobs) = self
+            .manifest
+            .clone()
+            .expect("Manifest not specified")
+            .for_intent();
+        SubintentV2 {
+            header: self.header.clone().expect("Header not specified"),
+            instructions,
+            blobs,
+            message: self.message.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Builds a V2 transaction, whose manifest can yield to the signed subintents added to it.
+pub struct TransactionV2Builder {
+    manifest: Option<TransactionManifestV1>,
+    header: Option<TransactionHeaderV1>,
+    message: Option<MessageV1>,
+    subintents: Vec<SignedSubintentV2>,
+    intent_signatures: Vec<SignatureWithPublicKeyV1>,
+    notary_signature: Option<SignatureV1>,
+}
+
+impl TransactionV2Builder {
+    pub fn new() -> Self {
+        Self {
+            manifest: None,
+            header: None,
+            message: None,
+            subintents: vec![],
+            intent_signatures: vec![],
+            notary_signature: None,
+        }
+    }
+
+    pub fn manifest(mut self, manifest: TransactionManifestV1) -> Self {
+        self.manifest = Some(manifest);
+        self
+    }
+
+    pub fn header(mut self, header: TransactionHeaderV1) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn message(mut self, message: MessageV1) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Adds a subintent, which the manifest refers to by the order it was added in.
+    pub fn add_signed_subintent(mut self, signed_subintent: SignedSubintentV2) -> Self {
+        self.subintents.push(signed_subintent);
+        self
+    }
+
+    pub fn sign<S: Signer + ?Sized>(self, signer: &S) -> Self {
+        self.try_sign(signer).expect("Signer failed to sign")
+    }
+
+    pub fn try_sign<S: Signer + ?Sized>(mut self, signer: &S) -> Result<Self, SigningError> {
+        let prepared = self
+            .transaction_intent()
+            .prepare()
+            .expect("Intent could be prepared");
+        self.intent_signatures
+            .push(signer.try_sign_with_public_key(prepared.intent_hash().as_hash())?);
+        Ok(self)
+    }
+
+    pub fn notarize<S: Signer + ?Sized>(self, signer: &S) -> Self {
+        self.try_notarize(signer).expect("Signer failed to sign")
+    }
+
+    pub fn try_notarize<S: Signer + ?Sized>(mut self, signer: &S) -> Result<Self, SigningError> {
+        let prepared = self
+            .signed_transaction_intent()
+            .prepare()
+            .expect("Signed intent could be prepared");
+        self.notary_signature =
+            Some(signer.try_sign_without_public_key(prepared.signed_intent_hash().as_hash())?);
+        Ok(self)
+    }
+
+    pub fn build(&self) -> NotarizedTransactionV2 {
+        NotarizedTransactionV2 {
+            signed_intent: self.signed_transaction_intent(),
+            notary_signature: NotarySignatureV1(
+                self.notary_signature.clone().expect("Not notarized"),
+            ),
+        }
+    }
+
+    fn transaction_intent(&self) -> IntentV2 {
+        let (instructions, blobs) = self
+            .manifest
+            .clone()
+            .expect("Manifest not specified")
+            .for_intent();
+        IntentV2 {
+            header: self.header.clone().expect("Header not specified"),
+            instructions,
+            blobs,
+            message: self.message.clone().unwrap_or_default(),
+            subintents: SubintentsV2(self.subintents.clone()),
+        }
+    }
+
+    fn signed_transaction_intent(&self) -> SignedIntentV2 {
+        SignedIntentV2 {
+            intent: self.transaction_intent(),
+            intent_signatures: IntentSignaturesV1 {
+                signatures: self
+                    .intent_signatures
+                    .iter()
+                    .cloned()
+                    .map(IntentSignatureV1)
+                    .collect(),
+            },
+        }
+    }
+}
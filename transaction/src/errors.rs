@@ -35,12 +35,77 @@ pub enum ManifestIdValidationError {
     AddressNotFound(u32),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestLifecycleError {
+    BucketNotFound(ManifestBucket),
+    BucketAlreadyConsumed {
+        bucket_id: ManifestBucket,
+        consumed_at: usize,
+    },
+    BucketLocked(ManifestBucket),
+    BucketNotConsumed(ManifestBucket),
+    ProofNotFound(ManifestProof),
+    ProofAlreadyDropped {
+        proof_id: ManifestProof,
+        dropped_at: usize,
+    },
+    AddressReservationNotFound(ManifestAddressReservation),
+    AddressReservationAlreadyUsed {
+        address_reservation_id: ManifestAddressReservation,
+        used_at: usize,
+    },
+    AddressReservationNotUsed(ManifestAddressReservation),
+    NamedAddressNotFound(u32),
+    ResourceLeftOnWorktop(ResourceAddress),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedManifestLifecycleError {
+    pub instruction_index: usize,
+    pub cause: ManifestLifecycleError,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CallDataValidationError {
     DecodeError(DecodeError),
     IdValidationError(ManifestIdValidationError),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubintentValidationError {
+    /// The intent uses subintent instructions, but doesn't support subintents.
+    SubintentInstructionsNotSupported,
+    DuplicateSubintent,
+    /// The root intent yielded to its children out of order.
+    ChildYieldedOutOfOrder {
+        expected_child_index: u32,
+        actual_child_index: u32,
+    },
+    InvalidChildIndex {
+        child_index: u32,
+    },
+    ChildNotYielded {
+        child_index: u32,
+    },
+    YieldToParentInRootIntent,
+    YieldToChildInSubintent {
+        subintent_index: usize,
+    },
+    /// The subintent doesn't end with its only `YieldToParent` instruction.
+    MissingFinalYieldToParent {
+        subintent_index: usize,
+    },
+    TransactionProcessorInvocation,
+    HeaderValidationError {
+        subintent_index: usize,
+        error: HeaderValidationError,
+    },
+    SignatureValidationError {
+        subintent_index: usize,
+        error: SignatureValidationError,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionValidationError {
     TransactionTooLarge,
@@ -51,6 +116,7 @@ pub enum TransactionValidationError {
     IdValidationError(ManifestIdValidationError),
     CallDataValidationError(CallDataValidationError),
     InvalidMessage(InvalidMessageError),
+    SubintentValidationError(SubintentValidationError),
 }
 
 impl From<PrepareError> for TransactionValidationError {
@@ -65,6 +131,12 @@ impl From<EncodeError> for TransactionValidationError {
     }
 }
 
+impl From<SubintentValidationError> for TransactionValidationError {
+    fn from(value: SubintentValidationError) -> Self {
+        Self::SubintentValidationError(value)
+    }
+}
+
 impl From<InvalidMessageError> for TransactionValidationError {
     fn from(value: InvalidMessageError) -> Self {
         Self::InvalidMessage(value)
@@ -98,3 +170,43 @@ pub enum InvalidMessageError {
         curve_type: CurveType,
     },
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageEncryptionError {
+    NoDecryptors,
+    InvalidDecryptorPublicKey(PublicKey),
+    InvalidEntropy,
+    EncodeError(EncodeError),
+    EncryptionFailed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageDecryptionError {
+    NotEncrypted,
+    NotADecryptor,
+    InvalidEphemeralPublicKey,
+    KeyUnwrapFailed,
+    DecryptionFailed,
+    DecodeError(DecodeError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartialSigningError {
+    PrepareError(PrepareError),
+    SigningError(SigningError),
+    InvalidSignature(IntentSignatureV1),
+    DuplicateSigner(PublicKey),
+    IntentMismatch,
+    MissingSigners(Vec<PublicKey>),
+    NotaryMismatch {
+        expected: PublicKey,
+        actual: PublicKey,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SigningError {
+    SignerUnavailable(String),
+    SigningRejected(String),
+    InvalidResponse(String),
+}
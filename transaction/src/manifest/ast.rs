@@ -142,6 +142,15 @@ pub enum Instruction {
         named_address: Value,
     },
 
+    YieldToChild {
+        child_index: Value,
+        args: Vec<Value>,
+    },
+
+    YieldToParent {
+        args: Vec<Value>,
+    },
+
     /* Call direct vault method aliases */
     RecallFromVault {
         vault_id: Value,
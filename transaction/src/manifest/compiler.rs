@@ -5,6 +5,10 @@ pub enum CompileError {
     LexerError(lexer::LexerError),
     ParserError(parser::ParserError),
     GeneratorError(generator::GeneratorError),
+    /// Yield instructions are only supported in the manifests of V2 intents and subintents.
+    SubintentInstructionNotSupported {
+        instruction_index: usize,
+    },
 }
 
 pub fn compile<B>(
@@ -12,6 +16,52 @@ pub fn compile<B>(
     network: &NetworkDefinition,
     blobs: B,
 ) -> Result<TransactionManifestV1, CompileError>
+where
+    B: IsBlobProvider,
+{
+    compile_with_parameters(s, network, blobs, index_map_new())
+}
+
+/// Compiles a manifest, substituting the values of the parameters it declares with `PARAM`.
+pub fn compile_with_parameters<B>(
+    s: &str,
+    network: &NetworkDefinition,
+    blobs: B,
+    parameters: IndexMap<String, String>,
+) -> Result<TransactionManifestV1, CompileError>
+where
+    B: IsBlobProvider,
+{
+    let manifest = compile_internal(s, network, blobs, parameters)?;
+    if let Some(instruction_index) = manifest.instructions.iter().position(|instruction| {
+        matches!(
+            instruction,
+            InstructionV1::YieldToChild { .. } | InstructionV1::YieldToParent { .. }
+        )
+    }) {
+        return Err(CompileError::SubintentInstructionNotSupported { instruction_index });
+    }
+    Ok(manifest)
+}
+
+/// Compiles the manifest of a V2 intent or subintent, which may yield to its children or parent.
+pub fn compile_v2<B>(
+    s: &str,
+    network: &NetworkDefinition,
+    blobs: B,
+) -> Result<TransactionManifestV1, CompileError>
+where
+    B: IsBlobProvider,
+{
+    compile_internal(s, network, blobs, index_map_new())
+}
+
+fn compile_internal<B>(
+    s: &str,
+    network: &NetworkDefinition,
+    blobs: B,
+    parameters: IndexMap<String, String>,
+) -> Result<TransactionManifestV1, CompileError>
 where
     B: IsBlobProvider,
 {
@@ -19,6 +69,7 @@ where
 
     let tokens = lexer::tokenize(s).map_err(CompileError::LexerError)?;
     let instructions = parser::Parser::new(tokens, parser::PARSER_MAX_DEPTH)
+        .with_parameters(parameters)
         .parse_manifest()
         .map_err(CompileError::ParserError)?;
     generator::generate_manifest(&instructions, &address_bech32_decoder, blobs)
@@ -85,6 +85,19 @@ lazy_static! {
                 ProofRule = 0;
                 AnyOf = 1;
                 AllOf = 2;
+                TimeRule = 3;
+            }
+        );
+
+        known_enum!(
+            m,
+            enum TimeRule {
+                AfterInstant = 0;
+                BeforeInstant = 1;
+                BetweenInstants = 2;
+                AfterEpoch = 3;
+                BeforeEpoch = 4;
+                BetweenEpochs = 5;
             }
         );
 
@@ -158,6 +171,7 @@ lazy_static! {
                 Free = 0;
                 Xrd = 1;
                 Usd = 2;
+                Resource = 3;
             }
         );
 
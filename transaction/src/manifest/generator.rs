@@ -597,6 +597,22 @@ where
             }
         }
 
+        ast::Instruction::YieldToChild { child_index, args } => {
+            let child_index = generate_u32(&child_index)?;
+            let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
+            id_validator
+                .process_call_data(&args)
+                .map_err(GeneratorError::IdValidationError)?;
+            InstructionV1::YieldToChild { child_index, args }
+        }
+        ast::Instruction::YieldToParent { args } => {
+            let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
+            id_validator
+                .process_call_data(&args)
+                .map_err(GeneratorError::IdValidationError)?;
+            InstructionV1::YieldToParent { args }
+        }
+
         /* direct vault method aliases */
         ast::Instruction::RecallFromVault { vault_id, args } => {
             InstructionV1::CallDirectVaultMethod {
@@ -845,6 +861,13 @@ fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     }
 }
 
+fn generate_u32(value: &ast::Value) -> Result<u32, GeneratorError> {
+    match value {
+        ast::Value::U32(n) => Ok(*n),
+        v => invalid_type!(v, ast::ValueKind::U32),
+    }
+}
+
 fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     match value {
         ast::Value::Decimal(inner) => match &**inner {
@@ -2192,4 +2215,38 @@ mod tests {
         let result = manifest_encode(&compiled);
         assert_eq!(result, Err(expected));
     }
+
+    #[test]
+    fn test_yield_instructions_only_compile_for_v2() {
+        use crate::manifest::*;
+
+        let manifest = r#"
+            YIELD_TO_CHILD 0u32;
+            YIELD_TO_PARENT Expression("ENTIRE_WORKTOP");
+        "#;
+
+        assert_eq!(
+            compile(
+                manifest,
+                &NetworkDefinition::simulator(),
+                BlobProvider::default()
+            ),
+            Err(CompileError::SubintentInstructionNotSupported {
+                instruction_index: 0
+            })
+        );
+        let compiled = compile_v2(
+            manifest,
+            &NetworkDefinition::simulator(),
+            BlobProvider::default(),
+        )
+        .unwrap();
+        assert!(matches!(
+            compiled.instructions.as_slice(),
+            [
+                InstructionV1::YieldToChild { child_index: 0, .. },
+                InstructionV1::YieldToParent { .. }
+            ]
+        ));
+    }
 }
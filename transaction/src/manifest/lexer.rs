@@ -39,6 +39,8 @@ pub enum TokenKind {
     StringLiteral(String),
 
     Ident(String),
+    /// A reference to a manifest parameter, e.g. `$amount`
+    Parameter(String),
 
     /* Punctuations */
     OpenParenthesis,
@@ -47,6 +49,7 @@ pub enum TokenKind {
     GreaterThan,
     Comma,
     Semicolon,
+    Colon,
     FatArrow,
 }
 
@@ -154,7 +157,8 @@ impl Lexer {
             '-' | '0'..='9' => self.tokenize_number(),
             '"' => self.tokenize_string(),
             'a'..='z' | 'A'..='Z' => self.tokenize_identifier(),
-            '{' | '}' | '(' | ')' | '<' | '>' | ',' | ';' | '&' | '=' => {
+            '$' => self.tokenize_parameter(),
+            '{' | '}' | '(' | ')' | '<' | '>' | ',' | ';' | ':' | '&' | '=' => {
                 self.tokenize_punctuation()
             }
             _ => Err(LexerError::UnexpectedChar(
@@ -315,8 +319,13 @@ impl Lexer {
         let mut id = String::from(self.advance()?);
         while !self.is_eof() {
             let next_char = self.peek()?;
-            let next_char_can_be_part_of_ident =
-                next_char.is_ascii_alphanumeric() || next_char == '_' || next_char == ':';
+            // A `:` is only part of an identifier as part of a `::` path separator, so that
+            // parameter declarations such as `PARAM amount: Decimal;` can be tokenized
+            let next_char_can_be_part_of_ident = next_char.is_ascii_alphanumeric()
+                || next_char == '_'
+                || (next_char == ':'
+                    && (id.ends_with(':')
+                        || self.text.get(self.current.full_index + 1) == Some(&':')));
             if !next_char_can_be_part_of_ident {
                 break;
             }
@@ -331,6 +340,27 @@ impl Lexer {
         Ok(self.new_token(kind, start, self.current))
     }
 
+    fn tokenize_parameter(&mut self) -> Result<Token, LexerError> {
+        let start = self.current;
+
+        // Skip the `$`
+        self.advance()?;
+
+        let mut name = String::new();
+        while !self.is_eof() {
+            let next_char = self.peek()?;
+            if !(next_char.is_ascii_alphanumeric() || next_char == '_') {
+                break;
+            }
+            name.push(self.advance()?);
+        }
+        if name.is_empty() {
+            return Err(self.unexpected_char_or_eof());
+        }
+
+        Ok(self.new_token(TokenKind::Parameter(name), start, self.current))
+    }
+
     fn tokenize_punctuation(&mut self) -> Result<Token, LexerError> {
         let start = self.current;
 
@@ -341,6 +371,7 @@ impl Lexer {
             '>' => TokenKind::GreaterThan,
             ',' => TokenKind::Comma,
             ';' => TokenKind::Semicolon,
+            ':' => TokenKind::Colon,
             '=' => match self.advance()? {
                 '>' => TokenKind::FatArrow,
                 _ => return Err(self.unexpected_char()),
@@ -363,6 +394,14 @@ impl Lexer {
     fn unexpected_char(&self) -> LexerError {
         LexerError::UnexpectedChar(self.text[self.current.full_index], self.current)
     }
+
+    fn unexpected_char_or_eof(&self) -> LexerError {
+        if self.is_eof() {
+            LexerError::UnexpectedEof
+        } else {
+            self.unexpected_char()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -526,4 +565,26 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_parameters() {
+        lex_ok!(
+            "PARAM amount: Decimal; Enum<Metadata::String>($amount)",
+            vec![
+                TokenKind::Ident("PARAM".to_string()),
+                TokenKind::Ident("amount".to_string()),
+                TokenKind::Colon,
+                TokenKind::Ident("Decimal".to_string()),
+                TokenKind::Semicolon,
+                TokenKind::Ident("Enum".to_string()),
+                TokenKind::LessThan,
+                TokenKind::Ident("Metadata::String".to_string()),
+                TokenKind::GreaterThan,
+                TokenKind::OpenParenthesis,
+                TokenKind::Parameter("amount".to_string()),
+                TokenKind::CloseParenthesis,
+            ]
+        );
+        lex_error!("$", LexerError::UnexpectedEof);
+    }
 }
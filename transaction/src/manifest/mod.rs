@@ -9,8 +9,10 @@ pub mod enums;
 pub mod generator;
 pub mod lexer;
 pub mod parser;
+pub mod static_analysis;
 
 pub use blob_provider::*;
-pub use compiler::{compile, CompileError};
+pub use compiler::{compile, compile_v2, compile_with_parameters, CompileError};
 pub use decompiler::{decompile, DecompileError};
 pub use enums::*;
+pub use static_analysis::*;
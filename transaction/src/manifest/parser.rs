@@ -1,7 +1,11 @@
 use crate::manifest::ast::{Instruction, Value, ValueKind};
 use crate::manifest::enums::KNOWN_ENUM_DISCRIMINATORS;
-use crate::manifest::lexer::{Token, TokenKind};
+use crate::manifest::lexer::{tokenize, Token, TokenKind};
 use radix_engine_interface::data::manifest::MANIFEST_SBOR_V1_MAX_DEPTH;
+use radix_engine_interface::data::scrypto::model::NonFungibleLocalId;
+use radix_engine_interface::math::{Decimal, PreciseDecimal};
+use sbor::rust::collections::{index_map_new, IndexMap};
+use sbor::rust::str::FromStr;
 
 // For values greater than below it is not possible to encode compiled manifest due to
 //   EncodeError::MaxDepthExceeded(MANIFEST_SBOR_V1_MAX_DEPTH)
@@ -10,12 +14,29 @@ pub const PARSER_MAX_DEPTH: usize = MANIFEST_SBOR_V1_MAX_DEPTH - 4;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParserError {
     UnexpectedEof,
-    UnexpectedToken { expected: TokenType, actual: Token },
-    InvalidNumberOfValues { expected: usize, actual: usize },
-    InvalidNumberOfTypes { expected: usize, actual: usize },
+    UnexpectedToken {
+        expected: TokenType,
+        actual: Token,
+    },
+    InvalidNumberOfValues {
+        expected: usize,
+        actual: usize,
+    },
+    InvalidNumberOfTypes {
+        expected: usize,
+        actual: usize,
+    },
     InvalidHex(String),
     UnknownEnumDiscriminator(String),
     MaxDepthExceeded(usize),
+    MissingParameter(String),
+    UndeclaredParameter(String),
+    DuplicateParameter(String),
+    InvalidParameterValue {
+        name: String,
+        value_kind: ValueKind,
+        value: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,6 +45,7 @@ pub enum TokenType {
     Value,
     ValueKind,
     EnumDiscriminator,
+    ParameterName,
     Exact(TokenKind),
 }
 
@@ -61,6 +83,8 @@ pub enum InstructionIdent {
     DropNamedProofs,
     DropAllProofs,
     AllocateGlobalAddress,
+    YieldToChild,
+    YieldToParent,
 
     // ==============
     // Call direct vault method aliases
@@ -156,6 +180,8 @@ impl InstructionIdent {
             "DROP_NAMED_PROOFS" => InstructionIdent::DropNamedProofs,
             "DROP_ALL_PROOFS" => InstructionIdent::DropAllProofs,
             "ALLOCATE_GLOBAL_ADDRESS" => InstructionIdent::AllocateGlobalAddress,
+            "YIELD_TO_CHILD" => InstructionIdent::YieldToChild,
+            "YIELD_TO_PARENT" => InstructionIdent::YieldToParent,
 
             // ==============
             // Call direct vault method aliases
@@ -384,6 +410,10 @@ pub struct Parser {
     current: usize,
     max_depth: usize,
     stack_depth: usize,
+    /// The raw values provided for the manifest parameters, by name
+    parameter_values: IndexMap<String, String>,
+    /// The typed values of the declared manifest parameters, by name
+    parameters: IndexMap<String, Value>,
 }
 
 #[macro_export]
@@ -414,9 +444,18 @@ impl Parser {
             current: 0,
             max_depth,
             stack_depth: 0,
+            parameter_values: index_map_new(),
+            parameters: index_map_new(),
         }
     }
 
+    /// Provides the raw values of the parameters declared by the manifest, which are checked
+    /// against and converted to the declared value kinds when the manifest is parsed.
+    pub fn with_parameters(mut self, parameter_values: IndexMap<String, String>) -> Self {
+        self.parameter_values = parameter_values;
+        self
+    }
+
     #[inline]
     fn track_stack_depth_increase(&mut self) -> Result<(), ParserError> {
         self.stack_depth += 1;
@@ -450,6 +489,8 @@ impl Parser {
     }
 
     pub fn parse_manifest(&mut self) -> Result<Vec<Instruction>, ParserError> {
+        self.parse_parameter_declarations()?;
+
         let mut instructions = Vec::<Instruction>::new();
 
         while !self.is_eof() {
@@ -459,6 +500,108 @@ impl Parser {
         Ok(instructions)
     }
 
+    /// Parses the `PARAM <name>: <value kind>;` declarations at the top of the manifest, and
+    /// resolves each of them against the provided parameter values.
+    fn parse_parameter_declarations(&mut self) -> Result<(), ParserError> {
+        while !self.is_eof() && self.peek()?.kind == TokenKind::Ident("PARAM".to_string()) {
+            self.advance()?;
+            let token = self.advance()?;
+            let name = match token.kind {
+                TokenKind::Ident(name) => name,
+                _ => {
+                    return Err(ParserError::UnexpectedToken {
+                        expected: TokenType::ParameterName,
+                        actual: token,
+                    })
+                }
+            };
+            advance_match!(self, TokenKind::Colon);
+            let value_kind = self.parse_type()?;
+            advance_match!(self, TokenKind::Semicolon);
+
+            if self.parameters.contains_key(&name) {
+                return Err(ParserError::DuplicateParameter(name));
+            }
+            let raw_value = self
+                .parameter_values
+                .get(&name)
+                .ok_or_else(|| ParserError::MissingParameter(name.clone()))?;
+            let value = Self::parse_parameter_value(&name, value_kind, raw_value)?;
+            self.parameters.insert(name, value);
+        }
+
+        if let Some(name) = self
+            .parameter_values
+            .keys()
+            .find(|name| !self.parameters.contains_key(*name))
+        {
+            return Err(ParserError::UndeclaredParameter(name.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Converts a raw parameter value into a value of the declared kind.
+    ///
+    /// Simple values (booleans, integers, strings, decimals, addresses and non-fungible local ids)
+    /// are given without any wrapping, e.g. `1.5` for a `Decimal` parameter. Any other value is
+    /// given in manifest syntax, e.g. `Tuple(1u8, "a")` for a `Tuple` parameter.
+    fn parse_parameter_value(
+        name: &str,
+        value_kind: ValueKind,
+        raw_value: &str,
+    ) -> Result<Value, ParserError> {
+        let invalid_value = || ParserError::InvalidParameterValue {
+            name: name.to_string(),
+            value_kind,
+            value: raw_value.to_string(),
+        };
+        let string_value = || Box::new(Value::String(raw_value.to_string()));
+
+        let value = match value_kind {
+            ValueKind::Bool => Value::Bool(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::I8 => Value::I8(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::I16 => Value::I16(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::I32 => Value::I32(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::I64 => Value::I64(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::I128 => Value::I128(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::U8 => Value::U8(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::U16 => Value::U16(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::U32 => Value::U32(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::U64 => Value::U64(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::U128 => Value::U128(raw_value.parse().map_err(|_| invalid_value())?),
+            ValueKind::String => Value::String(raw_value.to_string()),
+            ValueKind::Decimal => {
+                Decimal::from_str(raw_value).map_err(|_| invalid_value())?;
+                Value::Decimal(string_value())
+            }
+            ValueKind::PreciseDecimal => {
+                PreciseDecimal::from_str(raw_value).map_err(|_| invalid_value())?;
+                Value::PreciseDecimal(string_value())
+            }
+            ValueKind::NonFungibleLocalId => {
+                NonFungibleLocalId::from_str(raw_value).map_err(|_| invalid_value())?;
+                Value::NonFungibleLocalId(string_value())
+            }
+            // The address is decoded by the generator, as it depends on the network
+            ValueKind::Address
+            | ValueKind::PackageAddress
+            | ValueKind::ComponentAddress
+            | ValueKind::ResourceAddress => Value::Address(string_value()),
+            _ => {
+                let tokens = tokenize(raw_value).map_err(|_| invalid_value())?;
+                let mut parser = Parser::new(tokens, PARSER_MAX_DEPTH);
+                let value = parser.parse_value().map_err(|_| invalid_value())?;
+                if !parser.is_eof() || value.value_kind() != value_kind.value_kind() {
+                    return Err(invalid_value());
+                }
+                value
+            }
+        };
+
+        Ok(value)
+    }
+
     fn parse_values_till_semicolon(&mut self) -> Result<Vec<Value>, ParserError> {
         let mut values = Vec::new();
         while self.peek()?.kind != TokenKind::Semicolon {
@@ -613,6 +756,13 @@ impl Parser {
                 address_reservation: self.parse_value()?,
                 named_address: self.parse_value()?,
             },
+            InstructionIdent::YieldToChild => Instruction::YieldToChild {
+                child_index: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
+            InstructionIdent::YieldToParent => Instruction::YieldToParent {
+                args: self.parse_values_till_semicolon()?,
+            },
 
             /* Call direct vault method aliases */
             InstructionIdent::RecallFromVault => Instruction::RecallFromVault {
@@ -755,6 +905,11 @@ impl Parser {
             TokenKind::I64Literal(value) => Value::I64(*value),
             TokenKind::I128Literal(value) => Value::I128(*value),
             TokenKind::StringLiteral(value) => Value::String(value.clone()),
+            TokenKind::Parameter(name) => self
+                .parameters
+                .get(name)
+                .cloned()
+                .ok_or_else(|| ParserError::UndeclaredParameter(name.clone()))?,
             TokenKind::Ident(ident_str) => {
                 let value_ident =
                     SborValueIdent::from_ident(ident_str).ok_or(ParserError::UnexpectedToken {
@@ -1163,6 +1318,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parameters() {
+        let parse = |s: &str, parameters: &[(&str, &str)]| {
+            Parser::new(tokenize(s).unwrap(), PARSER_MAX_DEPTH)
+                .with_parameters(
+                    parameters
+                        .iter()
+                        .map(|(name, value)| (name.to_string(), value.to_string()))
+                        .collect(),
+                )
+                .parse_manifest()
+        };
+        let manifest = r#"
+            PARAM amount: Decimal;
+            PARAM ids: Array;
+            BURN_RESOURCE Tuple($amount, $ids);
+        "#;
+
+        assert_eq!(
+            parse(
+                manifest,
+                &[("amount", "1.5"), ("ids", r#"Array<String>("a")"#)]
+            ),
+            Ok(vec![Instruction::BurnResource {
+                bucket: Value::Tuple(vec![
+                    Value::Decimal(Box::new(Value::String("1.5".into()))),
+                    Value::Array(ValueKind::String, vec![Value::String("a".into())]),
+                ])
+            }])
+        );
+        assert_eq!(
+            parse(manifest, &[("amount", "1.5")]),
+            Err(ParserError::MissingParameter("ids".into()))
+        );
+        assert_eq!(
+            parse(
+                manifest,
+                &[("amount", "1.5"), ("ids", "Tuple()"), ("other", "1")]
+            ),
+            Err(ParserError::InvalidParameterValue {
+                name: "ids".into(),
+                value_kind: ValueKind::Array,
+                value: "Tuple()".into(),
+            })
+        );
+        assert_eq!(
+            parse(
+                manifest,
+                &[("amount", "abc"), ("ids", r#"Array<String>()"#)]
+            ),
+            Err(ParserError::InvalidParameterValue {
+                name: "amount".into(),
+                value_kind: ValueKind::Decimal,
+                value: "abc".into(),
+            })
+        );
+        assert_eq!(
+            parse(
+                manifest,
+                &[
+                    ("amount", "1"),
+                    ("ids", r#"Array<String>()"#),
+                    ("other", "1")
+                ]
+            ),
+            Err(ParserError::UndeclaredParameter("other".into()))
+        );
+        assert_eq!(
+            parse("BURN_RESOURCE $amount;", &[]),
+            Err(ParserError::UndeclaredParameter("amount".into()))
+        );
+    }
+
     #[test]
     fn test_deep_value_does_not_panic_with_stack_overflow() {
         let depth: usize = 1000;
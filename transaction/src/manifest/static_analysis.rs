@@ -0,0 +1,903 @@
+use crate::data::{transform, TransformHandler};
+use crate::internal_prelude::*;
+use core::convert::Infallible;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::pool::*;
+use radix_engine_interface::data::scrypto::model::Own;
+use sbor::traversal::ExpectedStart;
+
+/// The result of a static analysis of a manifest, see [`analyze_manifest`].
+///
+/// Everything in the summary is derived from the instructions alone, without access to the
+/// ledger. Resources and amounts are reported where they can be statically determined, and are
+/// otherwise reported as unknown.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub struct ManifestSummary {
+    /// A best-effort classification of the manifest.
+    pub classification: ManifestClass,
+    /// Accounts which resources are withdrawn from, in order of first withdrawal.
+    pub accounts_withdrawn_from: IndexSet<ComponentAddress>,
+    /// Accounts which resources are deposited into, in order of first deposit.
+    pub accounts_deposited_into: IndexSet<ComponentAddress>,
+    /// Accounts which lock fees.
+    pub fee_payers: IndexSet<ComponentAddress>,
+    pub withdrawals: Vec<AccountResourceMovement>,
+    pub deposits: Vec<AccountResourceMovement>,
+    pub proofs_created: Vec<ProofCreation>,
+    /// Accounts and identities whose owner role must be satisfied by the transaction.
+    pub entities_requiring_auth: IndexSet<GlobalAddress>,
+    /// The signature badges required to satisfy the owner role of the virtual accounts and
+    /// identities in `entities_requiring_auth`.
+    pub required_signature_badges: IndexSet<NonFungibleGlobalId>,
+    /// All the global addresses statically referenced by the manifest.
+    pub referenced_global_addresses: IndexSet<GlobalAddress>,
+    /// All the internal addresses statically referenced by the manifest.
+    pub referenced_internal_addresses: IndexSet<InternalAddress>,
+    /// The hashes of the blobs used by the manifest.
+    pub blobs_used: IndexSet<Hash>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ManifestSbor)]
+pub enum ManifestClass {
+    /// Only moves resources between accounts.
+    Transfer,
+    /// Only updates account deposit rules and authorized depositors.
+    AccountDepositSettingsUpdate,
+    ValidatorStake,
+    ValidatorUnstake,
+    ValidatorClaim,
+    PoolContribution,
+    PoolRedemption,
+    PackagePublish,
+    /// Anything else, including interactions with arbitrary components.
+    General,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum ResourceQuantity {
+    Amount(Decimal),
+    NonFungibles(IndexSet<NonFungibleLocalId>),
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub struct AccountResourceMovement {
+    pub instruction_index: usize,
+    pub account: ComponentAddress,
+    /// The resource, if statically known.
+    pub resource_address: Option<ResourceAddress>,
+    pub quantity: ResourceQuantity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum ProofSource {
+    AuthZone,
+    Bucket,
+    Account(ComponentAddress),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub struct ProofCreation {
+    pub instruction_index: usize,
+    pub source: ProofSource,
+    /// The resource, if statically known.
+    pub resource_address: Option<ResourceAddress>,
+    pub quantity: ResourceQuantity,
+}
+
+/// Statically analyzes the given instructions, see [`ManifestSummary`].
+///
+/// The analysis never fails: instructions which are invalid or can't be understood only make
+/// the summary less precise.
+pub fn analyze_manifest(instructions: &[InstructionV1]) -> ManifestSummary {
+    let mut analyzer = ManifestAnalyzer::default();
+    for (index, instruction) in instructions.iter().enumerate() {
+        analyzer.process_instruction(index, instruction);
+    }
+    analyzer.finish(instructions)
+}
+
+/// Returns the resources which are certainly left on the worktop after the given instructions.
+///
+/// Resources returned to the worktop by arbitrary invocations are unknown, hence this is a lower
+/// bound of what is left.
+pub(crate) fn resources_left_on_worktop(instructions: &[InstructionV1]) -> Vec<ResourceAddress> {
+    let mut analyzer = ManifestAnalyzer::default();
+    for (index, instruction) in instructions.iter().enumerate() {
+        analyzer.process_instruction(index, instruction);
+    }
+    analyzer
+        .worktop
+        .into_iter()
+        .filter(|(_, quantity)| match quantity {
+            ResourceQuantity::Amount(amount) => amount.is_positive(),
+            ResourceQuantity::NonFungibles(ids) => !ids.is_empty(),
+            ResourceQuantity::Unknown => false,
+        })
+        .map(|(resource_address, _)| resource_address)
+        .collect()
+}
+
+impl TransactionManifestV1 {
+    pub fn analyze(&self) -> ManifestSummary {
+        analyze_manifest(&self.instructions)
+    }
+}
+
+impl ResourceQuantity {
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Amount(a), Self::Amount(b)) => {
+                a.safe_add(b).map(Self::Amount).unwrap_or(Self::Unknown)
+            }
+            (Self::NonFungibles(mut a), Self::NonFungibles(b)) => {
+                a.extend(b);
+                Self::NonFungibles(a)
+            }
+            _ => Self::Unknown,
+        }
+    }
+
+    fn remove(self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::Amount(a), Self::Amount(b)) => {
+                a.safe_sub(*b).map(Self::Amount).unwrap_or(Self::Unknown)
+            }
+            (Self::NonFungibles(mut a), Self::NonFungibles(b)) => {
+                a.retain(|id| !b.contains(id));
+                Self::NonFungibles(a)
+            }
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// What an invocation means for the classification of the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum InvocationKind {
+    /// Fee locking, proof creation and other invocations which don't affect the classification.
+    Neutral,
+    AccountTransfer,
+    AccountDepositSettings,
+    Class(ManifestClass),
+    Other,
+}
+
+#[derive(Default)]
+struct ManifestAnalyzer {
+    id_allocator: ManifestIdAllocator,
+    /// The statically known contents of the buckets.
+    buckets: IndexMap<ManifestBucket, (Option<ResourceAddress>, ResourceQuantity)>,
+    /// The statically known contents of the worktop.
+    worktop: IndexMap<ResourceAddress, ResourceQuantity>,
+    /// Whether the worktop may contain resources which are not in `worktop`.
+    worktop_has_unknown_contents: bool,
+    invocation_kinds: IndexSet<InvocationKind>,
+    accounts_withdrawn_from: IndexSet<ComponentAddress>,
+    accounts_deposited_into: IndexSet<ComponentAddress>,
+    fee_payers: IndexSet<ComponentAddress>,
+    withdrawals: Vec<AccountResourceMovement>,
+    deposits: Vec<AccountResourceMovement>,
+    proofs_created: Vec<ProofCreation>,
+    entities_requiring_auth: IndexSet<GlobalAddress>,
+    blobs_used: IndexSet<Hash>,
+}
+
+/// Collects the buckets, expressions and blobs passed as call arguments.
+#[derive(Default)]
+struct CallArguments {
+    buckets: Vec<ManifestBucket>,
+    expressions: Vec<ManifestExpression>,
+    blobs: Vec<Hash>,
+}
+
+impl ManifestAnalyzer {
+    fn process_instruction(&mut self, index: usize, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                let quantity = match self.worktop.swap_remove(resource_address) {
+                    Some(quantity) if !self.worktop_has_unknown_contents => quantity,
+                    _ => ResourceQuantity::Unknown,
+                };
+                self.new_bucket(Some(*resource_address), quantity);
+            }
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                let quantity = ResourceQuantity::Amount(*amount);
+                self.take_from_worktop(resource_address, &quantity);
+                self.new_bucket(Some(*resource_address), quantity);
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                let quantity = ResourceQuantity::NonFungibles(ids.iter().cloned().collect());
+                self.take_from_worktop(resource_address, &quantity);
+                self.new_bucket(Some(*resource_address), quantity);
+            }
+            InstructionV1::ReturnToWorktop { bucket_id } => {
+                match self.buckets.swap_remove(bucket_id) {
+                    Some((Some(resource_address), quantity)) => {
+                        self.put_on_worktop(resource_address, quantity)
+                    }
+                    _ => self.worktop_has_unknown_contents = true,
+                }
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => self.proofs_created.push(ProofCreation {
+                instruction_index: index,
+                source: ProofSource::AuthZone,
+                resource_address: Some(*resource_address),
+                quantity: ResourceQuantity::Amount(*amount),
+            }),
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => self.proofs_created.push(ProofCreation {
+                instruction_index: index,
+                source: ProofSource::AuthZone,
+                resource_address: Some(*resource_address),
+                quantity: ResourceQuantity::NonFungibles(ids.iter().cloned().collect()),
+            }),
+            InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
+                self.proofs_created.push(ProofCreation {
+                    instruction_index: index,
+                    source: ProofSource::AuthZone,
+                    resource_address: Some(*resource_address),
+                    quantity: ResourceQuantity::Unknown,
+                })
+            }
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount } => {
+                self.proofs_created.push(ProofCreation {
+                    instruction_index: index,
+                    source: ProofSource::Bucket,
+                    resource_address: self.bucket_resource(bucket_id),
+                    quantity: ResourceQuantity::Amount(*amount),
+                })
+            }
+            InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids } => {
+                self.proofs_created.push(ProofCreation {
+                    instruction_index: index,
+                    source: ProofSource::Bucket,
+                    resource_address: self.bucket_resource(bucket_id),
+                    quantity: ResourceQuantity::NonFungibles(ids.iter().cloned().collect()),
+                })
+            }
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                self.proofs_created.push(ProofCreation {
+                    instruction_index: index,
+                    source: ProofSource::Bucket,
+                    resource_address: self.bucket_resource(bucket_id),
+                    quantity: self
+                        .buckets
+                        .get(bucket_id)
+                        .map(|(_, quantity)| quantity.clone())
+                        .unwrap_or(ResourceQuantity::Unknown),
+                })
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                self.buckets.swap_remove(bucket_id);
+                self.invocation_kinds.insert(InvocationKind::Other);
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                function_name,
+                args,
+                ..
+            } => {
+                let arguments = self.process_arguments(args);
+                let kind = match package_address {
+                    DynamicPackageAddress::Static(address)
+                        if *address == PACKAGE_PACKAGE
+                            && (function_name == PACKAGE_PUBLISH_WASM_IDENT
+                                || function_name == PACKAGE_PUBLISH_WASM_ADVANCED_IDENT) =>
+                    {
+                        InvocationKind::Class(ManifestClass::PackagePublish)
+                    }
+                    _ => InvocationKind::Other,
+                };
+                self.process_opaque_invocation(kind, arguments);
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let arguments = self.process_arguments(args);
+                match address {
+                    DynamicGlobalAddress::Static(address) if is_account(address) => {
+                        let account = ComponentAddress::new_or_panic(address.as_node_id().0);
+                        self.process_account_method(index, account, method_name, args, arguments);
+                    }
+                    DynamicGlobalAddress::Static(address) => {
+                        if is_identity(address) {
+                            self.entities_requiring_auth.insert(*address);
+                        }
+                        let kind = method_kind(address, method_name);
+                        self.process_opaque_invocation(kind, arguments);
+                    }
+                    DynamicGlobalAddress::Named(_) => {
+                        self.process_opaque_invocation(InvocationKind::Other, arguments)
+                    }
+                }
+            }
+            InstructionV1::CallRoyaltyMethod {
+                address,
+                method_name,
+                args,
+            }
+            | InstructionV1::CallMetadataMethod {
+                address,
+                method_name,
+                args,
+            }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let arguments = self.process_arguments(args);
+                if let DynamicGlobalAddress::Static(address) = address {
+                    if (is_account(address) || is_identity(address))
+                        && !method_name.starts_with("get")
+                    {
+                        self.entities_requiring_auth.insert(*address);
+                    }
+                }
+                self.process_opaque_invocation(InvocationKind::Other, arguments);
+            }
+            InstructionV1::CallDirectVaultMethod { args, .. } => {
+                let arguments = self.process_arguments(args);
+                self.process_opaque_invocation(InvocationKind::Other, arguments);
+            }
+            InstructionV1::AllocateGlobalAddress { .. } => {
+                self.invocation_kinds.insert(InvocationKind::Other);
+            }
+            InstructionV1::YieldToChild { args, .. } | InstructionV1::YieldToParent { args } => {
+                let arguments = self.process_arguments(args);
+                self.process_opaque_invocation(InvocationKind::Other, arguments);
+            }
+            InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs => {}
+        }
+    }
+
+    fn process_account_method(
+        &mut self,
+        index: usize,
+        account: ComponentAddress,
+        method_name: &str,
+        args: &ManifestValue,
+        arguments: CallArguments,
+    ) {
+        // Only the `try_deposit_*` methods are public, everything else requires the owner role
+        let is_public = matches!(
+            method_name,
+            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                | ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+                | ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT
+        );
+        if !is_public {
+            self.entities_requiring_auth.insert(account.into());
+        }
+
+        match method_name {
+            ACCOUNT_WITHDRAW_IDENT | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                let withdrawal = match method_name {
+                    ACCOUNT_WITHDRAW_IDENT => from_manifest_value::<AccountWithdrawInput>(args)
+                        .ok()
+                        .map(|input| (input.resource_address, input.amount)),
+                    _ => from_manifest_value::<AccountLockFeeAndWithdrawInput>(args)
+                        .ok()
+                        .map(|input| (input.resource_address, input.amount)),
+                };
+                if method_name == ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT {
+                    self.fee_payers.insert(account);
+                }
+                let (resource_address, quantity) = match withdrawal {
+                    Some((resource_address, amount)) => {
+                        (Some(resource_address), ResourceQuantity::Amount(amount))
+                    }
+                    None => (None, ResourceQuantity::Unknown),
+                };
+                self.withdraw(index, account, resource_address, quantity);
+                self.invocation_kinds
+                    .insert(InvocationKind::AccountTransfer);
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT
+            | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                let withdrawal = match method_name {
+                    ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                        from_manifest_value::<AccountWithdrawNonFungiblesInput>(args)
+                            .ok()
+                            .map(|input| (input.resource_address, input.ids))
+                    }
+                    _ => from_manifest_value::<AccountLockFeeAndWithdrawNonFungiblesInput>(args)
+                        .ok()
+                        .map(|input| (input.resource_address, input.ids)),
+                };
+                if method_name == ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT {
+                    self.fee_payers.insert(account);
+                }
+                let (resource_address, quantity) = match withdrawal {
+                    Some((resource_address, ids)) => (
+                        Some(resource_address),
+                        ResourceQuantity::NonFungibles(ids.into_iter().collect()),
+                    ),
+                    None => (None, ResourceQuantity::Unknown),
+                };
+                self.withdraw(index, account, resource_address, quantity);
+                self.invocation_kinds
+                    .insert(InvocationKind::AccountTransfer);
+            }
+            ACCOUNT_LOCK_FEE_IDENT | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                self.fee_payers.insert(account);
+                self.invocation_kinds.insert(InvocationKind::Neutral);
+            }
+            ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT => {
+                self.invocation_kinds.insert(InvocationKind::Neutral);
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                let input = from_manifest_value::<AccountCreateProofOfAmountInput>(args).ok();
+                self.proofs_created.push(ProofCreation {
+                    instruction_index: index,
+                    source: ProofSource::Account(account),
+                    resource_address: input.as_ref().map(|input| input.resource_address),
+                    quantity: input
+                        .map(|input| ResourceQuantity::Amount(input.amount))
+                        .unwrap_or(ResourceQuantity::Unknown),
+                });
+                self.invocation_kinds.insert(InvocationKind::Neutral);
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                let input = from_manifest_value::<AccountCreateProofOfNonFungiblesInput>(args).ok();
+                self.proofs_created.push(ProofCreation {
+                    instruction_index: index,
+                    source: ProofSource::Account(account),
+                    resource_address: input.as_ref().map(|input| input.resource_address),
+                    quantity: input
+                        .map(|input| {
+                            ResourceQuantity::NonFungibles(input.ids.into_iter().collect())
+                        })
+                        .unwrap_or(ResourceQuantity::Unknown),
+                });
+                self.invocation_kinds.insert(InvocationKind::Neutral);
+            }
+            ACCOUNT_DEPOSIT_IDENT
+            | ACCOUNT_DEPOSIT_BATCH_IDENT
+            | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+            | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+            | ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+            | ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+                self.deposit(index, account, arguments);
+                if method_name == ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+                    || method_name == ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT
+                {
+                    // Refunded resources are returned to the worktop
+                    self.worktop_has_unknown_contents = true;
+                }
+                self.invocation_kinds
+                    .insert(InvocationKind::AccountTransfer);
+            }
+            ACCOUNT_GET_VAULTS_IDENT | ACCOUNT_BALANCE_OF_IDENT => {
+                self.invocation_kinds.insert(InvocationKind::Neutral);
+            }
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT
+            | ACCOUNT_SET_DEPOSIT_RULE_IDENT
+            | ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_ADD_AUTHORIZED_DEPOSITOR
+            | ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => {
+                self.invocation_kinds
+                    .insert(InvocationKind::AccountDepositSettings);
+            }
+            _ => self.process_opaque_invocation(InvocationKind::Other, arguments),
+        }
+    }
+
+    /// Processes an invocation whose effects are unknown, which consumes the buckets passed to
+    /// it and may return anything to the worktop.
+    fn process_opaque_invocation(&mut self, kind: InvocationKind, arguments: CallArguments) {
+        for bucket in &arguments.buckets {
+            self.buckets.swap_remove(bucket);
+        }
+        if arguments
+            .expressions
+            .contains(&ManifestExpression::EntireWorktop)
+        {
+            self.worktop.clear();
+        }
+        self.worktop_has_unknown_contents = true;
+        self.invocation_kinds.insert(kind);
+    }
+
+    fn process_arguments(&mut self, args: &ManifestValue) -> CallArguments {
+        let mut arguments = CallArguments::default();
+        let _ = transform(args.clone(), &mut arguments);
+        self.blobs_used.extend(arguments.blobs.iter().cloned());
+        arguments
+    }
+
+    fn new_bucket(
+        &mut self,
+        resource_address: Option<ResourceAddress>,
+        quantity: ResourceQuantity,
+    ) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.buckets.insert(bucket_id, (resource_address, quantity));
+    }
+
+    fn bucket_resource(&self, bucket_id: &ManifestBucket) -> Option<ResourceAddress> {
+        self.buckets
+            .get(bucket_id)
+            .and_then(|(resource_address, _)| *resource_address)
+    }
+
+    fn put_on_worktop(&mut self, resource_address: ResourceAddress, quantity: ResourceQuantity) {
+        let quantity = match self.worktop.swap_remove(&resource_address) {
+            Some(existing) => existing.merge(quantity),
+            None => quantity,
+        };
+        self.worktop.insert(resource_address, quantity);
+    }
+
+    fn take_from_worktop(
+        &mut self,
+        resource_address: &ResourceAddress,
+        quantity: &ResourceQuantity,
+    ) {
+        if let Some(existing) = self.worktop.swap_remove(resource_address) {
+            self.worktop
+                .insert(*resource_address, existing.remove(quantity));
+        }
+    }
+
+    fn withdraw(
+        &mut self,
+        index: usize,
+        account: ComponentAddress,
+        resource_address: Option<ResourceAddress>,
+        quantity: ResourceQuantity,
+    ) {
+        self.accounts_withdrawn_from.insert(account);
+        match resource_address {
+            Some(resource_address) => self.put_on_worktop(resource_address, quantity.clone()),
+            None => self.worktop_has_unknown_contents = true,
+        }
+        self.withdrawals.push(AccountResourceMovement {
+            instruction_index: index,
+            account,
+            resource_address,
+            quantity,
+        });
+    }
+
+    fn deposit(&mut self, index: usize, account: ComponentAddress, arguments: CallArguments) {
+        let mut deposited = Vec::new();
+        for bucket in &arguments.buckets {
+            match self.buckets.swap_remove(bucket) {
+                Some(contents) => deposited.push(contents),
+                None => deposited.push((None, ResourceQuantity::Unknown)),
+            }
+        }
+        if arguments
+            .expressions
+            .contains(&ManifestExpression::EntireWorktop)
+        {
+            deposited.extend(
+                self.worktop
+                    .drain(..)
+                    .map(|(resource_address, quantity)| (Some(resource_address), quantity)),
+            );
+            if self.worktop_has_unknown_contents {
+                deposited.push((None, ResourceQuantity::Unknown));
+                self.worktop_has_unknown_contents = false;
+            }
+        }
+
+        self.accounts_deposited_into.insert(account);
+        for (resource_address, quantity) in deposited {
+            self.deposits.push(AccountResourceMovement {
+                instruction_index: index,
+                account,
+                resource_address,
+                quantity,
+            });
+        }
+    }
+
+    fn classify(&self) -> ManifestClass {
+        let kinds: IndexSet<InvocationKind> = self
+            .invocation_kinds
+            .iter()
+            .filter(|kind| **kind != InvocationKind::Neutral)
+            .cloned()
+            .collect();
+        let has_transfers = kinds.contains(&InvocationKind::AccountTransfer);
+        let others: Vec<&InvocationKind> = kinds
+            .iter()
+            .filter(|kind| **kind != InvocationKind::AccountTransfer)
+            .collect();
+
+        match others.as_slice() {
+            [] if has_transfers && !self.deposits.is_empty() => ManifestClass::Transfer,
+            [InvocationKind::AccountDepositSettings] if !has_transfers => {
+                ManifestClass::AccountDepositSettingsUpdate
+            }
+            [InvocationKind::Class(class)] => *class,
+            _ => ManifestClass::General,
+        }
+    }
+
+    fn finish(self, instructions: &[InstructionV1]) -> ManifestSummary {
+        let classification = self.classify();
+
+        let mut referenced_global_addresses = index_set_new();
+        let mut referenced_internal_addresses = index_set_new();
+        if let Ok(encoded) = manifest_encode(instructions) {
+            let references = extract_references(
+                &encoded,
+                ExpectedStart::PayloadPrefix(MANIFEST_SBOR_V1_PAYLOAD_PREFIX),
+            );
+            for reference in references {
+                let node_id = reference.as_node_id();
+                if let Ok(address) = GlobalAddress::try_from(node_id.as_bytes()) {
+                    referenced_global_addresses.insert(address);
+                } else if let Ok(address) = InternalAddress::try_from(node_id.as_bytes()) {
+                    referenced_internal_addresses.insert(address);
+                }
+            }
+        }
+
+        let required_signature_badges = self
+            .entities_requiring_auth
+            .iter()
+            .filter_map(|address| virtual_owner_badge(address.as_node_id()))
+            .collect();
+
+        ManifestSummary {
+            classification,
+            accounts_withdrawn_from: self.accounts_withdrawn_from,
+            accounts_deposited_into: self.accounts_deposited_into,
+            fee_payers: self.fee_payers,
+            withdrawals: self.withdrawals,
+            deposits: self.deposits,
+            proofs_created: self.proofs_created,
+            entities_requiring_auth: self.entities_requiring_auth,
+            required_signature_badges,
+            referenced_global_addresses,
+            referenced_internal_addresses,
+            blobs_used: self.blobs_used,
+        }
+    }
+}
+
+fn method_kind(address: &GlobalAddress, method_name: &str) -> InvocationKind {
+    match (address.as_node_id().entity_type(), method_name) {
+        (_, ACCOUNT_LOCK_FEE_IDENT)
+        | (_, ACCOUNT_LOCK_CONTINGENT_FEE_IDENT)
+        | (_, ACCOUNT_LOCK_ROYALTY_PAYMENT_IDENT) => InvocationKind::Neutral,
+        (Some(EntityType::GlobalValidator), VALIDATOR_STAKE_IDENT) => {
+            InvocationKind::Class(ManifestClass::ValidatorStake)
+        }
+        (Some(EntityType::GlobalValidator), VALIDATOR_UNSTAKE_IDENT)
+        | (Some(EntityType::GlobalValidator), VALIDATOR_UNSTAKE_FOR_CLAIMANT_IDENT) => {
+            InvocationKind::Class(ManifestClass::ValidatorUnstake)
+        }
+        (Some(EntityType::GlobalValidator), VALIDATOR_CLAIM_XRD_IDENT) => {
+            InvocationKind::Class(ManifestClass::ValidatorClaim)
+        }
+        (Some(EntityType::GlobalOneResourcePool), ONE_RESOURCE_POOL_CONTRIBUTE_IDENT)
+        | (Some(EntityType::GlobalTwoResourcePool), TWO_RESOURCE_POOL_CONTRIBUTE_IDENT)
+        | (Some(EntityType::GlobalMultiResourcePool), MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT) => {
+            InvocationKind::Class(ManifestClass::PoolContribution)
+        }
+        (Some(EntityType::GlobalOneResourcePool), ONE_RESOURCE_POOL_REDEEM_IDENT)
+        | (Some(EntityType::GlobalTwoResourcePool), TWO_RESOURCE_POOL_REDEEM_IDENT)
+        | (Some(EntityType::GlobalMultiResourcePool), MULTI_RESOURCE_POOL_REDEEM_IDENT) => {
+            InvocationKind::Class(ManifestClass::PoolRedemption)
+        }
+        _ => InvocationKind::Other,
+    }
+}
+
+fn is_account(address: &GlobalAddress) -> bool {
+    matches!(
+        address.as_node_id().entity_type(),
+        Some(EntityType::GlobalAccount)
+            | Some(EntityType::GlobalVirtualSecp256k1Account)
+            | Some(EntityType::GlobalVirtualEd25519Account)
+    )
+}
+
+fn is_identity(address: &GlobalAddress) -> bool {
+    matches!(
+        address.as_node_id().entity_type(),
+        Some(EntityType::GlobalIdentity)
+            | Some(EntityType::GlobalVirtualSecp256k1Identity)
+            | Some(EntityType::GlobalVirtualEd25519Identity)
+    )
+}
+
+/// The owner badge of a virtual account or identity is the signature badge of the public key
+/// whose hash is embedded in its address.
+fn virtual_owner_badge(node_id: &NodeId) -> Option<NonFungibleGlobalId> {
+    let public_key_hash: [u8; NodeId::RID_LENGTH] = copy_u8_array(&node_id.as_bytes()[1..]);
+    match node_id.entity_type()? {
+        EntityType::GlobalVirtualSecp256k1Account | EntityType::GlobalVirtualSecp256k1Identity => {
+            Some(NonFungibleGlobalId::from_public_key_hash(
+                Secp256k1PublicKeyHash(public_key_hash),
+            ))
+        }
+        EntityType::GlobalVirtualEd25519Account | EntityType::GlobalVirtualEd25519Identity => Some(
+            NonFungibleGlobalId::from_public_key_hash(Ed25519PublicKeyHash(public_key_hash)),
+        ),
+        _ => None,
+    }
+}
+
+impl TransformHandler<Infallible> for CallArguments {
+    fn replace_bucket(&mut self, b: ManifestBucket) -> Result<Own, Infallible> {
+        self.buckets.push(b);
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_proof(&mut self, _p: ManifestProof) -> Result<Own, Infallible> {
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_address_reservation(
+        &mut self,
+        _r: ManifestAddressReservation,
+    ) -> Result<Own, Infallible> {
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_named_address(&mut self, _a: u32) -> Result<Reference, Infallible> {
+        Ok(Reference(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_expression(&mut self, e: ManifestExpression) -> Result<Vec<Own>, Infallible> {
+        self.expressions.push(e);
+        Ok(Vec::new())
+    }
+
+    fn replace_blob(&mut self, b: ManifestBlobRef) -> Result<Vec<u8>, Infallible> {
+        self.blobs.push(Hash(b.0));
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(key: u64) -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(key).unwrap().public_key(),
+        )
+    }
+
+    #[test]
+    fn transfer_is_analyzed() {
+        let from = account(1);
+        let to = account(2);
+        let manifest = ManifestBuilder::new()
+            .lock_fee(from, 10)
+            .withdraw_from_account(from, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(40), "xrd")
+            .try_deposit_or_abort(to, None, "xrd")
+            .try_deposit_entire_worktop_or_abort(from, None)
+            .build();
+
+        let summary = manifest.analyze();
+
+        assert_eq!(summary.classification, ManifestClass::Transfer);
+        assert_eq!(
+            summary.fee_payers,
+            [from].into_iter().collect::<IndexSet<_>>()
+        );
+        assert_eq!(
+            summary.accounts_withdrawn_from,
+            [from].into_iter().collect::<IndexSet<_>>()
+        );
+        assert_eq!(
+            summary.accounts_deposited_into,
+            [to, from].into_iter().collect::<IndexSet<_>>()
+        );
+        assert_eq!(
+            summary.deposits,
+            vec![
+                AccountResourceMovement {
+                    instruction_index: 3,
+                    account: to,
+                    resource_address: Some(XRD),
+                    quantity: ResourceQuantity::Amount(dec!(40)),
+                },
+                AccountResourceMovement {
+                    instruction_index: 4,
+                    account: from,
+                    resource_address: Some(XRD),
+                    quantity: ResourceQuantity::Amount(dec!(60)),
+                },
+            ]
+        );
+        assert_eq!(
+            summary.entities_requiring_auth,
+            [GlobalAddress::from(from)]
+                .into_iter()
+                .collect::<IndexSet<_>>()
+        );
+        assert_eq!(
+            summary.required_signature_badges,
+            [NonFungibleGlobalId::from_public_key(
+                &Secp256k1PrivateKey::from_u64(1).unwrap().public_key()
+            )]
+            .into_iter()
+            .collect::<IndexSet<_>>()
+        );
+        assert!(summary
+            .referenced_global_addresses
+            .contains(&GlobalAddress::from(XRD)));
+    }
+
+    #[test]
+    fn unknown_invocation_makes_worktop_contents_unknown() {
+        let from = account(1);
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_all_from_worktop(XRD, "xrd")
+            .create_proof_from_bucket_of_all("xrd", "proof")
+            .drop_all_proofs()
+            .try_deposit_or_abort(from, None, "xrd")
+            .build();
+
+        let summary = manifest.analyze();
+
+        assert_eq!(summary.classification, ManifestClass::General);
+        assert!(summary.accounts_withdrawn_from.is_empty());
+        assert!(summary.entities_requiring_auth.is_empty());
+        assert_eq!(
+            summary.deposits,
+            vec![AccountResourceMovement {
+                instruction_index: 5,
+                account: from,
+                resource_address: Some(XRD),
+                quantity: ResourceQuantity::Unknown,
+            }]
+        );
+        assert_eq!(summary.proofs_created.len(), 1);
+        assert_eq!(summary.proofs_created[0].source, ProofSource::Bucket);
+        assert_eq!(summary.proofs_created[0].resource_address, Some(XRD));
+    }
+
+    #[test]
+    fn summary_can_be_encoded() {
+        let summary = ManifestBuilder::new()
+            .lock_fee(account(1), 10)
+            .build()
+            .analyze();
+
+        let encoded = manifest_encode(&summary).unwrap();
+
+        assert_eq!(manifest_decode::<ManifestSummary>(&encoded), Ok(summary));
+    }
+}
@@ -4,6 +4,8 @@ use crate::internal_prelude::*;
 pub struct AuthZoneParams {
     pub initial_proofs: BTreeSet<NonFungibleGlobalId>,
     pub virtual_resources: BTreeSet<ResourceAddress>,
+    /// The initial proofs of each subintent, by subintent hash.
+    pub subintent_initial_proofs: IndexMap<Hash, BTreeSet<NonFungibleGlobalId>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -77,6 +79,17 @@ impl Default for TransactionCostingParameters {
     }
 }
 
+/// Executable form of a subintent, post stateless validation.
+#[derive(Debug, Clone)]
+pub struct ExecutableSubintent<'a> {
+    pub encoded_instructions: &'a [u8],
+    pub references: &'a IndexSet<Reference>,
+    pub blobs: &'a IndexMap<Hash, Vec<u8>>,
+    pub intent_hash: TransactionIntentHash,
+    pub epoch_range: EpochRange,
+    pub initial_proofs: BTreeSet<NonFungibleGlobalId>,
+}
+
 /// Executable form of transaction, post stateless validation.
 #[derive(Debug)]
 pub struct Executable<'a> {
@@ -84,6 +97,7 @@ pub struct Executable<'a> {
     references: IndexSet<Reference>,
     blobs: &'a IndexMap<Hash, Vec<u8>>,
     context: ExecutionContext,
+    subintents: Vec<ExecutableSubintent<'a>>,
 }
 
 impl<'a> Executable<'a> {
@@ -116,7 +130,28 @@ impl<'a> Executable<'a> {
             references,
             blobs,
             context,
+            subintents: vec![],
+        }
+    }
+
+    /// Adds the subintents which the instructions can yield to, by index.
+    pub fn with_subintents(mut self, subintents: Vec<ExecutableSubintent<'a>>) -> Self {
+        for subintent in &subintents {
+            self.references.extend(subintent.references.iter().cloned());
+            for proof in &subintent.initial_proofs {
+                self.references
+                    .insert(proof.resource_address().clone().into());
+            }
+            self.context
+                .auth_zone_params
+                .subintent_initial_proofs
+                .insert(
+                    subintent.intent_hash.to_hash(),
+                    subintent.initial_proofs.clone(),
+                );
         }
+        self.subintents.extend(subintents);
+        self
     }
 
     pub fn intent_hash(&self) -> &TransactionIntentHash {
@@ -127,6 +162,10 @@ impl<'a> Executable<'a> {
         self.context.epoch_range.as_ref()
     }
 
+    pub fn subintents(&self) -> &[ExecutableSubintent<'a>] {
+        &self.subintents
+    }
+
     pub fn overwrite_intent_hash(&mut self, hash: Hash) {
         match &mut self.context.intent_hash {
             TransactionIntentHash::ToCheck { intent_hash, .. }
@@ -3,6 +3,7 @@ mod executable;
 mod hash;
 mod preparation;
 mod v1;
+mod v2;
 mod versioned;
 
 pub use concepts::*;
@@ -10,4 +11,5 @@ pub use executable::*;
 pub use hash::*;
 pub use preparation::*;
 pub use v1::*;
+pub use v2::*;
 pub use versioned::*;
@@ -5,6 +5,7 @@ use sbor::*;
 pub enum ValueType {
     Blob,
     Attachment,
+    Subintent,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
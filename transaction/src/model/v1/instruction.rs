@@ -698,6 +698,30 @@ pub enum InstructionV1 {
         package_address: PackageAddress,
         blueprint_name: String,
     },
+
+    //==============
+    // Subintent
+    //==============
+    // The yield instructions are only supported by V2 transactions. They are rejected when
+    // compiling a V1 manifest, by the V1 transaction validator, and by the transaction processor
+    // for any transaction without subintents, which covers preview, system and test transactions.
+    /// Runs the subintent at the given index until it yields back to its parent, passing it
+    /// the args. Buckets in the args are put on the subintent's worktop, and buckets yielded
+    /// back by the subintent are put on the worktop.
+    ///
+    /// Only valid in the root intent of a transaction with subintents, and each subintent must
+    /// be yielded to exactly once, in order.
+    #[sbor(discriminator(INSTRUCTION_YIELD_TO_CHILD_DISCRIMINATOR))]
+    YieldToChild {
+        child_index: u32,
+        args: ManifestValue,
+    },
+
+    /// Returns control to the parent intent, passing it the args.
+    ///
+    /// Only valid as the last instruction of a subintent.
+    #[sbor(discriminator(INSTRUCTION_YIELD_TO_PARENT_DISCRIMINATOR))]
+    YieldToParent { args: ManifestValue },
 }
 
 //===============================================================
@@ -766,3 +790,9 @@ pub const INSTRUCTION_CALL_DIRECT_VAULT_METHOD_DISCRIMINATOR: u8 = 0x45;
 pub const INSTRUCTION_DROP_NAMED_PROOFS_DISCRIMINATOR: u8 = 0x52;
 pub const INSTRUCTION_DROP_ALL_PROOFS_DISCRIMINATOR: u8 = 0x50;
 pub const INSTRUCTION_ALLOCATE_GLOBAL_ADDRESS_DISCRIMINATOR: u8 = 0x51;
+
+//==============
+// Subintent
+//==============
+pub const INSTRUCTION_YIELD_TO_CHILD_DISCRIMINATOR: u8 = 0x60;
+pub const INSTRUCTION_YIELD_TO_PARENT_DISCRIMINATOR: u8 = 0x61;
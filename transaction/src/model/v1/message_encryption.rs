@@ -0,0 +1,337 @@
+use super::*;
+use crate::internal_prelude::*;
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::Aes128Gcm;
+use aes_kw::KekAes256;
+use blake2::digest::consts::U32;
+use blake2::Blake2b;
+use hkdf::Hkdf;
+
+/// The length of the AES-GCM Nonce/IV prefixed to the cipher, see `AesGcmPayload`.
+const AES_GCM_NONCE_LENGTH: usize = 12;
+/// The length of the AES-GCM symmetric key.
+const AES_KEY_LENGTH: usize = 16;
+
+//============================================================================
+// MESSAGE CONSTRUCTION
+//============================================================================
+
+impl PlaintextMessageV1 {
+    pub fn text<S: Into<String>>(message: S) -> Self {
+        Self {
+            mime_type: "text/plain".to_owned(),
+            message: MessageContentsV1::String(message.into()),
+        }
+    }
+
+    pub fn bytes<S: Into<String>>(mime_type: S, message: Vec<u8>) -> Self {
+        Self {
+            mime_type: mime_type.into(),
+            message: MessageContentsV1::Bytes(message),
+        }
+    }
+}
+
+impl MessageV1 {
+    pub fn plaintext(message: PlaintextMessageV1) -> Self {
+        Self::Plaintext(message)
+    }
+
+    /// Encrypts the message for the given decryptors, see [`EncryptedMessageV1::encrypt`].
+    pub fn encrypted(
+        message: &PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        entropy: &[u8; 32],
+    ) -> Result<Self, MessageEncryptionError> {
+        EncryptedMessageV1::encrypt(message, decryptors, entropy).map(Self::Encrypted)
+    }
+
+    pub fn decrypt(
+        &self,
+        private_key: &PrivateKey,
+    ) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+        match self {
+            Self::Encrypted(message) => message.decrypt(private_key),
+            Self::None | Self::Plaintext(_) => Err(MessageDecryptionError::NotEncrypted),
+        }
+    }
+}
+
+//============================================================================
+// ENCRYPTION AND DECRYPTION
+//============================================================================
+
+impl EncryptedMessageV1 {
+    /// Encrypts the message for the given decryptors, as described on [`EncryptedMessageV1`].
+    ///
+    /// The ephemeral symmetric key, the Nonce/IV and the ephemeral Diffie-Hellman keys are all
+    /// derived from the `entropy`, which must be freshly generated from a cryptographically
+    /// secure random source for every message.
+    pub fn encrypt(
+        message: &PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        entropy: &[u8; 32],
+    ) -> Result<Self, MessageEncryptionError> {
+        if decryptors.is_empty() {
+            return Err(MessageEncryptionError::NoDecryptors);
+        }
+
+        let derive = |purpose: &str| hash([entropy.as_slice(), purpose.as_bytes()].concat());
+        let aes_key: [u8; AES_KEY_LENGTH] = copy_u8_array(&derive("aes_key").0[..AES_KEY_LENGTH]);
+        let nonce: [u8; AES_GCM_NONCE_LENGTH] =
+            copy_u8_array(&derive("aes_nonce").0[..AES_GCM_NONCE_LENGTH]);
+        let ed25519_ephemeral_key = Ed25519PrivateKey::from_bytes(&derive("ed25519").0)
+            .map_err(|_| MessageEncryptionError::InvalidEntropy)?;
+        let secp256k1_ephemeral_key = Secp256k1PrivateKey::from_bytes(&derive("secp256k1").0)
+            .map_err(|_| MessageEncryptionError::InvalidEntropy)?;
+
+        let mut ed25519_decryptors = index_map_new();
+        let mut secp256k1_decryptors = index_map_new();
+        for decryptor in decryptors {
+            let invalid = || MessageEncryptionError::InvalidDecryptorPublicKey(decryptor.clone());
+            match decryptor {
+                PublicKey::Ed25519(public_key) => {
+                    let shared_secret = ed25519_ephemeral_key
+                        .diffie_hellman(public_key)
+                        .map_err(|_| invalid())?;
+                    ed25519_decryptors.insert(
+                        PublicKeyFingerprint::from(decryptor.clone()),
+                        wrap_key(&shared_secret, &aes_key),
+                    );
+                }
+                PublicKey::Secp256k1(public_key) => {
+                    let shared_secret = secp256k1_ephemeral_key
+                        .diffie_hellman(public_key)
+                        .map_err(|_| invalid())?;
+                    secp256k1_decryptors.insert(
+                        PublicKeyFingerprint::from(decryptor.clone()),
+                        wrap_key(&shared_secret, &aes_key),
+                    );
+                }
+            }
+        }
+
+        let mut decryptors_by_curve = index_map_new();
+        if !ed25519_decryptors.is_empty() {
+            decryptors_by_curve.insert(
+                CurveType::Ed25519,
+                DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key: ed25519_ephemeral_key.public_key(),
+                    decryptors: ed25519_decryptors,
+                },
+            );
+        }
+        if !secp256k1_decryptors.is_empty() {
+            decryptors_by_curve.insert(
+                CurveType::Secp256k1,
+                DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key: secp256k1_ephemeral_key.public_key(),
+                    decryptors: secp256k1_decryptors,
+                },
+            );
+        }
+
+        let payload = manifest_encode(message).map_err(MessageEncryptionError::EncodeError)?;
+        let cipher = Aes128Gcm::new(GenericArray::from_slice(&aes_key))
+            .encrypt(GenericArray::from_slice(&nonce), payload.as_slice())
+            .map_err(|_| MessageEncryptionError::EncryptionFailed)?;
+
+        Ok(Self {
+            encrypted: AesGcmPayload([nonce.as_slice(), cipher.as_slice()].concat()),
+            decryptors_by_curve,
+        })
+    }
+
+    /// Decrypts the message with the private key of one of its decryptors.
+    pub fn decrypt(
+        &self,
+        private_key: &PrivateKey,
+    ) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+        let fingerprint = PublicKeyFingerprint::from(private_key.public_key());
+        let (shared_secret, wrapped_key) = match private_key {
+            PrivateKey::Ed25519(private_key) => {
+                match self.decryptors_by_curve.get(&CurveType::Ed25519) {
+                    Some(DecryptorsByCurve::Ed25519 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    }) => (
+                        private_key
+                            .diffie_hellman(dh_ephemeral_public_key)
+                            .map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?,
+                        decryptors.get(&fingerprint),
+                    ),
+                    _ => return Err(MessageDecryptionError::NotADecryptor),
+                }
+            }
+            PrivateKey::Secp256k1(private_key) => {
+                match self.decryptors_by_curve.get(&CurveType::Secp256k1) {
+                    Some(DecryptorsByCurve::Secp256k1 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    }) => (
+                        private_key
+                            .diffie_hellman(dh_ephemeral_public_key)
+                            .map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?,
+                        decryptors.get(&fingerprint),
+                    ),
+                    _ => return Err(MessageDecryptionError::NotADecryptor),
+                }
+            }
+        };
+        let wrapped_key = wrapped_key.ok_or(MessageDecryptionError::NotADecryptor)?;
+
+        let mut aes_key = [0u8; AES_KEY_LENGTH];
+        key_encrypting_key(&shared_secret)
+            .unwrap(&wrapped_key.0, &mut aes_key)
+            .map_err(|_| MessageDecryptionError::KeyUnwrapFailed)?;
+
+        if self.encrypted.0.len() < AES_GCM_NONCE_LENGTH {
+            return Err(MessageDecryptionError::DecryptionFailed);
+        }
+        let (nonce, cipher) = self.encrypted.0.split_at(AES_GCM_NONCE_LENGTH);
+        let payload = Aes128Gcm::new(GenericArray::from_slice(&aes_key))
+            .decrypt(GenericArray::from_slice(nonce), cipher)
+            .map_err(|_| MessageDecryptionError::DecryptionFailed)?;
+
+        manifest_decode(&payload).map_err(MessageDecryptionError::DecodeError)
+    }
+}
+
+/// Derives the KEK from the Diffie-Hellman shared secret:
+/// `KEK = HKDF(hash: Blake2b, secret: shared secret, salt: [], length: 256 bits)`.
+fn key_encrypting_key(shared_secret: &[u8; 32]) -> KekAes256 {
+    let mut kek = [0u8; 32];
+    Hkdf::<Blake2b<U32>>::new(None, shared_secret)
+        .expand(&[], &mut kek)
+        .expect("256 bits is a valid HKDF output length");
+    KekAes256::from(kek)
+}
+
+fn wrap_key(shared_secret: &[u8; 32], key: &[u8; AES_KEY_LENGTH]) -> AesWrapped128BitKey {
+    let mut wrapped = [0u8; AesWrapped128BitKey::LENGTH];
+    key_encrypting_key(shared_secret)
+        .wrap(key, &mut wrapped)
+        .expect("A 128-bit key can always be wrapped");
+    AesWrapped128BitKey(wrapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decryptor_keys() -> Vec<PrivateKey> {
+        vec![
+            Ed25519PrivateKey::from_u64(1).unwrap().into(),
+            Ed25519PrivateKey::from_u64(2).unwrap().into(),
+            Secp256k1PrivateKey::from_u64(3).unwrap().into(),
+        ]
+    }
+
+    /// A known-answer vector, fixing the key derivation, Diffie-Hellman, key wrapping and
+    /// encryption for a given entropy, message and decryptors.
+    #[test]
+    fn encryption_matches_known_answer_vector() {
+        let message = PlaintextMessageV1::text("Hello world!");
+        let ed25519_key: PrivateKey = Ed25519PrivateKey::from_u64(1).unwrap().into();
+        let secp256k1_key: PrivateKey = Secp256k1PrivateKey::from_u64(3).unwrap().into();
+        let decryptors = [ed25519_key.public_key(), secp256k1_key.public_key()];
+
+        let encrypted = EncryptedMessageV1::encrypt(&message, &decryptors, &[7u8; 32]).unwrap();
+
+        assert_eq!(
+            hex::encode(&encrypted.encrypted.0),
+            "344a338248689c11d2626c4af8a4b34856c7405d02a8ba8519e4e0cb0a3ef4745384c5ceb10ddbd305cd90eb8e45c1dbed58d8f3bcf0a4e3b8b93ceb"
+        );
+        match &encrypted.decryptors_by_curve[&CurveType::Ed25519] {
+            DecryptorsByCurve::Ed25519 {
+                dh_ephemeral_public_key,
+                decryptors,
+            } => {
+                assert_eq!(
+                    dh_ephemeral_public_key.to_string(),
+                    "76ba88d21cd27fced0fcb5fea6a6949b31db745edc5e09d3e641cb6ff0207a9f"
+                );
+                let fingerprint = PublicKeyFingerprint::from(ed25519_key.public_key());
+                assert_eq!(
+                    hex::encode(decryptors[&fingerprint].0),
+                    "7a7099ef09e7b8c112c8db1a83d56e102c705ac666bef150"
+                );
+            }
+            _ => panic!("Unexpected curve"),
+        }
+        match &encrypted.decryptors_by_curve[&CurveType::Secp256k1] {
+            DecryptorsByCurve::Secp256k1 {
+                dh_ephemeral_public_key,
+                decryptors,
+            } => {
+                assert_eq!(
+                    dh_ephemeral_public_key.to_string(),
+                    "02e603ed513c11d2ed2b14cb45900fac9b8b6bae19b13c4213bcb3045a0d1bbce3"
+                );
+                let fingerprint = PublicKeyFingerprint::from(secp256k1_key.public_key());
+                assert_eq!(
+                    hex::encode(decryptors[&fingerprint].0),
+                    "007721ba27f7c3a58d840ac4286bf10456985e369c56b32b"
+                );
+            }
+            _ => panic!("Unexpected curve"),
+        }
+        assert_eq!(encrypted.decrypt(&ed25519_key), Ok(message.clone()));
+        assert_eq!(encrypted.decrypt(&secp256k1_key), Ok(message));
+    }
+
+    #[test]
+    fn encrypted_message_can_be_decrypted_by_every_decryptor() {
+        let message = PlaintextMessageV1::text("Hello world!");
+        let decryptors: Vec<PublicKey> = decryptor_keys().iter().map(|k| k.public_key()).collect();
+
+        let encrypted = MessageV1::encrypted(&message, &decryptors, &[7u8; 32]).unwrap();
+
+        match &encrypted {
+            MessageV1::Encrypted(encrypted) => {
+                assert_eq!(encrypted.decryptors_by_curve.len(), 2);
+                assert_eq!(
+                    encrypted.decryptors_by_curve[&CurveType::Ed25519].number_of_decryptors(),
+                    2
+                );
+            }
+            _ => panic!("Message not encrypted"),
+        }
+        for private_key in decryptor_keys() {
+            assert_eq!(encrypted.decrypt(&private_key), Ok(message.clone()));
+        }
+    }
+
+    #[test]
+    fn encrypted_message_cannot_be_decrypted_by_others() {
+        let message = PlaintextMessageV1::bytes("application/octet-stream", vec![1, 2, 3]);
+        let decryptors: Vec<PublicKey> = decryptor_keys().iter().map(|k| k.public_key()).collect();
+        let encrypted = MessageV1::encrypted(&message, &decryptors, &[7u8; 32]).unwrap();
+
+        assert_eq!(
+            encrypted.decrypt(&Ed25519PrivateKey::from_u64(4).unwrap().into()),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+        assert_eq!(
+            encrypted.decrypt(&Secp256k1PrivateKey::from_u64(4).unwrap().into()),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+    }
+
+    #[test]
+    fn tampered_encrypted_message_cannot_be_decrypted() {
+        let message = PlaintextMessageV1::text("Hello world!");
+        let private_key: PrivateKey = Secp256k1PrivateKey::from_u64(3).unwrap().into();
+        let mut encrypted =
+            EncryptedMessageV1::encrypt(&message, &[private_key.public_key()], &[7u8; 32]).unwrap();
+
+        let last = encrypted.encrypted.0.len() - 1;
+        encrypted.encrypted.0[last] ^= 1;
+
+        assert_eq!(
+            encrypted.decrypt(&private_key),
+            Err(MessageDecryptionError::DecryptionFailed)
+        );
+    }
+}
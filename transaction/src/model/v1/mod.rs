@@ -8,8 +8,11 @@ mod intent;
 mod intent_signatures;
 mod manifest;
 mod message;
+mod message_encryption;
 mod notarized_transaction;
 mod notary_signature;
+mod partially_signed_transaction;
+mod payload_estimate;
 mod preview_transaction;
 mod signed_intent;
 mod system_transaction;
@@ -24,8 +27,11 @@ pub use intent::*;
 pub use intent_signatures::*;
 pub use manifest::*;
 pub use message::*;
+pub use message_encryption::*;
 pub use notarized_transaction::*;
 pub use notary_signature::*;
+pub use partially_signed_transaction::*;
+pub use payload_estimate::*;
 pub use preview_transaction::*;
 pub use signed_intent::*;
 pub use system_transaction::*;
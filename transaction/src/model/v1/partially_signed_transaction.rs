@@ -0,0 +1,241 @@
+use super::*;
+use crate::internal_prelude::*;
+
+/// An intent together with the intent signatures collected so far, for signing workflows where
+/// the signers are not available in the same process.
+///
+/// Each signer adds their signature to a copy of the partially signed transaction, the copies
+/// are merged, and the notary notarizes the transaction once all the required signers have
+/// signed.
+///
+/// This is not a transaction payload; it's encoded with [`manifest_encode`] for transport
+/// between the signers.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct PartiallySignedTransactionV1 {
+    pub intent: IntentV1,
+    pub intent_signatures: IntentSignaturesV1,
+    /// The public keys expected to sign the intent, as a hint for the signers.
+    pub required_signers: Vec<PublicKey>,
+}
+
+impl PartiallySignedTransactionV1 {
+    pub fn new(intent: IntentV1, required_signers: Vec<PublicKey>) -> Self {
+        Self {
+            intent,
+            intent_signatures: IntentSignaturesV1 { signatures: vec![] },
+            required_signers,
+        }
+    }
+
+    pub fn intent_hash(&self) -> Result<IntentHash, PartialSigningError> {
+        Ok(self
+            .intent
+            .prepare()
+            .map_err(PartialSigningError::PrepareError)?
+            .intent_hash())
+    }
+
+    /// Signs the intent and adds the signature.
+    pub fn sign<S: Signer + ?Sized>(&mut self, signer: &S) -> Result<(), PartialSigningError> {
+        let intent_hash = self.intent_hash()?;
+        let signature = signer
+            .try_sign_with_public_key(intent_hash.as_hash())
+            .map_err(PartialSigningError::SigningError)?;
+        self.add_signature(IntentSignatureV1(signature)).map(|_| ())
+    }
+
+    /// Verifies and adds a signature of the intent, returning the public key of the signer.
+    pub fn add_signature(
+        &mut self,
+        signature: IntentSignatureV1,
+    ) -> Result<PublicKey, PartialSigningError> {
+        let intent_hash = self.intent_hash()?;
+        let signer = verify_intent_signature(&intent_hash, &signature)?;
+        if self.signers()?.contains(&signer) {
+            return Err(PartialSigningError::DuplicateSigner(signer));
+        }
+        self.intent_signatures.signatures.push(signature);
+        Ok(signer)
+    }
+
+    /// Merges the signatures and required signer hints of another partially signed transaction
+    /// of the same intent. Signatures of signers who already signed are skipped.
+    pub fn merge(&mut self, other: Self) -> Result<(), PartialSigningError> {
+        if other.intent_hash()? != self.intent_hash()? {
+            return Err(PartialSigningError::IntentMismatch);
+        }
+        for public_key in other.required_signers {
+            if !self.required_signers.contains(&public_key) {
+                self.required_signers.push(public_key);
+            }
+        }
+        for signature in other.intent_signatures.signatures {
+            match self.add_signature(signature) {
+                Ok(_) | Err(PartialSigningError::DuplicateSigner(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies all the collected signatures, returning the public keys of the signers.
+    pub fn signers(&self) -> Result<Vec<PublicKey>, PartialSigningError> {
+        let intent_hash = self.intent_hash()?;
+        self.intent_signatures
+            .signatures
+            .iter()
+            .map(|signature| verify_intent_signature(&intent_hash, signature))
+            .collect()
+    }
+
+    /// Returns the required signers who haven't signed yet.
+    ///
+    /// If the notary is a signatory, the notary signature counts as their intent signature.
+    pub fn missing_signers(&self) -> Result<Vec<PublicKey>, PartialSigningError> {
+        let signers = self.signers()?;
+        let header = &self.intent.header;
+        Ok(self
+            .required_signers
+            .iter()
+            .filter(|public_key| !signers.contains(public_key))
+            .filter(|public_key| {
+                !(header.notary_is_signatory && **public_key == header.notary_public_key)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Notarizes the transaction, once all the required signers have signed.
+    pub fn notarize<S: Signer + ?Sized>(
+        self,
+        notary: &S,
+    ) -> Result<NotarizedTransactionV1, PartialSigningError> {
+        let missing_signers = self.missing_signers()?;
+        if !missing_signers.is_empty() {
+            return Err(PartialSigningError::MissingSigners(missing_signers));
+        }
+        let expected = self.intent.header.notary_public_key;
+        if notary.public_key() != expected {
+            return Err(PartialSigningError::NotaryMismatch {
+                expected,
+                actual: notary.public_key(),
+            });
+        }
+
+        let signed_intent = SignedIntentV1 {
+            intent: self.intent,
+            intent_signatures: self.intent_signatures,
+        };
+        let signed_intent_hash = signed_intent
+            .prepare()
+            .map_err(PartialSigningError::PrepareError)?
+            .signed_intent_hash();
+        Ok(NotarizedTransactionV1 {
+            signed_intent,
+            notary_signature: NotarySignatureV1(
+                notary
+                    .try_sign_without_public_key(signed_intent_hash.as_hash())
+                    .map_err(PartialSigningError::SigningError)?,
+            ),
+        })
+    }
+}
+
+fn verify_intent_signature(
+    intent_hash: &IntentHash,
+    signature: &IntentSignatureV1,
+) -> Result<PublicKey, PartialSigningError> {
+    recover(intent_hash.as_hash(), &signature.0)
+        .filter(|public_key| verify(intent_hash.as_hash(), public_key, &signature.0.signature()))
+        .ok_or_else(|| PartialSigningError::InvalidSignature(signature.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partially_signed_transaction(
+        notary: &Secp256k1PrivateKey,
+        required_signers: Vec<PublicKey>,
+    ) -> PartiallySignedTransactionV1 {
+        let (instructions, blobs) = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .build()
+            .for_intent();
+        PartiallySignedTransactionV1::new(
+            IntentV1 {
+                header: TransactionHeaderV1 {
+                    network_id: NetworkDefinition::simulator().id,
+                    start_epoch_inclusive: Epoch::zero(),
+                    end_epoch_exclusive: Epoch::of(100),
+                    nonce: 5,
+                    notary_public_key: notary.public_key().into(),
+                    notary_is_signatory: false,
+                    tip_percentage: 0,
+                },
+                instructions,
+                blobs,
+                message: MessageV1::None,
+            },
+            required_signers,
+        )
+    }
+
+    #[test]
+    fn signatures_from_separate_signers_can_be_merged_and_notarized() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer1 = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let signer2 = Ed25519PrivateKey::from_u64(3).unwrap();
+        let unsigned = partially_signed_transaction(
+            &notary,
+            vec![signer1.public_key().into(), signer2.public_key().into()],
+        );
+
+        let mut signed1 = unsigned.clone();
+        signed1.sign(&signer1).unwrap();
+        let mut signed2 = unsigned.clone();
+        signed2.sign(&signer2).unwrap();
+        let signed2 = manifest_decode(&manifest_encode(&signed2).unwrap()).unwrap();
+
+        assert_eq!(
+            signed1.missing_signers(),
+            Ok(vec![signer2.public_key().into()])
+        );
+        assert!(matches!(
+            signed1.clone().notarize(&notary),
+            Err(PartialSigningError::MissingSigners(_))
+        ));
+
+        signed1.merge(signed2).unwrap();
+        assert_eq!(signed1.missing_signers(), Ok(vec![]));
+
+        let transaction = signed1.notarize(&notary).unwrap();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        assert!(validator.validate(transaction.prepare().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn invalid_and_duplicate_signatures_are_rejected() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let mut transaction = partially_signed_transaction(&notary, vec![]);
+
+        transaction.sign(&signer).unwrap();
+        assert_eq!(
+            transaction.sign(&signer),
+            Err(PartialSigningError::DuplicateSigner(
+                signer.public_key().into()
+            ))
+        );
+
+        let other_signature = IntentSignatureV1(signer.sign_with_public_key(&hash("other")));
+        assert_eq!(
+            transaction.add_signature(other_signature.clone()),
+            Err(PartialSigningError::InvalidSignature(other_signature))
+        );
+        assert!(matches!(
+            transaction.notarize(&signer),
+            Err(PartialSigningError::NotaryMismatch { .. })
+        ));
+    }
+}
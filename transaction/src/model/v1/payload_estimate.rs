@@ -0,0 +1,128 @@
+use super::*;
+use crate::internal_prelude::*;
+
+/// An estimate of the notarized transaction payload of an intent, made before it's signed.
+///
+/// Signatures of a given curve always encode to the same size, so the estimate is exact as long
+/// as the intent is signed by signers of the given curves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotarizedPayloadEstimate {
+    /// The size of the notarized transaction payload, as checked against
+    /// [`ValidationConfig::max_notarized_payload_size`].
+    pub payload_size: usize,
+    /// The payload size the engine charges the payload validation and archive storage costs for.
+    pub effective_payload_size: usize,
+    /// The number of signatures the engine charges the signature verification costs for,
+    /// including the notary signature.
+    pub num_of_signature_validations: usize,
+}
+
+impl NotarizedPayloadEstimate {
+    pub fn validate_payload_size(
+        &self,
+        config: &ValidationConfig,
+    ) -> Result<(), TransactionValidationError> {
+        if self.payload_size > config.max_notarized_payload_size {
+            return Err(TransactionValidationError::TransactionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+impl IntentV1 {
+    /// Estimates the notarized transaction payload of this intent, once signed by signers of the
+    /// given curves and notarized by the notary in the header.
+    pub fn estimate_notarized_payload(
+        &self,
+        signer_curves: &[CurveType],
+    ) -> Result<NotarizedPayloadEstimate, TransactionValidationError> {
+        let signatures = signer_curves
+            .iter()
+            .map(|curve| {
+                IntentSignatureV1(match curve {
+                    CurveType::Secp256k1 => SignatureWithPublicKeyV1::Secp256k1 {
+                        signature: Secp256k1Signature([0; Secp256k1Signature::LENGTH]),
+                    },
+                    CurveType::Ed25519 => SignatureWithPublicKeyV1::Ed25519 {
+                        public_key: Ed25519PublicKey([0; Ed25519PublicKey::LENGTH]),
+                        signature: Ed25519Signature([0; Ed25519Signature::LENGTH]),
+                    },
+                })
+            })
+            .collect();
+        let notary_signature = match self.header.notary_public_key {
+            PublicKey::Secp256k1(_) => {
+                SignatureV1::Secp256k1(Secp256k1Signature([0; Secp256k1Signature::LENGTH]))
+            }
+            PublicKey::Ed25519(_) => {
+                SignatureV1::Ed25519(Ed25519Signature([0; Ed25519Signature::LENGTH]))
+            }
+        };
+        let transaction = NotarizedTransactionV1 {
+            signed_intent: SignedIntentV1 {
+                intent: self.clone(),
+                intent_signatures: IntentSignaturesV1 { signatures },
+            },
+            notary_signature: NotarySignatureV1(notary_signature),
+        };
+
+        Ok(NotarizedPayloadEstimate {
+            payload_size: transaction.to_payload_bytes()?.len(),
+            effective_payload_size: transaction.prepare()?.summary.effective_length,
+            num_of_signature_validations: signer_curves.len() + 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_matches_the_notarized_transaction() {
+        let notary = Ed25519PrivateKey::from_u64(1).unwrap();
+        let signer1 = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let signer2 = Ed25519PrivateKey::from_u64(3).unwrap();
+        let transaction = TransactionBuilder::new()
+            .header(TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce: 5,
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory: false,
+                tip_percentage: 0,
+            })
+            .manifest(ManifestBuilder::new().lock_fee_from_faucet().build())
+            .sign(&signer1)
+            .sign(&signer2)
+            .notarize(&notary)
+            .build();
+
+        let estimate = transaction
+            .signed_intent
+            .intent
+            .estimate_notarized_payload(&[CurveType::Secp256k1, CurveType::Ed25519])
+            .unwrap();
+
+        assert_eq!(
+            estimate.payload_size,
+            transaction.to_payload_bytes().unwrap().len()
+        );
+        assert_eq!(
+            estimate.effective_payload_size,
+            transaction.prepare().unwrap().summary.effective_length
+        );
+        assert_eq!(estimate.num_of_signature_validations, 3);
+        assert!(estimate
+            .validate_payload_size(&ValidationConfig::simulator())
+            .is_ok());
+        assert_eq!(
+            estimate.validate_payload_size(&ValidationConfig {
+                max_notarized_payload_size: estimate.payload_size - 1,
+                ..ValidationConfig::simulator()
+            }),
+            Err(TransactionValidationError::TransactionTooLarge)
+        );
+    }
+}
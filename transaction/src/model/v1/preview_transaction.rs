@@ -74,6 +74,7 @@ impl ValidatedPreviewIntent {
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtual_resources,
+                    subintent_initial_proofs: index_map_new(),
                 },
                 costing_parameters: fee_payment,
                 pre_allocated_addresses: vec![],
@@ -114,6 +114,7 @@ impl PreparedSystemTransactionV1 {
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtual_resources: BTreeSet::new(),
+                    subintent_initial_proofs: index_map_new(),
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: 0,
@@ -68,6 +68,7 @@ impl PreparedTestTransaction {
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtual_resources: BTreeSet::new(),
+                    subintent_initial_proofs: index_map_new(),
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: DEFAULT_TIP_PERCENTAGE,
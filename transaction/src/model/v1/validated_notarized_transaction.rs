@@ -55,6 +55,7 @@ impl ValidatedNotarizedTransactionV1 {
                 auth_zone_params: AuthZoneParams {
                     initial_proofs: AuthAddresses::signer_set(&self.signer_keys),
                     virtual_resources: BTreeSet::new(),
+                    subintent_initial_proofs: index_map_new(),
                 },
                 costing_parameters: TransactionCostingParameters {
                     tip_percentage: intent.header.inner.tip_percentage,
@@ -0,0 +1,80 @@
+use super::*;
+use crate::internal_prelude::*;
+
+/// The root intent of a V2 transaction, which can run pre-authorized subintents.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct IntentV2 {
+    pub header: TransactionHeaderV1,
+    pub instructions: InstructionsV1,
+    pub blobs: BlobsV1,
+    pub message: MessageV1,
+    pub subintents: SubintentsV2,
+}
+
+impl TransactionPayload for IntentV2 {
+    type Versioned = SborFixedEnumVariant<{ TransactionDiscriminator::V2Intent as u8 }, Self>;
+    type Prepared = PreparedIntentV2;
+    type Raw = RawIntent;
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PreparedIntentV2 {
+    pub header: PreparedTransactionHeaderV1,
+    pub instructions: PreparedInstructionsV1,
+    pub blobs: PreparedBlobsV1,
+    pub message: PreparedMessageV1,
+    pub subintents: PreparedSubintentsV2,
+    pub summary: Summary,
+}
+
+impl HasSummary for PreparedIntentV2 {
+    fn get_summary(&self) -> &Summary {
+        &self.summary
+    }
+}
+
+impl TransactionFullChildPreparable for PreparedIntentV2 {
+    fn prepare_as_full_body_child(decoder: &mut TransactionDecoder) -> Result<Self, PrepareError> {
+        // When embedded as an child, it's SBOR encoded as a struct
+        let ((header, instructions, blobs, message, subintents), summary) =
+            ConcatenatedDigest::prepare_from_transaction_child_struct(
+                decoder,
+                TransactionDiscriminator::V2Intent,
+            )?;
+        Ok(Self {
+            header,
+            instructions,
+            blobs,
+            message,
+            subintents,
+            summary,
+        })
+    }
+}
+
+impl TransactionPayloadPreparable for PreparedIntentV2 {
+    type Raw = RawIntent;
+
+    fn prepare_for_payload(decoder: &mut TransactionDecoder) -> Result<Self, PrepareError> {
+        // When embedded as full payload, it's SBOR encoded as an enum
+        let ((header, instructions, blobs, message, subintents), summary) =
+            ConcatenatedDigest::prepare_from_transaction_payload_enum(
+                decoder,
+                TransactionDiscriminator::V2Intent,
+            )?;
+        Ok(Self {
+            header,
+            instructions,
+            blobs,
+            message,
+            subintents,
+            summary,
+        })
+    }
+}
+
+impl HasIntentHash for PreparedIntentV2 {
+    fn intent_hash(&self) -> IntentHash {
+        IntentHash::from_hash(self.summary.hash)
+    }
+}
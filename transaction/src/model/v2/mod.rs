@@ -0,0 +1,13 @@
+use super::*;
+
+mod intent;
+mod notarized_transaction;
+mod signed_intent;
+mod subintent;
+mod validated_notarized_transaction;
+
+pub use intent::*;
+pub use notarized_transaction::*;
+pub use signed_intent::*;
+pub use subintent::*;
+pub use validated_notarized_transaction::*;
@@ -0,0 +1,150 @@
+use super::*;
+use crate::internal_prelude::*;
+use radix_engine_common::constants::MAX_NUMBER_OF_SUBINTENTS;
+
+//=================================================================================
+// NOTE:
+// A subintent is signed on its own, so its hash is calculated as if it was a
+// full payload, even when it's embedded in an intent.
+//=================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct SubintentHeaderV2 {
+    pub network_id: u8,
+    pub start_epoch_inclusive: Epoch,
+    pub end_epoch_exclusive: Epoch,
+    pub nonce: u32,
+}
+
+pub type PreparedSubintentHeaderV2 = SummarizedRawFullBody<SubintentHeaderV2>;
+
+/// A partial manifest, pre-authorized by its signers, which a transaction can run from its
+/// root intent with a `YieldToChild` instruction.
+///
+/// The subintent runs with its own worktop and auth zone, and must end by yielding back to its
+/// parent with a `YieldToParent` instruction.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct SubintentV2 {
+    pub header: SubintentHeaderV2,
+    pub instructions: InstructionsV1,
+    pub blobs: BlobsV1,
+    pub message: MessageV1,
+}
+
+impl TransactionPayload for SubintentV2 {
+    type Versioned = SborFixedEnumVariant<{ TransactionDiscriminator::V2Subintent as u8 }, Self>;
+    type Prepared = PreparedSubintentV2;
+    type Raw = RawSubintent;
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PreparedSubintentV2 {
+    pub header: PreparedSubintentHeaderV2,
+    pub instructions: PreparedInstructionsV1,
+    pub blobs: PreparedBlobsV1,
+    pub message: PreparedMessageV1,
+    pub summary: Summary,
+}
+
+impl HasSummary for PreparedSubintentV2 {
+    fn get_summary(&self) -> &Summary {
+        &self.summary
+    }
+}
+
+impl TransactionPayloadPreparable for PreparedSubintentV2 {
+    type Raw = RawSubintent;
+
+    fn prepare_for_payload(decoder: &mut TransactionDecoder) -> Result<Self, PrepareError> {
+        // When embedded as full payload, it's SBOR encoded as an enum
+        let ((header, instructions, blobs, message), summary) =
+            ConcatenatedDigest::prepare_from_transaction_payload_enum(
+                decoder,
+                TransactionDiscriminator::V2Subintent,
+            )?;
+        Ok(Self {
+            header,
+            instructions,
+            blobs,
+            message,
+            summary,
+        })
+    }
+}
+
+impl HasSubintentHash for PreparedSubintentV2 {
+    fn subintent_hash(&self) -> SubintentHash {
+        SubintentHash::from_hash(self.summary.hash)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct SignedSubintentV2 {
+    pub subintent: SubintentV2,
+    pub subintent_signatures: IntentSignaturesV1,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PreparedSignedSubintentV2 {
+    pub subintent: PreparedSubintentV2,
+    pub subintent_signatures: IntentSignaturesV1,
+}
+
+impl HasSubintentHash for PreparedSignedSubintentV2 {
+    fn subintent_hash(&self) -> SubintentHash {
+        self.subintent.subintent_hash()
+    }
+}
+
+/// The subintents of an intent, which its instructions refer to by index.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+#[sbor(transparent)]
+pub struct SubintentsV2(pub Vec<SignedSubintentV2>);
+
+impl TransactionPartialEncode for SubintentsV2 {
+    type Prepared = PreparedSubintentsV2;
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PreparedSubintentsV2 {
+    pub subintents: Vec<PreparedSignedSubintentV2>,
+    pub summary: Summary,
+}
+
+impl HasSummary for PreparedSubintentsV2 {
+    fn get_summary(&self) -> &Summary {
+        &self.summary
+    }
+}
+
+impl TransactionFullChildPreparable for PreparedSubintentsV2 {
+    fn prepare_as_full_body_child(decoder: &mut TransactionDecoder) -> Result<Self, PrepareError> {
+        // The subintents are hashed as a whole into the intent hash, and each subintent is
+        // prepared separately for its own hash
+        let raw = SummarizedRawFullBody::<SubintentsV2>::prepare_as_full_body_child(decoder)?;
+        if raw.inner.0.len() > MAX_NUMBER_OF_SUBINTENTS {
+            return Err(PrepareError::TooManyValues {
+                value_type: ValueType::Subintent,
+                actual: raw.inner.0.len(),
+                max: MAX_NUMBER_OF_SUBINTENTS,
+            });
+        }
+
+        let subintents = raw
+            .inner
+            .0
+            .into_iter()
+            .map(|signed_subintent| {
+                Ok(PreparedSignedSubintentV2 {
+                    subintent: signed_subintent.subintent.prepare()?,
+                    subintent_signatures: signed_subintent.subintent_signatures,
+                })
+            })
+            .collect::<Result<_, PrepareError>>()?;
+
+        Ok(Self {
+            subintents,
+            summary: raw.summary,
+        })
+    }
+}
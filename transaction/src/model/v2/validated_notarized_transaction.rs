@@ -0,0 +1,106 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ValidatedSubintentV2 {
+    pub encoded_instructions: Vec<u8>,
+    pub signer_keys: Vec<PublicKey>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ValidatedNotarizedTransactionV2 {
+    pub prepared: PreparedNotarizedTransactionV2,
+    pub encoded_instructions: Vec<u8>,
+    pub signer_keys: Vec<PublicKey>,
+    /// The validated subintents, in the same order as in the intent.
+    pub subintents: Vec<ValidatedSubintentV2>,
+    pub num_of_signature_validations: usize,
+}
+
+impl HasIntentHash for ValidatedNotarizedTransactionV2 {
+    fn intent_hash(&self) -> IntentHash {
+        self.prepared.intent_hash()
+    }
+}
+
+impl HasSignedIntentHash for ValidatedNotarizedTransactionV2 {
+    fn signed_intent_hash(&self) -> SignedIntentHash {
+        self.prepared.signed_intent_hash()
+    }
+}
+
+impl HasNotarizedTransactionHash for ValidatedNotarizedTransactionV2 {
+    fn notarized_transaction_hash(&self) -> NotarizedTransactionHash {
+        self.prepared.notarized_transaction_hash()
+    }
+}
+
+impl ValidatedNotarizedTransactionV2 {
+    pub fn get_executable_with_free_credit<'a>(
+        &'a self,
+        free_credit_in_xrd: Decimal,
+    ) -> Executable<'a> {
+        let intent = &self.prepared.signed_intent.intent;
+        let header = &intent.header.inner;
+        let intent_hash = intent.intent_hash();
+        let summary = &self.prepared.summary;
+
+        let subintents = intent
+            .subintents
+            .subintents
+            .iter()
+            .zip(self.subintents.iter())
+            .map(|(prepared, validated)| {
+                let subintent = &prepared.subintent;
+                let header = &subintent.header.inner;
+                ExecutableSubintent {
+                    encoded_instructions: &validated.encoded_instructions,
+                    references: &subintent.instructions.references,
+                    blobs: &subintent.blobs.blobs_by_hash,
+                    intent_hash: TransactionIntentHash::ToCheck {
+                        intent_hash: subintent.subintent_hash().into_hash(),
+                        expiry_epoch: header.end_epoch_exclusive,
+                    },
+                    epoch_range: EpochRange {
+                        start_epoch_inclusive: header.start_epoch_inclusive,
+                        end_epoch_exclusive: header.end_epoch_exclusive,
+                    },
+                    initial_proofs: AuthAddresses::signer_set(&validated.signer_keys),
+                }
+            })
+            .collect();
+
+        Executable::new(
+            &self.encoded_instructions,
+            &intent.instructions.references,
+            &intent.blobs.blobs_by_hash,
+            ExecutionContext {
+                intent_hash: TransactionIntentHash::ToCheck {
+                    intent_hash: intent_hash.into_hash(),
+                    expiry_epoch: header.end_epoch_exclusive,
+                },
+                epoch_range: Some(EpochRange {
+                    start_epoch_inclusive: header.start_epoch_inclusive,
+                    end_epoch_exclusive: header.end_epoch_exclusive,
+                }),
+                payload_size: summary.effective_length,
+                num_of_signature_validations: self.num_of_signature_validations,
+                auth_zone_params: AuthZoneParams {
+                    initial_proofs: AuthAddresses::signer_set(&self.signer_keys),
+                    virtual_resources: BTreeSet::new(),
+                    subintent_initial_proofs: index_map_new(),
+                },
+                costing_parameters: TransactionCostingParameters {
+                    tip_percentage: header.tip_percentage,
+                    free_credit_in_xrd,
+                },
+                pre_allocated_addresses: vec![],
+            },
+        )
+        .with_subintents(subintents)
+    }
+
+    pub fn get_executable<'a>(&'a self) -> Executable<'a> {
+        self.get_executable_with_free_credit(Decimal::ZERO)
+    }
+}
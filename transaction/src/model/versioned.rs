@@ -18,6 +18,10 @@ pub enum TransactionDiscriminator {
     V1RoundUpdate = V1_ROUND_UPDATE_TRANSACTION,
     V1Preview = V1_PREVIEW_TRANSACTION,
     V1Ledger = V1_LEDGER_TRANSACTION,
+    V2Subintent = V2_SUBINTENT,
+    V2Intent = V2_INTENT,
+    V2SignedIntent = V2_SIGNED_INTENT,
+    V2Notarized = V2_NOTARIZED_TRANSACTION,
 }
 
 const V1_INTENT: u8 = 1;
@@ -27,6 +31,10 @@ const V1_SYSTEM_TRANSACTION: u8 = 4;
 const V1_ROUND_UPDATE_TRANSACTION: u8 = 5;
 const V1_PREVIEW_TRANSACTION: u8 = 6;
 const V1_LEDGER_TRANSACTION: u8 = 7;
+const V2_SUBINTENT: u8 = 8;
+const V2_INTENT: u8 = 9;
+const V2_SIGNED_INTENT: u8 = 10;
+const V2_NOTARIZED_TRANSACTION: u8 = 11;
 
 // TODO - change this to use #[flatten] when REP-84 is out
 /// An enum of a variety of different transaction payload types
@@ -178,8 +186,8 @@ mod tests {
         //===================
         // SIGNED INTENT
         //===================
-        let sig1 = sig_1_private_key.sign_with_public_key(&intent_hash);
-        let sig2 = sig_2_private_key.sign_with_public_key(&intent_hash);
+        let sig1 = sig_1_private_key.sign_with_public_key(intent_hash.as_hash());
+        let sig2 = sig_2_private_key.sign_with_public_key(intent_hash.as_hash());
 
         let intent_signatures_v1 = IntentSignaturesV1 {
             signatures: vec![IntentSignatureV1(sig1), IntentSignatureV1(sig2)],
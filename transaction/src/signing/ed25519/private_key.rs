@@ -1,6 +1,8 @@
 use super::Ed25519Signature;
 use crate::internal_prelude::*;
-use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::scalar::Scalar;
+use ed25519_dalek::{ExpandedSecretKey, Keypair, PublicKey, SecretKey, Signer};
 
 pub struct Ed25519PrivateKey(SecretKey);
 
@@ -40,6 +42,24 @@ impl Ed25519PrivateKey {
 
         Ok(Self(SecretKey::from_bytes(&bytes).map_err(|_| ())?))
     }
+
+    /// Computes the X25519 shared secret with the given public key, with both keys converted
+    /// from their Edwards form to the birationally equivalent Montgomery form.
+    pub fn diffie_hellman(&self, public_key: &Ed25519PublicKey) -> Result<[u8; 32], ()> {
+        // The first half of the expanded key is the (clamped) secret scalar
+        let expanded = ExpandedSecretKey::from(&self.0).to_bytes();
+        let scalar = Scalar::from_bits(copy_u8_array(&expanded[..32]));
+        let point = CompressedEdwardsY(public_key.0)
+            .decompress()
+            .ok_or(())?
+            .to_montgomery();
+        let shared_secret = (scalar * point).to_bytes();
+        // Reject low order points, which yield a predictable shared secret
+        if shared_secret == [0u8; 32] {
+            return Err(());
+        }
+        Ok(shared_secret)
+    }
 }
 
 #[cfg(test)]
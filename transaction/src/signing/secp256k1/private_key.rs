@@ -41,6 +41,14 @@ impl Secp256k1PrivateKey {
 
         Ok(Self(SecretKey::from_slice(&bytes).map_err(|_| ())?))
     }
+
+    /// Computes the ECDH shared secret with the given public key, as the unhashed `x`
+    /// coordinate of the shared point (the ASN1 X9.63 variant of ECDH).
+    pub fn diffie_hellman(&self, public_key: &Secp256k1PublicKey) -> Result<[u8; 32], ()> {
+        let public_key = PublicKey::from_slice(&public_key.0).map_err(|_| ())?;
+        let point = ::secp256k1::ecdh::shared_secret_point(&public_key, &self.0);
+        Ok(copy_u8_array(&point[..32]))
+    }
 }
 
 #[cfg(test)]
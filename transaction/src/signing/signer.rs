@@ -1,5 +1,4 @@
 use crate::internal_prelude::*;
-use radix_engine_common::prelude::IsHash;
 
 pub enum PrivateKey {
     Secp256k1(Secp256k1PrivateKey),
@@ -27,19 +26,48 @@ impl From<Ed25519PrivateKey> for PrivateKey {
     }
 }
 
+/// A signer of transaction hashes.
+///
+/// Signing is fallible, so that a signer can delegate to a key held elsewhere, such as in a
+/// hardware security module or a local signing daemon. The trait is object safe, so signers of
+/// different kinds can be used together as `&dyn Signer`.
 pub trait Signer {
     fn public_key(&self) -> PublicKey;
-    fn sign_without_public_key(&self, message_hash: &impl IsHash) -> SignatureV1;
-    fn sign_with_public_key(&self, message_hash: &impl IsHash) -> SignatureWithPublicKeyV1;
+
+    fn try_sign_without_public_key(&self, message_hash: &Hash)
+        -> Result<SignatureV1, SigningError>;
+
+    fn try_sign_with_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureWithPublicKeyV1, SigningError>;
+
+    /// Panics if the signer fails to sign, see [`Signer::try_sign_without_public_key`].
+    fn sign_without_public_key(&self, message_hash: &Hash) -> SignatureV1 {
+        self.try_sign_without_public_key(message_hash)
+            .expect("Signer failed to sign")
+    }
+
+    /// Panics if the signer fails to sign, see [`Signer::try_sign_with_public_key`].
+    fn sign_with_public_key(&self, message_hash: &Hash) -> SignatureWithPublicKeyV1 {
+        self.try_sign_with_public_key(message_hash)
+            .expect("Signer failed to sign")
+    }
 }
 
 impl Signer for Secp256k1PrivateKey {
-    fn sign_without_public_key(&self, message_hash: &impl IsHash) -> SignatureV1 {
-        self.sign(message_hash).into()
+    fn try_sign_without_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureV1, SigningError> {
+        Ok(self.sign(message_hash).into())
     }
 
-    fn sign_with_public_key(&self, message_hash: &impl IsHash) -> SignatureWithPublicKeyV1 {
-        self.sign(message_hash).into()
+    fn try_sign_with_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureWithPublicKeyV1, SigningError> {
+        Ok(self.sign(message_hash).into())
     }
 
     fn public_key(&self) -> PublicKey {
@@ -48,12 +76,18 @@ impl Signer for Secp256k1PrivateKey {
 }
 
 impl Signer for Ed25519PrivateKey {
-    fn sign_without_public_key(&self, message_hash: &impl IsHash) -> SignatureV1 {
-        self.sign(message_hash).into()
+    fn try_sign_without_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureV1, SigningError> {
+        Ok(self.sign(message_hash).into())
     }
 
-    fn sign_with_public_key(&self, message_hash: &impl IsHash) -> SignatureWithPublicKeyV1 {
-        (self.public_key(), self.sign(message_hash)).into()
+    fn try_sign_with_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureWithPublicKeyV1, SigningError> {
+        Ok((self.public_key(), self.sign(message_hash)).into())
     }
 
     fn public_key(&self) -> PublicKey {
@@ -62,17 +96,23 @@ impl Signer for Ed25519PrivateKey {
 }
 
 impl Signer for PrivateKey {
-    fn sign_without_public_key(&self, message_hash: &impl IsHash) -> SignatureV1 {
+    fn try_sign_without_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureV1, SigningError> {
         match self {
-            PrivateKey::Secp256k1(key) => key.sign_without_public_key(message_hash),
-            PrivateKey::Ed25519(key) => key.sign_without_public_key(message_hash),
+            PrivateKey::Secp256k1(key) => key.try_sign_without_public_key(message_hash),
+            PrivateKey::Ed25519(key) => key.try_sign_without_public_key(message_hash),
         }
     }
 
-    fn sign_with_public_key(&self, message_hash: &impl IsHash) -> SignatureWithPublicKeyV1 {
+    fn try_sign_with_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureWithPublicKeyV1, SigningError> {
         match self {
-            PrivateKey::Secp256k1(key) => key.sign_with_public_key(message_hash),
-            PrivateKey::Ed25519(key) => key.sign_with_public_key(message_hash),
+            PrivateKey::Secp256k1(key) => key.try_sign_with_public_key(message_hash),
+            PrivateKey::Ed25519(key) => key.try_sign_with_public_key(message_hash),
         }
     }
 
@@ -80,3 +120,23 @@ impl Signer for PrivateKey {
         self.public_key()
     }
 }
+
+impl<S: Signer + ?Sized> Signer for Box<S> {
+    fn try_sign_without_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureV1, SigningError> {
+        self.as_ref().try_sign_without_public_key(message_hash)
+    }
+
+    fn try_sign_with_public_key(
+        &self,
+        message_hash: &Hash,
+    ) -> Result<SignatureWithPublicKeyV1, SigningError> {
+        self.as_ref().try_sign_with_public_key(message_hash)
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.as_ref().public_key()
+    }
+}
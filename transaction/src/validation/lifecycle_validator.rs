@@ -0,0 +1,399 @@
+use crate::data::{transform, TransformHandler};
+use crate::internal_prelude::*;
+use crate::manifest::static_analysis::resources_left_on_worktop;
+use core::convert::Infallible;
+use radix_engine_interface::data::scrypto::model::Own;
+
+/// Statically simulates the lifetimes of the buckets, proofs, address reservations and named
+/// addresses of a manifest, and reports all the violations which would otherwise only be
+/// discovered when executing the manifest.
+///
+/// Unlike the semantic validation of [`TransactionValidator`], this doesn't stop at the first
+/// violation, and additionally checks that all buckets and address reservations are consumed
+/// and that no resources are certainly left on the worktop.
+pub fn validate_manifest_lifecycle(
+    instructions: &[InstructionV1],
+) -> Result<(), Vec<LocatedManifestLifecycleError>> {
+    let mut validator = LifecycleValidator::default();
+    for (index, instruction) in instructions.iter().enumerate() {
+        validator.instruction_index = index;
+        validator.process_instruction(instruction);
+    }
+    validator.finish(instructions)
+}
+
+#[derive(Default)]
+struct LifecycleValidator {
+    instruction_index: usize,
+    id_allocator: ManifestIdAllocator,
+    /// Bucket id -> (index of creating instruction, lock count)
+    buckets: IndexMap<ManifestBucket, (usize, usize)>,
+    /// Bucket id -> index of consuming instruction
+    consumed_buckets: NonIterMap<ManifestBucket, usize>,
+    /// Proof id -> bucket the proof was created from
+    proofs: IndexMap<ManifestProof, Option<ManifestBucket>>,
+    /// Proof id -> index of dropping instruction
+    dropped_proofs: NonIterMap<ManifestProof, usize>,
+    /// Address reservation id -> index of creating instruction
+    address_reservations: IndexMap<ManifestAddressReservation, usize>,
+    /// Address reservation id -> index of using instruction
+    used_address_reservations: NonIterMap<ManifestAddressReservation, usize>,
+    named_addresses: IndexSet<u32>,
+    errors: Vec<LocatedManifestLifecycleError>,
+}
+
+impl LifecycleValidator {
+    fn process_instruction(&mut self, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { .. }
+            | InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. } => self.new_bucket(),
+            InstructionV1::ReturnToWorktop { bucket_id }
+            | InstructionV1::BurnResource { bucket_id } => self.consume_bucket(bucket_id),
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. } => self.new_proof(None),
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, .. }
+            | InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                if self.check_bucket(bucket_id) {
+                    self.buckets.get_mut(bucket_id).unwrap().1 += 1;
+                    self.new_proof(Some(bucket_id.clone()));
+                } else {
+                    // Keep the proof ids in sync with the ones allocated by the manifest
+                    self.new_proof(None);
+                }
+            }
+            InstructionV1::CloneProof { proof_id } => match self.proofs.get(proof_id).cloned() {
+                Some(bucket_id) => {
+                    if let Some((_, lock_count)) =
+                        bucket_id.and_then(|bucket_id| self.buckets.get_mut(&bucket_id))
+                    {
+                        *lock_count += 1;
+                    }
+                    self.new_proof(bucket_id);
+                }
+                None => {
+                    self.report_missing_proof(proof_id);
+                    self.new_proof(None);
+                }
+            },
+            InstructionV1::PushToAuthZone { proof_id } | InstructionV1::DropProof { proof_id } => {
+                self.drop_proof(proof_id)
+            }
+            InstructionV1::DropNamedProofs | InstructionV1::DropAllProofs => {
+                let proof_ids: Vec<ManifestProof> = self.proofs.keys().cloned().collect();
+                for proof_id in proof_ids {
+                    self.drop_proof(&proof_id);
+                }
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                args,
+                ..
+            } => {
+                if let DynamicPackageAddress::Named(address_id) = package_address {
+                    self.check_named_address(*address_id);
+                }
+                self.process_args(args);
+            }
+            InstructionV1::CallMethod { address, args, .. }
+            | InstructionV1::CallRoyaltyMethod { address, args, .. }
+            | InstructionV1::CallMetadataMethod { address, args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { address, args, .. } => {
+                if let DynamicGlobalAddress::Named(address_id) = address {
+                    self.check_named_address(*address_id);
+                }
+                self.process_args(args);
+            }
+            InstructionV1::CallDirectVaultMethod { args, .. }
+            | InstructionV1::YieldToChild { args, .. }
+            | InstructionV1::YieldToParent { args } => self.process_args(args),
+            InstructionV1::AllocateGlobalAddress { .. } => {
+                let address_reservation_id = self.id_allocator.new_address_reservation_id();
+                self.address_reservations
+                    .insert(address_reservation_id, self.instruction_index);
+                let address_id = self.id_allocator.new_address_id();
+                self.named_addresses.insert(address_id);
+            }
+            InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs => {}
+        }
+    }
+
+    fn process_args(&mut self, args: &ManifestValue) {
+        let _ = transform(args.clone(), self);
+    }
+
+    fn report(&mut self, cause: ManifestLifecycleError) {
+        self.errors.push(LocatedManifestLifecycleError {
+            instruction_index: self.instruction_index,
+            cause,
+        });
+    }
+
+    fn new_bucket(&mut self) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.buckets.insert(bucket_id, (self.instruction_index, 0));
+    }
+
+    /// Checks that the bucket exists, reporting an error if not.
+    fn check_bucket(&mut self, bucket_id: &ManifestBucket) -> bool {
+        if self.buckets.contains_key(bucket_id) {
+            return true;
+        }
+        let cause = match self.consumed_buckets.get(bucket_id) {
+            Some(consumed_at) => ManifestLifecycleError::BucketAlreadyConsumed {
+                bucket_id: bucket_id.clone(),
+                consumed_at: *consumed_at,
+            },
+            None => ManifestLifecycleError::BucketNotFound(bucket_id.clone()),
+        };
+        self.report(cause);
+        false
+    }
+
+    fn consume_bucket(&mut self, bucket_id: &ManifestBucket) {
+        if !self.check_bucket(bucket_id) {
+            return;
+        }
+        let (_, lock_count) = self.buckets[bucket_id];
+        if lock_count > 0 {
+            self.report(ManifestLifecycleError::BucketLocked(bucket_id.clone()));
+        }
+        self.buckets.shift_remove(bucket_id);
+        self.consumed_buckets
+            .insert(bucket_id.clone(), self.instruction_index);
+    }
+
+    fn new_proof(&mut self, bucket_id: Option<ManifestBucket>) {
+        let proof_id = self.id_allocator.new_proof_id();
+        self.proofs.insert(proof_id, bucket_id);
+    }
+
+    fn report_missing_proof(&mut self, proof_id: &ManifestProof) {
+        let cause = match self.dropped_proofs.get(proof_id) {
+            Some(dropped_at) => ManifestLifecycleError::ProofAlreadyDropped {
+                proof_id: proof_id.clone(),
+                dropped_at: *dropped_at,
+            },
+            None => ManifestLifecycleError::ProofNotFound(proof_id.clone()),
+        };
+        self.report(cause);
+    }
+
+    fn drop_proof(&mut self, proof_id: &ManifestProof) {
+        match self.proofs.shift_remove(proof_id) {
+            Some(bucket_id) => {
+                if let Some(bucket_id) = bucket_id {
+                    if let Some((_, lock_count)) = self.buckets.get_mut(&bucket_id) {
+                        *lock_count -= 1;
+                    }
+                }
+                self.dropped_proofs
+                    .insert(proof_id.clone(), self.instruction_index);
+            }
+            None => self.report_missing_proof(proof_id),
+        }
+    }
+
+    fn use_address_reservation(&mut self, address_reservation_id: &ManifestAddressReservation) {
+        if self
+            .address_reservations
+            .shift_remove(address_reservation_id)
+            .is_some()
+        {
+            self.used_address_reservations
+                .insert(address_reservation_id.clone(), self.instruction_index);
+            return;
+        }
+        let cause = match self.used_address_reservations.get(address_reservation_id) {
+            Some(used_at) => ManifestLifecycleError::AddressReservationAlreadyUsed {
+                address_reservation_id: address_reservation_id.clone(),
+                used_at: *used_at,
+            },
+            None => {
+                ManifestLifecycleError::AddressReservationNotFound(address_reservation_id.clone())
+            }
+        };
+        self.report(cause);
+    }
+
+    fn check_named_address(&mut self, address_id: u32) {
+        if !self.named_addresses.contains(&address_id) {
+            self.report(ManifestLifecycleError::NamedAddressNotFound(address_id));
+        }
+    }
+
+    fn finish(
+        mut self,
+        instructions: &[InstructionV1],
+    ) -> Result<(), Vec<LocatedManifestLifecycleError>> {
+        for (bucket_id, (created_at, _)) in self.buckets.drain(..) {
+            self.errors.push(LocatedManifestLifecycleError {
+                instruction_index: created_at,
+                cause: ManifestLifecycleError::BucketNotConsumed(bucket_id),
+            });
+        }
+        for (address_reservation_id, created_at) in self.address_reservations.drain(..) {
+            self.errors.push(LocatedManifestLifecycleError {
+                instruction_index: created_at,
+                cause: ManifestLifecycleError::AddressReservationNotUsed(address_reservation_id),
+            });
+        }
+        // Resources left on the worktop are reported at the last instruction
+        for resource_address in resources_left_on_worktop(instructions) {
+            self.errors.push(LocatedManifestLifecycleError {
+                instruction_index: instructions.len().saturating_sub(1),
+                cause: ManifestLifecycleError::ResourceLeftOnWorktop(resource_address),
+            });
+        }
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+impl TransformHandler<Infallible> for LifecycleValidator {
+    fn replace_bucket(&mut self, b: ManifestBucket) -> Result<Own, Infallible> {
+        self.consume_bucket(&b);
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_proof(&mut self, p: ManifestProof) -> Result<Own, Infallible> {
+        self.drop_proof(&p);
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_address_reservation(
+        &mut self,
+        r: ManifestAddressReservation,
+    ) -> Result<Own, Infallible> {
+        self.use_address_reservation(&r);
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_named_address(&mut self, a: u32) -> Result<Reference, Infallible> {
+        self.check_named_address(a);
+        Ok(Reference(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_expression(&mut self, _e: ManifestExpression) -> Result<Vec<Own>, Infallible> {
+        Ok(Vec::new())
+    }
+
+    fn replace_blob(&mut self, _b: ManifestBlobRef) -> Result<Vec<u8>, Infallible> {
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn located(
+        instruction_index: usize,
+        cause: ManifestLifecycleError,
+    ) -> LocatedManifestLifecycleError {
+        LocatedManifestLifecycleError {
+            instruction_index,
+            cause,
+        }
+    }
+
+    #[test]
+    fn valid_manifest_passes() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account, 10)
+            .withdraw_from_account(account, XRD, dec!(10))
+            .take_from_worktop(XRD, dec!(10), "xrd")
+            .create_proof_from_bucket_of_all("xrd", "proof")
+            .drop_proof("proof")
+            .try_deposit_or_abort(account, None, "xrd")
+            .build();
+
+        assert_eq!(validate_manifest_lifecycle(&manifest.instructions), Ok(()));
+    }
+
+    #[test]
+    fn all_violations_are_reported() {
+        let bucket = ManifestBucket(0);
+        let proof = ManifestProof(0);
+        let instructions = vec![
+            InstructionV1::TakeAllFromWorktop {
+                resource_address: XRD,
+            },
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id: bucket },
+            InstructionV1::BurnResource { bucket_id: bucket },
+            InstructionV1::ReturnToWorktop { bucket_id: bucket },
+            InstructionV1::DropProof { proof_id: proof },
+            InstructionV1::DropProof { proof_id: proof },
+            InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Named(0),
+                method_name: "free".to_string(),
+                args: manifest_args!().into(),
+            },
+            InstructionV1::TakeAllFromWorktop {
+                resource_address: XRD,
+            },
+        ];
+
+        assert_eq!(
+            validate_manifest_lifecycle(&instructions),
+            Err(vec![
+                located(2, ManifestLifecycleError::BucketLocked(bucket)),
+                located(
+                    3,
+                    ManifestLifecycleError::BucketAlreadyConsumed {
+                        bucket_id: bucket,
+                        consumed_at: 2
+                    }
+                ),
+                located(
+                    5,
+                    ManifestLifecycleError::ProofAlreadyDropped {
+                        proof_id: proof,
+                        dropped_at: 4
+                    }
+                ),
+                located(6, ManifestLifecycleError::NamedAddressNotFound(0)),
+                located(
+                    7,
+                    ManifestLifecycleError::BucketNotConsumed(ManifestBucket(1))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn resources_left_on_worktop_are_reported() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account, 10)
+            .withdraw_from_account(account, XRD, dec!(10))
+            .take_from_worktop(XRD, dec!(4), "xrd")
+            .try_deposit_or_abort(account, None, "xrd")
+            .build();
+
+        assert_eq!(
+            validate_manifest_lifecycle(&manifest.instructions),
+            Err(vec![located(
+                3,
+                ManifestLifecycleError::ResourceLeftOnWorktop(XRD)
+            )])
+        );
+    }
+}
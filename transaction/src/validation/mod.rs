@@ -1,9 +1,11 @@
 mod id_allocator;
 mod id_validator;
+mod lifecycle_validator;
 mod signature_validator;
 mod transaction_validator;
 
 pub use id_allocator::*;
 pub use id_validator::*;
+pub use lifecycle_validator::*;
 pub use signature_validator::*;
 pub use transaction_validator::*;
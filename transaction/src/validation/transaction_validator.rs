@@ -167,9 +167,202 @@ impl NotarizedTransactionValidator {
 
         Self::validate_instructions_v1(&intent.instructions.inner.0)?;
 
+        if intent.instructions.inner.0.iter().any(|instruction| {
+            matches!(
+                instruction,
+                InstructionV1::YieldToChild { .. } | InstructionV1::YieldToParent { .. }
+            )
+        }) {
+            return Err(SubintentValidationError::SubintentInstructionsNotSupported.into());
+        }
+
         return Ok(());
     }
 
+    pub fn validate_notarized_v2_from_raw(
+        &self,
+        raw: &RawNotarizedTransaction,
+    ) -> Result<ValidatedNotarizedTransactionV2, TransactionValidationError> {
+        if raw.as_slice().len() > self.config.max_notarized_payload_size {
+            return Err(TransactionValidationError::TransactionTooLarge);
+        }
+
+        self.validate_notarized_v2(PreparedNotarizedTransactionV2::prepare_from_raw(raw)?)
+    }
+
+    pub fn validate_notarized_v2(
+        &self,
+        transaction: PreparedNotarizedTransactionV2,
+    ) -> Result<ValidatedNotarizedTransactionV2, TransactionValidationError> {
+        let intent = &transaction.signed_intent.intent;
+        self.validate_intent_v2(intent)?;
+
+        let encoded_instructions = manifest_encode(&intent.instructions.inner.0)?;
+
+        let signer_keys = self
+            .validate_signatures_v2(&transaction)
+            .map_err(TransactionValidationError::SignatureValidationError)?;
+
+        let mut num_of_signature_validations = transaction
+            .signed_intent
+            .intent_signatures
+            .inner
+            .signatures
+            .len()
+            + 1;
+
+        let mut subintents = Vec::new();
+        for (subintent_index, signed_subintent) in intent.subintents.subintents.iter().enumerate() {
+            let signer_keys = Self::validate_intent_signatures(
+                &signed_subintent.subintent_hash().into_hash(),
+                &signed_subintent.subintent_signatures.signatures,
+            )
+            .map_err(|error| SubintentValidationError::SignatureValidationError {
+                subintent_index,
+                error,
+            })?;
+            num_of_signature_validations += signer_keys.len();
+            subintents.push(ValidatedSubintentV2 {
+                encoded_instructions: manifest_encode(
+                    &signed_subintent.subintent.instructions.inner.0,
+                )?,
+                signer_keys: signer_keys.into_iter().collect(),
+            });
+        }
+
+        Ok(ValidatedNotarizedTransactionV2 {
+            prepared: transaction,
+            encoded_instructions,
+            signer_keys,
+            subintents,
+            num_of_signature_validations,
+        })
+    }
+
+    pub fn validate_intent_v2(
+        &self,
+        intent: &PreparedIntentV2,
+    ) -> Result<(), TransactionValidationError> {
+        self.validate_header_v1(&intent.header.inner)
+            .map_err(TransactionValidationError::HeaderValidationError)?;
+
+        self.validate_message_v1(&intent.message.inner)?;
+
+        Self::validate_instructions_v1(&intent.instructions.inner.0)?;
+
+        for (subintent_index, signed_subintent) in intent.subintents.subintents.iter().enumerate() {
+            let subintent = &signed_subintent.subintent;
+            self.validate_subintent_header_v2(&subintent.header.inner)
+                .map_err(|error| SubintentValidationError::HeaderValidationError {
+                    subintent_index,
+                    error,
+                })?;
+
+            self.validate_message_v1(&subintent.message.inner)?;
+
+            Self::validate_instructions_v1(&subintent.instructions.inner.0)?;
+        }
+
+        Self::validate_subintent_yields_v2(
+            &intent.instructions.inner.0,
+            &intent.subintents.subintents,
+        )?;
+
+        Ok(())
+    }
+
+    /// Checks that the root intent yields to each subintent exactly once and in order, and that
+    /// each subintent ends by yielding back to it.
+    pub fn validate_subintent_yields_v2(
+        instructions: &[InstructionV1],
+        subintents: &[PreparedSignedSubintentV2],
+    ) -> Result<(), SubintentValidationError> {
+        let mut subintent_hashes = index_set_new();
+        for signed_subintent in subintents {
+            if !subintent_hashes.insert(signed_subintent.subintent_hash()) {
+                return Err(SubintentValidationError::DuplicateSubintent);
+            }
+        }
+
+        let mut next_child_index = 0u32;
+        for instruction in instructions {
+            match instruction {
+                InstructionV1::YieldToChild { child_index, .. } => {
+                    if *child_index != next_child_index {
+                        return Err(SubintentValidationError::ChildYieldedOutOfOrder {
+                            expected_child_index: next_child_index,
+                            actual_child_index: *child_index,
+                        });
+                    }
+                    if *child_index as usize >= subintents.len() {
+                        return Err(SubintentValidationError::InvalidChildIndex {
+                            child_index: *child_index,
+                        });
+                    }
+                    next_child_index += 1;
+                }
+                InstructionV1::YieldToParent { .. } => {
+                    return Err(SubintentValidationError::YieldToParentInRootIntent);
+                }
+                instruction => Self::validate_not_transaction_processor_invocation(instruction)?,
+            }
+        }
+        if (next_child_index as usize) < subintents.len() {
+            return Err(SubintentValidationError::ChildNotYielded {
+                child_index: next_child_index,
+            });
+        }
+
+        for (subintent_index, signed_subintent) in subintents.iter().enumerate() {
+            let instructions = &signed_subintent.subintent.instructions.inner.0;
+            for (index, instruction) in instructions.iter().enumerate() {
+                match instruction {
+                    InstructionV1::YieldToChild { .. } => {
+                        return Err(SubintentValidationError::YieldToChildInSubintent {
+                            subintent_index,
+                        });
+                    }
+                    InstructionV1::YieldToParent { .. } => {
+                        if index != instructions.len() - 1 {
+                            return Err(SubintentValidationError::MissingFinalYieldToParent {
+                                subintent_index,
+                            });
+                        }
+                    }
+                    instruction => {
+                        Self::validate_not_transaction_processor_invocation(instruction)?
+                    }
+                }
+            }
+            if !matches!(
+                instructions.last(),
+                Some(InstructionV1::YieldToParent { .. })
+            ) {
+                return Err(SubintentValidationError::MissingFinalYieldToParent {
+                    subintent_index,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The transaction processor runs subintents with their signers' proofs, so the intents must
+    /// not be able to call it directly.
+    fn validate_not_transaction_processor_invocation(
+        instruction: &InstructionV1,
+    ) -> Result<(), SubintentValidationError> {
+        if let InstructionV1::CallFunction {
+            package_address, ..
+        } = instruction
+        {
+            if package_address.is_static_global_package_of(&TRANSACTION_PROCESSOR_PACKAGE) {
+                return Err(SubintentValidationError::TransactionProcessorInvocation);
+            }
+        }
+        Ok(())
+    }
+
     pub fn validate_instructions_v1(
         instructions: &[InstructionV1],
     ) -> Result<(), TransactionValidationError> {
@@ -261,7 +454,9 @@ impl NotarizedTransactionValidator {
                 | InstructionV1::CallMethod { args, .. }
                 | InstructionV1::CallRoyaltyMethod { args, .. }
                 | InstructionV1::CallMetadataMethod { args, .. }
-                | InstructionV1::CallRoleAssignmentMethod { args, .. } => {
+                | InstructionV1::CallRoleAssignmentMethod { args, .. }
+                | InstructionV1::YieldToChild { args, .. }
+                | InstructionV1::YieldToParent { args } => {
                     Self::validate_call_args(&args, &mut id_validator)
                         .map_err(TransactionValidationError::CallDataValidationError)?;
                 }
@@ -311,38 +506,68 @@ impl NotarizedTransactionValidator {
         Ok(())
     }
 
+    pub fn validate_subintent_header_v2(
+        &self,
+        header: &SubintentHeaderV2,
+    ) -> Result<(), HeaderValidationError> {
+        // network
+        if header.network_id != self.config.network_id {
+            return Err(HeaderValidationError::InvalidNetwork);
+        }
+
+        // epoch
+        if header.end_epoch_exclusive <= header.start_epoch_inclusive {
+            return Err(HeaderValidationError::InvalidEpochRange);
+        }
+        let max_end_epoch = header
+            .start_epoch_inclusive
+            .after(self.config.max_epoch_range);
+        if header.end_epoch_exclusive > max_end_epoch {
+            return Err(HeaderValidationError::EpochRangeTooLarge);
+        }
+
+        Ok(())
+    }
+
     pub fn validate_signatures_v1(
         &self,
         transaction: &PreparedNotarizedTransactionV1,
     ) -> Result<Vec<PublicKey>, SignatureValidationError> {
-        // TODO: split into static validation part and runtime validation part to support more signatures
-        if transaction
-            .signed_intent
-            .intent_signatures
-            .inner
-            .signatures
-            .len()
-            > MAX_NUMBER_OF_INTENT_SIGNATURES
-        {
-            return Err(SignatureValidationError::TooManySignatures);
-        }
-
         // verify intent signature
-        let mut signers = index_set_new();
-        let intent_hash = transaction.intent_hash().into_hash();
-        for intent_signature in &transaction.signed_intent.intent_signatures.inner.signatures {
-            let public_key = recover(&intent_hash, &intent_signature.0)
-                .ok_or(SignatureValidationError::InvalidIntentSignature)?;
+        let mut signers = Self::validate_intent_signatures(
+            &transaction.intent_hash().into_hash(),
+            &transaction.signed_intent.intent_signatures.inner.signatures,
+        )?;
 
-            if !verify(&intent_hash, &public_key, &intent_signature.0.signature()) {
-                return Err(SignatureValidationError::InvalidIntentSignature);
-            }
+        let header = &transaction.signed_intent.intent.header.inner;
 
-            if !signers.insert(public_key) {
-                return Err(SignatureValidationError::DuplicateSigner);
-            }
+        if header.notary_is_signatory {
+            signers.insert(header.notary_public_key);
         }
 
+        // verify notary signature
+        let signed_intent_hash = transaction.signed_intent_hash().into_hash();
+        if !verify(
+            &signed_intent_hash,
+            &header.notary_public_key,
+            &transaction.notary_signature.inner.0,
+        ) {
+            return Err(SignatureValidationError::InvalidNotarySignature);
+        }
+
+        Ok(signers.into_iter().collect())
+    }
+
+    pub fn validate_signatures_v2(
+        &self,
+        transaction: &PreparedNotarizedTransactionV2,
+    ) -> Result<Vec<PublicKey>, SignatureValidationError> {
+        // verify intent signature
+        let mut signers = Self::validate_intent_signatures(
+            &transaction.intent_hash().into_hash(),
+            &transaction.signed_intent.intent_signatures.inner.signatures,
+        )?;
+
         let header = &transaction.signed_intent.intent.header.inner;
 
         if header.notary_is_signatory {
@@ -362,6 +587,33 @@ impl NotarizedTransactionValidator {
         Ok(signers.into_iter().collect())
     }
 
+    /// Verifies the signatures of an intent or subintent, and returns the signers.
+    fn validate_intent_signatures(
+        hash: &Hash,
+        signatures: &[IntentSignatureV1],
+    ) -> Result<IndexSet<PublicKey>, SignatureValidationError> {
+        // TODO: split into static validation part and runtime validation part to support more signatures
+        if signatures.len() > MAX_NUMBER_OF_INTENT_SIGNATURES {
+            return Err(SignatureValidationError::TooManySignatures);
+        }
+
+        let mut signers = index_set_new();
+        for intent_signature in signatures {
+            let public_key = recover(hash, &intent_signature.0)
+                .ok_or(SignatureValidationError::InvalidIntentSignature)?;
+
+            if !verify(hash, &public_key, &intent_signature.0.signature()) {
+                return Err(SignatureValidationError::InvalidIntentSignature);
+            }
+
+            if !signers.insert(public_key) {
+                return Err(SignatureValidationError::DuplicateSigner);
+            }
+        }
+
+        Ok(signers)
+    }
+
     pub fn validate_call_args(
         value: &ManifestValue,
         id_validator: &mut ManifestValidator,
@@ -440,8 +692,8 @@ mod tests {
 
     use super::*;
     use crate::{
-        builder::ManifestBuilder, builder::TransactionBuilder,
-        signing::secp256k1::Secp256k1PrivateKey,
+        builder::ManifestBuilder, builder::SubintentBuilder, builder::TransactionBuilder,
+        builder::TransactionV2Builder, signing::secp256k1::Secp256k1PrivateKey,
     };
 
     macro_rules! assert_invalid_tx {
@@ -515,6 +767,126 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_valid_subintents() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let transaction = create_transaction_v2(
+            ManifestBuilder::new()
+                .yield_to_child(0, ())
+                .yield_to_child(1, ())
+                .build(),
+            vec![
+                create_subintent(1, ManifestBuilder::new().yield_to_parent(()).build(), 3),
+                create_subintent(2, ManifestBuilder::new().yield_to_parent(()).build(), 4),
+            ],
+        );
+
+        let validated = validator
+            .validate_notarized_v2(transaction.prepare().unwrap())
+            .unwrap();
+
+        assert_eq!(validated.subintents.len(), 2);
+        assert_eq!(
+            validated.subintents[0].signer_keys,
+            vec![Secp256k1PrivateKey::from_u64(3).unwrap().public_key().into()]
+        );
+        assert_eq!(validated.num_of_signature_validations, 4);
+        assert_eq!(validated.get_executable().subintents().len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_subintents() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let validate = |manifest: TransactionManifestV1, subintents: Vec<SignedSubintentV2>| {
+            validator
+                .validate_notarized_v2(
+                    create_transaction_v2(manifest, subintents)
+                        .prepare()
+                        .unwrap(),
+                )
+                .expect_err("Should be an error")
+        };
+        let subintent = |nonce: u32| {
+            create_subintent(
+                nonce,
+                ManifestBuilder::new().yield_to_parent(()).build(),
+                3,
+            )
+        };
+
+        assert_eq!(
+            validate(
+                ManifestBuilder::new()
+                    .yield_to_child(1, ())
+                    .yield_to_child(0, ())
+                    .build(),
+                vec![subintent(1), subintent(2)]
+            ),
+            SubintentValidationError::ChildYieldedOutOfOrder {
+                expected_child_index: 0,
+                actual_child_index: 1
+            }
+            .into()
+        );
+        assert_eq!(
+            validate(
+                ManifestBuilder::new().yield_to_child(0, ()).build(),
+                vec![subintent(1), subintent(2)]
+            ),
+            SubintentValidationError::ChildNotYielded { child_index: 1 }.into()
+        );
+        assert_eq!(
+            validate(
+                ManifestBuilder::new()
+                    .yield_to_child(0, ())
+                    .yield_to_child(1, ())
+                    .build(),
+                vec![subintent(1), subintent(1)]
+            ),
+            SubintentValidationError::DuplicateSubintent.into()
+        );
+        assert_eq!(
+            validate(
+                ManifestBuilder::new().yield_to_child(0, ()).build(),
+                vec![create_subintent(
+                    1,
+                    ManifestBuilder::new()
+                        .yield_to_parent(())
+                        .drop_auth_zone_proofs()
+                        .build(),
+                    3
+                )]
+            ),
+            SubintentValidationError::MissingFinalYieldToParent { subintent_index: 0 }.into()
+        );
+        assert_eq!(
+            validate(
+                ManifestBuilder::new()
+                    .yield_to_child(0, ())
+                    .yield_to_parent(())
+                    .build(),
+                vec![subintent(1)]
+            ),
+            SubintentValidationError::YieldToParentInRootIntent.into()
+        );
+    }
+
+    #[test]
+    fn test_subintent_instructions_not_supported_in_v1() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let transaction = TransactionBuilder::new()
+            .header(create_header_v1(&sk_notary))
+            .manifest(ManifestBuilder::new().yield_to_child(0, ()).build())
+            .notarize(&sk_notary)
+            .build();
+
+        assert_eq!(
+            validator.validate(transaction.prepare().unwrap()),
+            Err(SubintentValidationError::SubintentInstructionsNotSupported.into())
+        );
+    }
+
     #[test]
     fn test_valid_messages() {
         // None
@@ -771,4 +1143,48 @@ mod tests {
 
         builder.build()
     }
+
+    fn create_header_v1(sk_notary: &Secp256k1PrivateKey) -> TransactionHeaderV1 {
+        TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: Epoch::zero(),
+            end_epoch_exclusive: Epoch::of(100),
+            nonce: 5,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+        }
+    }
+
+    fn create_subintent(
+        nonce: u32,
+        manifest: TransactionManifestV1,
+        signer: u64,
+    ) -> SignedSubintentV2 {
+        SubintentBuilder::new()
+            .header(SubintentHeaderV2 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce,
+            })
+            .manifest(manifest)
+            .sign(&Secp256k1PrivateKey::from_u64(signer).unwrap())
+            .build()
+    }
+
+    fn create_transaction_v2(
+        manifest: TransactionManifestV1,
+        subintents: Vec<SignedSubintentV2>,
+    ) -> NotarizedTransactionV2 {
+        let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+
+        let mut builder = TransactionV2Builder::new()
+            .header(create_header_v1(&sk_notary))
+            .manifest(manifest);
+        for subintent in subintents {
+            builder = builder.add_signed_subintent(subintent);
+        }
+        builder.notarize(&sk_notary).build()
+    }
 }